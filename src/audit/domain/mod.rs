@@ -0,0 +1,152 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// What the first entry of the log is chained to.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// Recorded before the attempt; an entry with how it went follows.
+    Started,
+    Succeeded,
+    /// The actor was not allowed to do it.
+    Denied,
+    Failed,
+}
+
+/// What an audited interactor tells the log about one attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditRecord {
+    pub actor_id: String,
+    /// One of the action constants, e.g. `DELETE_USER`.
+    pub action: String,
+    /// The id of whatever the action was performed on.
+    pub target: String,
+    pub outcome: AuditOutcome,
+    /// Why the attempt was denied or failed.
+    pub detail: Option<String>,
+}
+
+/// A record once it is in the log.
+///
+/// Every entry carries the hash of the one before it, so changing or removing an entry breaks
+/// the chain from there on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// 1-based, without gaps.
+    pub sequence: u64,
+    pub actor_id: String,
+    pub action: String,
+    pub target: String,
+    pub outcome: AuditOutcome,
+    pub detail: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+    pub previous_hash: String,
+    pub hash: String,
+}
+
+impl AuditEntry {
+    /// Chains the record to `previous`, or to `GENESIS_HASH` for the first entry.
+    pub fn chain(
+        record: AuditRecord,
+        previous: Option<&AuditEntry>,
+        recorded_at: DateTime<Utc>,
+    ) -> Self {
+        let mut entry = AuditEntry {
+            sequence: previous.map_or(1, |previous| previous.sequence + 1),
+            actor_id: record.actor_id,
+            action: record.action,
+            target: record.target,
+            outcome: record.outcome,
+            detail: record.detail,
+            recorded_at,
+            previous_hash: previous.map_or(GENESIS_HASH.into(), |previous| previous.hash.clone()),
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        entry
+    }
+
+    /// The hash of everything in the entry but `hash` itself.
+    pub fn compute_hash(&self) -> String {
+        let content = serde_json::json!([
+            self.sequence,
+            self.actor_id,
+            self.action,
+            self.target,
+            self.outcome,
+            self.detail,
+            self.recorded_at.to_rfc3339_opts(SecondsFormat::Nanos, true),
+            self.previous_hash,
+        ]);
+        format!("{:x}", Sha256::digest(content.to_string().as_bytes()))
+    }
+}
+
+/// The last entry of the log as of a verification, to keep somewhere its writers can not reach.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditHead {
+    pub sequence: u64,
+    pub hash: String,
+}
+
+/// Narrows a query of the log; fields left empty match every entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditFilter {
+    pub actor_id: Option<String>,
+    pub action: Option<String>,
+    pub target: Option<String>,
+    pub outcome: Option<AuditOutcome>,
+    /// Inclusive.
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive.
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl AuditFilter {
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        self.actor_id
+            .as_ref()
+            .is_none_or(|id| id == &entry.actor_id)
+            && self.action.as_ref().is_none_or(|a| a == &entry.action)
+            && self.target.as_ref().is_none_or(|t| t == &entry.target)
+            && self.outcome.is_none_or(|o| o == entry.outcome)
+            && self.from.is_none_or(|from| entry.recorded_at >= from)
+            && self.until.is_none_or(|until| entry.recorded_at < until)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn record(target: &str) -> AuditRecord {
+        AuditRecord {
+            actor_id: "admin".into(),
+            action: "DELETE_USER".into(),
+            target: target.into(),
+            outcome: AuditOutcome::Succeeded,
+            detail: None,
+        }
+    }
+
+    #[test]
+    fn should_chain_every_entry_to_the_one_before() {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        let first = AuditEntry::chain(record("jane"), None, at);
+        let second = AuditEntry::chain(record("john"), Some(&first), at);
+
+        assert_eq!((first.sequence, second.sequence), (1, 2));
+        assert_eq!(first.previous_hash, GENESIS_HASH);
+        assert_eq!(second.previous_hash, first.hash);
+        assert_eq!(second.hash, second.compute_hash());
+
+        let mut tampered = second.clone();
+        tampered.target = "someone else".into();
+        assert_ne!(tampered.compute_hash(), second.hash);
+    }
+}
@@ -0,0 +1,2 @@
+pub const QUERY_AUDIT_LOG_ACTION: &str = "QUERY_AUDIT_LOG_ACTION";
+pub const VERIFY_AUDIT_LOG_ACTION: &str = "VERIFY_AUDIT_LOG_ACTION";
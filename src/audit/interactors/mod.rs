@@ -0,0 +1,6 @@
+pub mod actions;
+pub mod query_audit_log;
+pub mod test_doubles;
+pub mod traits;
+pub mod utils;
+pub mod verify_audit_log;
@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use with_deps_proc_macro::WithDeps;
+
+use crate::audit::domain::{AuditEntry, AuditFilter, AuditOutcome};
+use crate::audit::interactors::actions::QUERY_AUDIT_LOG_ACTION;
+use crate::audit::interactors::traits::AuditLogRepository;
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationResult;
+use crate::utils::{AuthPayload, Validatable};
+
+pub const MAX_AUDIT_QUERY_LIMIT: usize = 100;
+
+#[derive(Debug, Clone)]
+pub struct QueryAuditLogInput {
+    pub actor_id: Option<String>,
+    pub action: Option<String>,
+    pub target: Option<String>,
+    pub outcome: Option<AuditOutcome>,
+    /// Inclusive.
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive.
+    pub until: Option<DateTime<Utc>>,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+impl Validatable for QueryAuditLogInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.limit == 0 || self.limit > MAX_AUDIT_QUERY_LIMIT {
+            return Err(ValidationError::new(
+                "limit".into(),
+                self.limit.to_string(),
+                format!("limit must be between 1 and {}", MAX_AUDIT_QUERY_LIMIT),
+            ));
+        }
+        if let (Some(from), Some(until)) = (self.from, self.until) {
+            if from > until {
+                return Err(ValidationError::new(
+                    "until".into(),
+                    until.to_rfc3339(),
+                    "until can not be before from".into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryAuditLogOutput {
+    /// Newest first.
+    pub entries: Vec<AuditEntry>,
+}
+
+#[derive(WithDeps)]
+pub struct QueryAuditLogInteractor {
+    repo: Arc<dyn AuditLogRepository>,
+}
+
+impl QueryAuditLogInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: QueryAuditLogInput,
+    ) -> ApplicationResult<QueryAuditLogOutput> {
+        auth.can_or_fail(QUERY_AUDIT_LOG_ACTION)?;
+        input.validate()?;
+
+        let filter = AuditFilter {
+            actor_id: input.actor_id,
+            action: input.action,
+            target: input.target,
+            outcome: input.outcome,
+            from: input.from,
+            until: input.until,
+        };
+        let entries = self.repo.query(&filter, input.offset, input.limit).await?;
+        Ok(QueryAuditLogOutput { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::audit::domain::AuditRecord;
+    use crate::audit::interactors::test_doubles::fake_audit_log_repository::FakeAuditLogRepository;
+    use crate::make_interactor_setup;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::fixed_now;
+    use crate::test_utils::errors_assertion::{
+        assert_forbidden_error, assert_validation_error_with_key,
+    };
+
+    use super::*;
+
+    /// Three entries an hour apart: jane deleted by admin, john denied to bob, jane's password.
+    fn entries() -> Vec<AuditEntry> {
+        let records = [
+            ("admin", "DELETE_USER", "jane", AuditOutcome::Succeeded),
+            ("bob", "DELETE_USER", "john", AuditOutcome::Denied),
+            (
+                "admin",
+                "CHANGE_OTHERS_PASSWORD",
+                "jane",
+                AuditOutcome::Succeeded,
+            ),
+        ];
+        let mut entries: Vec<AuditEntry> = vec![];
+        for (i, (actor, action, target, outcome)) in records.into_iter().enumerate() {
+            let record = AuditRecord {
+                actor_id: actor.into(),
+                action: action.into(),
+                target: target.into(),
+                outcome,
+                detail: None,
+            };
+            let at = fixed_now() + Duration::hours(i as i64);
+            entries.push(AuditEntry::chain(record, entries.last(), at));
+        }
+        entries
+    }
+
+    make_interactor_setup!(
+        QueryAuditLogInteractor,
+        [(
+            repo,
+            FakeAuditLogRepository::new_with_entries(&entries()),
+            FakeAuditLogRepository
+        )]
+    );
+
+    fn unfiltered() -> QueryAuditLogInput {
+        QueryAuditLogInput {
+            actor_id: None,
+            action: None,
+            target: None,
+            outcome: None,
+            from: None,
+            until: None,
+            limit: 10,
+            offset: 0,
+        }
+    }
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("ID".into())
+    }
+
+    fn sequences(output: QueryAuditLogOutput) -> Vec<u64> {
+        output.entries.iter().map(|e| e.sequence).collect()
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth, unfiltered()).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [QUERY_AUDIT_LOG_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_a_limit_out_of_range() {
+        let c = create_interactor();
+        for limit in [0, MAX_AUDIT_QUERY_LIMIT + 1] {
+            let mut input = unfiltered();
+            input.limit = limit;
+
+            let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+            assert_validation_error_with_key(err, "limit");
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_when_until_is_before_from() {
+        let c = create_interactor();
+        let mut input = unfiltered();
+        input.from = Some(fixed_now());
+        input.until = Some(fixed_now() - Duration::hours(1));
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "until");
+    }
+
+    #[tokio::test]
+    async fn should_return_the_newest_entries_first() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(&auth(), unfiltered()).await.unwrap();
+
+        assert_eq!(sequences(output), [3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn should_combine_the_filters() {
+        let c = create_interactor();
+        let mut input = unfiltered();
+        input.actor_id = Some("admin".into());
+        input.target = Some("jane".into());
+        input.action = Some("DELETE_USER".into());
+        input.outcome = Some(AuditOutcome::Succeeded);
+
+        let output = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(sequences(output), [1]);
+    }
+
+    #[tokio::test]
+    async fn should_filter_by_time_range() {
+        let c = create_interactor();
+        let mut input = unfiltered();
+        input.from = Some(fixed_now() + Duration::hours(1));
+        input.until = Some(fixed_now() + Duration::hours(2));
+
+        let output = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(sequences(output), [2]);
+    }
+
+    #[tokio::test]
+    async fn should_page_through_the_matches() {
+        let c = create_interactor();
+        let mut input = unfiltered();
+        input.limit = 1;
+        input.offset = 1;
+
+        let output = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(sequences(output), [2]);
+    }
+}
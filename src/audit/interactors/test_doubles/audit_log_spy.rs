@@ -0,0 +1,38 @@
+use std::sync::Mutex;
+
+use crate::audit::domain::{AuditEntry, AuditRecord};
+use crate::audit::interactors::traits::AuditLog;
+use crate::errors::UnknownResult;
+use crate::test_utils::clock::fake_clock::fixed_now;
+
+#[derive(Default)]
+pub struct AuditLogSpy {
+    pub recorded: Mutex<Vec<AuditRecord>>,
+    /// Records this many and fails from then on.
+    pub fail_after: Option<usize>,
+}
+
+#[allow(unused)]
+impl AuditLogSpy {
+    pub fn new_failing_after(records: usize) -> Self {
+        Self {
+            fail_after: Some(records),
+            ..Default::default()
+        }
+    }
+    pub fn get_recorded(&self) -> Vec<AuditRecord> {
+        self.recorded.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLog for AuditLogSpy {
+    async fn record(&self, record: AuditRecord) -> UnknownResult<AuditEntry> {
+        let mut recorded = self.recorded.lock().unwrap();
+        if self.fail_after.is_some_and(|after| recorded.len() >= after) {
+            return Err("the audit log is not available".into());
+        }
+        recorded.push(record.clone());
+        Ok(AuditEntry::chain(record, None, fixed_now()))
+    }
+}
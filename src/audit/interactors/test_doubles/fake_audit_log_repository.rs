@@ -0,0 +1,67 @@
+use std::sync::Mutex;
+
+use crate::audit::domain::{AuditEntry, AuditFilter};
+use crate::audit::interactors::traits::AuditLogRepository;
+use crate::errors::UnknownResult;
+
+pub struct FakeAuditLogRepository {
+    pub entries: Mutex<Vec<AuditEntry>>,
+}
+
+#[allow(unused)]
+impl FakeAuditLogRepository {
+    pub fn new_empty() -> Self {
+        Self::new_with_entries(&[])
+    }
+    pub fn new_with_entries(entries: &[AuditEntry]) -> Self {
+        Self {
+            entries: Mutex::new(entries.to_vec()),
+        }
+    }
+    pub fn get_entries(&self) -> Vec<AuditEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogRepository for FakeAuditLogRepository {
+    async fn append(&self, entry: &AuditEntry) -> UnknownResult<()> {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.iter().any(|e| e.sequence == entry.sequence) {
+            return Err(format!("audit entry {} already exists", entry.sequence).into());
+        }
+        entries.push(entry.clone());
+        Ok(())
+    }
+
+    async fn get_last(&self) -> UnknownResult<Option<AuditEntry>> {
+        let entries = self.entries.lock().unwrap();
+        Ok(entries.iter().max_by_key(|e| e.sequence).cloned())
+    }
+
+    async fn query(
+        &self,
+        filter: &AuditFilter,
+        offset: usize,
+        limit: usize,
+    ) -> UnknownResult<Vec<AuditEntry>> {
+        let mut entries = self.get_entries();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.sequence));
+        Ok(entries
+            .into_iter()
+            .filter(|e| filter.matches(e))
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn get_range(&self, from: u64, limit: usize) -> UnknownResult<Vec<AuditEntry>> {
+        let mut entries = self.get_entries();
+        entries.sort_by_key(|e| e.sequence);
+        Ok(entries
+            .into_iter()
+            .filter(|e| e.sequence >= from)
+            .take(limit)
+            .collect())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod audit_log_spy;
+pub mod fake_audit_log_repository;
@@ -0,0 +1,25 @@
+use crate::audit::domain::{AuditEntry, AuditFilter, AuditRecord};
+use crate::errors::UnknownResult;
+
+#[async_trait::async_trait]
+pub trait AuditLog: Send + Sync {
+    /// Appends the record after the last entry.
+    async fn record(&self, record: AuditRecord) -> UnknownResult<AuditEntry>;
+}
+
+/// Entries are only ever appended; there is no way to change or remove one.
+#[async_trait::async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// Fails when an entry with the same sequence exists, so two writers can not fork the chain.
+    async fn append(&self, entry: &AuditEntry) -> UnknownResult<()>;
+    async fn get_last(&self) -> UnknownResult<Option<AuditEntry>>;
+    /// At most `limit` matching entries after skipping `offset` of them, newest first.
+    async fn query(
+        &self,
+        filter: &AuditFilter,
+        offset: usize,
+        limit: usize,
+    ) -> UnknownResult<Vec<AuditEntry>>;
+    /// At most `limit` entries from the sequence `from` on, oldest first.
+    async fn get_range(&self, from: u64, limit: usize) -> UnknownResult<Vec<AuditEntry>>;
+}
@@ -0,0 +1,108 @@
+use std::future::Future;
+
+use crate::audit::domain::{AuditOutcome, AuditRecord};
+use crate::audit::interactors::traits::AuditLog;
+use crate::errors::ApplicationException::ForBiddenException;
+use crate::errors::ApplicationResult;
+use crate::utils::AuthPayload;
+
+/// Describes how an attempt at `action` by `auth` went.
+pub fn audit_record<T>(
+    auth: &(dyn AuthPayload),
+    action: &str,
+    target: &str,
+    result: &ApplicationResult<T>,
+) -> AuditRecord {
+    let (outcome, detail) = match result {
+        Ok(_) => (AuditOutcome::Succeeded, None),
+        Err(e @ ForBiddenException(_)) => (AuditOutcome::Denied, Some(e.to_string())),
+        Err(e) => (AuditOutcome::Failed, Some(e.to_string())),
+    };
+    AuditRecord {
+        actor_id: auth.get_user_id(),
+        action: action.into(),
+        target: target.into(),
+        outcome,
+        detail,
+    }
+}
+
+/// Runs `attempt` with a `Started` entry in the log before it and its outcome after it.
+///
+/// When the start can not be recorded the attempt does not run, so nothing is done without a
+/// trace. Once the attempt has run its result is returned even if the outcome can not be
+/// recorded; the `Started` entry without an outcome after it is what shows that.
+pub async fn audited<T>(
+    audit: &(dyn AuditLog),
+    auth: &(dyn AuthPayload),
+    action: &str,
+    target: &str,
+    attempt: impl Future<Output = ApplicationResult<T>>,
+) -> ApplicationResult<T> {
+    audit
+        .record(AuditRecord {
+            actor_id: auth.get_user_id(),
+            action: action.into(),
+            target: target.into(),
+            outcome: AuditOutcome::Started,
+            detail: None,
+        })
+        .await?;
+    let result = attempt.await;
+    let _ = audit
+        .record(audit_record(auth, action, target, &result))
+        .await;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::errors::ApplicationException::InternalException;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_record_the_start_and_the_outcome_of_an_attempt() {
+        let audit = AuditLogSpy::default();
+        let auth = AuthPayloadSpy::new_allowed("admin".into());
+
+        audited(&audit, &auth, "DELETE_USER", "jane", async { Ok(()) })
+            .await
+            .unwrap();
+
+        let outcomes: Vec<AuditOutcome> = audit.get_recorded().iter().map(|r| r.outcome).collect();
+        assert_eq!(outcomes, [AuditOutcome::Started, AuditOutcome::Succeeded]);
+    }
+
+    #[tokio::test]
+    async fn should_not_run_an_attempt_whose_start_can_not_be_recorded() {
+        let audit = AuditLogSpy::new_failing_after(0);
+        let auth = AuthPayloadSpy::new_allowed("admin".into());
+        let ran = AtomicBool::new(false);
+
+        let err = audited(&audit, &auth, "DELETE_USER", "jane", async {
+            ran.store(true, Ordering::SeqCst);
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, InternalException(_)));
+        assert!(!ran.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn should_return_the_result_when_the_outcome_can_not_be_recorded() {
+        let audit = AuditLogSpy::new_failing_after(1);
+        let auth = AuthPayloadSpy::new_allowed("admin".into());
+
+        let result = audited(&audit, &auth, "DELETE_USER", "jane", async { Ok(7) }).await;
+
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(audit.get_recorded()[0].outcome, AuditOutcome::Started);
+    }
+}
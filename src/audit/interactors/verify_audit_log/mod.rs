@@ -0,0 +1,263 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::audit::domain::{AuditEntry, AuditHead, GENESIS_HASH};
+use crate::audit::interactors::actions::VERIFY_AUDIT_LOG_ACTION;
+use crate::audit::interactors::traits::AuditLogRepository;
+use crate::errors::ApplicationResult;
+use crate::utils::AuthPayload;
+
+/// How many entries are loaded at a time while walking the chain.
+const VERIFY_BATCH_SIZE: usize = 500;
+
+#[derive(Debug, Clone, Default)]
+pub struct VerifyAuditLogInput {
+    /// The `head` of an earlier verification, which the log must still hold unchanged.
+    pub anchor: Option<AuditHead>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyAuditLogOutput {
+    /// How many entries were checked before the end of the log or the first broken one.
+    pub verified: u64,
+    /// The sequence of the first entry that was changed, or that follows a removed one.
+    pub first_broken: Option<u64>,
+    /// The last entry checked, `None` when there was none.
+    pub head: Option<AuditHead>,
+    /// Whether the log still holds the entry of the anchor, `None` when none was given.
+    pub anchor_intact: Option<bool>,
+}
+
+#[derive(WithDeps)]
+pub struct VerifyAuditLogInteractor {
+    repo: Arc<dyn AuditLogRepository>,
+}
+
+impl VerifyAuditLogInteractor {
+    /// Walks the whole chain from the first entry on.
+    ///
+    /// Removing entries from the end of the log leaves a chain that is intact, so the log alone
+    /// can not show it. Keep the `head` of each verification outside the log and pass it as the
+    /// `anchor` of the next one to find that out.
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: VerifyAuditLogInput,
+    ) -> ApplicationResult<VerifyAuditLogOutput> {
+        auth.can_or_fail(VERIFY_AUDIT_LOG_ACTION)?;
+
+        let mut verified = 0;
+        let mut previous_hash = GENESIS_HASH.to_string();
+        let mut anchor_found = false;
+        let mut first_broken = None;
+        'walk: loop {
+            let batch = self.repo.get_range(verified + 1, VERIFY_BATCH_SIZE).await?;
+            let done = batch.len() < VERIFY_BATCH_SIZE;
+            for entry in batch {
+                if !Self::is_intact(&entry, verified + 1, &previous_hash) {
+                    first_broken = Some(verified + 1);
+                    break 'walk;
+                }
+                verified += 1;
+                anchor_found |= input.anchor.as_ref().is_some_and(|anchor| {
+                    anchor.sequence == entry.sequence && anchor.hash == entry.hash
+                });
+                previous_hash = entry.hash;
+            }
+            if done {
+                break;
+            }
+        }
+        Ok(VerifyAuditLogOutput {
+            verified,
+            first_broken,
+            head: (verified > 0).then_some(AuditHead {
+                sequence: verified,
+                hash: previous_hash,
+            }),
+            anchor_intact: input.anchor.map(|_| anchor_found),
+        })
+    }
+
+    fn is_intact(entry: &AuditEntry, sequence: u64, previous_hash: &str) -> bool {
+        entry.sequence == sequence
+            && entry.previous_hash == previous_hash
+            && entry.hash == entry.compute_hash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::fake_audit_log_repository::FakeAuditLogRepository;
+    use crate::make_interactor_setup;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::fixed_now;
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+
+    use super::*;
+
+    fn entries(count: usize) -> Vec<AuditEntry> {
+        let mut entries: Vec<AuditEntry> = vec![];
+        for i in 0..count {
+            let record = AuditRecord {
+                actor_id: "admin".into(),
+                action: "DELETE_USER".into(),
+                target: i.to_string(),
+                outcome: AuditOutcome::Succeeded,
+                detail: None,
+            };
+            entries.push(AuditEntry::chain(record, entries.last(), fixed_now()));
+        }
+        entries
+    }
+
+    make_interactor_setup!(
+        VerifyAuditLogInteractor,
+        [(
+            repo,
+            FakeAuditLogRepository::new_with_entries(&entries(3)),
+            FakeAuditLogRepository
+        )]
+    );
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("ID".into())
+    }
+
+    fn input() -> VerifyAuditLogInput {
+        VerifyAuditLogInput::default()
+    }
+
+    fn head_of(entry: &AuditEntry) -> AuditHead {
+        AuditHead {
+            sequence: entry.sequence,
+            hash: entry.hash.clone(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [VERIFY_AUDIT_LOG_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_verify_an_intact_log_across_batches() {
+        let mut c = create_interactor();
+        let count = VERIFY_BATCH_SIZE + 2;
+        c.interactor
+            .set_repo(Arc::new(FakeAuditLogRepository::new_with_entries(
+                &entries(count),
+            )));
+
+        let output = c.interactor.execute(&auth(), input()).await.unwrap();
+
+        assert_eq!(
+            output,
+            VerifyAuditLogOutput {
+                verified: count as u64,
+                first_broken: None,
+                head: Some(head_of(&entries(count)[count - 1])),
+                anchor_intact: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_find_entries_removed_from_the_end_with_an_anchor() {
+        let mut c = create_interactor();
+        let all = entries(3);
+        let anchor = c
+            .interactor
+            .execute(&auth(), input())
+            .await
+            .unwrap()
+            .head
+            .unwrap();
+        c.interactor
+            .set_repo(Arc::new(FakeAuditLogRepository::new_with_entries(
+                &all[..2],
+            )));
+
+        let truncated = c
+            .interactor
+            .execute(
+                &auth(),
+                VerifyAuditLogInput {
+                    anchor: Some(anchor.clone()),
+                },
+            )
+            .await
+            .unwrap();
+        c.interactor
+            .set_repo(Arc::new(FakeAuditLogRepository::new_with_entries(&all)));
+        let kept = c
+            .interactor
+            .execute(
+                &auth(),
+                VerifyAuditLogInput {
+                    anchor: Some(anchor),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(truncated.first_broken, None);
+        assert_eq!(truncated.anchor_intact, Some(false));
+        assert_eq!(kept.anchor_intact, Some(true));
+    }
+
+    #[tokio::test]
+    async fn should_find_a_changed_entry() {
+        let mut c = create_interactor();
+        let mut tampered = entries(3);
+        tampered[1].outcome = AuditOutcome::Denied;
+        c.interactor
+            .set_repo(Arc::new(FakeAuditLogRepository::new_with_entries(
+                &tampered,
+            )));
+
+        let output = c.interactor.execute(&auth(), input()).await.unwrap();
+
+        assert_eq!(output.first_broken, Some(2));
+        assert_eq!(output.verified, 1);
+    }
+
+    #[tokio::test]
+    async fn should_find_a_removed_entry() {
+        let mut c = create_interactor();
+        let mut tampered = entries(3);
+        tampered.remove(1);
+        c.interactor
+            .set_repo(Arc::new(FakeAuditLogRepository::new_with_entries(
+                &tampered,
+            )));
+
+        let output = c.interactor.execute(&auth(), input()).await.unwrap();
+
+        assert_eq!(output.first_broken, Some(2));
+    }
+
+    #[tokio::test]
+    async fn should_find_an_entry_rehashed_without_its_successors() {
+        let mut c = create_interactor();
+        let mut tampered = entries(3);
+        tampered[0].target = "someone else".into();
+        tampered[0].hash = tampered[0].compute_hash();
+        c.interactor
+            .set_repo(Arc::new(FakeAuditLogRepository::new_with_entries(
+                &tampered,
+            )));
+
+        let output = c.interactor.execute(&auth(), input()).await.unwrap();
+
+        assert_eq!(output.first_broken, Some(2));
+    }
+}
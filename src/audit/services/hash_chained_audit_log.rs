@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::audit::domain::{AuditEntry, AuditRecord};
+use crate::audit::interactors::traits::{AuditLog, AuditLogRepository};
+use crate::errors::UnknownResult;
+use crate::utils::Clock;
+
+/// Appends every record chained to the last entry of the repository.
+///
+/// Records are appended one at a time so the entries of this process never race for a
+/// sequence; the repository refusing duplicate sequences covers other processes.
+pub struct HashChainedAuditLog {
+    repo: Arc<dyn AuditLogRepository>,
+    clock: Arc<dyn Clock>,
+    appending: Mutex<()>,
+}
+
+impl HashChainedAuditLog {
+    pub fn new(repo: Arc<dyn AuditLogRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            repo,
+            clock,
+            appending: Mutex::new(()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLog for HashChainedAuditLog {
+    async fn record(&self, record: AuditRecord) -> UnknownResult<AuditEntry> {
+        let _appending = self.appending.lock().await;
+        let last = self.repo.get_last().await?;
+        let entry = AuditEntry::chain(record, last.as_ref(), self.clock.now());
+        self.repo.append(&entry).await?;
+        Ok(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::audit::domain::{AuditOutcome, GENESIS_HASH};
+    use crate::audit::interactors::test_doubles::fake_audit_log_repository::FakeAuditLogRepository;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+
+    use super::*;
+
+    fn record(target: &str) -> AuditRecord {
+        AuditRecord {
+            actor_id: "admin".into(),
+            action: "DELETE_USER".into(),
+            target: target.into(),
+            outcome: AuditOutcome::Succeeded,
+            detail: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_chain_records_to_the_last_stored_entry() {
+        let repo = Arc::new(FakeAuditLogRepository::new_empty());
+        let log = HashChainedAuditLog::new(repo.clone(), Arc::new(FakeClock::new_fixed()));
+
+        let first = log.record(record("jane")).await.unwrap();
+        let second = log.record(record("john")).await.unwrap();
+
+        assert_eq!(first.previous_hash, GENESIS_HASH);
+        assert_eq!(first.recorded_at, fixed_now());
+        assert_eq!(second.sequence, 2);
+        assert_eq!(second.previous_hash, first.hash);
+        assert_eq!(repo.get_entries(), [first, second]);
+    }
+
+    #[tokio::test]
+    async fn should_give_concurrent_records_their_own_sequence() {
+        let repo = Arc::new(FakeAuditLogRepository::new_empty());
+        let log = Arc::new(HashChainedAuditLog::new(
+            repo.clone(),
+            Arc::new(FakeClock::new_fixed()),
+        ));
+
+        let handles: Vec<_> = (0..10)
+            .map(|i| {
+                let log = log.clone();
+                tokio::spawn(async move { log.record(record(&i.to_string())).await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let mut sequences: Vec<u64> = repo.get_entries().iter().map(|e| e.sequence).collect();
+        sequences.sort();
+        assert_eq!(sequences, (1..=10).collect::<Vec<u64>>());
+    }
+}
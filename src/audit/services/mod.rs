@@ -0,0 +1,3 @@
+pub use hash_chained_audit_log::HashChainedAuditLog;
+
+mod hash_chained_audit_log;
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::categories::domain::Category;
+use crate::comments::domain::{Comment, SpamModel};
+use crate::imports::domain::ImportMapping;
+use crate::media::domain::MediaItem;
+use crate::posts::domain::{Post, PostRevision, PostStatusChange};
+use crate::tags::domain::Tag;
+use crate::utils::RetiredSlug;
+
+/// Identifies the files of this format, which are not told apart by their extension.
+pub const BACKUP_FORMAT: &str = "blog-rust-backup";
+/// Raised on every change to the records that older versions could not restore.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// One line of a backup, which is newline delimited JSON.
+///
+/// A backup starts with a `Header` and ends with an `End`, so a file cut short is told apart
+/// from a complete one. Records come in the order they are restored in: everything a record
+/// refers to comes before it, except for parents, which may come in any order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum BackupRecord {
+    Header(BackupHeader),
+    User(BackupUser),
+    Category(Category),
+    Tag(Tag),
+    Post(Post),
+    PostRevision(PostRevision),
+    PostStatusChange(PostStatusChange),
+    Comment(Comment),
+    Media(MediaItem),
+    RetiredSlug(RetiredSlug),
+    ImportMapping(ImportMapping),
+    SpamModel(SpamModel),
+    /// The number of records before it, the header included.
+    End(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupHeader {
+    pub format: String,
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A user with the role stored by name, the way it is given when the user is created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupUser {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    /// The password hash.
+    pub password: String,
+    pub role: String,
+}
+
+/// How many records of each kind a backup holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackupCounts {
+    pub users: usize,
+    pub categories: usize,
+    pub tags: usize,
+    pub posts: usize,
+    pub post_revisions: usize,
+    pub post_status_changes: usize,
+    pub comments: usize,
+    pub media: usize,
+    pub retired_slugs: usize,
+    pub import_mappings: usize,
+}
+
+impl BackupCounts {
+    pub fn count(&mut self, record: &BackupRecord) {
+        let count = match record {
+            BackupRecord::User(_) => &mut self.users,
+            BackupRecord::Category(_) => &mut self.categories,
+            BackupRecord::Tag(_) => &mut self.tags,
+            BackupRecord::Post(_) => &mut self.posts,
+            BackupRecord::PostRevision(_) => &mut self.post_revisions,
+            BackupRecord::PostStatusChange(_) => &mut self.post_status_changes,
+            BackupRecord::Comment(_) => &mut self.comments,
+            BackupRecord::Media(_) => &mut self.media,
+            BackupRecord::RetiredSlug(_) => &mut self.retired_slugs,
+            BackupRecord::ImportMapping(_) => &mut self.import_mappings,
+            BackupRecord::Header(_) | BackupRecord::SpamModel(_) | BackupRecord::End(_) => return,
+        };
+        *count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::categories::domain::CategoryId;
+
+    use super::*;
+
+    #[test]
+    fn should_write_records_as_single_json_lines() {
+        let record = BackupRecord::Category(Category {
+            id: CategoryId::new("rust"),
+            name: "Rust".into(),
+            description: "Line one\nline two".into(),
+            created_at: DateTime::from_timestamp(0, 0).unwrap(),
+            slug: "rust".into(),
+            parent_id: None,
+        });
+
+        let line = serde_json::to_string(&record).unwrap();
+
+        assert_eq!(
+            line,
+            r#"{"type":"category","data":{"id":"rust","name":"Rust","description":"Line one\nline two","created_at":"1970-01-01T00:00:00Z","slug":"rust","parent_id":null}}"#
+        );
+        assert!(matches!(
+            serde_json::from_str(&line).unwrap(),
+            BackupRecord::Category(category) if category.description == "Line one\nline two"
+        ));
+    }
+}
@@ -0,0 +1,2 @@
+pub const BACKUP_BLOG_ACTION: &str = "BACKUP_BLOG_ACTION";
+pub const RESTORE_BLOG_ACTION: &str = "RESTORE_BLOG_ACTION";
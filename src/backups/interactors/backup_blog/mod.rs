@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::access_management::RoleNamer;
+use crate::backups::domain::{
+    BackupCounts, BackupHeader, BackupRecord, BackupUser, BACKUP_FORMAT, BACKUP_FORMAT_VERSION,
+};
+use crate::backups::interactors::actions::BACKUP_BLOG_ACTION;
+use crate::backups::interactors::traits::{BackupStorage, BackupWriter};
+use crate::backups::interactors::utils::BlogRepositories;
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::utils::{AuthPayload, Clock};
+
+#[derive(Debug, Clone)]
+pub struct BackupBlogInput {
+    pub name: String,
+}
+
+/// Writes everything stored about the blog to a backup, one record at a time.
+///
+/// Media records only describe the files; the files themselves stay in the media storage and
+/// are copied along with it. Records of each kind are sorted by id so two backups of the same
+/// blog are the same apart from their header.
+#[derive(WithDeps)]
+pub struct BackupBlogInteractor {
+    storage: Arc<dyn BackupStorage>,
+    repos: Arc<BlogRepositories>,
+    role_namer: Arc<dyn RoleNamer>,
+    clock: Arc<dyn Clock>,
+}
+
+impl BackupBlogInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: BackupBlogInput,
+    ) -> ApplicationResult<BackupCounts> {
+        auth.can_or_fail(BACKUP_BLOG_ACTION)?;
+
+        let mut out = RecordWriter {
+            writer: self.storage.create(&input.name).await?,
+            counts: BackupCounts::default(),
+            records: 0,
+        };
+        out.write(BackupRecord::Header(BackupHeader {
+            format: BACKUP_FORMAT.into(),
+            version: BACKUP_FORMAT_VERSION,
+            created_at: self.clock.now(),
+        }))
+        .await?;
+
+        let mut users = self.repos.users_repo.get_all().await?;
+        users.sort_by(|a, b| a.id.cmp(&b.id));
+        for user in users {
+            out.write(BackupRecord::User(BackupUser {
+                role: self.role_namer.name_role(user.role),
+                id: user.id,
+                name: user.name,
+                email: user.email,
+                password: user.password,
+            }))
+            .await?;
+        }
+
+        let mut categories = self.repos.categories_repo.get_all().await?;
+        categories.sort_by_key(|category| category.id.to_string());
+        for category in categories {
+            out.write(BackupRecord::Category(category)).await?;
+        }
+
+        let mut tags = self.repos.tags_repo.get_all().await?;
+        tags.sort_by_key(|tag| tag.id.to_string());
+        for tag in tags {
+            out.write(BackupRecord::Tag(tag)).await?;
+        }
+
+        let mut posts = self.repos.posts_repo.get_all().await?;
+        posts.sort_by_key(|post| post.id.to_string());
+        for post in &posts {
+            out.write(BackupRecord::Post(post.clone())).await?;
+        }
+        for post in &posts {
+            for revision in self.repos.revisions_repo.get_by_post_id(&post.id).await? {
+                out.write(BackupRecord::PostRevision(revision)).await?;
+            }
+            for change in self
+                .repos
+                .status_changes_repo
+                .get_by_post_id(&post.id)
+                .await?
+            {
+                out.write(BackupRecord::PostStatusChange(change)).await?;
+            }
+        }
+        for post in &posts {
+            let mut comments = self.repos.comments_repo.get_by_post_id(&post.id).await?;
+            comments.sort_by_key(|comment| comment.id.to_string());
+            for comment in comments {
+                out.write(BackupRecord::Comment(comment)).await?;
+            }
+        }
+
+        let mut media = self.repos.media_repo.get_all().await?;
+        media.sort_by_key(|item| item.id.to_string());
+        for item in media {
+            out.write(BackupRecord::Media(item)).await?;
+        }
+
+        for retired in self.repos.slug_history.get_all().await? {
+            out.write(BackupRecord::RetiredSlug(retired)).await?;
+        }
+        for mapping in self.repos.import_mappings.get_all().await? {
+            out.write(BackupRecord::ImportMapping(mapping)).await?;
+        }
+        if let Some(model) = self.repos.spam_model_repo.get().await? {
+            out.write(BackupRecord::SpamModel(model)).await?;
+        }
+
+        let counts = out.counts;
+        out.write(BackupRecord::End(out.records)).await?;
+        out.writer.finish().await?;
+        Ok(counts)
+    }
+}
+
+struct RecordWriter {
+    writer: Box<dyn BackupWriter>,
+    counts: BackupCounts,
+    records: usize,
+}
+
+impl RecordWriter {
+    async fn write(&mut self, record: BackupRecord) -> UnknownResult<()> {
+        self.writer
+            .write_line(&serde_json::to_string(&record)?)
+            .await?;
+        self.counts.count(&record);
+        self.records += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backups::interactors::test_doubles::fake_backup_storage::FakeBackupStorage;
+    use crate::backups::interactors::test_doubles::fake_blog_repositories::FakeBlogRepositories;
+    use crate::backups::interactors::test_doubles::sample_blog::*;
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::make_interactor_setup;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::access_management::role_namer_spy::RoleNamerSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+
+    use super::*;
+
+    make_interactor_setup!(
+        BackupBlogInteractor,
+        [
+            (storage, FakeBackupStorage::new_empty(), FakeBackupStorage),
+            (
+                repos,
+                FakeBlogRepositories::new_with_sample_blog().to_repositories(),
+                BlogRepositories
+            ),
+            (
+                role_namer,
+                RoleNamerSpy::new_returning("admin".into()),
+                RoleNamerSpy
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock)
+        ]
+    );
+
+    fn input() -> BackupBlogInput {
+        BackupBlogInput {
+            name: "nightly".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [BACKUP_BLOG_ACTION]);
+        assert_forbidden_error(err);
+        assert_eq!(c.storage.get_lines("nightly"), None);
+    }
+
+    #[tokio::test]
+    async fn should_write_a_header_every_record_and_an_end() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let counts = c.interactor.execute(&auth, input()).await.unwrap();
+
+        assert_eq!(
+            counts,
+            BackupCounts {
+                users: 1,
+                categories: 2,
+                tags: 1,
+                posts: 1,
+                post_revisions: 1,
+                comments: 2,
+                ..Default::default()
+            }
+        );
+        let lines = c.storage.get_lines("nightly").unwrap();
+        assert_eq!(lines.len(), 10);
+        assert_eq!(
+            lines[0],
+            format!(
+                r#"{{"type":"header","data":{{"format":"blog-rust-backup","version":1,"created_at":{}}}}}"#,
+                serde_json::to_string(&fixed_now()).unwrap()
+            )
+        );
+        assert_eq!(
+            lines[1],
+            r#"{"type":"user","data":{"id":"jane","name":"Jane","email":"jane@example.com","password":"HASH","role":"admin"}}"#
+        );
+        assert!(lines[2].contains(r#""id":"async""#));
+        assert_eq!(lines[9], r#"{"type":"end","data":9}"#);
+    }
+
+    #[tokio::test]
+    async fn should_write_the_same_records_whatever_order_they_are_stored_in() {
+        let mut c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        c.interactor.execute(&auth, input()).await.unwrap();
+        let first = c.storage.get_lines("nightly").unwrap();
+        let mut categories = sample_categories();
+        categories.reverse();
+        c.interactor.set_repos(Arc::new(BlogRepositories {
+            categories_repo: Arc::new(FakeCategoriesRepository::new_with_data(&categories)),
+            ..(*c.repos).clone()
+        }));
+
+        c.interactor.execute(&auth, input()).await.unwrap();
+
+        assert_eq!(c.storage.get_lines("nightly").unwrap(), first);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod actions;
+pub mod backup_blog;
+pub mod restore_blog;
+pub mod test_doubles;
+pub mod traits;
+pub mod utils;
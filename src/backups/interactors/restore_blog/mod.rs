@@ -0,0 +1,697 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::access_management::RoleFactory;
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
+use crate::backups::domain::{BackupCounts, BackupRecord, BACKUP_FORMAT, BACKUP_FORMAT_VERSION};
+use crate::backups::interactors::actions::RESTORE_BLOG_ACTION;
+use crate::backups::interactors::traits::{BackupReader, BackupStorage};
+use crate::backups::interactors::utils::BlogRepositories;
+use crate::comments::domain::CommentId;
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::{ApplicationException, ApplicationResult};
+use crate::posts::domain::PostId;
+use crate::tags::domain::TagId;
+use crate::users::domain::User;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone)]
+pub struct RestoreBlogInput {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestoreBlogOutput {
+    pub restored: BackupCounts,
+    /// Users of the backup that were already there, such as the one restoring it.
+    pub kept_users: usize,
+}
+
+/// Restores a backup into a blog without categories, tags, posts or media.
+///
+/// The backup is read twice: first to check it through, and only if that finds nothing wrong
+/// to write it, so a bad backup leaves the blog as it was. The check covers the format and
+/// version, that no id is used twice, that roles are known, and that every parent, author,
+/// category, tag and post referred to is in the backup. The users recorded in the history of
+/// posts, comments and media are kept as they are, they may name users deleted since.
+///
+/// Users already in the blog are kept, as long as they have the same id and email as in the
+/// backup.
+#[derive(WithDeps)]
+pub struct RestoreBlogInteractor {
+    storage: Arc<dyn BackupStorage>,
+    repos: Arc<BlogRepositories>,
+    role_factory: Arc<dyn RoleFactory>,
+    audit: Arc<dyn AuditLog>,
+}
+
+impl RestoreBlogInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: RestoreBlogInput,
+    ) -> ApplicationResult<RestoreBlogOutput> {
+        let target = input.name.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            RESTORE_BLOG_ACTION,
+            &target,
+            self.restore_backup(auth, input),
+        )
+        .await
+    }
+
+    async fn restore_backup(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: RestoreBlogInput,
+    ) -> ApplicationResult<RestoreBlogOutput> {
+        auth.can_or_fail(RESTORE_BLOG_ACTION)?;
+
+        self.ensure_blog_is_empty().await?;
+        let mut check = BackupCheck::default();
+        let mut reader = self.open(&input.name).await?;
+        while let Some(record) = check.next(reader.as_mut()).await? {
+            self.check_record(&mut check, &record).await?;
+        }
+        check.check_references()?;
+
+        let mut output = RestoreBlogOutput {
+            restored: BackupCounts::default(),
+            kept_users: 0,
+        };
+        let mut reader = self.open(&input.name).await?;
+        while let Some(line) = reader.read_line().await? {
+            let record = parse(&line).map_err(|_| invalid(&input.name, "changed while read"))?;
+            if let BackupRecord::User(user) = &record {
+                if check.kept_users.contains(&user.id) {
+                    output.kept_users += 1;
+                    continue;
+                }
+            }
+            self.restore(&record).await?;
+            output.restored.count(&record);
+        }
+        Ok(output)
+    }
+
+    async fn open(&self, name: &str) -> ApplicationResult<Box<dyn BackupReader>> {
+        self.storage
+            .open(name)
+            .await?
+            .ok_or_else(|| NotFoundException(format!("backup {} not found", name)))
+    }
+
+    async fn ensure_blog_is_empty(&self) -> ApplicationResult<()> {
+        let has_content = !self.repos.categories_repo.get_all().await?.is_empty()
+            || !self.repos.tags_repo.get_all().await?.is_empty()
+            || !self.repos.posts_repo.get_all().await?.is_empty()
+            || !self.repos.media_repo.get_all().await?.is_empty();
+        if has_content {
+            return Err(invalid(
+                "blog",
+                "backups can only be restored into a blog without content",
+            ));
+        }
+        Ok(())
+    }
+
+    async fn check_record(
+        &self,
+        check: &mut BackupCheck,
+        record: &BackupRecord,
+    ) -> ApplicationResult<()> {
+        match record {
+            BackupRecord::User(user) => {
+                check.unique("user", &user.id, |c| &mut c.users)?;
+                if !check.emails.insert(user.email.clone()) {
+                    return Err(invalid(&user.email, "two users have this email"));
+                }
+                if !self.role_factory.is_valid_role_name(&user.role) {
+                    return Err(invalid(&user.role, "unknown role"));
+                }
+                let by_id = self.repos.users_repo.get_by_id(&user.id).await?;
+                let by_email = self.repos.users_repo.get_by_email(&user.email).await?;
+                match (by_id, by_email) {
+                    (None, None) => {}
+                    (Some(existing), _) if existing.email == user.email => {
+                        check.kept_users.insert(user.id.clone());
+                    }
+                    _ => {
+                        return Err(invalid(
+                            &user.id,
+                            "another user of the blog has this id or email",
+                        ))
+                    }
+                }
+            }
+            BackupRecord::Category(category) => {
+                check.unique("category", &category.id.to_string(), |c| &mut c.categories)?;
+                if let Some(parent_id) = &category.parent_id {
+                    check
+                        .parent_refs
+                        .push((category.id.to_string(), parent_id.to_string()));
+                }
+            }
+            BackupRecord::Tag(tag) => check.unique("tag", &tag.id.to_string(), |c| &mut c.tags)?,
+            BackupRecord::Post(post) => {
+                check.unique("post", &post.id.to_string(), |c| &mut c.posts)?;
+                check
+                    .user_refs
+                    .push((post.id.to_string(), post.author_id.clone()));
+                if let Some(category_id) = &post.category_id {
+                    check
+                        .category_refs
+                        .push((post.id.to_string(), category_id.to_string()));
+                }
+                for tag_id in &post.tag_ids {
+                    check.tag_refs.push((post.id.clone(), tag_id.clone()));
+                }
+            }
+            BackupRecord::PostRevision(revision) => check.post_refs.push(revision.post_id.clone()),
+            BackupRecord::PostStatusChange(change) => check.post_refs.push(change.post_id.clone()),
+            BackupRecord::Comment(comment) => {
+                check.unique("comment", &comment.id.to_string(), |c| &mut c.comments)?;
+                check.post_refs.push(comment.post_id.clone());
+                if let Some(parent_id) = &comment.parent_id {
+                    check
+                        .comment_refs
+                        .push((comment.id.clone(), parent_id.clone()));
+                }
+            }
+            BackupRecord::Media(item) => {
+                check.unique("media", &item.id.to_string(), |c| &mut c.media)?
+            }
+            BackupRecord::Header(_)
+            | BackupRecord::RetiredSlug(_)
+            | BackupRecord::ImportMapping(_)
+            | BackupRecord::SpamModel(_)
+            | BackupRecord::End(_) => {}
+        }
+        Ok(())
+    }
+
+    async fn restore(&self, record: &BackupRecord) -> ApplicationResult<()> {
+        match record {
+            BackupRecord::User(user) => {
+                let role = self
+                    .role_factory
+                    .create_role(&user.role)
+                    .ok_or_else(|| invalid(&user.role, "unknown role"))?;
+                self.repos
+                    .users_repo
+                    .create(&User {
+                        id: user.id.clone(),
+                        name: user.name.clone(),
+                        email: user.email.clone(),
+                        password: user.password.clone(),
+                        role,
+                    })
+                    .await?;
+            }
+            BackupRecord::Category(category) => {
+                self.repos.categories_repo.create(category).await?;
+            }
+            BackupRecord::Tag(tag) => {
+                self.repos.tags_repo.create(tag).await?;
+            }
+            BackupRecord::Post(post) => {
+                self.repos.posts_repo.create(post).await?;
+            }
+            BackupRecord::PostRevision(revision) => {
+                self.repos.revisions_repo.create(revision).await?;
+            }
+            BackupRecord::PostStatusChange(change) => {
+                self.repos.status_changes_repo.create(change).await?;
+            }
+            BackupRecord::Comment(comment) => {
+                self.repos.comments_repo.create(comment).await?;
+            }
+            BackupRecord::Media(item) => {
+                self.repos.media_repo.create(item).await?;
+            }
+            BackupRecord::RetiredSlug(retired) => self.repos.slug_history.retire(retired).await?,
+            BackupRecord::ImportMapping(mapping) => {
+                self.repos.import_mappings.save(mapping).await?
+            }
+            BackupRecord::SpamModel(model) => self.repos.spam_model_repo.save(model).await?,
+            BackupRecord::Header(_) | BackupRecord::End(_) => {}
+        }
+        Ok(())
+    }
+}
+
+fn parse(line: &str) -> serde_json::Result<BackupRecord> {
+    serde_json::from_str(line)
+}
+
+fn invalid(value: &str, message: &str) -> ApplicationException {
+    ValidationError::new("backup".into(), value.into(), message.into()).into()
+}
+
+/// What the first read of a backup learns about it.
+#[derive(Default)]
+struct BackupCheck {
+    line: usize,
+    ended: bool,
+    users: HashSet<String>,
+    emails: HashSet<String>,
+    kept_users: HashSet<String>,
+    categories: HashSet<String>,
+    tags: HashSet<String>,
+    posts: HashSet<String>,
+    comments: HashSet<String>,
+    media: HashSet<String>,
+    /// `(post id, author id)`
+    user_refs: Vec<(String, String)>,
+    /// `(category id, parent id)`
+    parent_refs: Vec<(String, String)>,
+    /// `(post id, category id)`
+    category_refs: Vec<(String, String)>,
+    tag_refs: Vec<(PostId, TagId)>,
+    post_refs: Vec<PostId>,
+    comment_refs: Vec<(CommentId, CommentId)>,
+}
+
+impl BackupCheck {
+    /// Reads the next record, checking it is where it may be.
+    async fn next(
+        &mut self,
+        reader: &mut (dyn BackupReader),
+    ) -> ApplicationResult<Option<BackupRecord>> {
+        let line = match reader.read_line().await? {
+            Some(line) => line,
+            None if self.ended => return Ok(None),
+            None => return Err(invalid("end", "the backup is cut short")),
+        };
+        let number = (self.line + 1).to_string();
+        if self.ended {
+            return Err(invalid(&number, "a line comes after the end"));
+        }
+        let record = parse(&line).map_err(|e| invalid(&number, &e.to_string()))?;
+        match (&record, self.line) {
+            (BackupRecord::Header(header), 0) => {
+                if header.format != BACKUP_FORMAT {
+                    return Err(invalid(&header.format, "not a backup"));
+                }
+                if header.version != BACKUP_FORMAT_VERSION {
+                    return Err(invalid(
+                        &header.version.to_string(),
+                        "unsupported backup version",
+                    ));
+                }
+            }
+            (_, 0) => return Err(invalid(&number, "the backup does not start with a header")),
+            (BackupRecord::Header(_), _) => return Err(invalid(&number, "a second header")),
+            (BackupRecord::End(records), _) => {
+                if *records != self.line {
+                    return Err(invalid(&number, "the record count does not match"));
+                }
+                self.ended = true;
+            }
+            _ => {}
+        }
+        self.line += 1;
+        Ok(Some(record))
+    }
+
+    fn unique(
+        &mut self,
+        kind: &str,
+        id: &str,
+        ids: impl Fn(&mut Self) -> &mut HashSet<String>,
+    ) -> ApplicationResult<()> {
+        if !ids(self).insert(id.into()) {
+            return Err(invalid(
+                id,
+                &format!("two records of {} have this id", kind),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_references(&self) -> ApplicationResult<()> {
+        for (id, user_id) in &self.user_refs {
+            if !self.users.contains(user_id) {
+                return Err(invalid(id, &format!("author {} is missing", user_id)));
+            }
+        }
+        for (id, parent_id) in &self.parent_refs {
+            if !self.categories.contains(parent_id) {
+                return Err(invalid(
+                    id,
+                    &format!("parent category {} is missing", parent_id),
+                ));
+            }
+        }
+        for (id, category_id) in &self.category_refs {
+            if !self.categories.contains(category_id) {
+                return Err(invalid(id, &format!("category {} is missing", category_id)));
+            }
+        }
+        for (post_id, tag_id) in &self.tag_refs {
+            if !self.tags.contains(&tag_id.to_string()) {
+                return Err(invalid(
+                    &post_id.to_string(),
+                    &format!("tag {} is missing", tag_id),
+                ));
+            }
+        }
+        for post_id in &self.post_refs {
+            if !self.posts.contains(&post_id.to_string()) {
+                return Err(invalid(&post_id.to_string(), "post is missing"));
+            }
+        }
+        for (id, parent_id) in &self.comment_refs {
+            if !self.comments.contains(&parent_id.to_string()) {
+                return Err(invalid(
+                    &id.to_string(),
+                    &format!("parent comment {} is missing", parent_id),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::access_management::variants::Admin;
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::backups::interactors::backup_blog::{BackupBlogInput, BackupBlogInteractor};
+    use crate::backups::interactors::test_doubles::fake_backup_storage::FakeBackupStorage;
+    use crate::backups::interactors::test_doubles::fake_blog_repositories::FakeBlogRepositories;
+    use crate::backups::interactors::test_doubles::sample_blog::*;
+    use crate::backups::services::LocalBackupStorage;
+    use crate::categories::interactors::traits::CategoriesRepository;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::search::domain::SearchFilter;
+    use crate::search::interactors::traits::SearchIndex;
+    use crate::search::services::{IndexingPostsRepository, TantivySearchIndex};
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::access_management::role_factory_spy::RoleFactorySpy;
+    use crate::test_utils::access_management::role_namer_spy::RoleNamerSpy;
+    use crate::test_utils::clock::fake_clock::FakeClock;
+    use crate::test_utils::errors_assertion::*;
+    use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
+
+    use super::*;
+
+    struct CreationResult {
+        interactor: RestoreBlogInteractor,
+        storage: Arc<FakeBackupStorage>,
+        repos: FakeBlogRepositories,
+        role_factory: Arc<RoleFactorySpy>,
+        audit: Arc<AuditLogSpy>,
+    }
+
+    fn create_interactor() -> CreationResult {
+        let storage = Arc::new(FakeBackupStorage::new_empty());
+        let repos = FakeBlogRepositories::new_empty();
+        let role_factory = Arc::new(RoleFactorySpy::new(Some(Box::new(Admin))));
+        let audit = Arc::new(AuditLogSpy::default());
+        let interactor = RestoreBlogInteractor::new(
+            storage.clone(),
+            Arc::new(repos.to_repositories()),
+            role_factory.clone(),
+            audit.clone(),
+        );
+        CreationResult {
+            interactor,
+            storage,
+            repos,
+            role_factory,
+            audit,
+        }
+    }
+
+    /// Backs up the sample blog, kept in the in-memory repositories.
+    async fn back_up_sample_blog(storage: Arc<dyn BackupStorage>) {
+        let interactor = BackupBlogInteractor::new(
+            storage,
+            Arc::new(FakeBlogRepositories::new_with_sample_blog().to_repositories()),
+            Arc::new(RoleNamerSpy::new_returning("admin".into())),
+            Arc::new(FakeClock::new_fixed()),
+        );
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        interactor
+            .execute(
+                &auth,
+                BackupBlogInput {
+                    name: "nightly".into(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn sample_lines() -> Vec<String> {
+        let storage = Arc::new(FakeBackupStorage::new_empty());
+        back_up_sample_blog(storage.clone()).await;
+        storage.get_lines("nightly").unwrap()
+    }
+
+    /// Drops the records `remove` picks and corrects the count at the end.
+    fn without(lines: Vec<String>, remove: impl Fn(&str) -> bool) -> Vec<String> {
+        let mut lines: Vec<String> = lines.into_iter().filter(|l| !remove(l)).collect();
+        lines.pop();
+        lines.push(serde_json::to_string(&BackupRecord::End(lines.len())).unwrap());
+        lines
+    }
+
+    fn input() -> RestoreBlogInput {
+        RestoreBlogInput {
+            name: "nightly".into(),
+        }
+    }
+
+    async fn assert_nothing_restored(c: &CreationResult) {
+        assert!(c.repos.users_repo.get_users().is_empty());
+        assert!(c.repos.categories_repo.get_all().await.unwrap().is_empty());
+        assert!(c.repos.posts_repo.get_posts().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [RESTORE_BLOG_ACTION]);
+        assert_forbidden_error(err);
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Denied);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_error_if_there_is_no_such_backup() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_restore_in_the_audit_log() {
+        let c = create_interactor();
+        c.storage.set_lines("nightly", sample_lines().await);
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor.execute(&auth, input()).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: RESTORE_BLOG_ACTION.into(),
+                    target: "nightly".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: RESTORE_BLOG_ACTION.into(),
+                    target: "nightly".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_restore_every_record() {
+        let c = create_interactor();
+        c.storage.set_lines("nightly", sample_lines().await);
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c.interactor.execute(&auth, input()).await.unwrap();
+
+        assert_eq!(
+            output,
+            RestoreBlogOutput {
+                restored: BackupCounts {
+                    users: 1,
+                    categories: 2,
+                    tags: 1,
+                    posts: 1,
+                    post_revisions: 1,
+                    comments: 2,
+                    ..Default::default()
+                },
+                kept_users: 0,
+            }
+        );
+        let users = c.repos.users_repo.get_users();
+        assert_eq!(users[0].email, "jane@example.com");
+        assert_eq!(users[0].password, "HASH");
+        assert_eq!(c.role_factory.get_create_role_calls(), ["admin"]);
+        assert_eq!(
+            c.repos.posts_repo.get_posts()[0].tag_ids,
+            sample_posts()[0].tag_ids
+        );
+        assert_eq!(c.repos.revisions_repo.get_revisions(), sample_revisions());
+        assert_eq!(c.repos.comments_repo.get_comments(), sample_comments());
+    }
+
+    #[tokio::test]
+    async fn should_keep_users_that_are_already_there() {
+        let mut c = create_interactor();
+        c.storage.set_lines("nightly", sample_lines().await);
+        c.interactor.set_repos(Arc::new(BlogRepositories {
+            users_repo: Arc::new(FakeUsersRepository::new_with_data(&sample_users())),
+            ..c.repos.to_repositories()
+        }));
+        let auth = AuthPayloadSpy::new_allowed("jane".into());
+
+        let output = c.interactor.execute(&auth, input()).await.unwrap();
+
+        assert_eq!(output.restored.users, 0);
+        assert_eq!(output.kept_users, 1);
+        assert_eq!(output.restored.posts, 1);
+        assert!(c.role_factory.get_create_role_calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_refuse_users_that_clash_with_another_user() {
+        let mut c = create_interactor();
+        c.storage.set_lines("nightly", sample_lines().await);
+        let mut other = sample_users().remove(0);
+        other.id = "someone-else".into();
+        c.interactor.set_repos(Arc::new(BlogRepositories {
+            users_repo: Arc::new(FakeUsersRepository::new_with_data(&[other])),
+            ..c.repos.to_repositories()
+        }));
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "backup");
+        assert!(c.repos.posts_repo.get_posts().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_refuse_backups_with_missing_references_before_writing_anything() {
+        type Remove = fn(&str) -> bool;
+        let cases: [(&str, Remove); 4] = [
+            ("parent category", |l| {
+                l.starts_with(r#"{"type":"category","data":{"id":"rust""#)
+            }),
+            ("author", |l| l.starts_with(r#"{"type":"user""#)),
+            ("tag", |l| l.starts_with(r#"{"type":"tag""#)),
+            ("parent comment", |l| {
+                l.starts_with(r#"{"type":"comment","data":{"id":"c1""#)
+            }),
+        ];
+        for (missing, remove) in cases {
+            let c = create_interactor();
+            c.storage
+                .set_lines("nightly", without(sample_lines().await, remove));
+            let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+            let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+            assert!(
+                matches!(&err, ApplicationException::ValidationException { message, .. } if message.contains(missing)),
+                "{}: {:?}",
+                missing,
+                err
+            );
+            assert_nothing_restored(&c).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn should_refuse_backups_that_are_cut_short_or_of_another_version() {
+        let mut short = sample_lines().await;
+        short.pop();
+        let mut newer = sample_lines().await;
+        newer[0] = newer[0].replace(r#""version":1"#, r#""version":2"#);
+        let mut duplicated = sample_lines().await;
+        duplicated.insert(2, duplicated[2].clone());
+        let duplicated = without(duplicated, |_| false);
+        for lines in [short, newer, duplicated, vec!["not json".to_string()]] {
+            let c = create_interactor();
+            c.storage.set_lines("nightly", lines);
+            let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+            let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+            assert_validation_error_with_key(err, "backup");
+            assert_nothing_restored(&c).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn should_only_restore_into_a_blog_without_content() {
+        let mut c = create_interactor();
+        c.storage.set_lines("nightly", sample_lines().await);
+        c.interactor.set_repos(Arc::new(BlogRepositories {
+            tags_repo: Arc::new(FakeTagsRepository::new_with_data(&sample_tags())),
+            ..c.repos.to_repositories()
+        }));
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "backup");
+        assert_nothing_restored(&c).await;
+    }
+
+    #[tokio::test]
+    async fn should_restore_a_backup_of_one_storage_into_another() {
+        let root = std::env::temp_dir().join(format!("restore-{}", std::process::id()));
+        let files = Arc::new(LocalBackupStorage::new(&root));
+        back_up_sample_blog(files.clone()).await;
+        let mut c = create_interactor();
+        let index = Arc::new(TantivySearchIndex::open_in_ram().unwrap());
+        let posts = Arc::new(FakePostsRepository::new_empty());
+        c.interactor.set_storage(files);
+        c.interactor.set_repos(Arc::new(BlogRepositories {
+            posts_repo: Arc::new(IndexingPostsRepository::new(posts.clone(), index.clone())),
+            ..c.repos.to_repositories()
+        }));
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c.interactor.execute(&auth, input()).await.unwrap();
+
+        assert_eq!(output.restored.posts, 1);
+        assert_eq!(posts.get_posts()[0].title, "Hello async");
+        let hits = index
+            .search("futures", &SearchFilter::default(), 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(hits[0].post_id, PostId::new("hello"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::backups::interactors::traits::{BackupReader, BackupStorage, BackupWriter};
+use crate::errors::UnknownResult;
+
+type Backups = Arc<Mutex<HashMap<String, Vec<String>>>>;
+
+pub struct FakeBackupStorage {
+    pub backups: Backups,
+}
+
+#[allow(unused)]
+impl FakeBackupStorage {
+    pub fn new_empty() -> Self {
+        Self {
+            backups: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+    pub fn new_with_backup(name: &str, lines: &[&str]) -> Self {
+        let storage = Self::new_empty();
+        storage.set_lines(name, lines.iter().map(|line| line.to_string()).collect());
+        storage
+    }
+    pub fn get_lines(&self, name: &str) -> Option<Vec<String>> {
+        self.backups.lock().unwrap().get(name).cloned()
+    }
+    pub fn set_lines(&self, name: &str, lines: Vec<String>) {
+        self.backups.lock().unwrap().insert(name.into(), lines);
+    }
+}
+
+#[async_trait::async_trait]
+impl BackupStorage for FakeBackupStorage {
+    async fn create(&self, name: &str) -> UnknownResult<Box<dyn BackupWriter>> {
+        Ok(Box::new(FakeBackupWriter {
+            backups: self.backups.clone(),
+            name: name.into(),
+            lines: vec![],
+        }))
+    }
+
+    async fn open(&self, name: &str) -> UnknownResult<Option<Box<dyn BackupReader>>> {
+        Ok(self.get_lines(name).map(|lines| {
+            Box::new(FakeBackupReader {
+                lines: lines.into_iter(),
+            }) as Box<dyn BackupReader>
+        }))
+    }
+}
+
+struct FakeBackupWriter {
+    backups: Backups,
+    name: String,
+    lines: Vec<String>,
+}
+
+#[async_trait::async_trait]
+impl BackupWriter for FakeBackupWriter {
+    async fn write_line(&mut self, line: &str) -> UnknownResult<()> {
+        assert!(!line.contains('\n'), "line break in {:?}", line);
+        self.lines.push(line.into());
+        Ok(())
+    }
+
+    async fn finish(self: Box<Self>) -> UnknownResult<()> {
+        self.backups.lock().unwrap().insert(self.name, self.lines);
+        Ok(())
+    }
+}
+
+struct FakeBackupReader {
+    lines: std::vec::IntoIter<String>,
+}
+
+#[async_trait::async_trait]
+impl BackupReader for FakeBackupReader {
+    async fn read_line(&mut self) -> UnknownResult<Option<String>> {
+        Ok(self.lines.next())
+    }
+}
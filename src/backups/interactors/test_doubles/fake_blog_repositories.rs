@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::backups::interactors::test_doubles::sample_blog::*;
+use crate::backups::interactors::utils::BlogRepositories;
+use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+use crate::comments::interactors::test_doubles::fake_comments_repository::FakeCommentsRepository;
+use crate::comments::interactors::test_doubles::fake_spam_model_repository::FakeSpamModelRepository;
+use crate::imports::interactors::test_doubles::fake_import_mappings_repository::FakeImportMappingsRepository;
+use crate::media::interactors::test_doubles::fake_media_repository::FakeMediaRepository;
+use crate::posts::interactors::test_doubles::fake_post_revisions_repository::FakePostRevisionsRepository;
+use crate::posts::interactors::test_doubles::fake_post_status_changes_repository::FakePostStatusChangesRepository;
+use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
+
+/// The in-memory repositories behind `BlogRepositories`, kept so tests can look inside them.
+pub struct FakeBlogRepositories {
+    pub users_repo: Arc<FakeUsersRepository>,
+    pub categories_repo: Arc<FakeCategoriesRepository>,
+    pub tags_repo: Arc<FakeTagsRepository>,
+    pub posts_repo: Arc<FakePostsRepository>,
+    pub revisions_repo: Arc<FakePostRevisionsRepository>,
+    pub status_changes_repo: Arc<FakePostStatusChangesRepository>,
+    pub comments_repo: Arc<FakeCommentsRepository>,
+    pub media_repo: Arc<FakeMediaRepository>,
+    pub slug_history: Arc<FakeSlugHistoryRepository>,
+    pub import_mappings: Arc<FakeImportMappingsRepository>,
+    pub spam_model_repo: Arc<FakeSpamModelRepository>,
+}
+
+#[allow(unused)]
+impl FakeBlogRepositories {
+    pub fn new_empty() -> Self {
+        Self {
+            users_repo: Arc::new(FakeUsersRepository::new_empty()),
+            categories_repo: Arc::new(FakeCategoriesRepository::new_empty()),
+            tags_repo: Arc::new(FakeTagsRepository::new_empty()),
+            posts_repo: Arc::new(FakePostsRepository::new_empty()),
+            revisions_repo: Arc::new(FakePostRevisionsRepository::new_empty()),
+            status_changes_repo: Arc::new(FakePostStatusChangesRepository::new_empty()),
+            comments_repo: Arc::new(FakeCommentsRepository::new_empty()),
+            media_repo: Arc::new(FakeMediaRepository::new_empty()),
+            slug_history: Arc::new(FakeSlugHistoryRepository::new_empty()),
+            import_mappings: Arc::new(FakeImportMappingsRepository::new_empty()),
+            spam_model_repo: Arc::new(FakeSpamModelRepository::new_empty()),
+        }
+    }
+    pub fn new_with_sample_blog() -> Self {
+        Self {
+            users_repo: Arc::new(FakeUsersRepository::new_with_data(&sample_users())),
+            categories_repo: Arc::new(
+                FakeCategoriesRepository::new_with_data(&sample_categories()),
+            ),
+            tags_repo: Arc::new(FakeTagsRepository::new_with_data(&sample_tags())),
+            posts_repo: Arc::new(FakePostsRepository::new_with_data(&sample_posts())),
+            revisions_repo: Arc::new(FakePostRevisionsRepository::new_with_data(
+                &sample_revisions(),
+            )),
+            comments_repo: Arc::new(FakeCommentsRepository::new_with_data(&sample_comments())),
+            ..Self::new_empty()
+        }
+    }
+    pub fn to_repositories(&self) -> BlogRepositories {
+        BlogRepositories {
+            users_repo: self.users_repo.clone(),
+            categories_repo: self.categories_repo.clone(),
+            tags_repo: self.tags_repo.clone(),
+            posts_repo: self.posts_repo.clone(),
+            revisions_repo: self.revisions_repo.clone(),
+            status_changes_repo: self.status_changes_repo.clone(),
+            comments_repo: self.comments_repo.clone(),
+            media_repo: self.media_repo.clone(),
+            slug_history: self.slug_history.clone(),
+            import_mappings: self.import_mappings.clone(),
+            spam_model_repo: self.spam_model_repo.clone(),
+        }
+    }
+}
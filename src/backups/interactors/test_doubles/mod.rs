@@ -0,0 +1,3 @@
+pub mod fake_backup_storage;
+pub mod fake_blog_repositories;
+pub mod sample_blog;
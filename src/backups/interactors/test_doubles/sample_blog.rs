@@ -0,0 +1,97 @@
+use crate::access_management::variants::Admin;
+use crate::categories::domain::{Category, CategoryId};
+use crate::comments::domain::{Comment, CommentAuthor, CommentId, CommentStatus};
+use crate::posts::domain::{Post, PostId, PostRevision, PostStatus};
+use crate::tags::domain::{Tag, TagId};
+use crate::test_utils::clock::fake_clock::fixed_now;
+use crate::users::domain::User;
+
+pub fn sample_users() -> Vec<User> {
+    vec![User {
+        id: "jane".into(),
+        name: "Jane".into(),
+        email: "jane@example.com".into(),
+        password: "HASH".into(),
+        role: Box::new(Admin),
+    }]
+}
+
+pub fn sample_categories() -> Vec<Category> {
+    let category = |id: &str, parent: Option<&str>| Category {
+        id: CategoryId::new(id),
+        name: id.to_uppercase(),
+        description: "".into(),
+        created_at: fixed_now(),
+        slug: id.into(),
+        parent_id: parent.map(CategoryId::new),
+    };
+    vec![category("async", Some("rust")), category("rust", None)]
+}
+
+pub fn sample_tags() -> Vec<Tag> {
+    vec![Tag {
+        id: TagId::new("tokio"),
+        name: "Tokio".into(),
+        slug: "tokio".into(),
+        created_at: fixed_now(),
+    }]
+}
+
+pub fn sample_posts() -> Vec<Post> {
+    vec![Post {
+        id: PostId::new("hello"),
+        title: "Hello async".into(),
+        slug: "hello".into(),
+        content: "Futures all the way down".into(),
+        author_id: "jane".into(),
+        category_id: Some(CategoryId::new("async")),
+        tag_ids: vec![TagId::new("tokio")],
+        status: PostStatus::Published,
+        publish_at: None,
+        published_at: Some(fixed_now()),
+        created_at: fixed_now(),
+    }]
+}
+
+pub fn sample_revisions() -> Vec<PostRevision> {
+    vec![PostRevision {
+        post_id: PostId::new("hello"),
+        number: 1,
+        title: "Hello".into(),
+        content: "Futures".into(),
+        author_id: "jane".into(),
+        created_at: fixed_now(),
+        restored_from: None,
+    }]
+}
+
+pub fn sample_comments() -> Vec<Comment> {
+    let comment = |id: &str, parent: Option<&str>, author: CommentAuthor| Comment {
+        id: CommentId::new(id),
+        post_id: PostId::new("hello"),
+        parent_id: parent.map(CommentId::new),
+        depth: parent.map_or(0, |_| 1),
+        author,
+        content: format!("Comment {}", id),
+        status: CommentStatus::Approved,
+        trained_as: None,
+        created_at: fixed_now(),
+    };
+    vec![
+        comment(
+            "c1",
+            None,
+            CommentAuthor::Anonymous {
+                name: "Bob".into(),
+                email: None,
+            },
+        ),
+        comment(
+            "c2",
+            Some("c1"),
+            CommentAuthor::User {
+                user_id: "jane".into(),
+            },
+        ),
+    ]
+}
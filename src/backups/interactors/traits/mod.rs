@@ -0,0 +1,23 @@
+use crate::errors::UnknownResult;
+
+/// Where backups are kept, each under a name.
+#[async_trait::async_trait]
+pub trait BackupStorage: Send + Sync {
+    /// Starts writing a backup. It only replaces the one with the same name once it is finished.
+    async fn create(&self, name: &str) -> UnknownResult<Box<dyn BackupWriter>>;
+    /// Starts reading a backup from its first line, `None` if there is none with that name.
+    async fn open(&self, name: &str) -> UnknownResult<Option<Box<dyn BackupReader>>>;
+}
+
+#[async_trait::async_trait]
+pub trait BackupWriter: Send {
+    /// Appends a line, which must not contain a line break.
+    async fn write_line(&mut self, line: &str) -> UnknownResult<()>;
+    async fn finish(self: Box<Self>) -> UnknownResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait BackupReader: Send {
+    /// The next line, `None` after the last one.
+    async fn read_line(&mut self) -> UnknownResult<Option<String>>;
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::comments::interactors::traits::{CommentsRepository, SpamModelRepository};
+use crate::imports::interactors::traits::ImportMappingsRepository;
+use crate::media::interactors::traits::MediaRepository;
+use crate::posts::interactors::traits::{
+    PostRevisionsRepository, PostStatusChangesRepository, PostsRepository,
+};
+use crate::tags::interactors::traits::TagsRepository;
+use crate::users::interactors::traits::UsersRepository;
+use crate::utils::SlugHistoryRepository;
+
+/// Every repository a backup reads and a restore writes.
+#[derive(Clone)]
+pub struct BlogRepositories {
+    pub users_repo: Arc<dyn UsersRepository>,
+    pub categories_repo: Arc<dyn CategoriesRepository>,
+    pub tags_repo: Arc<dyn TagsRepository>,
+    pub posts_repo: Arc<dyn PostsRepository>,
+    pub revisions_repo: Arc<dyn PostRevisionsRepository>,
+    pub status_changes_repo: Arc<dyn PostStatusChangesRepository>,
+    pub comments_repo: Arc<dyn CommentsRepository>,
+    pub media_repo: Arc<dyn MediaRepository>,
+    pub slug_history: Arc<dyn SlugHistoryRepository>,
+    pub import_mappings: Arc<dyn ImportMappingsRepository>,
+    pub spam_model_repo: Arc<dyn SpamModelRepository>,
+}
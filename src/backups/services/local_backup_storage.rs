@@ -0,0 +1,123 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter, Lines};
+
+use crate::backups::interactors::traits::{BackupReader, BackupStorage, BackupWriter};
+use crate::errors::UnknownResult;
+
+/// Keeps every backup as a `<name>.ndjson` file in one directory.
+///
+/// A backup is written to a `.partial` file first and renamed when it is finished, so a crash
+/// half way never leaves a truncated file in place of a good one.
+pub struct LocalBackupStorage {
+    root: PathBuf,
+}
+
+impl LocalBackupStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_of(&self, name: &str) -> UnknownResult<PathBuf> {
+        let valid = name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        if name.is_empty() || name.starts_with('.') || !valid {
+            return Err(format!("invalid backup name {:?}", name).into());
+        }
+        Ok(self.root.join(format!("{}.ndjson", name)))
+    }
+}
+
+#[async_trait::async_trait]
+impl BackupStorage for LocalBackupStorage {
+    async fn create(&self, name: &str) -> UnknownResult<Box<dyn BackupWriter>> {
+        let path = self.path_of(name)?;
+        let partial = path.with_extension("ndjson.partial");
+        tokio::fs::create_dir_all(&self.root).await?;
+        let file = File::create(&partial).await?;
+        Ok(Box::new(LocalBackupWriter {
+            file: BufWriter::new(file),
+            partial,
+            path,
+        }))
+    }
+
+    async fn open(&self, name: &str) -> UnknownResult<Option<Box<dyn BackupReader>>> {
+        match File::open(self.path_of(name)?).await {
+            Ok(file) => Ok(Some(Box::new(LocalBackupReader {
+                lines: BufReader::new(file).lines(),
+            }))),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+struct LocalBackupWriter {
+    file: BufWriter<File>,
+    partial: PathBuf,
+    path: PathBuf,
+}
+
+#[async_trait::async_trait]
+impl BackupWriter for LocalBackupWriter {
+    async fn write_line(&mut self, line: &str) -> UnknownResult<()> {
+        self.file.write_all(line.as_bytes()).await?;
+        self.file.write_all(b"\n").await?;
+        Ok(())
+    }
+
+    async fn finish(mut self: Box<Self>) -> UnknownResult<()> {
+        self.file.flush().await?;
+        self.file.get_ref().sync_all().await?;
+        tokio::fs::rename(&self.partial, &self.path).await?;
+        Ok(())
+    }
+}
+
+struct LocalBackupReader {
+    lines: Lines<BufReader<File>>,
+}
+
+#[async_trait::async_trait]
+impl BackupReader for LocalBackupReader {
+    async fn read_line(&mut self) -> UnknownResult<Option<String>> {
+        Ok(self.lines.next_line().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_only_replace_a_backup_once_the_new_one_is_finished() {
+        let root = std::env::temp_dir().join(format!("backups-{}", std::process::id()));
+        let storage = LocalBackupStorage::new(&root);
+        let mut first = storage.create("nightly").await.unwrap();
+        first.write_line("{\"a\":1}").await.unwrap();
+        first.finish().await.unwrap();
+
+        let mut second = storage.create("nightly").await.unwrap();
+        second.write_line("{\"b\":2}").await.unwrap();
+        let mut reader = storage.open("nightly").await.unwrap().unwrap();
+
+        assert_eq!(
+            reader.read_line().await.unwrap().as_deref(),
+            Some("{\"a\":1}")
+        );
+        assert_eq!(reader.read_line().await.unwrap(), None);
+        second.finish().await.unwrap();
+        let mut reader = storage.open("nightly").await.unwrap().unwrap();
+        assert_eq!(
+            reader.read_line().await.unwrap().as_deref(),
+            Some("{\"b\":2}")
+        );
+        assert!(storage.open("weekly").await.unwrap().is_none());
+        assert!(storage.create("../outside").await.is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
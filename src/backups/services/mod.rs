@@ -0,0 +1,3 @@
+pub use local_backup_storage::LocalBackupStorage;
+
+mod local_backup_storage;
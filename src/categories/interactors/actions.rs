@@ -1,4 +1,5 @@
 pub const CREATE_CATEGORY_ACTION: &str = "CREATE_CATEGORY_ACTION";
 pub const REPLACE_CATEGORY_ACTION: &str = "REPLACE_CATEGORY_ACTION";
 pub const DELETE_RECURSIVE_CATEGORY_ACTION: &str = "DELETE_RECURSIVE_CATEGORY_ACTION";
-pub const UPDATE_CATEGORY_ACTION: &str = "UPDATE_CATEGORY_ACTION";
\ No newline at end of file
+pub const UPDATE_CATEGORY_ACTION: &str = "UPDATE_CATEGORY_ACTION";
+pub const MERGE_CATEGORY_ACTION: &str = "MERGE_CATEGORY_ACTION";
@@ -1,7 +1,5 @@
 use std::sync::Arc;
 
-use chrono::Utc;
-use slug::slugify;
 use with_deps_proc_macro::WithDeps;
 
 use ApplicationException::*;
@@ -10,12 +8,22 @@ use crate::categories::domain::{Category, CategoryId};
 use crate::categories::interactors::actions::CREATE_CATEGORY_ACTION;
 use crate::categories::interactors::traits::CategoriesRepository;
 use crate::errors::{ApplicationException, ApplicationResult};
-use crate::utils::{AuthPayload, RandomService};
+use crate::events::domain::CategoryCreated;
+use crate::events::interactors::traits::EventPublisher;
+use crate::utils::{
+    AuthPayload, Clock, RandomService, RepositorySlugAvailability, SlugGenerator,
+    SlugHistoryRepository, SlugOwnerKind, UnitOfWork,
+};
 
 #[derive(WithDeps)]
 pub struct CreateCategoryInteractor {
     repo: Arc<dyn CategoriesRepository>,
     random: Arc<dyn RandomService>,
+    slug_history: Arc<dyn SlugHistoryRepository>,
+    slug_generator: Arc<dyn SlugGenerator>,
+    clock: Arc<dyn Clock>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
 }
 
 impl CreateCategoryInteractor {
@@ -26,32 +34,82 @@ impl CreateCategoryInteractor {
     ) -> ApplicationResult<CreateCategoryOutput> {
         auth.can_or_fail(CREATE_CATEGORY_ACTION)?;
 
-        let slug = input.slug.clone().unwrap_or(slugify(&input.name));
-
-        if self.repo.get_by_slug(&slug).await?.is_some() {
-            return Err(DuplicationException {
-                value: slug.into(),
-                key: "slug".into(),
-            });
-        }
-
         self.check_parent_id(&input).await?;
 
+        let slug = self.resolve_slug(&input).await?;
+        let explicit_slug = input.slug.is_some();
+        let reclaim_retired_slug = input.reclaim_retired_slug;
+
         let category = Category {
             id: CategoryId::new(&self.random.random_id().await?),
             name: input.name,
             description: input.description,
-            created_at: Utc::now(),
+            created_at: self.clock.now(),
             slug,
             parent_id: None,
         };
-        self.repo.create(&category).await?;
+        self.unit_of_work
+            .run(Box::pin(async {
+                if explicit_slug {
+                    self.slug_history
+                        .claim(
+                            SlugOwnerKind::Category,
+                            &category.slug,
+                            None,
+                            reclaim_retired_slug,
+                        )
+                        .await?;
+                }
+                self.repo.create(&category).await?;
+                self.events
+                    .publish(
+                        CategoryCreated {
+                            category: category.clone(),
+                        }
+                        .into(),
+                    )
+                    .await?;
+                Ok(())
+            }))
+            .await?;
         Ok(Self::create_output(category))
     }
 
+    async fn resolve_slug(&self, input: &CreateCategoryInput) -> ApplicationResult<String> {
+        let slug = match &input.slug {
+            Some(slug) => slug.clone(),
+            None => {
+                let availability = RepositorySlugAvailability {
+                    repo: self.repo.as_ref(),
+                    slug_history: self.slug_history.as_ref(),
+                    owner_id: None,
+                };
+                return self
+                    .slug_generator
+                    .generate(&input.name, &availability)
+                    .await;
+            }
+        };
+
+        if self.slug_generator.is_reserved(&slug) {
+            return Err(ValidationException {
+                key: "slug".into(),
+                value: slug,
+                message: "slug is reserved".into(),
+            });
+        }
+        if self.repo.get_by_slug(&slug).await?.is_some() {
+            return Err(DuplicationException {
+                value: slug,
+                key: "slug".into(),
+            });
+        }
+        Ok(slug)
+    }
+
     async fn check_parent_id(&self, input: &CreateCategoryInput) -> ApplicationResult<()> {
         if let Some(id) = &input.parent_id {
-            self.repo.get_by_id_or_fail(&CategoryId::new(&id)).await?;
+            self.repo.get_by_id_or_fail(&CategoryId::new(id)).await?;
         }
         Ok(())
     }
@@ -82,20 +140,25 @@ pub struct CreateCategoryInput {
     pub slug: Option<String>,
     pub description: String,
     pub parent_id: Option<String>,
+    pub reclaim_retired_slug: bool,
 }
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
-
-    use chrono::{DateTime, Duration, Utc};
+    use chrono::Utc;
 
     use crate::categories::domain::{Category, CategoryId};
     use crate::categories::interactors::actions::CREATE_CATEGORY_ACTION;
     use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
     use crate::make_interactor_setup;
     use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
     use crate::test_utils::crypto::random_service_spy::{RandomServiceSpy, RANDOM_ID};
     use crate::test_utils::errors_assertion::*;
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
+    use crate::utils::{RetiredSlug, TransliteratingSlugGenerator};
 
     use super::*;
 
@@ -107,7 +170,20 @@ mod tests {
                 FakeCategoriesRepository::new_with_data(&[existing_category()]),
                 FakeCategoriesRepository
             ),
-            (random, RandomServiceSpy::new(), RandomServiceSpy)
+            (random, RandomServiceSpy::new(), RandomServiceSpy),
+            (
+                slug_history,
+                FakeSlugHistoryRepository::new_with_data(&[retired_slug()]),
+                FakeSlugHistoryRepository
+            ),
+            (
+                slug_generator,
+                TransliteratingSlugGenerator::default(),
+                TransliteratingSlugGenerator
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock),
+            (events, EventPublisherSpy::default(), EventPublisherSpy),
+            (unit_of_work, UnitOfWorkSpy::default(), UnitOfWorkSpy)
         ]
     );
     fn valid_input() -> CreateCategoryInput {
@@ -116,12 +192,22 @@ mod tests {
             slug: Some("test-category".to_string()),
             description: "Test Category Description".to_string(),
             parent_id: None,
+            reclaim_retired_slug: false,
+        }
+    }
+
+    fn retired_slug() -> RetiredSlug {
+        RetiredSlug {
+            kind: SlugOwnerKind::Category,
+            slug: "retired-slug".to_string(),
+            owner_id: existing_category().id.to_string(),
+            retired_at: Utc::now(),
         }
     }
 
     fn existing_category() -> Category {
         Category {
-            id: CategoryId::new("test".into()),
+            id: CategoryId::new("test"),
             name: "category".to_string(),
             description: "description of the category".to_string(),
             created_at: Utc::now(),
@@ -200,9 +286,7 @@ mod tests {
         assert_eq!(result.id, RANDOM_ID);
         assert_eq!(result.name, input.name);
         assert_eq!(result.description, input.description);
-        assert!(
-            Utc::now() - DateTime::from_str(&result.created_at).unwrap() < Duration::seconds(1)
-        );
+        assert_eq!(result.created_at, fixed_now().to_string());
         assert_eq!(result.slug, input.slug.unwrap());
         assert_eq!(result.parent_id, input.parent_id);
     }
@@ -216,4 +300,86 @@ mod tests {
 
         c.random.assert_random_id_called();
     }
+
+    #[tokio::test]
+    async fn should_throw_error_if_slug_is_retired_by_another_category() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.slug = Some(retired_slug().slug);
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_duplication_error(err, "slug");
+    }
+
+    #[tokio::test]
+    async fn should_release_a_retired_slug_when_reclaiming_explicitly() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.slug = Some(retired_slug().slug);
+        input.reclaim_retired_slug = true;
+
+        c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert!(c.slug_history.get_retired().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_suffix_a_generated_slug_that_is_taken() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.name = existing_category().slug;
+        input.slug = None;
+
+        let result = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(result.slug, "slug-2");
+    }
+
+    #[tokio::test]
+    async fn should_skip_slugs_retired_by_other_categories_when_generating() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.name = "Retired Slug".into();
+        input.slug = None;
+
+        let result = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(result.slug, "retired-slug-2");
+    }
+
+    #[tokio::test]
+    async fn should_transliterate_non_latin_names() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.name = "سلام دنیا".into();
+        input.slug = None;
+
+        let result = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(result.slug, "slam-dnya");
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_if_explicit_slug_is_reserved() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.slug = Some("admin".into());
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "slug");
+    }
+
+    #[tokio::test]
+    async fn should_publish_category_created_event() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let created = c.repo.get_all().await.unwrap()[1].clone();
+        let expected: DomainEvent = CategoryCreated { category: created }.into();
+        assert_eq!(c.events.get_published(), [expected]);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
 }
@@ -2,15 +2,22 @@ use std::sync::Arc;
 
 use with_deps_proc_macro::WithDeps;
 
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
 use crate::categories::interactors::actions::DELETE_RECURSIVE_CATEGORY_ACTION;
 use crate::categories::interactors::traits::{CategoriesRepository, CategoryDeletionUtility};
 use crate::errors::ApplicationResult;
-use crate::utils::AuthPayload;
+use crate::events::domain::CategoryDeleted;
+use crate::events::interactors::traits::EventPublisher;
+use crate::utils::{AuthPayload, DeletionResult, UnitOfWork};
 
 #[derive(WithDeps)]
 pub struct DeleteRecursiveCategoryInteractor {
     repo: Arc<dyn CategoriesRepository>,
     deleter: Arc<dyn CategoryDeletionUtility>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    audit: Arc<dyn AuditLog>,
 }
 
 impl DeleteRecursiveCategoryInteractor {
@@ -19,14 +26,44 @@ impl DeleteRecursiveCategoryInteractor {
         auth: &(dyn AuthPayload),
         input: DeleteRecursiveInput,
     ) -> ApplicationResult<()> {
-        auth.can_or_fail(DELETE_RECURSIVE_CATEGORY_ACTION)?;
+        let target = input.id.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            DELETE_RECURSIVE_CATEGORY_ACTION,
+            &target,
+            self.delete(auth, input),
+        )
+        .await
+    }
 
-        dbg!(auth);
+    async fn delete(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: DeleteRecursiveInput,
+    ) -> ApplicationResult<()> {
+        auth.can_or_fail(DELETE_RECURSIVE_CATEGORY_ACTION)?;
 
         let id = input.id.into();
-        self.repo.get_by_id_or_fail(&id).await?;
-        self.deleter.delete_recursive(&id).await?;
-        Ok(())
+        let category = self.repo.get_by_id_or_fail(&id).await?;
+        self.unit_of_work
+            .run(Box::pin(async {
+                let outcome = self.deleter.delete_recursive(&id).await?;
+                if outcome.result == DeletionResult::Deleted {
+                    self.events
+                        .publish(
+                            CategoryDeleted {
+                                category,
+                                deleted_categories: outcome.deleted_categories,
+                                detached_posts: outcome.detached_posts,
+                            }
+                            .into(),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }))
+            .await
     }
 }
 
@@ -38,13 +75,18 @@ pub struct DeleteRecursiveInput {
 mod tests {
     use chrono::Utc;
 
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
     use crate::categories::domain::Category;
     use crate::categories::interactors::actions::DELETE_RECURSIVE_CATEGORY_ACTION;
     use crate::categories::interactors::test_doubles::category_deleter_spy::CategoryDeletionUtilsSpy;
     use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
     use crate::make_interactor_setup;
     use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
     use crate::test_utils::errors_assertion::{assert_forbidden_error, assert_not_found_error};
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
 
     use super::*;
 
@@ -70,7 +112,10 @@ mod tests {
                 deleter,
                 CategoryDeletionUtilsSpy::new_default(),
                 CategoryDeletionUtilsSpy
-            )
+            ),
+            (events, EventPublisherSpy::default(), EventPublisherSpy),
+            (unit_of_work, UnitOfWorkSpy::default(), UnitOfWorkSpy),
+            (audit, AuditLogSpy::default(), AuditLogSpy)
         ]
     );
 
@@ -111,6 +156,77 @@ mod tests {
         assert_eq!(c.deleter.get_delete_recursive_calls(), ["ID".into()]);
     }
 
+    #[tokio::test]
+    async fn should_publish_the_deleted_category() {
+        let c = create_interactor();
+        let category = c.repo.get_by_id_or_fail(&"ID".into()).await.unwrap();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let expected: DomainEvent = CategoryDeleted {
+            category,
+            deleted_categories: 0,
+            detached_posts: 0,
+        }
+        .into();
+        assert_eq!(c.events.get_published(), [expected]);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_publish_anything_when_the_category_was_already_gone() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_deleter(Arc::new(CategoryDeletionUtilsSpy::new(
+                DeletionResult::NotFound,
+                DeletionResult::NotFound,
+            )));
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert!(c.events.get_published().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_record_the_deletion_in_the_audit_log() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: DELETE_RECURSIVE_CATEGORY_ACTION.into(),
+                    target: "ID".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: DELETE_RECURSIVE_CATEGORY_ACTION.into(),
+                    target: "ID".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_record_an_attempt_on_a_missing_category_as_failed() {
+        let mut c = create_interactor();
+        c.interactor.repo = Arc::new(FakeCategoriesRepository::new_with_data(&[]));
+
+        c.interactor
+            .execute(&auth(), valid_input())
+            .await
+            .unwrap_err();
+
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Failed);
+    }
+
     fn auth() -> AuthPayloadSpy {
         AuthPayloadSpy::new_allowed("ID".into())
     }
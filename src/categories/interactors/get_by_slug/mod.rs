@@ -5,19 +5,44 @@ pub use with_deps_proc_macro::WithDeps;
 use crate::categories::interactors::traits::CategoriesRepository;
 use crate::categories::interactors::utils::VisibleCategory;
 use crate::errors::ApplicationResult;
+use crate::utils::{SlugHistoryRepository, SlugOwnerKind};
 
 #[derive(WithDeps)]
 pub struct GetBySlugInteractor {
     repo: Arc<dyn CategoriesRepository>,
+    slug_history: Arc<dyn SlugHistoryRepository>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GetBySlugOutput {
+    pub category: VisibleCategory,
+    pub canonical_slug: String,
+    pub is_redirect: bool,
 }
 
 impl GetBySlugInteractor {
-    pub async fn execute(&self, slug: &str) -> ApplicationResult<Option<VisibleCategory>> {
+    pub async fn execute(&self, slug: &str) -> ApplicationResult<Option<GetBySlugOutput>> {
+        if let Some(category) = self.repo.get_by_slug(slug).await? {
+            return Ok(Some(Self::create_output(category.into(), false)));
+        }
+        let retired = self.slug_history.get(SlugOwnerKind::Category, slug).await?;
+        let retired = match retired {
+            Some(retired) => retired,
+            None => return Ok(None),
+        };
         Ok(self
             .repo
-            .get_by_slug(slug)
+            .get_by_id(&retired.owner_id.into())
             .await?
-            .map(|category| VisibleCategory::from(category)))
+            .map(|category| Self::create_output(category.into(), true)))
+    }
+
+    fn create_output(category: VisibleCategory, is_redirect: bool) -> GetBySlugOutput {
+        GetBySlugOutput {
+            canonical_slug: category.slug.clone(),
+            category,
+            is_redirect,
+        }
     }
 }
 
@@ -28,18 +53,39 @@ mod tests {
     use crate::categories::domain::{Category, CategoryId};
     use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
     use crate::make_interactor_setup;
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::utils::RetiredSlug;
 
     use super::*;
 
     make_interactor_setup!(
         GetBySlugInteractor,
-        [(
-            repo,
-            FakeCategoriesRepository::new_with_data(&[existing_category()]),
-            FakeCategoriesRepository
-        )]
+        [
+            (
+                repo,
+                FakeCategoriesRepository::new_with_data(&[existing_category()]),
+                FakeCategoriesRepository
+            ),
+            (
+                slug_history,
+                FakeSlugHistoryRepository::new_with_data(&[
+                    retired("old-slug", "id"),
+                    retired("dangling-slug", "deleted-id"),
+                ]),
+                FakeSlugHistoryRepository
+            )
+        ]
     );
 
+    fn retired(slug: &str, owner_id: &str) -> RetiredSlug {
+        RetiredSlug {
+            kind: SlugOwnerKind::Category,
+            slug: slug.to_string(),
+            owner_id: owner_id.to_string(),
+            retired_at: Utc::now(),
+        }
+    }
+
     fn existing_category() -> Category {
         Category {
             id: CategoryId::new("id"),
@@ -70,4 +116,36 @@ mod tests {
 
         assert!(result.is_some());
     }
+
+    #[tokio::test]
+    async fn should_report_the_slug_as_canonical_when_it_is_current() {
+        let c = create_interactor();
+        let result = c
+            .interactor
+            .execute(&existing_category().slug)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(!result.is_redirect);
+        assert_eq!(result.canonical_slug, existing_category().slug);
+    }
+
+    #[tokio::test]
+    async fn should_resolve_a_retired_slug_to_the_canonical_category() {
+        let c = create_interactor();
+        let result = c.interactor.execute("old-slug").await.unwrap().unwrap();
+
+        assert!(result.is_redirect);
+        assert_eq!(result.canonical_slug, existing_category().slug);
+        assert_eq!(result.category.id, existing_category().id.to_string());
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_the_redirect_target_no_longer_exists() {
+        let c = create_interactor();
+        let result = c.interactor.execute("dangling-slug").await.unwrap();
+
+        assert!(result.is_none());
+    }
 }
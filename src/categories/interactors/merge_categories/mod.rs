@@ -0,0 +1,441 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
+use crate::categories::domain::Category;
+use crate::categories::interactors::actions::MERGE_CATEGORY_ACTION;
+use crate::categories::interactors::traits::{CategoriesRepository, CategoryDeletionUtility};
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationResult;
+use crate::events::domain::{CategoryReplaced, CategoryUpdated, DomainEvent};
+use crate::events::interactors::traits::EventPublisher;
+use crate::utils::{
+    AuthPayload, Clock, DeletionResult, RetiredSlug, SlugHistoryRepository, SlugOwnerKind,
+    UnitOfWork, Validatable,
+};
+
+#[derive(Debug, Clone)]
+pub struct MergeCategoriesInput {
+    pub id: String,
+    pub target_id: String,
+}
+
+impl Validatable for MergeCategoriesInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.id == self.target_id {
+            return Err(ValidationError::new(
+                "target_id".into(),
+                self.target_id.clone(),
+                "can not merge a category into itself".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeCategoriesOutput {
+    pub canonical_slug: String,
+    pub redirected_slug: String,
+    pub moved_posts: i32,
+    pub moved_children: i32,
+}
+
+#[derive(WithDeps)]
+pub struct MergeCategoriesInteractor {
+    repo: Arc<dyn CategoriesRepository>,
+    deleter: Arc<dyn CategoryDeletionUtility>,
+    slug_history: Arc<dyn SlugHistoryRepository>,
+    clock: Arc<dyn Clock>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    audit: Arc<dyn AuditLog>,
+}
+
+impl MergeCategoriesInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: MergeCategoriesInput,
+    ) -> ApplicationResult<MergeCategoriesOutput> {
+        let target = input.id.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            MERGE_CATEGORY_ACTION,
+            &target,
+            self.merge(auth, input),
+        )
+        .await
+    }
+
+    async fn merge(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: MergeCategoriesInput,
+    ) -> ApplicationResult<MergeCategoriesOutput> {
+        auth.can_or_fail(MERGE_CATEGORY_ACTION)?;
+        input.validate()?;
+
+        let source = self.repo.get_by_id_or_fail(&input.id.into()).await?;
+        let before = self.repo.get_by_id_or_fail(&input.target_id.into()).await?;
+        let mut target = before.clone();
+
+        target.description = Self::merge_descriptions(&target, &source);
+
+        let (mut moved_posts, mut moved_children) = (0, 0);
+        self.unit_of_work
+            .run(Box::pin(async {
+                self.repo.update(&target).await?;
+
+                let outcome = self.deleter.replace_with(&source.id, &target.id).await?;
+                (moved_posts, moved_children) = (outcome.moved_posts, outcome.moved_children);
+
+                let (source_id, target_id) = (source.id.to_string(), target.id.to_string());
+                self.slug_history
+                    .retarget(SlugOwnerKind::Category, &source_id, &target_id)
+                    .await?;
+                self.slug_history
+                    .retire(&RetiredSlug {
+                        kind: SlugOwnerKind::Category,
+                        slug: source.slug.clone(),
+                        owner_id: target_id,
+                        retired_at: self.clock.now(),
+                    })
+                    .await?;
+
+                let mut events: Vec<DomainEvent> = vec![];
+                if before != target {
+                    events.push(
+                        CategoryUpdated {
+                            before,
+                            after: target.clone(),
+                        }
+                        .into(),
+                    );
+                }
+                if outcome.result == DeletionResult::Deleted {
+                    events.push(
+                        CategoryReplaced {
+                            category: source.clone(),
+                            replacement_id: target.id.clone(),
+                            moved_posts,
+                            moved_children,
+                        }
+                        .into(),
+                    );
+                }
+                for event in events {
+                    self.events.publish(event).await?;
+                }
+                Ok(())
+            }))
+            .await?;
+
+        Ok(MergeCategoriesOutput {
+            canonical_slug: target.slug,
+            redirected_slug: source.slug,
+            moved_posts,
+            moved_children,
+        })
+    }
+
+    fn merge_descriptions(target: &Category, source: &Category) -> String {
+        let source_description = source.description.trim();
+        if source_description.is_empty() || target.description.contains(source_description) {
+            return target.description.clone();
+        }
+        if target.description.trim().is_empty() {
+            return source_description.to_string();
+        }
+        format!(
+            "{}\n\n{}",
+            target.description.trim_end(),
+            source_description
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::categories::interactors::test_doubles::category_deleter_spy::CategoryDeletionUtilsSpy;
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
+    use crate::make_interactor_setup;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::errors_assertion::{
+        assert_forbidden_error, assert_not_found_error, assert_validation_error_with_key,
+    };
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
+
+    use super::*;
+
+    make_interactor_setup!(
+        MergeCategoriesInteractor,
+        [
+            (
+                repo,
+                FakeCategoriesRepository::new_with_data(&[source_category(), target_category()]),
+                FakeCategoriesRepository
+            ),
+            (
+                deleter,
+                CategoryDeletionUtilsSpy::new_default(),
+                CategoryDeletionUtilsSpy
+            ),
+            (
+                slug_history,
+                FakeSlugHistoryRepository::new_with_data(&[older_retired_slug()]),
+                FakeSlugHistoryRepository
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock),
+            (events, EventPublisherSpy::default(), EventPublisherSpy),
+            (unit_of_work, UnitOfWorkSpy::default(), UnitOfWorkSpy),
+            (audit, AuditLogSpy::default(), AuditLogSpy)
+        ]
+    );
+
+    fn source_category() -> Category {
+        Category {
+            id: "source".into(),
+            name: "rust-lang".to_string(),
+            description: "all about rust".to_string(),
+            created_at: Utc::now(),
+            slug: "rust-lang".to_string(),
+            parent_id: None,
+        }
+    }
+
+    fn target_category() -> Category {
+        Category {
+            id: "target".into(),
+            name: "rust".to_string(),
+            description: "the rust language".to_string(),
+            created_at: Utc::now(),
+            slug: "rust".to_string(),
+            parent_id: None,
+        }
+    }
+
+    fn older_retired_slug() -> RetiredSlug {
+        RetiredSlug {
+            kind: SlugOwnerKind::Category,
+            slug: "rustlang".to_string(),
+            owner_id: source_category().id.to_string(),
+            retired_at: Utc::now(),
+        }
+    }
+
+    fn valid_input() -> MergeCategoriesInput {
+        MergeCategoriesInput {
+            id: source_category().id.to_string(),
+            target_id: target_category().id.to_string(),
+        }
+    }
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("ID".into())
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, valid_input())
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [MERGE_CATEGORY_ACTION]);
+        assert_forbidden_error(err);
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Denied);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_when_merging_into_itself() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.target_id = input.id.clone();
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "target_id");
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_when_either_category_is_missing() {
+        let c = create_interactor();
+        let inputs = vec![
+            MergeCategoriesInput {
+                id: "missing".into(),
+                ..valid_input()
+            },
+            MergeCategoriesInput {
+                target_id: "missing".into(),
+                ..valid_input()
+            },
+        ];
+        for input in inputs {
+            let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+            assert_not_found_error(err);
+        }
+        assert!(c.deleter.get_replace_calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_replace_the_source_with_the_target() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(
+            c.deleter.get_replace_calls(),
+            [(source_category().id, target_category().id)]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_combine_the_descriptions() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let target = c
+            .repo
+            .get_by_id(&target_category().id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(target.description, "the rust language\n\nall about rust");
+    }
+
+    #[tokio::test]
+    async fn should_not_duplicate_a_description_the_target_already_has() {
+        let mut c = create_interactor();
+        let mut target = target_category();
+        target.description = source_category().description;
+        c.interactor
+            .set_repo(Arc::new(FakeCategoriesRepository::new_with_data(&[
+                source_category(),
+                target.clone(),
+            ])));
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let stored = c.interactor.repo.get_by_id(&target.id).await.unwrap();
+        assert_eq!(stored.unwrap().description, target.description);
+        assert!(matches!(
+            c.events.get_published()[..],
+            [DomainEvent::CategoryReplaced(_)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_publish_the_updated_target_and_the_replaced_source() {
+        let c = create_interactor();
+        let source = c
+            .repo
+            .get_by_id_or_fail(&source_category().id)
+            .await
+            .unwrap();
+        let before = c
+            .repo
+            .get_by_id_or_fail(&target_category().id)
+            .await
+            .unwrap();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let after = c
+            .repo
+            .get_by_id_or_fail(&target_category().id)
+            .await
+            .unwrap();
+        let expected: [DomainEvent; 2] = [
+            CategoryUpdated {
+                before,
+                after: after.clone(),
+            }
+            .into(),
+            CategoryReplaced {
+                category: source,
+                replacement_id: after.id,
+                moved_posts: 0,
+                moved_children: 0,
+            }
+            .into(),
+        ];
+        assert_eq!(c.events.get_published(), expected);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_retired_slug_as_a_redirect_to_the_target() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let retired = c
+            .slug_history
+            .get(SlugOwnerKind::Category, &source_category().slug)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(retired.owner_id, target_category().id.to_string());
+        assert_eq!(retired.retired_at, fixed_now());
+        assert_eq!(output.canonical_slug, target_category().slug);
+        assert_eq!(output.redirected_slug, source_category().slug);
+    }
+
+    #[tokio::test]
+    async fn should_retarget_slugs_retired_by_the_source() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let owners: Vec<String> = c
+            .slug_history
+            .get_retired()
+            .into_iter()
+            .map(|r| r.owner_id)
+            .collect();
+        assert_eq!(owners, ["target", "target"]);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_merge_in_the_audit_log() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: MERGE_CATEGORY_ACTION.into(),
+                    target: "source".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: MERGE_CATEGORY_ACTION.into(),
+                    target: "source".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+}
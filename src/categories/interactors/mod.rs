@@ -4,6 +4,7 @@ pub mod create_category;
 pub mod delete_recursive_category;
 pub mod get_all;
 pub mod get_by_slug;
+pub mod merge_categories;
 pub mod replace_category;
 pub mod test_doubles;
 pub mod traits;
@@ -2,20 +2,41 @@ use std::sync::Arc;
 
 use with_deps_proc_macro::WithDeps;
 
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
 use crate::categories::interactors::actions::REPLACE_CATEGORY_ACTION;
 use crate::categories::interactors::traits::{CategoriesRepository, CategoryDeletionUtility};
+use crate::errors::validation::ValidationError;
 use crate::errors::ApplicationResult;
-use crate::utils::AuthPayload;
+use crate::events::domain::CategoryReplaced;
+use crate::events::interactors::traits::EventPublisher;
+use crate::utils::{AuthPayload, DeletionResult, UnitOfWork, Validatable};
 
 pub struct ReplaceCategoryInput {
     pub id: String,
     pub replacement_id: String,
 }
 
+impl Validatable for ReplaceCategoryInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.id == self.replacement_id {
+            return Err(ValidationError::new(
+                "replacement_id".into(),
+                self.replacement_id.clone(),
+                "a category can not replace itself".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(WithDeps)]
 struct ReplaceCategoryInteractor {
     repo: Arc<dyn CategoriesRepository>,
     deleter: Arc<dyn CategoryDeletionUtility>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    audit: Arc<dyn AuditLog>,
 }
 
 impl ReplaceCategoryInteractor {
@@ -23,17 +44,52 @@ impl ReplaceCategoryInteractor {
         &self,
         auth: &(dyn AuthPayload),
         input: ReplaceCategoryInput,
+    ) -> ApplicationResult<()> {
+        let target = input.id.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            REPLACE_CATEGORY_ACTION,
+            &target,
+            self.replace(auth, input),
+        )
+        .await
+    }
+
+    async fn replace(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ReplaceCategoryInput,
     ) -> ApplicationResult<()> {
         auth.can_or_fail(REPLACE_CATEGORY_ACTION)?;
+        input.validate()?;
         let source = self.repo.get_by_id_or_fail(&input.id.into()).await?;
         let replacement = self
             .repo
             .get_by_id_or_fail(&input.replacement_id.into())
             .await?;
-        self.deleter
-            .replace_with(&source.id, &replacement.id)
-            .await?;
-        Ok(())
+        self.unit_of_work
+            .run(Box::pin(async {
+                let outcome = self
+                    .deleter
+                    .replace_with(&source.id, &replacement.id)
+                    .await?;
+                if outcome.result == DeletionResult::Deleted {
+                    self.events
+                        .publish(
+                            CategoryReplaced {
+                                category: source,
+                                replacement_id: replacement.id,
+                                moved_posts: outcome.moved_posts,
+                                moved_children: outcome.moved_children,
+                            }
+                            .into(),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }))
+            .await
     }
 }
 
@@ -43,16 +99,20 @@ mod tests {
 
     use chrono::Utc;
 
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
     use crate::categories::domain::Category;
     use crate::categories::interactors::actions::REPLACE_CATEGORY_ACTION;
     use crate::categories::interactors::test_doubles::category_deleter_spy::CategoryDeletionUtilsSpy;
     use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
-    use crate::categories::interactors::traits::{CategoriesRepository, CategoryDeletionUtility};
-    use crate::errors::ApplicationException::NotFoundException;
-    use crate::errors::ApplicationResult;
+    use crate::categories::interactors::traits::CategoriesRepository;
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
     use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
-    use crate::test_utils::errors_assertion::{assert_forbidden_error, assert_not_found_error};
-    use crate::utils::AuthPayload;
+    use crate::test_utils::errors_assertion::{
+        assert_forbidden_error, assert_not_found_error, assert_validation_error_with_key,
+    };
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
 
     use super::*;
 
@@ -82,6 +142,9 @@ mod tests {
         interactor: ReplaceCategoryInteractor,
         repo: Arc<FakeCategoriesRepository>,
         replacer: Arc<CategoryDeletionUtilsSpy>,
+        events: Arc<EventPublisherSpy>,
+        unit_of_work: Arc<UnitOfWorkSpy>,
+        audit: Arc<AuditLogSpy>,
     }
 
     fn create_interactor() -> CreationResult {
@@ -90,11 +153,23 @@ mod tests {
             replacement_category(),
         ]));
         let replacer = Arc::new(CategoryDeletionUtilsSpy::new_default());
-        let interactor = ReplaceCategoryInteractor::new(repo.clone(), replacer.clone());
+        let events = Arc::new(EventPublisherSpy::default());
+        let unit_of_work = Arc::new(UnitOfWorkSpy::default());
+        let audit = Arc::new(AuditLogSpy::default());
+        let interactor = ReplaceCategoryInteractor::new(
+            repo.clone(),
+            replacer.clone(),
+            events.clone(),
+            unit_of_work.clone(),
+            audit.clone(),
+        );
         CreationResult {
             interactor,
             repo,
             replacer,
+            events,
+            unit_of_work,
+            audit,
         }
     }
 
@@ -156,6 +231,27 @@ mod tests {
 
         assert_eq!(auth.get_called(), &[REPLACE_CATEGORY_ACTION]);
         assert_forbidden_error(err);
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Denied);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_when_replacing_a_category_with_itself() {
+        let c = create_interactor();
+
+        let err = c
+            .interactor
+            .execute(
+                &auth(),
+                ReplaceCategoryInput {
+                    replacement_id: source_category().id.to_string(),
+                    ..valid_input()
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_validation_error_with_key(err, "replacement_id");
+        assert!(c.replacer.get_replace_calls().is_empty());
     }
 
     #[tokio::test]
@@ -169,4 +265,53 @@ mod tests {
             &[(source_category().id, replacement_category().id)]
         );
     }
+
+    #[tokio::test]
+    async fn should_publish_the_replaced_category() {
+        let c = create_interactor();
+        let source = c
+            .repo
+            .get_by_id_or_fail(&source_category().id)
+            .await
+            .unwrap();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let expected: DomainEvent = CategoryReplaced {
+            category: source,
+            replacement_id: replacement_category().id,
+            moved_posts: 0,
+            moved_children: 0,
+        }
+        .into();
+        assert_eq!(c.events.get_published(), [expected]);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_replacement_in_the_audit_log() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: REPLACE_CATEGORY_ACTION.into(),
+                    target: "source".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: REPLACE_CATEGORY_ACTION.into(),
+                    target: "source".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
 }
@@ -2,8 +2,8 @@ use std::collections::HashMap;
 use std::sync::Mutex;
 
 use crate::categories::domain::{Category, CategoryId};
-use crate::categories::interactors::traits::CategoryDeletionUtility;
-use crate::errors::UnknownResult;
+use crate::categories::interactors::traits::{CategoryDeletionOutcome, CategoryDeletionUtility};
+use crate::errors::ApplicationResult;
 use crate::utils::DeletionResult;
 
 pub struct CategoryDeletionUtilsSpy {
@@ -16,21 +16,24 @@ pub struct CategoryDeletionUtilsSpy {
 
 #[async_trait::async_trait]
 impl CategoryDeletionUtility for CategoryDeletionUtilsSpy {
-    async fn delete_recursive(&self, id: &CategoryId) -> UnknownResult<DeletionResult> {
+    async fn delete_recursive(
+        &self,
+        id: &CategoryId,
+    ) -> ApplicationResult<CategoryDeletionOutcome> {
         self.delete_recursive_calls.lock().unwrap().push(id.clone());
-        Ok(self.recursive_deletion_result)
+        Ok(self.recursive_deletion_result.into())
     }
 
     async fn replace_with(
         &self,
         id: &CategoryId,
         replacement_id: &CategoryId,
-    ) -> UnknownResult<DeletionResult> {
+    ) -> ApplicationResult<CategoryDeletionOutcome> {
         self.replace_calls
             .lock()
             .unwrap()
             .push((id.clone(), replacement_id.clone()));
-        Ok(self.replace_deletion_result)
+        Ok(self.replace_deletion_result.into())
     }
 }
 
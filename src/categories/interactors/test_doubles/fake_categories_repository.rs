@@ -3,6 +3,7 @@ use std::sync::Mutex;
 use crate::categories::domain::{Category, CategoryId};
 use crate::categories::interactors::traits::CategoriesRepository;
 use crate::errors::UnknownResult;
+use crate::utils::DeletionResult;
 
 pub struct FakeCategoriesRepository {
     pub categories: Mutex<Vec<Category>>,
@@ -49,6 +50,17 @@ impl CategoriesRepository for FakeCategoriesRepository {
         Ok(category.clone())
     }
 
+    async fn delete(&self, id: &CategoryId) -> UnknownResult<DeletionResult> {
+        let mut categories = self.categories.lock().unwrap();
+        match categories.iter().position(|c| c.id == *id) {
+            Some(index) => {
+                categories.remove(index);
+                Ok(DeletionResult::Deleted)
+            }
+            None => Ok(DeletionResult::NotFound),
+        }
+    }
+
     async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Category>> {
         let categories = self.categories.lock().unwrap();
         let category = categories
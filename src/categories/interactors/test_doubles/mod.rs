@@ -1,3 +1,3 @@
 pub mod category_deleter_spy;
-pub mod fake_categories_repository;
 pub mod category_meta_calculator_spy;
+pub mod fake_categories_repository;
@@ -9,6 +9,7 @@ pub trait CategoriesRepository: Send + Sync {
     async fn get_all(&self) -> UnknownResult<Vec<Category>>;
     async fn create(&self, category: &Category) -> UnknownResult<Category>;
     async fn update(&self, category: &Category) -> UnknownResult<Category>;
+    async fn delete(&self, id: &CategoryId) -> UnknownResult<DeletionResult>;
 
     async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Category>>;
 
@@ -25,14 +26,36 @@ pub trait CategoriesRepository: Send + Sync {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryDeletionOutcome {
+    pub result: DeletionResult,
+    pub deleted_categories: i32,
+    pub moved_posts: i32,
+    pub detached_posts: i32,
+    pub moved_children: i32,
+}
+
+impl From<DeletionResult> for CategoryDeletionOutcome {
+    fn from(result: DeletionResult) -> Self {
+        CategoryDeletionOutcome {
+            result,
+            deleted_categories: 0,
+            moved_posts: 0,
+            detached_posts: 0,
+            moved_children: 0,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 pub trait CategoryDeletionUtility: Send + Sync {
-    async fn delete_recursive(&self, id: &CategoryId) -> UnknownResult<DeletionResult>;
+    async fn delete_recursive(&self, id: &CategoryId)
+        -> ApplicationResult<CategoryDeletionOutcome>;
     async fn replace_with(
         &self,
         id: &CategoryId,
         replacement_id: &CategoryId,
-    ) -> UnknownResult<DeletionResult>;
+    ) -> ApplicationResult<CategoryDeletionOutcome>;
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
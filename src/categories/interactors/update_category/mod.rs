@@ -1,19 +1,28 @@
 use std::sync::Arc;
 
-use slug::slugify;
 use with_deps_proc_macro::WithDeps;
 
 use crate::categories::domain::CategoryId;
 use crate::categories::interactors::actions::UPDATE_CATEGORY_ACTION;
 use crate::categories::interactors::traits::CategoriesRepository;
 use crate::errors::validation::ValidationError;
-use crate::errors::ApplicationException::DuplicationException;
+use crate::errors::ApplicationException::{DuplicationException, ValidationException};
 use crate::errors::ApplicationResult;
-use crate::utils::{AuthPayload, Validatable};
+use crate::events::domain::CategoryUpdated;
+use crate::events::interactors::traits::EventPublisher;
+use crate::utils::{
+    AuthPayload, Clock, RepositorySlugAvailability, RetiredSlug, SlugGenerator,
+    SlugHistoryRepository, SlugOwnerKind, UnitOfWork, Validatable,
+};
 
 #[derive(WithDeps)]
 pub struct UpdateCategoryInteractor {
     repo: Arc<dyn CategoriesRepository>,
+    slug_history: Arc<dyn SlugHistoryRepository>,
+    slug_generator: Arc<dyn SlugGenerator>,
+    clock: Arc<dyn Clock>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
 }
 
 impl UpdateCategoryInteractor {
@@ -31,24 +40,92 @@ impl UpdateCategoryInteractor {
             self.repo.get_by_id_or_fail(&c.into()).await?;
         }
 
-        let mut category = self.repo.get_by_id_or_fail(&id).await?;
+        let before = self.repo.get_by_id_or_fail(&id).await?;
+        let mut category = before.clone();
 
-        let slug = input.slug.unwrap_or(slugify(&input.name));
+        let slug = match input.slug {
+            Some(slug) => self.check_explicit_slug(slug, &id).await?,
+            None => self.generate_slug(&input.name, &id).await?,
+        };
 
+        let reclaim_retired_slug = input.reclaim_retired_slug;
+        category.slug = slug;
+        category.name = input.name;
+        category.parent_id = input.parent_id.map(|c| c.into());
+        category.description = input.description;
+
+        self.unit_of_work
+            .run(Box::pin(async {
+                if category.slug != before.slug {
+                    self.retire_slug(&before.slug, &category.slug, &id, reclaim_retired_slug)
+                        .await?;
+                }
+                self.repo.update(&category).await?;
+                self.events
+                    .publish(
+                        CategoryUpdated {
+                            before,
+                            after: category,
+                        }
+                        .into(),
+                    )
+                    .await?;
+                Ok(())
+            }))
+            .await
+    }
+
+    async fn check_explicit_slug(
+        &self,
+        slug: String,
+        id: &CategoryId,
+    ) -> ApplicationResult<String> {
+        if self.slug_generator.is_reserved(&slug) {
+            return Err(ValidationException {
+                key: "slug".into(),
+                value: slug,
+                message: "slug is reserved".into(),
+            });
+        }
         if let Some(c) = self.repo.get_by_slug(&slug).await? {
-            if c.id != id {
+            if c.id != *id {
                 return Err(DuplicationException {
                     value: slug,
                     key: "slug".into(),
                 });
             }
         }
+        Ok(slug)
+    }
 
-        category.slug = slug;
-        category.name = input.name;
-        category.parent_id = input.parent_id.map(|c| c.into());
-        category.description = input.description;
-        self.repo.update(&category).await?;
+    async fn generate_slug(&self, name: &str, id: &CategoryId) -> ApplicationResult<String> {
+        let availability = RepositorySlugAvailability {
+            repo: self.repo.as_ref(),
+            slug_history: self.slug_history.as_ref(),
+            owner_id: Some(id.to_string()),
+        };
+        self.slug_generator.generate(name, &availability).await
+    }
+
+    async fn retire_slug(
+        &self,
+        old_slug: &str,
+        new_slug: &str,
+        id: &CategoryId,
+        reclaim: bool,
+    ) -> ApplicationResult<()> {
+        let owner_id = id.to_string();
+        self.slug_history
+            .claim(SlugOwnerKind::Category, new_slug, Some(&owner_id), reclaim)
+            .await?;
+        self.slug_history
+            .retire(&RetiredSlug {
+                kind: SlugOwnerKind::Category,
+                slug: old_slug.into(),
+                owner_id,
+                retired_at: self.clock.now(),
+            })
+            .await?;
         Ok(())
     }
 }
@@ -60,6 +137,7 @@ pub struct UpdateCategoryInteractorInput {
     pub description: String,
     pub parent_id: Option<String>,
     pub slug: Option<String>,
+    pub reclaim_retired_slug: bool,
 }
 
 impl Validatable for UpdateCategoryInteractorInput {
@@ -109,17 +187,26 @@ mod tests {
     use crate::categories::domain::{Category, CategoryId};
     use crate::categories::interactors::actions::UPDATE_CATEGORY_ACTION;
     use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
     use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
     use crate::test_utils::errors_assertion::{
         assert_duplication_error, assert_forbidden_error, assert_not_found_error,
         assert_validation_error, assert_validation_error_with_key,
     };
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
+    use crate::utils::TransliteratingSlugGenerator;
 
     use super::*;
 
     struct CreationResult {
         interactor: UpdateCategoryInteractor,
         repo: Arc<FakeCategoriesRepository>,
+        slug_history: Arc<FakeSlugHistoryRepository>,
+        events: Arc<EventPublisherSpy>,
+        unit_of_work: Arc<UnitOfWorkSpy>,
     }
 
     fn create_interactor() -> CreationResult {
@@ -127,10 +214,26 @@ mod tests {
             existing_category(),
             another_category(),
         ]));
-        let interactor = UpdateCategoryInteractor { repo: arc.clone() };
+        let slug_history = Arc::new(FakeSlugHistoryRepository::new_with_data(&[retired_slug(
+            "retired-slug",
+            &another_category().id.to_string(),
+        )]));
+        let events = Arc::new(EventPublisherSpy::default());
+        let unit_of_work = Arc::new(UnitOfWorkSpy::default());
+        let interactor = UpdateCategoryInteractor {
+            repo: arc.clone(),
+            slug_history: slug_history.clone(),
+            slug_generator: Arc::new(TransliteratingSlugGenerator::default()),
+            clock: Arc::new(FakeClock::new_fixed()),
+            events: events.clone(),
+            unit_of_work: unit_of_work.clone(),
+        };
         CreationResult {
             interactor,
             repo: arc,
+            slug_history,
+            events,
+            unit_of_work,
         }
     }
 
@@ -183,16 +286,43 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn should_throw_if_slug_is_is_duplicate_which_extracted_from_name() {
+    async fn should_suffix_slug_extracted_from_name_when_it_is_taken() {
         let c = create_interactor();
         let mut input = valid_input();
 
         input.slug = None;
         input.name = "another slug".into();
 
+        c.interactor.execute(&auth(), input.clone()).await.unwrap();
+
+        let category = c.repo.get_by_id(&input.id.into()).await.unwrap().unwrap();
+        assert_eq!(category.slug, "another-slug-2");
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_current_slug_when_the_name_generates_it() {
+        let c = create_interactor();
+        let mut input = valid_input();
+
+        input.slug = None;
+        input.name = existing_category().slug;
+
+        c.interactor.execute(&auth(), input.clone()).await.unwrap();
+
+        let category = c.repo.get_by_id(&input.id.into()).await.unwrap().unwrap();
+        assert_eq!(category.slug, existing_category().slug);
+        assert!(c.slug_history.get_retired().len() == 1);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_if_explicit_slug_is_reserved() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.slug = Some("login".into());
+
         let err = c.interactor.execute(&auth(), input).await.unwrap_err();
 
-        assert_duplication_error(err, "slug");
+        assert_validation_error_with_key(err, "slug");
     }
 
     #[tokio::test]
@@ -253,10 +383,104 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn should_retire_the_old_slug_when_it_changes() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.slug = Some("new-slug".into());
+
+        c.interactor.execute(&auth(), input).await.unwrap();
+
+        let retired = c
+            .slug_history
+            .get(SlugOwnerKind::Category, &existing_category().slug)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(retired.owner_id, existing_category().id.to_string());
+        assert_eq!(retired.retired_at, fixed_now());
+    }
+
+    #[tokio::test]
+    async fn should_not_retire_anything_when_the_slug_is_unchanged() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(c.slug_history.get_retired().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_a_slug_retired_by_another_category() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.slug = Some("retired-slug".into());
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_duplication_error(err, "slug");
+    }
+
+    #[tokio::test]
+    async fn should_release_a_retired_slug_when_reclaiming_explicitly() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.slug = Some("retired-slug".into());
+        input.reclaim_retired_slug = true;
+
+        c.interactor.execute(&auth(), input).await.unwrap();
+
+        let retired = c
+            .slug_history
+            .get(SlugOwnerKind::Category, "retired-slug")
+            .await
+            .unwrap();
+        assert!(retired.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_allow_taking_back_its_own_retired_slug() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.id = another_category().id.into();
+        input.slug = Some("retired-slug".into());
+
+        c.interactor.execute(&auth(), input).await.unwrap();
+
+        let category = c
+            .repo
+            .get_by_id(&another_category().id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(category.slug, "retired-slug");
+    }
+
     fn auth() -> AuthPayloadSpy {
         AuthPayloadSpy::new_allowed("ID".into())
     }
 
+    #[tokio::test]
+    async fn should_publish_the_category_before_and_after_the_update() {
+        let c = create_interactor();
+        let before = c
+            .repo
+            .get_by_id_or_fail(&existing_category().id)
+            .await
+            .unwrap();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let after = c
+            .repo
+            .get_by_id_or_fail(&existing_category().id)
+            .await
+            .unwrap();
+        let expected: DomainEvent = CategoryUpdated { before, after }.into();
+        assert_eq!(c.events.get_published(), [expected]);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
+
     fn valid_input() -> UpdateCategoryInteractorInput {
         UpdateCategoryInteractorInput {
             id: existing_category().id.to_string(),
@@ -264,6 +488,16 @@ mod tests {
             description: "description".to_string(),
             parent_id: None,
             slug: "slug".to_string().into(),
+            reclaim_retired_slug: false,
+        }
+    }
+
+    fn retired_slug(slug: &str, owner_id: &str) -> RetiredSlug {
+        RetiredSlug {
+            kind: SlugOwnerKind::Category,
+            slug: slug.to_string(),
+            owner_id: owner_id.to_string(),
+            retired_at: Utc::now(),
         }
     }
 
@@ -280,7 +514,7 @@ mod tests {
 
     fn existing_category() -> Category {
         Category {
-            id: CategoryId::new("test".into()),
+            id: CategoryId::new("test"),
             name: "category".to_string(),
             description: "description of the category".to_string(),
             created_at: Utc::now(),
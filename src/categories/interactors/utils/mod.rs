@@ -1,4 +1,9 @@
-use crate::categories::domain::Category;
+use std::collections::HashSet;
+
+use crate::categories::domain::{Category, CategoryId};
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::errors::UnknownResult;
+use crate::utils::{SlugOwnerKind, SlugOwners};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct VisibleCategory {
@@ -22,3 +27,35 @@ impl From<Category> for VisibleCategory {
         }
     }
 }
+
+/// Returns the ids of `id` and all its descendants, parents before children.
+pub fn category_subtree(categories: &[Category], id: &CategoryId) -> Vec<CategoryId> {
+    let mut result = vec![id.clone()];
+    let mut visited: HashSet<CategoryId> = HashSet::from([id.clone()]);
+    let mut index = 0;
+    while index < result.len() {
+        let current = result[index].clone();
+        for child in categories
+            .iter()
+            .filter(|c| c.parent_id.as_ref() == Some(&current))
+        {
+            if visited.insert(child.id.clone()) {
+                result.push(child.id.clone());
+            }
+        }
+        index += 1;
+    }
+    result
+}
+
+#[async_trait::async_trait]
+impl SlugOwners for dyn CategoriesRepository {
+    const KIND: SlugOwnerKind = SlugOwnerKind::Category;
+
+    async fn get_owner_of(&self, slug: &str) -> UnknownResult<Option<String>> {
+        Ok(self
+            .get_by_slug(slug)
+            .await?
+            .map(|category| category.id.to_string()))
+    }
+}
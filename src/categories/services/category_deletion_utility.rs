@@ -0,0 +1,378 @@
+use std::sync::Arc;
+
+use crate::categories::domain::{Category, CategoryId};
+use crate::categories::interactors::traits::{
+    CategoriesRepository, CategoryDeletionOutcome, CategoryDeletionUtility,
+};
+use crate::categories::interactors::utils::category_subtree;
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationException::BadRequestException;
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::posts::interactors::traits::PostsRepository;
+use crate::utils::DeletionResult;
+
+/// Deletes categories through the repositories and takes care of the posts attached to them.
+///
+/// Posts of a recursively deleted subtree are moved to `fallback_category_id` when it is set;
+/// otherwise they are detached from any category. A subtree holding the fallback itself can
+/// not be deleted until the fallback is changed.
+pub struct RepositoryCategoryDeletionUtility {
+    categories_repo: Arc<dyn CategoriesRepository>,
+    posts_repo: Arc<dyn PostsRepository>,
+    fallback_category_id: Option<CategoryId>,
+}
+
+impl RepositoryCategoryDeletionUtility {
+    pub fn new(
+        categories_repo: Arc<dyn CategoriesRepository>,
+        posts_repo: Arc<dyn PostsRepository>,
+        fallback_category_id: Option<CategoryId>,
+    ) -> Self {
+        Self {
+            categories_repo,
+            posts_repo,
+            fallback_category_id,
+        }
+    }
+
+    async fn reassign_posts(
+        &self,
+        from: &CategoryId,
+        to: Option<&CategoryId>,
+    ) -> UnknownResult<i32> {
+        let posts = self.posts_repo.get_by_category_id(from).await?;
+        for mut post in posts.iter().cloned() {
+            post.category_id = to.cloned();
+            self.posts_repo.update(&post).await?;
+        }
+        Ok(posts.len() as i32)
+    }
+}
+
+#[async_trait::async_trait]
+impl CategoryDeletionUtility for RepositoryCategoryDeletionUtility {
+    async fn delete_recursive(
+        &self,
+        id: &CategoryId,
+    ) -> ApplicationResult<CategoryDeletionOutcome> {
+        let categories = self.categories_repo.get_all().await?;
+        if !categories.iter().any(|c| c.id == *id) {
+            return Ok(DeletionResult::NotFound.into());
+        }
+
+        let subtree = category_subtree(&categories, id);
+        if let Some(fallback) = self
+            .fallback_category_id
+            .as_ref()
+            .filter(|fallback| subtree.contains(fallback))
+        {
+            return Err(BadRequestException(format!(
+                "category {} holds the fallback category {}, which can not be deleted",
+                id.to_string(),
+                fallback.to_string()
+            )));
+        }
+        let fallback = self
+            .fallback_category_id
+            .as_ref()
+            .filter(|fallback| categories.iter().any(|c| c.id == **fallback));
+
+        let mut outcome = CategoryDeletionOutcome::from(DeletionResult::Deleted);
+        for category_id in subtree.iter().rev() {
+            let count = self.reassign_posts(category_id, fallback).await?;
+            match fallback {
+                Some(_) => outcome.moved_posts += count,
+                None => outcome.detached_posts += count,
+            }
+            self.categories_repo.delete(category_id).await?;
+            outcome.deleted_categories += 1;
+        }
+        Ok(outcome)
+    }
+
+    async fn replace_with(
+        &self,
+        id: &CategoryId,
+        replacement_id: &CategoryId,
+    ) -> ApplicationResult<CategoryDeletionOutcome> {
+        if id == replacement_id {
+            return Err(ValidationError::new(
+                "replacement_id".into(),
+                replacement_id.to_string(),
+                "a category can not replace itself".into(),
+            )
+            .into());
+        }
+        let categories = self.categories_repo.get_all().await?;
+        let source = categories.iter().find(|c| c.id == *id);
+        let replacement = categories.iter().find(|c| c.id == *replacement_id);
+        let (source, mut replacement) = match (source, replacement) {
+            (Some(source), Some(replacement)) => (source.clone(), replacement.clone()),
+            _ => return Ok(DeletionResult::NotFound.into()),
+        };
+
+        let mut outcome = CategoryDeletionOutcome::from(DeletionResult::Deleted);
+
+        // lift the replacement out of the source subtree so that re-parenting can not form a cycle
+        if category_subtree(&categories, id).contains(replacement_id) {
+            replacement.parent_id = source.parent_id.clone();
+            self.categories_repo.update(&replacement).await?;
+        }
+
+        for mut child in categories
+            .into_iter()
+            .filter(|c| c.parent_id.as_ref() == Some(id) && c.id != *replacement_id)
+        {
+            child.parent_id = Some(replacement_id.clone());
+            self.categories_repo.update(&child).await?;
+            outcome.moved_children += 1;
+        }
+
+        outcome.moved_posts = self.reassign_posts(id, Some(replacement_id)).await?;
+        self.categories_repo.delete(id).await?;
+        outcome.deleted_categories = 1;
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::posts::domain::{Post, PostId, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::errors_assertion::{
+        assert_bad_request_error, assert_validation_error_with_key,
+    };
+
+    use super::*;
+
+    struct CreationResult {
+        utility: RepositoryCategoryDeletionUtility,
+        categories_repo: Arc<FakeCategoriesRepository>,
+        posts_repo: Arc<FakePostsRepository>,
+    }
+
+    fn create_utility(fallback_category_id: Option<&str>) -> CreationResult {
+        let categories_repo = Arc::new(FakeCategoriesRepository::new_with_data(&categories()));
+        let posts_repo = Arc::new(FakePostsRepository::new_with_data(&posts()));
+        let utility = RepositoryCategoryDeletionUtility::new(
+            categories_repo.clone(),
+            posts_repo.clone(),
+            fallback_category_id.map(|id| id.into()),
+        );
+        CreationResult {
+            utility,
+            categories_repo,
+            posts_repo,
+        }
+    }
+
+    fn category(id: &str, parent_id: Option<&str>) -> Category {
+        Category {
+            id: id.into(),
+            name: id.to_string(),
+            description: "".to_string(),
+            created_at: Utc::now(),
+            slug: id.to_string(),
+            parent_id: parent_id.map(|id| id.into()),
+        }
+    }
+
+    fn post(id: &str, category_id: &str) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.to_string(),
+            slug: id.to_string(),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            category_id: Some(category_id.into()),
+            tag_ids: vec![],
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn categories() -> Vec<Category> {
+        vec![
+            category("root", None),
+            category("child", Some("root")),
+            category("grandchild", Some("child")),
+            category("other", None),
+        ]
+    }
+
+    fn posts() -> Vec<Post> {
+        vec![
+            post("1", "root"),
+            post("2", "child"),
+            post("3", "grandchild"),
+            post("4", "other"),
+        ]
+    }
+
+    fn category_ids(repo: &FakeCategoriesRepository) -> Vec<String> {
+        let categories = repo.categories.lock().unwrap();
+        categories.iter().map(|c| c.id.to_string()).collect()
+    }
+
+    async fn category_of_post(repo: &FakePostsRepository, id: &str) -> Option<CategoryId> {
+        repo.get_by_id(&id.into())
+            .await
+            .unwrap()
+            .unwrap()
+            .category_id
+    }
+
+    async fn parent_of(repo: &FakeCategoriesRepository, id: &str) -> Option<CategoryId> {
+        repo.get_by_id(&id.into()).await.unwrap().unwrap().parent_id
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_deleting_a_missing_category() {
+        let c = create_utility(None);
+
+        let outcome = c.utility.delete_recursive(&"missing".into()).await.unwrap();
+
+        assert_eq!(outcome, DeletionResult::NotFound.into());
+        assert_eq!(category_ids(&c.categories_repo).len(), categories().len());
+    }
+
+    #[tokio::test]
+    async fn should_delete_the_whole_subtree() {
+        let c = create_utility(None);
+
+        let outcome = c.utility.delete_recursive(&"child".into()).await.unwrap();
+
+        assert_eq!(outcome.result, DeletionResult::Deleted);
+        assert_eq!(outcome.deleted_categories, 2);
+        assert_eq!(category_ids(&c.categories_repo), ["root", "other"]);
+    }
+
+    #[tokio::test]
+    async fn should_detach_posts_when_no_fallback_is_configured() {
+        let c = create_utility(None);
+
+        let outcome = c.utility.delete_recursive(&"root".into()).await.unwrap();
+
+        assert_eq!(outcome.detached_posts, 3);
+        assert_eq!(outcome.moved_posts, 0);
+        assert_eq!(category_of_post(&c.posts_repo, "3").await, None);
+        assert_eq!(
+            category_of_post(&c.posts_repo, "4").await,
+            Some("other".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_move_posts_to_the_fallback_category() {
+        let c = create_utility(Some("other"));
+
+        let outcome = c.utility.delete_recursive(&"child".into()).await.unwrap();
+
+        assert_eq!(outcome.moved_posts, 2);
+        assert_eq!(outcome.detached_posts, 0);
+        assert_eq!(
+            category_of_post(&c.posts_repo, "3").await,
+            Some("other".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_refuse_to_delete_a_subtree_holding_the_fallback() {
+        let c = create_utility(Some("grandchild"));
+
+        let err = c
+            .utility
+            .delete_recursive(&"child".into())
+            .await
+            .unwrap_err();
+
+        assert_bad_request_error(err);
+        assert_eq!(category_ids(&c.categories_repo).len(), categories().len());
+        assert_eq!(
+            category_of_post(&c.posts_repo, "2").await,
+            Some("child".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_replacing_with_a_missing_category() {
+        let c = create_utility(None);
+
+        let outcome = c
+            .utility
+            .replace_with(&"root".into(), &"missing".into())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.result, DeletionResult::NotFound);
+        assert_eq!(category_ids(&c.categories_repo).len(), categories().len());
+    }
+
+    #[tokio::test]
+    async fn should_refuse_to_replace_a_category_with_itself() {
+        let c = create_utility(None);
+
+        let err = c
+            .utility
+            .replace_with(&"root".into(), &"root".into())
+            .await
+            .unwrap_err();
+
+        assert_validation_error_with_key(err, "replacement_id");
+    }
+
+    #[tokio::test]
+    async fn should_move_posts_and_children_to_the_replacement() {
+        let c = create_utility(None);
+
+        let outcome = c
+            .utility
+            .replace_with(&"child".into(), &"other".into())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.result, DeletionResult::Deleted);
+        assert_eq!(outcome.deleted_categories, 1);
+        assert_eq!(outcome.moved_posts, 1);
+        assert_eq!(outcome.moved_children, 1);
+        assert_eq!(
+            category_of_post(&c.posts_repo, "2").await,
+            Some("other".into())
+        );
+        assert_eq!(
+            parent_of(&c.categories_repo, "grandchild").await,
+            Some("other".into())
+        );
+        assert_eq!(
+            category_ids(&c.categories_repo),
+            ["root", "grandchild", "other"]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_lift_a_descendant_replacement_to_the_source_parent() {
+        let c = create_utility(None);
+
+        let outcome = c
+            .utility
+            .replace_with(&"root".into(), &"grandchild".into())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.moved_children, 1);
+        assert_eq!(parent_of(&c.categories_repo, "grandchild").await, None);
+        assert_eq!(
+            parent_of(&c.categories_repo, "child").await,
+            Some("grandchild".into())
+        );
+        assert_eq!(
+            category_of_post(&c.posts_repo, "1").await,
+            Some("grandchild".into())
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use crate::categories::domain::{Category, CategoryId};
+use crate::categories::interactors::traits::{
+    CategoriesRepository, CategoryMeta, CategoryMetaCalculator,
+};
+use crate::errors::UnknownResult;
+use crate::posts::domain::Post;
+use crate::posts::interactors::traits::PostsRepository;
+
+/// Computes category meta from the posts and categories repositories.
+///
+/// The counters are built once from the repositories and then kept up to date through the
+/// hooks, so a lookup never walks the category tree. `MetaTrackingPostsRepository` and
+/// `MetaTrackingCategoriesRepository` call the hooks for every write that goes through them;
+/// wiring them in place of the plain repositories keeps the counters right.
+pub struct CachedCategoryMetaCalculator {
+    categories_repo: Arc<dyn CategoriesRepository>,
+    posts_repo: Arc<dyn PostsRepository>,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    counters: Option<CategoryCounters>,
+    /// Bumped by every hook, so a rebuild that raced with a write is not kept.
+    generation: u64,
+}
+
+impl CachedCategoryMetaCalculator {
+    pub fn new(
+        categories_repo: Arc<dyn CategoriesRepository>,
+        posts_repo: Arc<dyn PostsRepository>,
+    ) -> Self {
+        Self {
+            categories_repo,
+            posts_repo,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    pub fn post_added(&self, category_id: &CategoryId) {
+        self.update_counters(|c| c.add_posts(category_id, 1));
+    }
+
+    pub fn post_removed(&self, category_id: &CategoryId) {
+        self.update_counters(|c| c.add_posts(category_id, -1));
+    }
+
+    pub fn post_moved(&self, from: Option<&CategoryId>, to: Option<&CategoryId>) {
+        self.update_counters(|c| {
+            if let Some(from) = from {
+                c.add_posts(from, -1);
+            }
+            if let Some(to) = to {
+                c.add_posts(to, 1);
+            }
+        });
+    }
+
+    pub fn category_added(&self, category: &Category) {
+        self.update_counters(|c| c.add_category(category));
+    }
+
+    /// For changes to the tree other than adding a leaf (re-parenting, deletion).
+    pub fn invalidate(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.counters = None;
+        state.generation += 1;
+    }
+
+    fn update_counters(&self, f: impl FnOnce(&mut CategoryCounters)) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+        if let Some(counters) = state.counters.as_mut() {
+            f(counters);
+        }
+    }
+
+    async fn rebuild(&self) -> UnknownResult<CategoryCounters> {
+        let generation = self.state.lock().unwrap().generation;
+        let categories = self.categories_repo.get_all().await?;
+        let posts = self.posts_repo.get_all().await?;
+        let counters = CategoryCounters::build(&categories, &posts);
+        let mut state = self.state.lock().unwrap();
+        if state.generation == generation {
+            state.counters = Some(counters.clone());
+        }
+        Ok(counters)
+    }
+}
+
+#[async_trait::async_trait]
+impl CategoryMetaCalculator for CachedCategoryMetaCalculator {
+    async fn get_meta(&self, id: &CategoryId) -> UnknownResult<Option<CategoryMeta>> {
+        if let Some(counters) = &self.state.lock().unwrap().counters {
+            return Ok(counters.meta(id));
+        }
+        Ok(self.rebuild().await?.meta(id))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct CategoryCounters {
+    parents: HashMap<CategoryId, Option<CategoryId>>,
+    children: HashMap<CategoryId, i32>,
+    direct: HashMap<CategoryId, i32>,
+    total: HashMap<CategoryId, i32>,
+}
+
+impl CategoryCounters {
+    fn build(categories: &[Category], posts: &[Post]) -> Self {
+        let mut counters = CategoryCounters::default();
+        for category in categories {
+            counters.add_category(category);
+        }
+        for category_id in posts.iter().filter_map(|p| p.category_id.as_ref()) {
+            counters.add_posts(category_id, 1);
+        }
+        counters
+    }
+
+    fn add_category(&mut self, category: &Category) {
+        self.parents
+            .insert(category.id.clone(), category.parent_id.clone());
+        if let Some(parent_id) = &category.parent_id {
+            *self.children.entry(parent_id.clone()).or_default() += 1;
+        }
+    }
+
+    fn meta(&self, id: &CategoryId) -> Option<CategoryMeta> {
+        if !self.parents.contains_key(id) {
+            return None;
+        }
+        Some(CategoryMeta {
+            direct_posts_count: self.direct.get(id).copied().unwrap_or_default(),
+            children_count: self.children.get(id).copied().unwrap_or_default(),
+            total_post_count: self.total.get(id).copied().unwrap_or_default(),
+        })
+    }
+
+    fn add_posts(&mut self, id: &CategoryId, delta: i32) {
+        if !self.parents.contains_key(id) {
+            return;
+        }
+        *self.direct.entry(id.clone()).or_default() += delta;
+        for ancestor in self.self_and_ancestors(id) {
+            *self.total.entry(ancestor).or_default() += delta;
+        }
+    }
+
+    fn self_and_ancestors(&self, id: &CategoryId) -> Vec<CategoryId> {
+        let mut visited = HashSet::new();
+        let mut current = Some(id.clone());
+        while let Some(id) = current {
+            if !visited.insert(id.clone()) {
+                break;
+            }
+            current = self.parents.get(&id).cloned().flatten();
+        }
+        visited.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::posts::domain::{PostId, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+
+    use super::*;
+
+    struct CreationResult {
+        calculator: CachedCategoryMetaCalculator,
+        categories_repo: Arc<FakeCategoriesRepository>,
+        posts_repo: Arc<FakePostsRepository>,
+    }
+
+    fn create_calculator() -> CreationResult {
+        let categories_repo = Arc::new(FakeCategoriesRepository::new_with_data(&categories()));
+        let posts_repo = Arc::new(FakePostsRepository::new_with_data(&posts()));
+        let calculator =
+            CachedCategoryMetaCalculator::new(categories_repo.clone(), posts_repo.clone());
+        CreationResult {
+            calculator,
+            categories_repo,
+            posts_repo,
+        }
+    }
+
+    fn category(id: &str, parent_id: Option<&str>) -> Category {
+        Category {
+            id: id.into(),
+            name: id.to_string(),
+            description: "".to_string(),
+            created_at: Utc::now(),
+            slug: id.to_string(),
+            parent_id: parent_id.map(|id| id.into()),
+        }
+    }
+
+    fn post(id: &str, category_id: Option<&str>) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.to_string(),
+            slug: id.to_string(),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            category_id: category_id.map(|id| id.into()),
+            tag_ids: vec![],
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn categories() -> Vec<Category> {
+        vec![
+            category("root", None),
+            category("child", Some("root")),
+            category("child2", Some("root")),
+            category("grandchild", Some("child")),
+        ]
+    }
+
+    fn posts() -> Vec<Post> {
+        vec![
+            post("1", Some("root")),
+            post("2", Some("child")),
+            post("3", Some("grandchild")),
+            post("4", Some("grandchild")),
+            post("5", None),
+        ]
+    }
+
+    async fn meta_of(calculator: &CachedCategoryMetaCalculator, id: &str) -> CategoryMeta {
+        calculator.get_meta(&id.into()).await.unwrap().unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_category_does_not_exist() {
+        let c = create_calculator();
+
+        let meta = c.calculator.get_meta(&"not-found".into()).await.unwrap();
+
+        assert!(meta.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_count_direct_posts_and_children() {
+        let c = create_calculator();
+
+        let meta = meta_of(&c.calculator, "child").await;
+
+        assert_eq!(meta.direct_posts_count, 1);
+        assert_eq!(meta.children_count, 1);
+    }
+
+    #[tokio::test]
+    async fn should_aggregate_total_over_the_whole_subtree() {
+        let c = create_calculator();
+
+        assert_eq!(meta_of(&c.calculator, "root").await.total_post_count, 4);
+        assert_eq!(meta_of(&c.calculator, "child").await.total_post_count, 3);
+        assert_eq!(meta_of(&c.calculator, "child2").await.total_post_count, 0);
+        assert_eq!(
+            meta_of(&c.calculator, "grandchild").await.total_post_count,
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn should_apply_hooks_without_reloading() {
+        let c = create_calculator();
+        meta_of(&c.calculator, "root").await;
+
+        c.calculator.post_added(&"grandchild".into());
+        assert_eq!(meta_of(&c.calculator, "root").await.total_post_count, 5);
+        assert_eq!(
+            meta_of(&c.calculator, "grandchild")
+                .await
+                .direct_posts_count,
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn should_update_both_branches_when_a_post_moves() {
+        let c = create_calculator();
+        meta_of(&c.calculator, "root").await;
+
+        c.calculator
+            .post_moved(Some(&"grandchild".into()), Some(&"child2".into()));
+
+        assert_eq!(meta_of(&c.calculator, "child").await.total_post_count, 2);
+        assert_eq!(meta_of(&c.calculator, "child2").await.total_post_count, 1);
+        assert_eq!(meta_of(&c.calculator, "root").await.total_post_count, 4);
+    }
+
+    #[tokio::test]
+    async fn should_count_categories_added_after_the_cache() {
+        let c = create_calculator();
+        meta_of(&c.calculator, "root").await;
+
+        c.calculator.category_added(&category("new", Some("root")));
+
+        let meta = meta_of(&c.calculator, "new").await;
+        assert_eq!(meta, CategoryMeta::default());
+        assert_eq!(meta_of(&c.calculator, "root").await.children_count, 3);
+    }
+
+    #[tokio::test]
+    async fn should_not_reload_on_lookups_of_unknown_ids() {
+        let c = create_calculator();
+        meta_of(&c.calculator, "root").await;
+
+        // written behind the calculator's back, so only a reload would find it
+        c.categories_repo
+            .create(&category("new", Some("root")))
+            .await
+            .unwrap();
+
+        assert!(c
+            .calculator
+            .get_meta(&"new".into())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn should_reload_from_repositories_after_invalidate() {
+        let c = create_calculator();
+        meta_of(&c.calculator, "root").await;
+
+        c.posts_repo.create(&post("6", Some("root"))).await.unwrap();
+        c.calculator.invalidate();
+
+        assert_eq!(meta_of(&c.calculator, "root").await.direct_posts_count, 2);
+    }
+}
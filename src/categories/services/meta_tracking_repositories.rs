@@ -0,0 +1,289 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::categories::domain::{Category, CategoryId};
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::categories::services::CachedCategoryMetaCalculator;
+use crate::errors::UnknownResult;
+use crate::posts::domain::{Post, PostId};
+use crate::posts::interactors::traits::PostsRepository;
+use crate::tags::domain::TagId;
+use crate::utils::DeletionResult;
+
+/// Keeps the counters of a `CachedCategoryMetaCalculator` in step with the posts written
+/// through this repository.
+///
+/// Wiring it in place of the plain repository covers every interactor, service and importer
+/// that writes posts.
+pub struct MetaTrackingPostsRepository {
+    inner: Arc<dyn PostsRepository>,
+    calculator: Arc<CachedCategoryMetaCalculator>,
+}
+
+impl MetaTrackingPostsRepository {
+    pub fn new(
+        inner: Arc<dyn PostsRepository>,
+        calculator: Arc<CachedCategoryMetaCalculator>,
+    ) -> Self {
+        Self { inner, calculator }
+    }
+}
+
+#[async_trait::async_trait]
+impl PostsRepository for MetaTrackingPostsRepository {
+    async fn get_by_id(&self, id: &PostId) -> UnknownResult<Option<Post>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Post>> {
+        self.inner.get_by_slug(slug).await
+    }
+
+    async fn get_all(&self) -> UnknownResult<Vec<Post>> {
+        self.inner.get_all().await
+    }
+
+    async fn get_by_category_id(&self, id: &CategoryId) -> UnknownResult<Vec<Post>> {
+        self.inner.get_by_category_id(id).await
+    }
+
+    async fn get_by_tag_id(&self, id: &TagId) -> UnknownResult<Vec<Post>> {
+        self.inner.get_by_tag_id(id).await
+    }
+
+    async fn create(&self, post: &Post) -> UnknownResult<Post> {
+        let created = self.inner.create(post).await?;
+        if let Some(category_id) = &created.category_id {
+            self.calculator.post_added(category_id);
+        }
+        Ok(created)
+    }
+
+    async fn update(&self, post: &Post) -> UnknownResult<Post> {
+        let before = self.inner.get_by_id(&post.id).await?;
+        let updated = self.inner.update(post).await?;
+        let from = before.and_then(|before| before.category_id);
+        if from != updated.category_id {
+            self.calculator
+                .post_moved(from.as_ref(), updated.category_id.as_ref());
+        }
+        Ok(updated)
+    }
+
+    async fn publish_if_scheduled_for(
+        &self,
+        id: &PostId,
+        publish_at: DateTime<Utc>,
+    ) -> UnknownResult<Option<Post>> {
+        self.inner.publish_if_scheduled_for(id, publish_at).await
+    }
+
+    async fn get_scheduled_until(&self, time: DateTime<Utc>) -> UnknownResult<Vec<Post>> {
+        self.inner.get_scheduled_until(time).await
+    }
+
+    async fn delete(&self, id: &PostId) -> UnknownResult<DeletionResult> {
+        let before = self.inner.get_by_id(id).await?;
+        let result = self.inner.delete(id).await?;
+        if result == DeletionResult::Deleted {
+            if let Some(category_id) = before.and_then(|before| before.category_id) {
+                self.calculator.post_removed(&category_id);
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Tells a `CachedCategoryMetaCalculator` about the categories written through this repository.
+pub struct MetaTrackingCategoriesRepository {
+    inner: Arc<dyn CategoriesRepository>,
+    calculator: Arc<CachedCategoryMetaCalculator>,
+}
+
+impl MetaTrackingCategoriesRepository {
+    pub fn new(
+        inner: Arc<dyn CategoriesRepository>,
+        calculator: Arc<CachedCategoryMetaCalculator>,
+    ) -> Self {
+        Self { inner, calculator }
+    }
+}
+
+#[async_trait::async_trait]
+impl CategoriesRepository for MetaTrackingCategoriesRepository {
+    async fn get_by_id(&self, id: &CategoryId) -> UnknownResult<Option<Category>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_all(&self) -> UnknownResult<Vec<Category>> {
+        self.inner.get_all().await
+    }
+
+    async fn create(&self, category: &Category) -> UnknownResult<Category> {
+        let created = self.inner.create(category).await?;
+        self.calculator.category_added(&created);
+        Ok(created)
+    }
+
+    async fn update(&self, category: &Category) -> UnknownResult<Category> {
+        let before = self.inner.get_by_id(&category.id).await?;
+        let updated = self.inner.update(category).await?;
+        if before.map(|before| before.parent_id) != Some(updated.parent_id.clone()) {
+            self.calculator.invalidate();
+        }
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: &CategoryId) -> UnknownResult<DeletionResult> {
+        let result = self.inner.delete(id).await?;
+        if result == DeletionResult::Deleted {
+            self.calculator.invalidate();
+        }
+        Ok(result)
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Category>> {
+        self.inner.get_by_slug(slug).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::categories::interactors::traits::{
+        CategoryDeletionUtility, CategoryMeta, CategoryMetaCalculator,
+    };
+    use crate::categories::services::RepositoryCategoryDeletionUtility;
+    use crate::posts::domain::PostStatus;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+
+    use super::*;
+
+    struct CreationResult {
+        calculator: Arc<CachedCategoryMetaCalculator>,
+        categories_repo: Arc<MetaTrackingCategoriesRepository>,
+        posts_repo: Arc<MetaTrackingPostsRepository>,
+    }
+
+    fn create_repos() -> CreationResult {
+        let inner_categories = Arc::new(FakeCategoriesRepository::new_with_data(&[
+            category("root", None),
+            category("child", Some("root")),
+            category("other", None),
+        ]));
+        let inner_posts = Arc::new(FakePostsRepository::new_with_data(&[
+            post("1", Some("child")),
+            post("2", Some("root")),
+        ]));
+        let calculator = Arc::new(CachedCategoryMetaCalculator::new(
+            inner_categories.clone(),
+            inner_posts.clone(),
+        ));
+        CreationResult {
+            categories_repo: Arc::new(MetaTrackingCategoriesRepository::new(
+                inner_categories,
+                calculator.clone(),
+            )),
+            posts_repo: Arc::new(MetaTrackingPostsRepository::new(
+                inner_posts,
+                calculator.clone(),
+            )),
+            calculator,
+        }
+    }
+
+    fn category(id: &str, parent_id: Option<&str>) -> Category {
+        Category {
+            id: id.into(),
+            name: id.to_string(),
+            description: "".to_string(),
+            created_at: Utc::now(),
+            slug: id.to_string(),
+            parent_id: parent_id.map(|id| id.into()),
+        }
+    }
+
+    fn post(id: &str, category_id: Option<&str>) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.to_string(),
+            slug: id.to_string(),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            category_id: category_id.map(|id| id.into()),
+            tag_ids: vec![],
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    async fn meta_of(c: &CreationResult, id: &str) -> Option<CategoryMeta> {
+        c.calculator.get_meta(&id.into()).await.unwrap()
+    }
+
+    async fn total_of(c: &CreationResult, id: &str) -> i32 {
+        meta_of(c, id).await.unwrap().total_post_count
+    }
+
+    #[tokio::test]
+    async fn should_count_posts_created_moved_and_deleted() {
+        let c = create_repos();
+        assert_eq!(total_of(&c, "root").await, 2);
+
+        c.posts_repo
+            .create(&post("3", Some("child")))
+            .await
+            .unwrap();
+        assert_eq!(total_of(&c, "root").await, 3);
+
+        c.posts_repo
+            .update(&post("3", Some("other")))
+            .await
+            .unwrap();
+        assert_eq!(total_of(&c, "root").await, 2);
+        assert_eq!(total_of(&c, "other").await, 1);
+
+        c.posts_repo.delete(&PostId::new("3")).await.unwrap();
+        assert_eq!(total_of(&c, "other").await, 0);
+    }
+
+    #[tokio::test]
+    async fn should_follow_changes_to_the_tree() {
+        let c = create_repos();
+        assert_eq!(total_of(&c, "root").await, 2);
+
+        c.categories_repo
+            .create(&category("new", Some("other")))
+            .await
+            .unwrap();
+        assert_eq!(meta_of(&c, "other").await.unwrap().children_count, 1);
+
+        c.categories_repo
+            .update(&category("child", Some("other")))
+            .await
+            .unwrap();
+        assert_eq!(total_of(&c, "root").await, 1);
+        assert_eq!(total_of(&c, "other").await, 1);
+    }
+
+    #[tokio::test]
+    async fn should_follow_a_recursive_deletion() {
+        let c = create_repos();
+        assert_eq!(total_of(&c, "root").await, 2);
+        let deleter = RepositoryCategoryDeletionUtility::new(
+            c.categories_repo.clone(),
+            c.posts_repo.clone(),
+            Some("other".into()),
+        );
+
+        deleter.delete_recursive(&"root".into()).await.unwrap();
+
+        assert!(meta_of(&c, "root").await.is_none());
+        assert_eq!(total_of(&c, "other").await, 2);
+    }
+}
@@ -0,0 +1,9 @@
+pub use category_deletion_utility::RepositoryCategoryDeletionUtility;
+pub use category_meta_calculator::CachedCategoryMetaCalculator;
+pub use meta_tracking_repositories::{
+    MetaTrackingCategoriesRepository, MetaTrackingPostsRepository,
+};
+
+mod category_deletion_utility;
+mod category_meta_calculator;
+mod meta_tracking_repositories;
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::posts::domain::PostId;
+
+pub use spam::*;
+
+mod spam;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: CommentId,
+    pub post_id: PostId,
+    /// The comment this one replies to, `None` for a top-level comment.
+    pub parent_id: Option<CommentId>,
+    /// 0 for a top-level comment, one more than the parent for a reply.
+    pub depth: u32,
+    pub author: CommentAuthor,
+    pub content: String,
+    pub status: CommentStatus,
+    /// What the spam classifier learned this comment as, `true` for spam, so the lesson can be
+    /// taken back when a moderator changes their mind.
+    pub trained_as: Option<bool>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CommentAuthor {
+    /// A reader without an account; the email is kept for the moderators and never shown.
+    Anonymous {
+        name: String,
+        email: Option<String>,
+    },
+    User {
+        user_id: String,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentStatus {
+    /// Waiting for a moderator; only approved comments are shown to readers.
+    Pending,
+    Approved,
+    Spam,
+    Deleted,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CommentId(String);
+
+impl CommentId {
+    pub fn new(id: &str) -> Self {
+        CommentId(id.into())
+    }
+}
+
+impl Display for CommentId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<CommentId> for String {
+    fn from(id: CommentId) -> Self {
+        id.0
+    }
+}
+
+impl From<String> for CommentId {
+    fn from(s: String) -> Self {
+        CommentId(s)
+    }
+}
+
+impl From<&str> for CommentId {
+    fn from(s: &str) -> Self {
+        CommentId(s.to_string())
+    }
+}
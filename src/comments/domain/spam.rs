@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// What a spam classifier gets to see of a comment before it is stored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentSubmission {
+    pub content: String,
+    pub author_name: Option<String>,
+    pub author_email: Option<String>,
+    /// A form field hidden from people; anything in it was filled in by a bot.
+    pub honeypot: Option<String>,
+    /// When the comment form was served, as vouched for by the stamp the server put in it;
+    /// `None` when the stamp is missing or was not issued by the server.
+    pub form_rendered_at: Option<DateTime<Utc>>,
+    pub submitted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpamSignal {
+    HoneypotFilled,
+    /// The form came back without a valid stamp, so it was not the one the blog served.
+    FormNotStamped,
+    SubmittedTooFast,
+    TooManyLinks,
+    BlockedWord(String),
+    BlockedDomain(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpamAssessment {
+    /// The chance the comment is spam, from 0 to 1.
+    pub probability: f64,
+    pub signals: Vec<SpamSignal>,
+}
+
+/// Word counts of the comments moderators marked as spam or ham, for naive Bayes.
+///
+/// A word is counted once per comment however often it appears in it.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SpamModel {
+    pub spam_comments: u32,
+    pub ham_comments: u32,
+    pub spam_words: HashMap<String, u32>,
+    pub ham_words: HashMap<String, u32>,
+}
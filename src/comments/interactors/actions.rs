@@ -0,0 +1,2 @@
+pub const VIEW_COMMENT_QUEUE_ACTION: &str = "VIEW_COMMENT_QUEUE_ACTION";
+pub const MODERATE_COMMENT_ACTION: &str = "MODERATE_COMMENT_ACTION";
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::comments::interactors::traits::FormStamper;
+use crate::errors::ApplicationResult;
+use crate::utils::Clock;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CommentFormOutput {
+    /// Goes in a hidden field of the form and comes back as `PostCommentInput::form_stamp`.
+    pub form_stamp: String,
+}
+
+/// What the comment form needs to be served; the stamp tells the spam classifier how long the
+/// commenter took.
+#[derive(WithDeps)]
+pub struct CommentFormInteractor {
+    form_stamper: Arc<dyn FormStamper>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CommentFormInteractor {
+    pub async fn execute(&self) -> ApplicationResult<CommentFormOutput> {
+        Ok(CommentFormOutput {
+            form_stamp: self.form_stamper.issue(self.clock.now()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::comments::services::HmacFormStamper;
+    use crate::make_interactor_setup;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+
+    use super::*;
+
+    make_interactor_setup!(
+        CommentFormInteractor,
+        [
+            (
+                form_stamper,
+                HmacFormStamper::new(
+                    b"secret",
+                    Arc::new(FakeClock::new_fixed()),
+                    Duration::hours(1)
+                ),
+                HmacFormStamper
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock)
+        ]
+    );
+
+    #[tokio::test]
+    async fn should_stamp_the_form_with_the_current_time() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute().await.unwrap();
+
+        assert_eq!(c.form_stamper.verify(&output.form_stamp), Some(fixed_now()));
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::comments::interactors::traits::CommentsMetaCalculator;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::ApplicationResult;
+use crate::posts::domain::PostId;
+
+pub struct CommentsInfoInput {
+    pub post_id: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct CommentsInfoOutput {
+    pub post_id: String,
+    /// Only approved comments are counted; readers never see the others.
+    pub comments_count: i32,
+}
+
+#[derive(WithDeps)]
+pub struct CommentsInfoInteractor {
+    comments_meta_calculator: Arc<dyn CommentsMetaCalculator>,
+}
+
+impl CommentsInfoInteractor {
+    pub async fn execute(&self, input: CommentsInfoInput) -> ApplicationResult<CommentsInfoOutput> {
+        let post_id = PostId::from(input.post_id);
+        let meta = self
+            .comments_meta_calculator
+            .get_meta(&post_id)
+            .await?
+            .ok_or_else(|| NotFoundException(format!("Post with id {} not found", post_id)))?;
+        Ok(CommentsInfoOutput {
+            post_id: post_id.to_string(),
+            comments_count: meta.approved_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::comments::interactors::test_doubles::comments_meta_calculator_spy::CommentsMetaCalculatorSpy;
+    use crate::comments::interactors::traits::CommentsMeta;
+    use crate::make_interactor_setup;
+    use crate::test_utils::errors_assertion::assert_not_found_error;
+
+    use super::*;
+
+    make_interactor_setup!(
+        CommentsInfoInteractor,
+        [(
+            comments_meta_calculator,
+            CommentsMetaCalculatorSpy::new_returning(Some(CommentsMeta {
+                approved_count: 3,
+                pending_count: 2,
+                spam_count: 1,
+            })),
+            CommentsMetaCalculatorSpy
+        )]
+    );
+
+    fn input() -> CommentsInfoInput {
+        CommentsInfoInput {
+            post_id: "post".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_error_if_post_does_not_exist() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_comments_meta_calculator(Arc::new(CommentsMetaCalculatorSpy::new_returning(None)));
+
+        let err = c.interactor.execute(input()).await.unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_return_the_approved_comments_count() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(input()).await.unwrap();
+
+        assert_eq!(
+            output,
+            CommentsInfoOutput {
+                post_id: "post".into(),
+                comments_count: 3,
+            }
+        );
+        assert_eq!(
+            *c.comments_meta_calculator.calls.lock().unwrap(),
+            [PostId::new("post")]
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::comments::domain::{Comment, CommentId, CommentStatus};
+use crate::comments::interactors::traits::CommentsRepository;
+use crate::comments::interactors::utils::VisibleComment;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::ApplicationResult;
+use crate::posts::domain::{PostId, PostStatus};
+use crate::posts::interactors::traits::PostsRepository;
+
+#[derive(Debug, Clone)]
+pub struct ListCommentsInput {
+    pub post_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentNode {
+    pub id: String,
+    /// `None` for a deleted comment kept in place because it has replies.
+    pub comment: Option<VisibleComment>,
+    /// Oldest first.
+    pub replies: Vec<CommentNode>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListCommentsOutput {
+    /// The top-level comments, oldest first.
+    pub comments: Vec<CommentNode>,
+}
+
+/// Lists the approved comments of a published post as a thread.
+///
+/// Replies to a comment that was later marked spam or sent back for moderation are hidden
+/// with it.
+#[derive(WithDeps)]
+pub struct ListCommentsInteractor {
+    repo: Arc<dyn CommentsRepository>,
+    posts_repo: Arc<dyn PostsRepository>,
+}
+
+impl ListCommentsInteractor {
+    pub async fn execute(&self, input: ListCommentsInput) -> ApplicationResult<ListCommentsOutput> {
+        let post_id = PostId::from(input.post_id);
+        let post = self.posts_repo.get_by_id_or_fail(&post_id).await?;
+        if post.status != PostStatus::Published {
+            return Err(NotFoundException(format!(
+                "Post with id {} not found",
+                post_id
+            )));
+        }
+
+        let comments = self.repo.get_by_post_id(&post_id).await?;
+        let mut replies_of: HashMap<Option<&CommentId>, Vec<&Comment>> = HashMap::new();
+        for comment in &comments {
+            replies_of
+                .entry(comment.parent_id.as_ref())
+                .or_default()
+                .push(comment);
+        }
+
+        Ok(ListCommentsOutput {
+            comments: Self::nodes(&replies_of, None),
+        })
+    }
+
+    fn nodes(
+        replies_of: &HashMap<Option<&CommentId>, Vec<&Comment>>,
+        parent_id: Option<&CommentId>,
+    ) -> Vec<CommentNode> {
+        let Some(comments) = replies_of.get(&parent_id) else {
+            return vec![];
+        };
+        comments
+            .iter()
+            .filter_map(|comment| {
+                let replies = || Self::nodes(replies_of, Some(&comment.id));
+                let (comment_shown, replies) = match comment.status {
+                    CommentStatus::Approved => (Some((*comment).clone().into()), replies()),
+                    CommentStatus::Deleted => match replies() {
+                        replies if replies.is_empty() => return None,
+                        replies => (None, replies),
+                    },
+                    CommentStatus::Pending | CommentStatus::Spam => return None,
+                };
+                Some(CommentNode {
+                    id: comment.id.to_string(),
+                    comment: comment_shown,
+                    replies,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::comments::domain::CommentAuthor;
+    use crate::comments::interactors::test_doubles::fake_comments_repository::FakeCommentsRepository;
+    use crate::make_interactor_setup;
+    use crate::posts::domain::Post;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::errors_assertion::assert_not_found_error;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ListCommentsInteractor,
+        [
+            (
+                repo,
+                FakeCommentsRepository::new_empty(),
+                FakeCommentsRepository
+            ),
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&[
+                    post("post", PostStatus::Published),
+                    post("draft", PostStatus::Draft),
+                ]),
+                FakePostsRepository
+            )
+        ]
+    );
+
+    fn post(id: &str, status: PostStatus) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.into(),
+            slug: id.into(),
+            content: "".into(),
+            author_id: "author".into(),
+            category_id: None,
+            tag_ids: vec![],
+            status,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn comment(id: &str, parent_id: Option<&str>, status: CommentStatus, minute: i64) -> Comment {
+        Comment {
+            id: CommentId::new(id),
+            post_id: PostId::new("post"),
+            parent_id: parent_id.map(CommentId::new),
+            depth: u32::from(parent_id.is_some()),
+            author: CommentAuthor::User {
+                user_id: "user".into(),
+            },
+            content: id.into(),
+            status,
+            trained_as: None,
+            created_at: Utc::now() + Duration::minutes(minute),
+        }
+    }
+
+    fn create_interactor_with(comments: &[Comment]) -> CreationResult {
+        let c = create_interactor();
+        *c.repo.comments.lock().unwrap() = comments.to_vec();
+        c
+    }
+
+    fn outline(nodes: &[CommentNode]) -> Vec<(String, bool, Vec<String>)> {
+        nodes
+            .iter()
+            .map(|node| {
+                (
+                    node.id.clone(),
+                    node.comment.is_some(),
+                    node.replies.iter().map(|reply| reply.id.clone()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    async fn list(c: &CreationResult) -> Vec<CommentNode> {
+        c.interactor
+            .execute(ListCommentsInput {
+                post_id: "post".into(),
+            })
+            .await
+            .unwrap()
+            .comments
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_post_is_missing_or_not_published() {
+        let c = create_interactor();
+
+        for post_id in ["missing", "draft"] {
+            let err = c
+                .interactor
+                .execute(ListCommentsInput {
+                    post_id: post_id.into(),
+                })
+                .await
+                .unwrap_err();
+            assert_not_found_error(err);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_thread_approved_comments_oldest_first() {
+        let c = create_interactor_with(&[
+            comment("second", None, CommentStatus::Approved, 2),
+            comment("first", None, CommentStatus::Approved, 1),
+            comment("late-reply", Some("first"), CommentStatus::Approved, 4),
+            comment("reply", Some("first"), CommentStatus::Approved, 3),
+        ]);
+
+        let comments = list(&c).await;
+
+        assert_eq!(
+            outline(&comments),
+            [
+                (
+                    "first".to_string(),
+                    true,
+                    vec!["reply".to_string(), "late-reply".to_string()]
+                ),
+                ("second".to_string(), true, vec![]),
+            ]
+        );
+        assert_eq!(comments[0].comment.as_ref().unwrap().content, "first");
+    }
+
+    #[tokio::test]
+    async fn should_hide_unapproved_comments_with_their_replies() {
+        let c = create_interactor_with(&[
+            comment("pending", None, CommentStatus::Pending, 1),
+            comment("spam", None, CommentStatus::Spam, 2),
+            comment("reply", Some("spam"), CommentStatus::Approved, 3),
+        ]);
+
+        assert!(list(&c).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_keep_deleted_comments_as_placeholders_only_when_replied_to() {
+        let c = create_interactor_with(&[
+            comment("deleted", None, CommentStatus::Deleted, 1),
+            comment("reply", Some("deleted"), CommentStatus::Approved, 2),
+            comment("lonely", None, CommentStatus::Deleted, 3),
+        ]);
+
+        assert_eq!(
+            outline(&list(&c).await),
+            [("deleted".to_string(), false, vec!["reply".to_string()])]
+        );
+    }
+}
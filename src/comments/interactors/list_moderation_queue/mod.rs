@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::comments::domain::CommentStatus;
+use crate::comments::interactors::actions::VIEW_COMMENT_QUEUE_ACTION;
+use crate::comments::interactors::traits::CommentsRepository;
+use crate::comments::interactors::utils::VisibleComment;
+use crate::errors::ApplicationResult;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone)]
+pub struct ListModerationQueueInput {
+    /// `None` lists the pending comments.
+    pub status: Option<CommentStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListModerationQueueOutput {
+    /// Oldest first, so the longest waiting comments are handled first.
+    pub comments: Vec<VisibleComment>,
+}
+
+#[derive(WithDeps)]
+pub struct ListModerationQueueInteractor {
+    repo: Arc<dyn CommentsRepository>,
+}
+
+impl ListModerationQueueInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ListModerationQueueInput,
+    ) -> ApplicationResult<ListModerationQueueOutput> {
+        auth.can_or_fail(VIEW_COMMENT_QUEUE_ACTION)?;
+
+        let status = input.status.unwrap_or(CommentStatus::Pending);
+        let comments = self.repo.get_by_status(status).await?;
+        Ok(ListModerationQueueOutput {
+            comments: comments.into_iter().map(|c| c.into()).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::comments::domain::{Comment, CommentAuthor, CommentId};
+    use crate::comments::interactors::test_doubles::fake_comments_repository::FakeCommentsRepository;
+    use crate::make_interactor_setup;
+    use crate::posts::domain::PostId;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ListModerationQueueInteractor,
+        [(
+            repo,
+            FakeCommentsRepository::new_with_data(&[
+                comment("newer", CommentStatus::Pending, 2),
+                comment("older", CommentStatus::Pending, 1),
+                comment("approved", CommentStatus::Approved, 0),
+                comment("spam", CommentStatus::Spam, 0),
+            ]),
+            FakeCommentsRepository
+        )]
+    );
+
+    fn comment(id: &str, status: CommentStatus, minute: i64) -> Comment {
+        Comment {
+            id: CommentId::new(id),
+            post_id: PostId::new("post"),
+            parent_id: None,
+            depth: 0,
+            author: CommentAuthor::Anonymous {
+                name: "Reader".into(),
+                email: None,
+            },
+            content: "comment".into(),
+            status,
+            trained_as: None,
+            created_at: Utc::now() + Duration::minutes(minute),
+        }
+    }
+
+    fn ids(output: ListModerationQueueOutput) -> Vec<String> {
+        output.comments.into_iter().map(|c| c.id).collect()
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, ListModerationQueueInput { status: None })
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [VIEW_COMMENT_QUEUE_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_list_pending_comments_oldest_first_by_default() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c
+            .interactor
+            .execute(&auth, ListModerationQueueInput { status: None })
+            .await
+            .unwrap();
+
+        assert_eq!(ids(output), ["older", "newer"]);
+    }
+
+    #[tokio::test]
+    async fn should_list_comments_of_the_given_status() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c
+            .interactor
+            .execute(
+                &auth,
+                ListModerationQueueInput {
+                    status: Some(CommentStatus::Spam),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ids(output), ["spam"]);
+    }
+}
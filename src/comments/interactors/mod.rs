@@ -0,0 +1,10 @@
+pub mod actions;
+pub mod comment_form;
+pub mod comments_info;
+pub mod list_comments;
+pub mod list_moderation_queue;
+pub mod moderate_comment;
+pub mod post_comment;
+pub mod test_doubles;
+pub mod traits;
+pub mod utils;
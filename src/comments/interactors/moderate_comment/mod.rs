@@ -0,0 +1,293 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
+use crate::comments::domain::{Comment, CommentId, CommentStatus};
+use crate::comments::interactors::actions::MODERATE_COMMENT_ACTION;
+use crate::comments::interactors::traits::{CommentsRepository, SpamClassifier};
+use crate::comments::interactors::utils::VisibleComment;
+use crate::errors::ApplicationException::BadRequestException;
+use crate::errors::ApplicationResult;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone)]
+pub struct ModerateCommentInput {
+    pub comment_id: String,
+    /// Approved, spam or deleted; comments do not go back to pending.
+    pub status: CommentStatus,
+}
+
+/// Sets the status of a comment. Marking one spam or approving it trains the spam classifier.
+///
+/// A comment is learned with one label at most, the last a moderator gave it.
+#[derive(WithDeps)]
+pub struct ModerateCommentInteractor {
+    repo: Arc<dyn CommentsRepository>,
+    spam_classifier: Arc<dyn SpamClassifier>,
+    audit: Arc<dyn AuditLog>,
+}
+
+impl ModerateCommentInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ModerateCommentInput,
+    ) -> ApplicationResult<VisibleComment> {
+        let target = input.comment_id.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            MODERATE_COMMENT_ACTION,
+            &target,
+            self.moderate(auth, input),
+        )
+        .await
+    }
+
+    async fn moderate(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ModerateCommentInput,
+    ) -> ApplicationResult<VisibleComment> {
+        auth.can_or_fail(MODERATE_COMMENT_ACTION)?;
+        if input.status == CommentStatus::Pending {
+            return Err(BadRequestException(
+                "comments cannot be sent back to pending".into(),
+            ));
+        }
+
+        let mut comment = self
+            .repo
+            .get_by_id_or_fail(&CommentId::from(input.comment_id))
+            .await?;
+        if comment.status != input.status {
+            comment.status = input.status;
+            self.retrain(&mut comment).await?;
+            self.repo.update(&comment).await?;
+        }
+        Ok(comment.into())
+    }
+
+    /// Teaches the classifier the label the new status gives the comment, taking back the one
+    /// it was taught before. Deleting a comment says nothing about it, so the label stays.
+    async fn retrain(&self, comment: &mut Comment) -> ApplicationResult<()> {
+        let label = match comment.status {
+            CommentStatus::Spam => true,
+            CommentStatus::Approved => false,
+            CommentStatus::Pending | CommentStatus::Deleted => return Ok(()),
+        };
+        if comment.trained_as == Some(label) {
+            return Ok(());
+        }
+        if let Some(previous) = comment.trained_as {
+            self.spam_classifier
+                .untrain(&comment.content, previous)
+                .await?;
+        }
+        self.spam_classifier.train(&comment.content, label).await?;
+        comment.trained_as = Some(label);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::comments::domain::CommentAuthor;
+    use crate::comments::interactors::test_doubles::fake_comments_repository::FakeCommentsRepository;
+    use crate::comments::interactors::test_doubles::spam_classifier_spy::SpamClassifierSpy;
+    use crate::make_interactor_setup;
+    use crate::posts::domain::PostId;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::*;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ModerateCommentInteractor,
+        [
+            (
+                repo,
+                FakeCommentsRepository::new_with_data(&[pending_comment()]),
+                FakeCommentsRepository
+            ),
+            (
+                spam_classifier,
+                SpamClassifierSpy::default(),
+                SpamClassifierSpy
+            ),
+            (audit, AuditLogSpy::default(), AuditLogSpy)
+        ]
+    );
+
+    fn pending_comment() -> Comment {
+        Comment {
+            id: CommentId::new("comment"),
+            post_id: PostId::new("post"),
+            parent_id: None,
+            depth: 0,
+            author: CommentAuthor::Anonymous {
+                name: "Reader".into(),
+                email: None,
+            },
+            content: "comment".into(),
+            status: CommentStatus::Pending,
+            trained_as: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn input(status: CommentStatus) -> ModerateCommentInput {
+        ModerateCommentInput {
+            comment_id: "comment".into(),
+            status,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input(CommentStatus::Approved))
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [MODERATE_COMMENT_ACTION]);
+        assert_forbidden_error(err);
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Denied);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_comment_does_not_exist() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(
+                &auth,
+                ModerateCommentInput {
+                    comment_id: "missing".into(),
+                    status: CommentStatus::Approved,
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_bad_request_when_moving_back_to_pending() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input(CommentStatus::Pending))
+            .await
+            .unwrap_err();
+
+        assert_bad_request_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_store_the_new_status() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        for status in [
+            CommentStatus::Approved,
+            CommentStatus::Spam,
+            CommentStatus::Deleted,
+        ] {
+            let output = c.interactor.execute(&auth, input(status)).await.unwrap();
+
+            assert_eq!(output.status, status);
+            assert_eq!(c.repo.get_comments()[0].status, status);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_train_the_spam_classifier_once_per_label() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        for status in [
+            CommentStatus::Spam,
+            CommentStatus::Deleted,
+            CommentStatus::Spam,
+        ] {
+            c.interactor.execute(&auth, input(status)).await.unwrap();
+        }
+
+        assert_eq!(
+            c.spam_classifier.get_trained(),
+            [("comment".to_string(), true)]
+        );
+        assert!(c.spam_classifier.get_untrained().is_empty());
+        assert_eq!(c.repo.get_comments()[0].trained_as, Some(true));
+    }
+
+    #[tokio::test]
+    async fn should_take_back_the_previous_label_when_it_changes() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        for status in [CommentStatus::Spam, CommentStatus::Approved] {
+            c.interactor.execute(&auth, input(status)).await.unwrap();
+        }
+
+        assert_eq!(
+            c.spam_classifier.get_trained(),
+            [
+                ("comment".to_string(), true),
+                ("comment".to_string(), false)
+            ]
+        );
+        assert_eq!(
+            c.spam_classifier.get_untrained(),
+            [("comment".to_string(), true)]
+        );
+        assert_eq!(c.repo.get_comments()[0].trained_as, Some(false));
+    }
+
+    #[tokio::test]
+    async fn should_record_the_moderation_in_the_audit_log() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor
+            .execute(&auth, input(CommentStatus::Spam))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: MODERATE_COMMENT_ACTION.into(),
+                    target: "comment".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: MODERATE_COMMENT_ACTION.into(),
+                    target: "comment".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+}
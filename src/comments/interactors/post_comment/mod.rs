@@ -0,0 +1,549 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::comments::domain::{
+    Comment, CommentAuthor, CommentId, CommentStatus, CommentSubmission,
+};
+use crate::comments::interactors::actions::MODERATE_COMMENT_ACTION;
+use crate::comments::interactors::traits::{CommentsRepository, FormStamper, SpamClassifier};
+use crate::comments::interactors::utils::{CommentsConfig, VisibleComment};
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationException::{BadRequestException, NotFoundException};
+use crate::errors::ApplicationResult;
+use crate::posts::domain::{PostId, PostStatus};
+use crate::posts::interactors::traits::PostsRepository;
+use crate::utils::{AuthPayload, Clock, RandomService, Validatable};
+
+#[derive(Debug, Clone)]
+pub struct PostCommentInput {
+    pub post_id: String,
+    pub parent_id: Option<String>,
+    /// Required from anonymous commenters, ignored for signed in ones.
+    pub author_name: Option<String>,
+    pub author_email: Option<String>,
+    pub content: String,
+    /// The hidden form field bots fill in.
+    pub honeypot: Option<String>,
+    /// The stamp the comment form was served with, see `CommentFormInteractor`.
+    pub form_stamp: Option<String>,
+}
+
+impl Validatable for PostCommentInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.content.trim().is_empty() {
+            return Err(ValidationError::new(
+                "content".into(),
+                self.content.clone(),
+                "content is empty".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Adds a comment or a reply to a published post.
+///
+/// Comments wait for a moderator unless their author is a moderator themselves, or go
+/// straight to spam when the classifier is sure enough.
+#[derive(WithDeps)]
+pub struct PostCommentInteractor {
+    repo: Arc<dyn CommentsRepository>,
+    posts_repo: Arc<dyn PostsRepository>,
+    spam_classifier: Arc<dyn SpamClassifier>,
+    form_stamper: Arc<dyn FormStamper>,
+    random: Arc<dyn RandomService>,
+    clock: Arc<dyn Clock>,
+    config: Arc<CommentsConfig>,
+}
+
+impl PostCommentInteractor {
+    pub async fn execute(
+        &self,
+        auth: Option<&(dyn AuthPayload)>,
+        input: PostCommentInput,
+    ) -> ApplicationResult<VisibleComment> {
+        input.validate()?;
+        if input.content.chars().count() > self.config.max_length {
+            return Err(ValidationError::new(
+                "content".into(),
+                input.content,
+                format!(
+                    "content is longer than {} characters",
+                    self.config.max_length
+                ),
+            )
+            .into());
+        }
+        let author = Self::author(auth, &input)?;
+
+        let post_id = PostId::new(&input.post_id);
+        let post = self.posts_repo.get_by_id_or_fail(&post_id).await?;
+        if post.status != PostStatus::Published {
+            return Err(NotFoundException(format!(
+                "Post with id {} not found",
+                post_id
+            )));
+        }
+
+        let depth = match &input.parent_id {
+            Some(parent_id) => {
+                self.reply_depth(&post_id, parent_id.as_str().into())
+                    .await?
+            }
+            None => 0,
+        };
+
+        let status = match auth {
+            Some(auth) if auth.can(MODERATE_COMMENT_ACTION) => CommentStatus::Approved,
+            _ => self.unmoderated_status(&input).await?,
+        };
+        let comment = Comment {
+            id: CommentId::new(&self.random.random_id().await?),
+            post_id,
+            parent_id: input.parent_id.map(CommentId::from),
+            depth,
+            author,
+            content: input.content.trim().to_string(),
+            status,
+            trained_as: None,
+            created_at: self.clock.now(),
+        };
+        self.repo.create(&comment).await?;
+        Ok(comment.into())
+    }
+
+    fn author(
+        auth: Option<&(dyn AuthPayload)>,
+        input: &PostCommentInput,
+    ) -> ApplicationResult<CommentAuthor> {
+        if let Some(auth) = auth {
+            return Ok(CommentAuthor::User {
+                user_id: auth.get_user_id(),
+            });
+        }
+        let name = input.author_name.as_deref().unwrap_or_default().trim();
+        if name.is_empty() {
+            return Err(ValidationError::new(
+                "author_name".into(),
+                name.into(),
+                "anonymous comments need a name".into(),
+            )
+            .into());
+        }
+        Ok(CommentAuthor::Anonymous {
+            name: name.into(),
+            email: input.author_email.clone(),
+        })
+    }
+
+    async fn unmoderated_status(
+        &self,
+        input: &PostCommentInput,
+    ) -> ApplicationResult<CommentStatus> {
+        let submission = CommentSubmission {
+            content: input.content.clone(),
+            author_name: input.author_name.clone(),
+            author_email: input.author_email.clone(),
+            honeypot: input.honeypot.clone(),
+            form_rendered_at: input
+                .form_stamp
+                .as_deref()
+                .and_then(|stamp| self.form_stamper.verify(stamp)),
+            submitted_at: self.clock.now(),
+        };
+        let assessment = self.spam_classifier.assess(&submission).await?;
+        if assessment.probability >= self.config.spam_threshold {
+            Ok(CommentStatus::Spam)
+        } else {
+            Ok(CommentStatus::Pending)
+        }
+    }
+
+    /// Replies go under approved comments of the same post only.
+    async fn reply_depth(&self, post_id: &PostId, parent_id: CommentId) -> ApplicationResult<u32> {
+        let parent = match self.repo.get_by_id(&parent_id).await? {
+            Some(parent)
+                if parent.post_id == *post_id && parent.status == CommentStatus::Approved =>
+            {
+                parent
+            }
+            _ => {
+                return Err(NotFoundException(format!(
+                    "Comment with id {} not found",
+                    parent_id
+                )))
+            }
+        };
+
+        let depth = parent.depth + 1;
+        if depth > self.config.max_depth {
+            return Err(BadRequestException(format!(
+                "replies cannot nest deeper than {} levels",
+                self.config.max_depth
+            )));
+        }
+        Ok(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::comments::interactors::test_doubles::fake_comments_repository::FakeCommentsRepository;
+    use crate::comments::interactors::test_doubles::spam_classifier_spy::SpamClassifierSpy;
+    use crate::comments::services::HmacFormStamper;
+    use crate::make_interactor_setup;
+    use crate::posts::domain::Post;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::crypto::random_service_spy::{RandomServiceSpy, RANDOM_ID};
+    use crate::test_utils::errors_assertion::*;
+
+    use super::*;
+
+    const SECRET: &[u8] = b"secret";
+
+    make_interactor_setup!(
+        PostCommentInteractor,
+        [
+            (
+                repo,
+                FakeCommentsRepository::new_with_data(&[
+                    comment("top", 0, CommentStatus::Approved),
+                    comment("deepest", 2, CommentStatus::Approved),
+                    comment("pending", 0, CommentStatus::Pending),
+                ]),
+                FakeCommentsRepository
+            ),
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&[
+                    post("post", PostStatus::Published),
+                    post("draft", PostStatus::Draft),
+                ]),
+                FakePostsRepository
+            ),
+            (
+                spam_classifier,
+                SpamClassifierSpy::new_returning(0.5),
+                SpamClassifierSpy
+            ),
+            (form_stamper, form_stamper(SECRET), HmacFormStamper),
+            (random, RandomServiceSpy::new(), RandomServiceSpy),
+            (clock, FakeClock::new_fixed(), FakeClock),
+            (
+                config,
+                CommentsConfig {
+                    max_depth: 2,
+                    max_length: 20,
+                    spam_threshold: 0.9,
+                },
+                CommentsConfig
+            )
+        ]
+    );
+
+    fn form_stamper(secret: &[u8]) -> HmacFormStamper {
+        HmacFormStamper::new(secret, Arc::new(FakeClock::new_fixed()), Duration::hours(2))
+    }
+
+    fn post(id: &str, status: PostStatus) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.into(),
+            slug: id.into(),
+            content: "".into(),
+            author_id: "author".into(),
+            category_id: None,
+            tag_ids: vec![],
+            status,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn comment(id: &str, depth: u32, status: CommentStatus) -> Comment {
+        Comment {
+            id: CommentId::new(id),
+            post_id: PostId::new("post"),
+            parent_id: None,
+            depth,
+            author: CommentAuthor::User {
+                user_id: "user".into(),
+            },
+            content: "comment".into(),
+            status,
+            trained_as: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn anonymous_input() -> PostCommentInput {
+        PostCommentInput {
+            post_id: "post".into(),
+            parent_id: None,
+            author_name: Some(" Reader ".into()),
+            author_email: Some("reader@example.com".into()),
+            content: " Nice post ".into(),
+            honeypot: None,
+            form_stamp: Some(form_stamper(SECRET).issue(fixed_now() - Duration::minutes(1))),
+        }
+    }
+
+    fn created(c: &CreationResult) -> Comment {
+        c.repo
+            .get_comments()
+            .into_iter()
+            .find(|comment| comment.id.to_string() == RANDOM_ID)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_hold_anonymous_comments_for_moderation() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(None, anonymous_input()).await.unwrap();
+
+        assert_eq!(
+            created(&c),
+            Comment {
+                id: CommentId::new(RANDOM_ID),
+                post_id: PostId::new("post"),
+                parent_id: None,
+                depth: 0,
+                author: CommentAuthor::Anonymous {
+                    name: "Reader".into(),
+                    email: Some("reader@example.com".into()),
+                },
+                content: "Nice post".into(),
+                status: CommentStatus::Pending,
+                trained_as: None,
+                created_at: fixed_now(),
+            }
+        );
+        assert_eq!(output, created(&c).into());
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_if_anonymous_commenter_has_no_name() {
+        let c = create_interactor();
+
+        let err = c
+            .interactor
+            .execute(
+                None,
+                PostCommentInput {
+                    author_name: Some("  ".into()),
+                    ..anonymous_input()
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_validation_error_with_key(err, "author_name");
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_empty_or_long_content() {
+        let c = create_interactor();
+
+        for content in ["  ", "a comment longer than twenty characters"] {
+            let err = c
+                .interactor
+                .execute(
+                    None,
+                    PostCommentInput {
+                        content: content.into(),
+                        ..anonymous_input()
+                    },
+                )
+                .await
+                .unwrap_err();
+            assert_validation_error_with_key(err, "content");
+        }
+    }
+
+    #[tokio::test]
+    async fn should_attribute_comments_to_signed_in_users() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("user-id".into());
+
+        c.interactor
+            .execute(Some(&auth), anonymous_input())
+            .await
+            .unwrap();
+
+        let comment = created(&c);
+        assert_eq!(
+            comment.author,
+            CommentAuthor::User {
+                user_id: "user-id".into()
+            }
+        );
+        assert_eq!(comment.status, CommentStatus::Pending);
+        assert_eq!(auth.get_called(), [MODERATE_COMMENT_ACTION]);
+    }
+
+    #[tokio::test]
+    async fn should_approve_comments_of_moderators_right_away() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("moderator".into());
+
+        c.interactor
+            .execute(Some(&auth), anonymous_input())
+            .await
+            .unwrap();
+
+        assert_eq!(created(&c).status, CommentStatus::Approved);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_post_is_missing_or_not_published() {
+        let c = create_interactor();
+
+        for post_id in ["missing", "draft"] {
+            let err = c
+                .interactor
+                .execute(
+                    None,
+                    PostCommentInput {
+                        post_id: post_id.into(),
+                        ..anonymous_input()
+                    },
+                )
+                .await
+                .unwrap_err();
+            assert_not_found_error(err);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_nest_replies_one_level_below_the_parent() {
+        let c = create_interactor();
+
+        c.interactor
+            .execute(
+                None,
+                PostCommentInput {
+                    parent_id: Some("top".into()),
+                    ..anonymous_input()
+                },
+            )
+            .await
+            .unwrap();
+
+        let reply = created(&c);
+        assert_eq!(reply.parent_id, Some(CommentId::new("top")));
+        assert_eq!(reply.depth, 1);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_when_replying_to_unapproved_comments() {
+        let c = create_interactor();
+
+        for parent_id in ["missing", "pending"] {
+            let err = c
+                .interactor
+                .execute(
+                    None,
+                    PostCommentInput {
+                        parent_id: Some(parent_id.into()),
+                        ..anonymous_input()
+                    },
+                )
+                .await
+                .unwrap_err();
+            assert_not_found_error(err);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_bad_request_past_the_depth_limit() {
+        let c = create_interactor();
+
+        let err = c
+            .interactor
+            .execute(
+                None,
+                PostCommentInput {
+                    parent_id: Some("deepest".into()),
+                    ..anonymous_input()
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_bad_request_error(err);
+        assert_eq!(c.repo.get_comments().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn should_pass_the_submission_to_the_spam_classifier() {
+        let c = create_interactor();
+
+        c.interactor.execute(None, anonymous_input()).await.unwrap();
+
+        assert_eq!(
+            c.spam_classifier.get_assessed(),
+            [CommentSubmission {
+                content: " Nice post ".into(),
+                author_name: Some(" Reader ".into()),
+                author_email: Some("reader@example.com".into()),
+                honeypot: None,
+                form_rendered_at: Some(fixed_now() - Duration::minutes(1)),
+                submitted_at: fixed_now(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_trust_a_forged_or_missing_form_stamp() {
+        let c = create_interactor();
+        let forged = form_stamper(b"guessed").issue(fixed_now() - Duration::hours(1));
+
+        for form_stamp in [Some(forged), None] {
+            c.interactor
+                .execute(
+                    None,
+                    PostCommentInput {
+                        form_stamp,
+                        ..anonymous_input()
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        let assessed = c.spam_classifier.get_assessed();
+        assert_eq!(assessed.len(), 2);
+        assert!(assessed.iter().all(|s| s.form_rendered_at.is_none()));
+    }
+
+    #[tokio::test]
+    async fn should_send_likely_spam_straight_to_spam() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_spam_classifier(Arc::new(SpamClassifierSpy::new_returning(0.9)));
+
+        c.interactor.execute(None, anonymous_input()).await.unwrap();
+
+        assert_eq!(created(&c).status, CommentStatus::Spam);
+    }
+
+    #[tokio::test]
+    async fn should_not_classify_comments_of_moderators() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("moderator".into());
+
+        c.interactor
+            .execute(Some(&auth), anonymous_input())
+            .await
+            .unwrap();
+
+        assert!(c.spam_classifier.get_assessed().is_empty());
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Mutex;
+
+use crate::comments::interactors::traits::{CommentsMeta, CommentsMetaCalculator};
+use crate::errors::UnknownResult;
+use crate::posts::domain::PostId;
+
+#[derive(Debug)]
+pub struct CommentsMetaCalculatorSpy {
+    pub result: Option<CommentsMeta>,
+    pub calls: Mutex<Vec<PostId>>,
+}
+
+#[async_trait::async_trait]
+impl CommentsMetaCalculator for CommentsMetaCalculatorSpy {
+    async fn get_meta(&self, post_id: &PostId) -> UnknownResult<Option<CommentsMeta>> {
+        self.calls.lock().unwrap().push(post_id.clone());
+        Ok(self.result.clone())
+    }
+}
+
+impl CommentsMetaCalculatorSpy {
+    pub fn new_returning(result: Option<CommentsMeta>) -> Self {
+        Self {
+            result,
+            calls: Mutex::new(vec![]),
+        }
+    }
+}
+
+impl Default for CommentsMetaCalculatorSpy {
+    fn default() -> Self {
+        Self {
+            result: Some(CommentsMeta::default()),
+            calls: Mutex::new(vec![]),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Mutex;
+
+use crate::comments::domain::{Comment, CommentId, CommentStatus};
+use crate::comments::interactors::traits::CommentsRepository;
+use crate::errors::UnknownResult;
+use crate::posts::domain::PostId;
+
+pub struct FakeCommentsRepository {
+    pub comments: Mutex<Vec<Comment>>,
+}
+
+impl FakeCommentsRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            comments: Mutex::new(Vec::new()),
+        }
+    }
+    pub fn new_with_data(comments: &[Comment]) -> Self {
+        Self {
+            comments: Mutex::new(comments.to_vec()),
+        }
+    }
+    pub fn get_comments(&self) -> Vec<Comment> {
+        self.comments.lock().unwrap().clone()
+    }
+
+    fn find(&self, f: impl Fn(&Comment) -> bool) -> Vec<Comment> {
+        let mut comments: Vec<Comment> = self
+            .comments
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| f(c))
+            .cloned()
+            .collect();
+        comments.sort_by_key(|c| c.created_at);
+        comments
+    }
+}
+
+#[async_trait::async_trait]
+impl CommentsRepository for FakeCommentsRepository {
+    async fn get_by_id(&self, id: &CommentId) -> UnknownResult<Option<Comment>> {
+        let comments = self.comments.lock().unwrap();
+        Ok(comments.iter().find(|c| c.id == *id).cloned())
+    }
+
+    async fn get_by_post_id(&self, post_id: &PostId) -> UnknownResult<Vec<Comment>> {
+        Ok(self.find(|c| c.post_id == *post_id))
+    }
+
+    async fn get_by_status(&self, status: CommentStatus) -> UnknownResult<Vec<Comment>> {
+        Ok(self.find(|c| c.status == status))
+    }
+
+    async fn create(&self, comment: &Comment) -> UnknownResult<Comment> {
+        self.comments.lock().unwrap().push(comment.clone());
+        Ok(comment.clone())
+    }
+
+    async fn update(&self, comment: &Comment) -> UnknownResult<Comment> {
+        let mut comments = self.comments.lock().unwrap();
+        let index = comments.iter().position(|c| c.id == comment.id).unwrap();
+        comments[index] = comment.clone();
+        Ok(comment.clone())
+    }
+}
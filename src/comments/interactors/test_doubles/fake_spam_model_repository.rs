@@ -0,0 +1,32 @@
+use std::sync::Mutex;
+
+use crate::comments::domain::SpamModel;
+use crate::comments::interactors::traits::SpamModelRepository;
+use crate::errors::UnknownResult;
+
+pub struct FakeSpamModelRepository {
+    pub model: Mutex<Option<SpamModel>>,
+}
+
+impl FakeSpamModelRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            model: Mutex::new(None),
+        }
+    }
+    pub fn get_model(&self) -> Option<SpamModel> {
+        self.model.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl SpamModelRepository for FakeSpamModelRepository {
+    async fn get(&self) -> UnknownResult<Option<SpamModel>> {
+        Ok(self.get_model())
+    }
+
+    async fn save(&self, model: &SpamModel) -> UnknownResult<()> {
+        *self.model.lock().unwrap() = Some(model.clone());
+        Ok(())
+    }
+}
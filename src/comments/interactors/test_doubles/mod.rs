@@ -0,0 +1,4 @@
+pub mod comments_meta_calculator_spy;
+pub mod fake_comments_repository;
+pub mod fake_spam_model_repository;
+pub mod spam_classifier_spy;
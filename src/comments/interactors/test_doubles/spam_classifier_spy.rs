@@ -0,0 +1,65 @@
+use std::sync::Mutex;
+
+use crate::comments::domain::{CommentSubmission, SpamAssessment};
+use crate::comments::interactors::traits::SpamClassifier;
+use crate::errors::UnknownResult;
+
+pub struct SpamClassifierSpy {
+    pub probability: f64,
+    pub assessed: Mutex<Vec<CommentSubmission>>,
+    pub trained: Mutex<Vec<(String, bool)>>,
+    pub untrained: Mutex<Vec<(String, bool)>>,
+}
+
+#[async_trait::async_trait]
+impl SpamClassifier for SpamClassifierSpy {
+    async fn assess(&self, submission: &CommentSubmission) -> UnknownResult<SpamAssessment> {
+        self.assessed.lock().unwrap().push(submission.clone());
+        Ok(SpamAssessment {
+            probability: self.probability,
+            signals: vec![],
+        })
+    }
+
+    async fn train(&self, content: &str, is_spam: bool) -> UnknownResult<()> {
+        self.trained
+            .lock()
+            .unwrap()
+            .push((content.to_string(), is_spam));
+        Ok(())
+    }
+
+    async fn untrain(&self, content: &str, is_spam: bool) -> UnknownResult<()> {
+        self.untrained
+            .lock()
+            .unwrap()
+            .push((content.to_string(), is_spam));
+        Ok(())
+    }
+}
+
+impl SpamClassifierSpy {
+    pub fn new_returning(probability: f64) -> Self {
+        Self {
+            probability,
+            assessed: Mutex::new(vec![]),
+            trained: Mutex::new(vec![]),
+            untrained: Mutex::new(vec![]),
+        }
+    }
+    pub fn get_assessed(&self) -> Vec<CommentSubmission> {
+        self.assessed.lock().unwrap().clone()
+    }
+    pub fn get_trained(&self) -> Vec<(String, bool)> {
+        self.trained.lock().unwrap().clone()
+    }
+    pub fn get_untrained(&self) -> Vec<(String, bool)> {
+        self.untrained.lock().unwrap().clone()
+    }
+}
+
+impl Default for SpamClassifierSpy {
+    fn default() -> Self {
+        Self::new_returning(0.0)
+    }
+}
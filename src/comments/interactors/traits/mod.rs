@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+
+use crate::comments::domain::{
+    Comment, CommentId, CommentStatus, CommentSubmission, SpamAssessment, SpamModel,
+};
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::posts::domain::PostId;
+
+#[async_trait::async_trait]
+pub trait CommentsRepository: Send + Sync {
+    async fn get_by_id(&self, id: &CommentId) -> UnknownResult<Option<Comment>>;
+    /// Every comment on the post whatever its status, oldest first.
+    async fn get_by_post_id(&self, post_id: &PostId) -> UnknownResult<Vec<Comment>>;
+    /// Oldest first.
+    async fn get_by_status(&self, status: CommentStatus) -> UnknownResult<Vec<Comment>>;
+    async fn create(&self, comment: &Comment) -> UnknownResult<Comment>;
+    async fn update(&self, comment: &Comment) -> UnknownResult<Comment>;
+
+    async fn get_by_id_or_fail(&self, id: &CommentId) -> ApplicationResult<Comment> {
+        let comment = self.get_by_id(id).await?;
+        comment.ok_or_else(|| NotFoundException(format!("Comment with id {} not found", id)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CommentsMeta {
+    pub approved_count: i32,
+    pub pending_count: i32,
+    pub spam_count: i32,
+}
+
+#[async_trait::async_trait]
+pub trait CommentsMetaCalculator: Send + Sync {
+    /// Returns `None` when the post does not exist.
+    async fn get_meta(&self, post_id: &PostId) -> UnknownResult<Option<CommentsMeta>>;
+}
+
+#[async_trait::async_trait]
+pub trait SpamClassifier: Send + Sync {
+    async fn assess(&self, submission: &CommentSubmission) -> UnknownResult<SpamAssessment>;
+    /// Learns from a comment a moderator marked as spam or as ham.
+    async fn train(&self, content: &str, is_spam: bool) -> UnknownResult<()>;
+    /// Forgets a comment it was trained on with the same label.
+    async fn untrain(&self, content: &str, is_spam: bool) -> UnknownResult<()>;
+}
+
+/// Signs when the comment form was served, so the commenter cannot claim a different time.
+pub trait FormStamper: Send + Sync {
+    fn issue(&self, rendered_at: DateTime<Utc>) -> String;
+    /// When the form carrying `stamp` was served, `None` when the stamp was not issued here or
+    /// is too old to trust.
+    fn verify(&self, stamp: &str) -> Option<DateTime<Utc>>;
+}
+
+#[async_trait::async_trait]
+pub trait SpamModelRepository: Send + Sync {
+    async fn get(&self) -> UnknownResult<Option<SpamModel>>;
+    async fn save(&self, model: &SpamModel) -> UnknownResult<()>;
+}
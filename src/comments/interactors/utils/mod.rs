@@ -0,0 +1,54 @@
+use crate::comments::domain::{Comment, CommentAuthor, CommentStatus};
+
+#[derive(Debug, Clone)]
+pub struct CommentsConfig {
+    /// The deepest a reply may nest; 0 allows top-level comments only.
+    pub max_depth: u32,
+    /// In characters.
+    pub max_length: usize,
+    /// Comments at least this likely to be spam skip the moderation queue and go to spam.
+    pub spam_threshold: f64,
+}
+
+impl Default for CommentsConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 4,
+            max_length: 5000,
+            spam_threshold: 0.9,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct VisibleComment {
+    pub id: String,
+    pub post_id: String,
+    pub parent_id: Option<String>,
+    /// Set for anonymous commenters.
+    pub author_name: Option<String>,
+    /// Set for commenters with an account.
+    pub author_id: Option<String>,
+    pub content: String,
+    pub status: CommentStatus,
+    pub created_at: String,
+}
+
+impl From<Comment> for VisibleComment {
+    fn from(comment: Comment) -> Self {
+        let (author_name, author_id) = match comment.author {
+            CommentAuthor::Anonymous { name, .. } => (Some(name), None),
+            CommentAuthor::User { user_id } => (None, Some(user_id)),
+        };
+        VisibleComment {
+            id: comment.id.to_string(),
+            post_id: comment.post_id.to_string(),
+            parent_id: comment.parent_id.map(|id| id.to_string()),
+            author_name,
+            author_id,
+            content: comment.content,
+            status: comment.status,
+            created_at: comment.created_at.to_rfc2822(),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::comments::domain::CommentStatus;
+use crate::comments::interactors::traits::{
+    CommentsMeta, CommentsMetaCalculator, CommentsRepository,
+};
+use crate::errors::UnknownResult;
+use crate::posts::domain::PostId;
+use crate::posts::interactors::traits::PostsRepository;
+
+/// Counts the comments of a post straight from the repositories on every lookup.
+pub struct RepositoryCommentsMetaCalculator {
+    comments_repo: Arc<dyn CommentsRepository>,
+    posts_repo: Arc<dyn PostsRepository>,
+}
+
+impl RepositoryCommentsMetaCalculator {
+    pub fn new(
+        comments_repo: Arc<dyn CommentsRepository>,
+        posts_repo: Arc<dyn PostsRepository>,
+    ) -> Self {
+        Self {
+            comments_repo,
+            posts_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommentsMetaCalculator for RepositoryCommentsMetaCalculator {
+    async fn get_meta(&self, post_id: &PostId) -> UnknownResult<Option<CommentsMeta>> {
+        if self.posts_repo.get_by_id(post_id).await?.is_none() {
+            return Ok(None);
+        }
+        let mut meta = CommentsMeta::default();
+        for comment in self.comments_repo.get_by_post_id(post_id).await? {
+            match comment.status {
+                CommentStatus::Approved => meta.approved_count += 1,
+                CommentStatus::Pending => meta.pending_count += 1,
+                CommentStatus::Spam => meta.spam_count += 1,
+                CommentStatus::Deleted => {}
+            }
+        }
+        Ok(Some(meta))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::comments::domain::{Comment, CommentAuthor, CommentId};
+    use crate::comments::interactors::test_doubles::fake_comments_repository::FakeCommentsRepository;
+    use crate::posts::domain::{Post, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+
+    use super::*;
+
+    fn create_calculator() -> RepositoryCommentsMetaCalculator {
+        RepositoryCommentsMetaCalculator::new(
+            Arc::new(FakeCommentsRepository::new_with_data(&[
+                comment("1", "post", CommentStatus::Approved),
+                comment("2", "post", CommentStatus::Approved),
+                comment("3", "post", CommentStatus::Pending),
+                comment("4", "post", CommentStatus::Spam),
+                comment("5", "post", CommentStatus::Deleted),
+                comment("6", "other", CommentStatus::Approved),
+            ])),
+            Arc::new(FakePostsRepository::new_with_data(&[
+                post("post"),
+                post("quiet"),
+            ])),
+        )
+    }
+
+    fn post(id: &str) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.into(),
+            slug: id.into(),
+            content: "".into(),
+            author_id: "author".into(),
+            category_id: None,
+            tag_ids: vec![],
+            status: PostStatus::Published,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn comment(id: &str, post_id: &str, status: CommentStatus) -> Comment {
+        Comment {
+            id: CommentId::new(id),
+            post_id: PostId::new(post_id),
+            parent_id: None,
+            depth: 0,
+            author: CommentAuthor::User {
+                user_id: "user".into(),
+            },
+            content: "".into(),
+            status,
+            trained_as: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_post_does_not_exist() {
+        let meta = create_calculator()
+            .get_meta(&PostId::new("missing"))
+            .await
+            .unwrap();
+
+        assert!(meta.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_count_the_comments_of_the_post_by_status() {
+        let calculator = create_calculator();
+
+        assert_eq!(
+            calculator.get_meta(&PostId::new("post")).await.unwrap(),
+            Some(CommentsMeta {
+                approved_count: 2,
+                pending_count: 1,
+                spam_count: 1,
+            })
+        );
+        assert_eq!(
+            calculator.get_meta(&PostId::new("quiet")).await.unwrap(),
+            Some(CommentsMeta::default())
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::comments::interactors::traits::FormStamper;
+use crate::utils::Clock;
+
+/// Stamps are the time in milliseconds and its HMAC-SHA256 under a secret only the server
+/// knows, joined by a dot.
+///
+/// Stamps older than `max_age` are refused, so a captured stamp can not be replayed to skip
+/// the fill time check forever.
+pub struct HmacFormStamper {
+    secret: Vec<u8>,
+    clock: Arc<dyn Clock>,
+    max_age: Duration,
+}
+
+impl HmacFormStamper {
+    pub fn new(secret: &[u8], clock: Arc<dyn Clock>, max_age: Duration) -> Self {
+        Self {
+            secret: secret.to_vec(),
+            clock,
+            max_age,
+        }
+    }
+
+    fn mac(&self, millis: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret).unwrap();
+        mac.update(millis.as_bytes());
+        mac
+    }
+}
+
+impl FormStamper for HmacFormStamper {
+    fn issue(&self, rendered_at: DateTime<Utc>) -> String {
+        let millis = rendered_at.timestamp_millis().to_string();
+        let signature = self.mac(&millis).finalize().into_bytes();
+        format!("{}.{:x}", millis, signature)
+    }
+
+    fn verify(&self, stamp: &str) -> Option<DateTime<Utc>> {
+        let (millis, signature) = stamp.split_once('.')?;
+        self.mac(millis)
+            .verify_slice(&decode_hex(signature)?)
+            .ok()?;
+        let rendered_at = DateTime::from_timestamp_millis(millis.parse().ok()?)?;
+        let age = self.clock.now() - rendered_at;
+        (age >= Duration::zero() && age <= self.max_age).then_some(rendered_at)
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+
+    use super::*;
+
+    fn create_stamper(secret: &[u8]) -> HmacFormStamper {
+        HmacFormStamper::new(secret, Arc::new(FakeClock::new_fixed()), Duration::hours(1))
+    }
+
+    #[test]
+    fn should_verify_the_stamps_it_issued() {
+        let stamper = create_stamper(b"secret");
+
+        let stamp = stamper.issue(fixed_now());
+
+        assert_eq!(stamper.verify(&stamp), Some(fixed_now()));
+    }
+
+    #[test]
+    fn should_reject_stamps_past_their_age_or_from_the_future() {
+        let stamper = create_stamper(b"secret");
+
+        for rendered_at in [
+            fixed_now() - Duration::hours(1) - Duration::seconds(1),
+            fixed_now() + Duration::seconds(1),
+        ] {
+            assert_eq!(stamper.verify(&stamper.issue(rendered_at)), None);
+        }
+        let oldest = fixed_now() - Duration::hours(1);
+        assert_eq!(stamper.verify(&stamper.issue(oldest)), Some(oldest));
+    }
+
+    #[test]
+    fn should_reject_forged_or_malformed_stamps() {
+        let stamper = create_stamper(b"secret");
+        let stamp = stamper.issue(fixed_now());
+        let (_, signature) = stamp.split_once('.').unwrap();
+        let earlier = (fixed_now().timestamp_millis() - 60_000).to_string();
+
+        for forged in [
+            format!("{}.{}", earlier, signature),
+            create_stamper(b"other").issue(fixed_now()),
+            earlier,
+            format!("{}.zz", fixed_now().timestamp_millis()),
+            String::new(),
+        ] {
+            assert_eq!(stamper.verify(&forged), None, "{}", forged);
+        }
+    }
+}
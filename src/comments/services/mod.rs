@@ -0,0 +1,7 @@
+pub use comments_meta_calculator::RepositoryCommentsMetaCalculator;
+pub use hmac_form_stamper::HmacFormStamper;
+pub use offline_spam_classifier::{OfflineSpamClassifier, SpamFilterConfig};
+
+mod comments_meta_calculator;
+mod hmac_form_stamper;
+mod offline_spam_classifier;
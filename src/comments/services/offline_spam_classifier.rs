@@ -0,0 +1,424 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Duration;
+use regex::Regex;
+use tokio::sync::Mutex;
+
+use crate::comments::domain::{CommentSubmission, SpamAssessment, SpamModel, SpamSignal};
+use crate::comments::interactors::traits::{SpamClassifier, SpamModelRepository};
+use crate::errors::UnknownResult;
+
+// log-odds each signal adds on top of what the model thinks; a missing stamp alone stays below
+// the default spam threshold, API clients posting without a form being legitimate
+const FORM_NOT_STAMPED_WEIGHT: f64 = 2.0;
+const SUBMITTED_TOO_FAST_WEIGHT: f64 = 3.0;
+const TOO_MANY_LINKS_WEIGHT: f64 = 2.5;
+const BLOCKED_WORD_WEIGHT: f64 = 4.0;
+const BLOCKED_DOMAIN_WEIGHT: f64 = 5.0;
+
+#[derive(Debug, Clone)]
+pub struct SpamFilterConfig {
+    /// Compared with the words of the comment and the commenter's name, ignoring case.
+    pub blocked_words: Vec<String>,
+    /// Also blocks their subdomains, in links and in the commenter's email.
+    pub blocked_domains: Vec<String>,
+    /// Links per word above which a comment with more than one link is suspicious.
+    pub max_link_density: f64,
+    /// People take at least this long to write a comment after the form shows up.
+    pub min_fill_time: Duration,
+}
+
+impl Default for SpamFilterConfig {
+    fn default() -> Self {
+        Self {
+            blocked_words: vec![],
+            blocked_domains: vec![],
+            max_link_density: 0.05,
+            min_fill_time: Duration::seconds(3),
+        }
+    }
+}
+
+/// Scores comments without calling any outside service.
+///
+/// A filled honeypot marks a comment as spam outright. Otherwise a naive Bayes model trained
+/// on moderated comments gives the starting odds, and every other signal found raises them.
+/// The model has no opinion until it has seen both spam and ham.
+pub struct OfflineSpamClassifier {
+    model_repo: Arc<dyn SpamModelRepository>,
+    config: SpamFilterConfig,
+    link: Regex,
+    /// Loaded on first use; the lock is held through training so no update is lost.
+    model: Mutex<Option<SpamModel>>,
+}
+
+impl OfflineSpamClassifier {
+    pub fn new(model_repo: Arc<dyn SpamModelRepository>, config: SpamFilterConfig) -> Self {
+        Self {
+            model_repo,
+            config,
+            link: Regex::new(r"(?i)\b(?:https?://|www\.)([a-z0-9-]+(?:\.[a-z0-9-]+)+)").unwrap(),
+            model: Mutex::new(None),
+        }
+    }
+
+    fn signals(&self, submission: &CommentSubmission) -> Vec<SpamSignal> {
+        let mut signals = vec![];
+        match submission.form_rendered_at {
+            None => signals.push(SpamSignal::FormNotStamped),
+            Some(rendered_at)
+                if submission.submitted_at - rendered_at < self.config.min_fill_time =>
+            {
+                signals.push(SpamSignal::SubmittedTooFast)
+            }
+            Some(_) => {}
+        }
+
+        let domains: Vec<String> = self
+            .link
+            .captures_iter(&submission.content)
+            .map(|c| c[1].to_lowercase())
+            .collect();
+        let words = submission.content.split_whitespace().count().max(1);
+        if domains.len() > 1 && domains.len() as f64 / words as f64 > self.config.max_link_density {
+            signals.push(SpamSignal::TooManyLinks);
+        }
+
+        let text = format!(
+            "{} {}",
+            submission.content,
+            submission.author_name.as_deref().unwrap_or_default()
+        );
+        let words = words_of(&text);
+        for blocked in &self.config.blocked_words {
+            if words.contains(&blocked.to_lowercase()) {
+                signals.push(SpamSignal::BlockedWord(blocked.clone()));
+            }
+        }
+
+        let email_domain = submission
+            .author_email
+            .as_deref()
+            .and_then(|email| email.rsplit_once('@'))
+            .map(|(_, domain)| domain.to_lowercase());
+        for blocked in &self.config.blocked_domains {
+            let blocked_lower = blocked.to_lowercase();
+            let is_blocked = |domain: &String| {
+                *domain == blocked_lower || domain.ends_with(&format!(".{}", blocked_lower))
+            };
+            if domains.iter().chain(&email_domain).any(is_blocked) {
+                signals.push(SpamSignal::BlockedDomain(blocked.clone()));
+            }
+        }
+        signals
+    }
+
+    /// Applies `change` to the comment count and to the count of every word of `content` on
+    /// the side of the model `is_spam` picks, then saves the model.
+    async fn update_model(
+        &self,
+        content: &str,
+        is_spam: bool,
+        change: impl Fn(&mut u32),
+    ) -> UnknownResult<()> {
+        let mut guard = self.model.lock().await;
+        self.load(&mut guard).await?;
+        let mut model = guard.clone().unwrap();
+
+        let (comments, counts) = if is_spam {
+            (&mut model.spam_comments, &mut model.spam_words)
+        } else {
+            (&mut model.ham_comments, &mut model.ham_words)
+        };
+        change(comments);
+        for word in words_of(content) {
+            change(counts.entry(word).or_default());
+        }
+        counts.retain(|_, count| *count > 0);
+
+        self.model_repo.save(&model).await?;
+        *guard = Some(model);
+        Ok(())
+    }
+
+    async fn load(&self, model: &mut Option<SpamModel>) -> UnknownResult<()> {
+        if model.is_none() {
+            *model = Some(self.model_repo.get().await?.unwrap_or_default());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl SpamClassifier for OfflineSpamClassifier {
+    async fn assess(&self, submission: &CommentSubmission) -> UnknownResult<SpamAssessment> {
+        let honeypot = submission.honeypot.as_deref().unwrap_or_default();
+        if !honeypot.trim().is_empty() {
+            return Ok(SpamAssessment {
+                probability: 1.0,
+                signals: vec![SpamSignal::HoneypotFilled],
+            });
+        }
+
+        let model_log_odds = {
+            let mut model = self.model.lock().await;
+            self.load(&mut model).await?;
+            log_odds(model.as_ref().unwrap(), &words_of(&submission.content))
+        };
+        let signals = self.signals(submission);
+        let log_odds = model_log_odds + signals.iter().map(weight).sum::<f64>();
+        Ok(SpamAssessment {
+            probability: 1.0 / (1.0 + (-log_odds).exp()),
+            signals,
+        })
+    }
+
+    async fn train(&self, content: &str, is_spam: bool) -> UnknownResult<()> {
+        self.update_model(content, is_spam, |count| *count += 1)
+            .await
+    }
+
+    async fn untrain(&self, content: &str, is_spam: bool) -> UnknownResult<()> {
+        self.update_model(content, is_spam, |count| *count = count.saturating_sub(1))
+            .await
+    }
+}
+
+fn weight(signal: &SpamSignal) -> f64 {
+    match signal {
+        SpamSignal::HoneypotFilled => f64::INFINITY,
+        SpamSignal::FormNotStamped => FORM_NOT_STAMPED_WEIGHT,
+        SpamSignal::SubmittedTooFast => SUBMITTED_TOO_FAST_WEIGHT,
+        SpamSignal::TooManyLinks => TOO_MANY_LINKS_WEIGHT,
+        SpamSignal::BlockedWord(_) => BLOCKED_WORD_WEIGHT,
+        SpamSignal::BlockedDomain(_) => BLOCKED_DOMAIN_WEIGHT,
+    }
+}
+
+fn words_of(text: &str) -> HashSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| (2..=30).contains(&word.chars().count()))
+        .map(|word| word.to_string())
+        .collect()
+}
+
+/// The log-odds of spam over ham for a comment with `words`, by Bernoulli naive Bayes with
+/// add-one smoothing. Words the model has never seen are left out.
+fn log_odds(model: &SpamModel, words: &HashSet<String>) -> f64 {
+    if model.spam_comments == 0 || model.ham_comments == 0 {
+        return 0.0;
+    }
+    let spam = f64::from(model.spam_comments);
+    let ham = f64::from(model.ham_comments);
+
+    let mut log_odds = (spam / ham).ln();
+    for word in words {
+        let in_spam = f64::from(model.spam_words.get(word).copied().unwrap_or_default());
+        let in_ham = f64::from(model.ham_words.get(word).copied().unwrap_or_default());
+        if in_spam + in_ham == 0.0 {
+            continue;
+        }
+        log_odds += ((in_spam + 1.0) / (spam + 2.0)).ln() - ((in_ham + 1.0) / (ham + 2.0)).ln();
+    }
+    log_odds
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::comments::interactors::test_doubles::fake_spam_model_repository::FakeSpamModelRepository;
+    use crate::comments::interactors::utils::CommentsConfig;
+    use crate::test_utils::clock::fake_clock::fixed_now;
+
+    use super::*;
+
+    struct CreationResult {
+        classifier: OfflineSpamClassifier,
+        model_repo: Arc<FakeSpamModelRepository>,
+    }
+
+    fn create_classifier() -> CreationResult {
+        let model_repo = Arc::new(FakeSpamModelRepository::new_empty());
+        let classifier = OfflineSpamClassifier::new(
+            model_repo.clone(),
+            SpamFilterConfig {
+                blocked_words: vec!["Casino".into()],
+                blocked_domains: vec!["spam.example".into()],
+                ..SpamFilterConfig::default()
+            },
+        );
+        CreationResult {
+            classifier,
+            model_repo,
+        }
+    }
+
+    fn submission(content: &str) -> CommentSubmission {
+        CommentSubmission {
+            content: content.into(),
+            author_name: Some("Reader".into()),
+            author_email: Some("reader@example.com".into()),
+            honeypot: None,
+            form_rendered_at: Some(fixed_now() - Duration::minutes(2)),
+            submitted_at: fixed_now(),
+        }
+    }
+
+    async fn assess(classifier: &OfflineSpamClassifier, s: CommentSubmission) -> SpamAssessment {
+        classifier.assess(&s).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_have_no_opinion_on_clean_comments_before_training() {
+        let c = create_classifier();
+
+        let assessment = assess(&c.classifier, submission("Thanks for the post")).await;
+
+        assert_eq!(
+            assessment,
+            SpamAssessment {
+                probability: 0.5,
+                signals: vec![],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_mark_filled_honeypots_as_certain_spam() {
+        let c = create_classifier();
+
+        let assessment = assess(
+            &c.classifier,
+            CommentSubmission {
+                honeypot: Some("http://example.com".into()),
+                ..submission("Thanks for the post")
+            },
+        )
+        .await;
+
+        assert_eq!(assessment.probability, 1.0);
+        assert_eq!(assessment.signals, [SpamSignal::HoneypotFilled]);
+    }
+
+    #[tokio::test]
+    async fn should_flag_comments_submitted_too_fast_or_without_a_stamp() {
+        let c = create_classifier();
+
+        let fast = assess(
+            &c.classifier,
+            CommentSubmission {
+                form_rendered_at: Some(fixed_now() - Duration::seconds(1)),
+                ..submission("Thanks for the post")
+            },
+        )
+        .await;
+        let unstamped = assess(
+            &c.classifier,
+            CommentSubmission {
+                form_rendered_at: None,
+                ..submission("Thanks for the post")
+            },
+        )
+        .await;
+
+        assert_eq!(fast.signals, [SpamSignal::SubmittedTooFast]);
+        assert!(fast.probability > 0.9);
+        assert_eq!(unstamped.signals, [SpamSignal::FormNotStamped]);
+        assert!(unstamped.probability > 0.5);
+        assert!(unstamped.probability < CommentsConfig::default().spam_threshold);
+    }
+
+    #[tokio::test]
+    async fn should_flag_comments_dense_with_links() {
+        let c = create_classifier();
+
+        let dense = assess(
+            &c.classifier,
+            submission("see https://a.example and www.b.example now"),
+        )
+        .await;
+        let single = assess(&c.classifier, submission("see https://a.example")).await;
+
+        assert_eq!(dense.signals, [SpamSignal::TooManyLinks]);
+        assert!(single.signals.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_flag_blocked_words_and_domains() {
+        let c = create_classifier();
+
+        let word = assess(&c.classifier, submission("Best CASINO bonus")).await;
+        let link = assess(
+            &c.classifier,
+            submission("Visit https://www.spam.example/offer today"),
+        )
+        .await;
+        let email = assess(
+            &c.classifier,
+            CommentSubmission {
+                author_email: Some("bot@Spam.Example".into()),
+                ..submission("Thanks for the post")
+            },
+        )
+        .await;
+
+        assert_eq!(word.signals, [SpamSignal::BlockedWord("Casino".into())]);
+        assert_eq!(
+            link.signals,
+            [SpamSignal::BlockedDomain("spam.example".into())]
+        );
+        assert_eq!(email.signals, link.signals);
+    }
+
+    #[tokio::test]
+    async fn should_learn_from_moderated_comments() {
+        let c = create_classifier();
+        for spam in ["cheap pills online", "buy cheap watches"] {
+            c.classifier.train(spam, true).await.unwrap();
+        }
+        for ham in ["great article thanks", "thanks for the examples"] {
+            c.classifier.train(ham, false).await.unwrap();
+        }
+
+        let spam = assess(&c.classifier, submission("cheap pills here")).await;
+        let ham = assess(&c.classifier, submission("thanks, great examples")).await;
+
+        assert!(spam.probability > 0.8, "{}", spam.probability);
+        assert!(ham.probability < 0.2, "{}", ham.probability);
+        let saved = c.model_repo.get_model().unwrap();
+        assert_eq!((saved.spam_comments, saved.ham_comments), (2, 2));
+        assert_eq!(saved.spam_words["cheap"], 2);
+    }
+
+    #[tokio::test]
+    async fn should_forget_untrained_comments() {
+        let c = create_classifier();
+        c.classifier.train("cheap pills", true).await.unwrap();
+        c.classifier.train("cheap watches", true).await.unwrap();
+
+        c.classifier.untrain("cheap pills", true).await.unwrap();
+
+        let saved = c.model_repo.get_model().unwrap();
+        assert_eq!(saved.spam_comments, 1);
+        assert_eq!(saved.spam_words["cheap"], 1);
+        assert!(!saved.spam_words.contains_key("pills"));
+    }
+
+    #[tokio::test]
+    async fn should_start_from_the_stored_model() {
+        let c = create_classifier();
+        c.classifier.train("cheap pills", true).await.unwrap();
+        c.classifier.train("nice post", false).await.unwrap();
+
+        let reloaded =
+            OfflineSpamClassifier::new(c.model_repo.clone(), SpamFilterConfig::default());
+
+        assert!(
+            assess(&reloaded, submission("cheap pills"))
+                .await
+                .probability
+                > 0.5
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::categories::domain::{Category, CategoryId};
+
+/// Something that happened to the blog, told to everyone who subscribed to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum DomainEvent {
+    UserCreated(UserCreated),
+    UserDeleted(UserDeleted),
+    PasswordChanged(PasswordChanged),
+    CategoryCreated(CategoryCreated),
+    CategoryUpdated(CategoryUpdated),
+    CategoryDeleted(CategoryDeleted),
+    CategoryReplaced(CategoryReplaced),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserCreated {
+    pub user_id: String,
+    pub name: String,
+    pub email: String,
+    pub role: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserDeleted {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasswordChanged {
+    pub user_id: String,
+    /// The user themselves, or whoever may change the passwords of others.
+    pub changed_by: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryCreated {
+    pub category: Category,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryUpdated {
+    pub before: Category,
+    pub after: Category,
+}
+
+/// The category was deleted along with all of its descendants.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryDeleted {
+    pub category: Category,
+    pub deleted_categories: i32,
+    pub detached_posts: i32,
+}
+
+/// The category was deleted after its posts and children moved to the replacement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryReplaced {
+    pub category: Category,
+    pub replacement_id: CategoryId,
+    pub moved_posts: i32,
+    pub moved_children: i32,
+}
+
+/// An event type subscribers can ask for, or `DomainEvent` itself for every event.
+pub trait Event: Send + Sync + 'static {
+    fn from_domain(event: &DomainEvent) -> Option<&Self>;
+}
+
+impl Event for DomainEvent {
+    fn from_domain(event: &DomainEvent) -> Option<&Self> {
+        Some(event)
+    }
+}
+
+macro_rules! domain_events {
+    ($($name:ident),+) => {
+        $(
+            impl From<$name> for DomainEvent {
+                fn from(event: $name) -> Self {
+                    DomainEvent::$name(event)
+                }
+            }
+
+            impl Event for $name {
+                fn from_domain(event: &DomainEvent) -> Option<&Self> {
+                    match event {
+                        DomainEvent::$name(event) => Some(event),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+domain_events!(
+    UserCreated,
+    UserDeleted,
+    PasswordChanged,
+    CategoryCreated,
+    CategoryUpdated,
+    CategoryDeleted,
+    CategoryReplaced
+);
+
+/// A published event along with the async subscribers it still has to reach.
+///
+/// It is stored in the same unit of work as the change the event describes, so a subscriber
+/// that fails, or that the app stops before telling, is told again later until it succeeds.
+/// Subscribers may therefore see an event more than once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    pub id: String,
+    pub event: DomainEvent,
+    pub published_at: DateTime<Utc>,
+    /// The names of the subscribers the event was not delivered to yet.
+    pub pending: Vec<String>,
+    /// How many deliveries failed so far.
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// The subscribers that were still pending when the event ran out of attempts. They are
+    /// not retried unless moved back to `pending`.
+    pub failed: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_only_match_events_of_the_asked_type() {
+        let event: DomainEvent = UserDeleted {
+            user_id: "jane".into(),
+        }
+        .into();
+
+        assert_eq!(
+            UserDeleted::from_domain(&event).map(|e| e.user_id.as_str()),
+            Some("jane")
+        );
+        assert_eq!(UserCreated::from_domain(&event), None);
+        assert_eq!(DomainEvent::from_domain(&event), Some(&event));
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"type":"user_deleted","data":{"user_id":"jane"}}"#
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod test_doubles;
+pub mod traits;
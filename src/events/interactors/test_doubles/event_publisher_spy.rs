@@ -0,0 +1,24 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::events::domain::DomainEvent;
+use crate::events::interactors::traits::EventPublisher;
+
+#[derive(Default)]
+pub struct EventPublisherSpy {
+    pub published: Mutex<Vec<DomainEvent>>,
+}
+
+impl EventPublisherSpy {
+    pub fn get_published(&self) -> Vec<DomainEvent> {
+        self.published.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventPublisher for EventPublisherSpy {
+    async fn publish(&self, event: DomainEvent) -> UnknownResult<()> {
+        self.published.lock().unwrap().push(event);
+        Ok(())
+    }
+}
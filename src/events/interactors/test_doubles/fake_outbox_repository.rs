@@ -0,0 +1,49 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::events::domain::OutboxEntry;
+use crate::events::interactors::traits::OutboxRepository;
+
+pub struct FakeOutboxRepository {
+    pub entries: Mutex<Vec<OutboxEntry>>,
+}
+
+#[allow(unused)]
+impl FakeOutboxRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            entries: Mutex::new(vec![]),
+        }
+    }
+    pub fn get_entries(&self) -> Vec<OutboxEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxRepository for FakeOutboxRepository {
+    async fn create(&self, entry: &OutboxEntry) -> UnknownResult<OutboxEntry> {
+        self.entries.lock().unwrap().push(entry.clone());
+        Ok(entry.clone())
+    }
+
+    async fn update(&self, entry: &OutboxEntry) -> UnknownResult<OutboxEntry> {
+        let mut entries = self.entries.lock().unwrap();
+        let stored = entries
+            .iter_mut()
+            .find(|e| e.id == entry.id)
+            .ok_or("outbox entry not found")?;
+        *stored = entry.clone();
+        Ok(entry.clone())
+    }
+
+    async fn get_pending(&self, limit: usize) -> UnknownResult<Vec<OutboxEntry>> {
+        let entries = self.entries.lock().unwrap();
+        Ok(entries
+            .iter()
+            .filter(|e| !e.pending.is_empty())
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod event_publisher_spy;
+pub mod fake_outbox_repository;
@@ -0,0 +1,31 @@
+use crate::errors::UnknownResult;
+use crate::events::domain::{DomainEvent, Event, OutboxEntry};
+
+#[async_trait::async_trait]
+pub trait EventPublisher: Send + Sync {
+    /// Runs the sync subscribers and puts the event in the outbox for the async ones.
+    ///
+    /// Called inside the `UnitOfWork` of the change the event describes, so the event is stored
+    /// if and only if the change is.
+    async fn publish(&self, event: DomainEvent) -> UnknownResult<()>;
+}
+
+/// Runs while the event is published, inside the unit of work of the change; an error rolls
+/// the change back.
+pub trait SyncSubscriber<E: Event>: Send + Sync {
+    fn handle(&self, event: &E) -> UnknownResult<()>;
+}
+
+/// Runs from the outbox once the change is committed, at least once.
+#[async_trait::async_trait]
+pub trait AsyncSubscriber<E: Event>: Send + Sync {
+    async fn handle(&self, event: &E) -> UnknownResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait OutboxRepository: Send + Sync {
+    async fn create(&self, entry: &OutboxEntry) -> UnknownResult<OutboxEntry>;
+    async fn update(&self, entry: &OutboxEntry) -> UnknownResult<OutboxEntry>;
+    /// At most `limit` entries that still have pending subscribers, oldest first.
+    async fn get_pending(&self, limit: usize) -> UnknownResult<Vec<OutboxEntry>>;
+}
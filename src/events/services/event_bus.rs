@@ -0,0 +1,444 @@
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crate::errors::{UnknownException, UnknownResult};
+use crate::events::domain::{DomainEvent, Event, OutboxEntry};
+use crate::events::interactors::traits::{
+    AsyncSubscriber, EventPublisher, OutboxRepository, SyncSubscriber,
+};
+use crate::utils::{run_every, Clock, RandomService};
+
+/// How many outbox entries one `deliver_pending` call goes through.
+pub const OUTBOX_BATCH_SIZE: usize = 100;
+/// After this many failed deliveries an event is no longer retried.
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 10;
+
+/// Delivers events to the subscribers of their type, in process and through an outbox.
+///
+/// Sync subscribers run while the event is published, inside the unit of work of the change.
+/// For the async ones the event is stored in the outbox in that same unit, along with their
+/// names, and `deliver_pending`, which the app runs in the background, hands it to them until
+/// each one took it. The outbox tells who is still due an event by name, so a subscriber has to
+/// keep its name across restarts.
+pub struct EventBus {
+    outbox: Arc<dyn OutboxRepository>,
+    random: Arc<dyn RandomService>,
+    clock: Arc<dyn Clock>,
+    subscriptions: RwLock<Vec<(String, Handler)>>,
+}
+
+#[derive(Clone)]
+enum Handler {
+    Sync(Arc<dyn SyncHandler>),
+    Async(Arc<dyn AsyncHandler>),
+}
+
+impl Handler {
+    fn wants(&self, event: &DomainEvent) -> bool {
+        match self {
+            Handler::Sync(handler) => handler.wants(event),
+            Handler::Async(handler) => handler.wants(event),
+        }
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> UnknownResult<()> {
+        match self {
+            Handler::Sync(handler) => handler.handle(event),
+            Handler::Async(handler) => handler.handle(event).await,
+        }
+    }
+}
+
+trait SyncHandler: Send + Sync {
+    fn wants(&self, event: &DomainEvent) -> bool;
+    fn handle(&self, event: &DomainEvent) -> UnknownResult<()>;
+}
+
+#[async_trait::async_trait]
+trait AsyncHandler: Send + Sync {
+    fn wants(&self, event: &DomainEvent) -> bool;
+    async fn handle(&self, event: &DomainEvent) -> UnknownResult<()>;
+}
+
+/// Hands a subscriber the events of its type only.
+struct Typed<S: ?Sized>(Arc<S>);
+
+impl<E: Event> SyncHandler for Typed<dyn SyncSubscriber<E>> {
+    fn wants(&self, event: &DomainEvent) -> bool {
+        E::from_domain(event).is_some()
+    }
+
+    fn handle(&self, event: &DomainEvent) -> UnknownResult<()> {
+        E::from_domain(event).map_or(Ok(()), |event| self.0.handle(event))
+    }
+}
+
+#[async_trait::async_trait]
+impl<E: Event> AsyncHandler for Typed<dyn AsyncSubscriber<E>> {
+    fn wants(&self, event: &DomainEvent) -> bool {
+        E::from_domain(event).is_some()
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> UnknownResult<()> {
+        match E::from_domain(event) {
+            Some(event) => self.0.handle(event).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl EventBus {
+    pub fn new(
+        outbox: Arc<dyn OutboxRepository>,
+        random: Arc<dyn RandomService>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            outbox,
+            random,
+            clock,
+            subscriptions: RwLock::new(vec![]),
+        }
+    }
+
+    /// Subscribes to the events of type `E`, or to all of them with `DomainEvent`.
+    ///
+    /// Panics when the name is taken, since the outbox could not tell the two apart.
+    pub fn subscribe_sync<E: Event>(&self, name: &str, subscriber: Arc<dyn SyncSubscriber<E>>) {
+        self.subscribe(name, Handler::Sync(Arc::new(Typed(subscriber))));
+    }
+
+    /// Like `subscribe_sync`, for subscribers run by `deliver_pending`.
+    pub fn subscribe_async<E: Event>(&self, name: &str, subscriber: Arc<dyn AsyncSubscriber<E>>) {
+        self.subscribe(name, Handler::Async(Arc::new(Typed(subscriber))));
+    }
+
+    fn subscribe(&self, name: &str, handler: Handler) {
+        let mut subscriptions = self.subscriptions.write().unwrap();
+        assert!(
+            subscriptions.iter().all(|(taken, _)| taken != name),
+            "an event subscriber named {} already exists",
+            name
+        );
+        subscriptions.push((name.into(), handler));
+    }
+
+    fn handler(&self, name: &str) -> Option<Handler> {
+        let subscriptions = self.subscriptions.read().unwrap();
+        subscriptions
+            .iter()
+            .find(|(taken, _)| taken == name)
+            .map(|(_, handler)| handler.clone())
+    }
+
+    /// Retries every pending delivery of the oldest entries and returns how many succeeded.
+    ///
+    /// A name nobody subscribed under, as while the app is still starting, counts as a failed
+    /// delivery, so the event waits for the subscriber until it runs out of attempts.
+    pub async fn deliver_pending(&self) -> UnknownResult<usize> {
+        let mut delivered = 0;
+        for mut entry in self.outbox.get_pending(OUTBOX_BATCH_SIZE).await? {
+            let mut failed = false;
+            for name in entry.pending.clone() {
+                let result = match self.handler(&name) {
+                    Some(handler) => handler.handle(&entry.event).await,
+                    None => Err("not subscribed".into()),
+                };
+                match result {
+                    Ok(()) => {
+                        entry.pending.retain(|pending| pending != &name);
+                        delivered += 1;
+                    }
+                    Err(e) => {
+                        failed = true;
+                        entry.last_error = Some(format!("{}: {}", name, e));
+                    }
+                }
+            }
+            if failed {
+                Self::count_failure(&mut entry);
+            }
+            self.outbox.update(&entry).await?;
+        }
+        Ok(delivered)
+    }
+
+    fn count_failure(entry: &mut OutboxEntry) {
+        entry.attempts += 1;
+        if entry.attempts >= MAX_DELIVERY_ATTEMPTS {
+            entry.failed.append(&mut entry.pending);
+        }
+    }
+
+    /// Runs `deliver_pending` with `run_every`.
+    pub async fn run_until(
+        &self,
+        interval: Duration,
+        shutdown: impl Future<Output = ()>,
+        on_error: impl Fn(UnknownException),
+    ) {
+        run_every(interval, shutdown, on_error, || self.deliver_pending()).await
+    }
+}
+
+#[async_trait::async_trait]
+impl EventPublisher for EventBus {
+    async fn publish(&self, event: DomainEvent) -> UnknownResult<()> {
+        let subscribers: Vec<(String, Handler)> = self
+            .subscriptions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, handler)| handler.wants(&event))
+            .cloned()
+            .collect();
+        let mut pending = vec![];
+        for (name, handler) in subscribers {
+            match handler {
+                Handler::Sync(handler) => handler
+                    .handle(&event)
+                    .map_err(|e| format!("{}: {}", name, e))?,
+                Handler::Async(_) => pending.push(name),
+            }
+        }
+        self.outbox
+            .create(&OutboxEntry {
+                id: self.random.random_id().await?,
+                event,
+                published_at: self.clock.now(),
+                pending,
+                attempts: 0,
+                last_error: None,
+                failed: vec![],
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use crate::events::domain::{CategoryDeleted, UserCreated, UserDeleted};
+    use crate::events::interactors::test_doubles::fake_outbox_repository::FakeOutboxRepository;
+    use crate::test_utils::clock::fake_clock::FakeClock;
+    use crate::test_utils::crypto::sequential_random_service::SequentialRandomService;
+
+    use super::*;
+
+    /// Records what it is told and fails the first `failures` times.
+    struct RecordingSubscriber<E> {
+        received: Mutex<Vec<E>>,
+        failures: Mutex<u32>,
+    }
+
+    impl<E: Clone> RecordingSubscriber<E> {
+        fn new(failures: u32) -> Arc<Self> {
+            Arc::new(Self {
+                received: Mutex::new(vec![]),
+                failures: Mutex::new(failures),
+            })
+        }
+
+        fn record(&self, event: &E) -> UnknownResult<()> {
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err("subscriber is down".into());
+            }
+            self.received.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+
+        fn get_received(&self) -> Vec<E> {
+            self.received.lock().unwrap().clone()
+        }
+    }
+
+    impl<E: Event + Clone> SyncSubscriber<E> for RecordingSubscriber<E> {
+        fn handle(&self, event: &E) -> UnknownResult<()> {
+            self.record(event)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<E: Event + Clone> AsyncSubscriber<E> for RecordingSubscriber<E> {
+        async fn handle(&self, event: &E) -> UnknownResult<()> {
+            self.record(event)
+        }
+    }
+
+    fn create_bus() -> (EventBus, Arc<FakeOutboxRepository>) {
+        let outbox = Arc::new(FakeOutboxRepository::new_empty());
+        let bus = EventBus::new(
+            outbox.clone(),
+            Arc::new(SequentialRandomService::new()),
+            Arc::new(FakeClock::new_fixed()),
+        );
+        (bus, outbox)
+    }
+
+    fn user_created() -> UserCreated {
+        UserCreated {
+            user_id: "jane".into(),
+            name: "Jane".into(),
+            email: "jane@example.com".into(),
+            role: "admin".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_run_sync_subscribers_while_publishing() {
+        let (bus, outbox) = create_bus();
+        let sync = RecordingSubscriber::<UserCreated>::new(0);
+        let all = RecordingSubscriber::<DomainEvent>::new(0);
+        bus.subscribe_sync("welcome-mail", sync.clone());
+        bus.subscribe_async("search", all.clone());
+
+        bus.publish(user_created().into()).await.unwrap();
+
+        assert_eq!(sync.get_received(), [user_created()]);
+        assert!(all.get_received().is_empty());
+        let entries = outbox.get_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].event, user_created().into());
+        assert_eq!(entries[0].pending, ["search"]);
+    }
+
+    #[tokio::test]
+    async fn should_only_deliver_the_events_a_subscriber_asked_for() {
+        let (bus, outbox) = create_bus();
+        let created = RecordingSubscriber::<UserCreated>::new(0);
+        bus.subscribe_async("welcome-mail", created.clone());
+
+        bus.publish(
+            UserDeleted {
+                user_id: "jane".into(),
+            }
+            .into(),
+        )
+        .await
+        .unwrap();
+        bus.publish(user_created().into()).await.unwrap();
+        let delivered = bus.deliver_pending().await.unwrap();
+
+        assert_eq!(delivered, 1);
+        assert_eq!(created.get_received(), [user_created()]);
+        assert!(outbox.get_entries()[0].pending.is_empty());
+        assert_eq!(bus.deliver_pending().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn should_fail_publishing_when_a_sync_subscriber_fails() {
+        let (bus, outbox) = create_bus();
+        bus.subscribe_sync("cache", RecordingSubscriber::<UserCreated>::new(1));
+
+        let err = bus.publish(user_created().into()).await.unwrap_err();
+
+        assert_eq!(err.to_string(), "cache: subscriber is down");
+        assert!(outbox.get_entries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_retry_failed_deliveries_until_they_succeed() {
+        let (bus, outbox) = create_bus();
+        let flaky = RecordingSubscriber::<UserCreated>::new(2);
+        bus.subscribe_async("mailer", flaky.clone());
+
+        bus.publish(user_created().into()).await.unwrap();
+        assert_eq!(bus.deliver_pending().await.unwrap(), 0);
+        assert_eq!(bus.deliver_pending().await.unwrap(), 0);
+        let entry = outbox.get_entries().remove(0);
+        assert_eq!(entry.attempts, 2);
+        assert_eq!(
+            entry.last_error.as_deref(),
+            Some("mailer: subscriber is down")
+        );
+
+        assert_eq!(bus.deliver_pending().await.unwrap(), 1);
+        assert_eq!(flaky.get_received(), [user_created()]);
+        assert!(outbox.get_entries()[0].pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_hand_failed_runs_to_the_caller() {
+        let (bus, _) = create_bus();
+        let bus = EventBus::new(
+            Arc::new(FailingOutboxRepository),
+            bus.random.clone(),
+            bus.clock.clone(),
+        );
+        let errors = Mutex::new(vec![]);
+
+        bus.run_until(
+            Duration::from_millis(10),
+            tokio::time::sleep(Duration::from_millis(25)),
+            |e| errors.lock().unwrap().push(e.to_string()),
+        )
+        .await;
+
+        let errors = errors.into_inner().unwrap();
+        assert!(!errors.is_empty());
+        assert!(errors.iter().all(|e| e == "outbox is down"));
+    }
+
+    struct FailingOutboxRepository;
+
+    #[async_trait::async_trait]
+    impl OutboxRepository for FailingOutboxRepository {
+        async fn create(&self, _: &OutboxEntry) -> UnknownResult<OutboxEntry> {
+            Err("outbox is down".into())
+        }
+        async fn update(&self, _: &OutboxEntry) -> UnknownResult<OutboxEntry> {
+            Err("outbox is down".into())
+        }
+        async fn get_pending(&self, _: usize) -> UnknownResult<Vec<OutboxEntry>> {
+            Err("outbox is down".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn should_give_up_after_too_many_attempts() {
+        let (bus, outbox) = create_bus();
+        let broken = RecordingSubscriber::<DomainEvent>::new(u32::MAX);
+        bus.subscribe_async("webhook", broken.clone());
+        bus.publish(user_created().into()).await.unwrap();
+
+        for _ in 0..MAX_DELIVERY_ATTEMPTS + 1 {
+            bus.deliver_pending().await.unwrap();
+        }
+
+        let entry = outbox.get_entries().remove(0);
+        assert_eq!(entry.attempts, MAX_DELIVERY_ATTEMPTS);
+        assert!(entry.pending.is_empty());
+        assert_eq!(entry.failed, ["webhook"]);
+    }
+
+    #[tokio::test]
+    async fn should_keep_events_for_subscribers_that_are_not_subscribed_yet() {
+        let (bus, outbox) = create_bus();
+        bus.subscribe_async("search", RecordingSubscriber::<DomainEvent>::new(0));
+        bus.publish(user_created().into()).await.unwrap();
+        let restarted = EventBus::new(outbox.clone(), bus.random.clone(), bus.clock.clone());
+
+        assert_eq!(restarted.deliver_pending().await.unwrap(), 0);
+        let entry = outbox.get_entries().remove(0);
+        assert_eq!(entry.pending, ["search"]);
+        assert_eq!(entry.last_error.as_deref(), Some("search: not subscribed"));
+
+        let search = RecordingSubscriber::<DomainEvent>::new(0);
+        restarted.subscribe_async("search", search.clone());
+        assert_eq!(restarted.deliver_pending().await.unwrap(), 1);
+        assert_eq!(search.get_received(), [user_created().into()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "an event subscriber named search already exists")]
+    fn should_refuse_a_second_subscriber_with_the_same_name() {
+        let (bus, _) = create_bus();
+        bus.subscribe_async("search", RecordingSubscriber::<DomainEvent>::new(0));
+        bus.subscribe_sync("search", RecordingSubscriber::<CategoryDeleted>::new(0));
+    }
+}
@@ -0,0 +1,3 @@
+pub use event_bus::{EventBus, MAX_DELIVERY_ATTEMPTS, OUTBOX_BATCH_SIZE};
+
+mod event_bus;
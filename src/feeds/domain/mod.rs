@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+impl FeedFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "rss",
+            FeedFormat::Atom => "atom",
+        }
+    }
+}
+
+/// Which posts a feed covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedScope {
+    Site,
+    /// The posts of the category with this slug and of all its descendants.
+    Category(String),
+    /// The posts of the user with this id.
+    Author(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedContent {
+    /// Every entry carries the whole rendered post besides its excerpt.
+    Full,
+    Excerpt,
+}
+
+/// A format independent feed, written out by `write_rss` or `write_atom`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    /// The page the feed belongs to.
+    pub link: String,
+    /// Where the feed itself is served.
+    pub self_link: String,
+    pub language: String,
+    pub updated: DateTime<Utc>,
+    pub entries: Vec<FeedEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedEntry {
+    pub title: String,
+    pub link: String,
+    pub author_name: String,
+    pub published: DateTime<Utc>,
+    pub categories: Vec<String>,
+    /// Plain text.
+    pub summary: String,
+    /// Sanitized HTML, only set for `FeedContent::Full`.
+    pub content: Option<String>,
+}
@@ -0,0 +1,469 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use with_deps_proc_macro::WithDeps;
+
+use crate::categories::domain::{Category, CategoryId};
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::categories::interactors::utils::category_subtree;
+use crate::errors::ApplicationResult;
+use crate::feeds::domain::{Feed, FeedContent, FeedEntry, FeedFormat, FeedScope};
+use crate::feeds::interactors::utils::{excerpt, FeedConfig};
+use crate::feeds::services::{write_atom, write_rss};
+use crate::posts::domain::{Post, PostStatus};
+use crate::posts::interactors::traits::PostsRepository;
+use crate::posts::interactors::utils::post_sanitization_policy;
+use crate::users::domain::User;
+use crate::users::interactors::traits::UsersRepository;
+use crate::utils::{ContentRenderer, HtmlSanitizer};
+
+#[derive(Debug, Clone)]
+pub struct GetFeedInput {
+    pub scope: FeedScope,
+    pub format: FeedFormat,
+    pub content: FeedContent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetFeedOutput {
+    pub body: String,
+    pub content_type: &'static str,
+    /// A strong validator of `body`, quoted as it goes into the `ETag` header.
+    pub etag: String,
+    /// The publish time of the newest entry as an HTTP date, the epoch for an empty feed so it
+    /// validates the same on every request.
+    pub last_modified: String,
+}
+
+/// Builds the public feeds of published posts, newest first.
+#[derive(WithDeps)]
+pub struct GetFeedInteractor {
+    posts_repo: Arc<dyn PostsRepository>,
+    categories_repo: Arc<dyn CategoriesRepository>,
+    users_repo: Arc<dyn UsersRepository>,
+    renderer: Arc<dyn ContentRenderer>,
+    sanitizer: Arc<dyn HtmlSanitizer>,
+    config: Arc<FeedConfig>,
+}
+
+impl GetFeedInteractor {
+    pub async fn execute(&self, input: GetFeedInput) -> ApplicationResult<GetFeedOutput> {
+        let categories = self.categories_repo.get_all().await?;
+        let (title, link, posts) = match &input.scope {
+            FeedScope::Site => (
+                self.config.title.clone(),
+                self.config.urls.home(),
+                self.posts_repo.get_all().await?,
+            ),
+            FeedScope::Category(slug) => {
+                let category = self.categories_repo.get_by_slug_or_fail(slug).await?;
+                let mut posts = vec![];
+                for id in category_subtree(&categories, &category.id) {
+                    posts.extend(self.posts_repo.get_by_category_id(&id).await?);
+                }
+                (
+                    format!("{} - {}", self.config.title, category.name),
+                    self.config.urls.category(&category.slug),
+                    posts,
+                )
+            }
+            FeedScope::Author(id) => {
+                let author = self.users_repo.get_by_id_or_fail(id).await?;
+                let posts = self.posts_repo.get_all().await?;
+                (
+                    format!("{} - {}", self.config.title, author.name),
+                    self.config.urls.author(&author.id),
+                    posts.into_iter().filter(|p| &p.author_id == id).collect(),
+                )
+            }
+        };
+
+        let entries = self
+            .create_entries(Self::latest_published(posts), &categories, input.content)
+            .await?;
+        let feed = Feed {
+            title,
+            description: self.config.description.clone(),
+            self_link: self.config.feed_url(&link, input.format),
+            link,
+            language: self.config.language.clone(),
+            updated: entries
+                .first()
+                .map_or(DateTime::UNIX_EPOCH, |entry| entry.published),
+            entries,
+        };
+        Ok(Self::create_output(&feed, input.format))
+    }
+
+    fn latest_published(posts: Vec<Post>) -> Vec<(Post, DateTime<Utc>)> {
+        let mut published: Vec<_> = posts
+            .into_iter()
+            .filter(|post| post.status == PostStatus::Published)
+            .filter_map(|post| post.published_at.map(|at| (post, at)))
+            .collect();
+        published.sort_by_key(|(_, at)| std::cmp::Reverse(*at));
+        published
+    }
+
+    async fn create_entries(
+        &self,
+        posts: Vec<(Post, DateTime<Utc>)>,
+        categories: &[Category],
+        content: FeedContent,
+    ) -> ApplicationResult<Vec<FeedEntry>> {
+        let category_names: HashMap<&CategoryId, &str> = categories
+            .iter()
+            .map(|category| (&category.id, category.name.as_str()))
+            .collect();
+        let mut authors: HashMap<String, Option<User>> = HashMap::new();
+        let mut entries = vec![];
+        for (post, published) in posts.into_iter().take(self.config.max_entries) {
+            if !authors.contains_key(&post.author_id) {
+                let author = self.users_repo.get_by_id(&post.author_id).await?;
+                authors.insert(post.author_id.clone(), author);
+            }
+            let author = authors[&post.author_id].as_ref();
+            let content = match content {
+                FeedContent::Full => Some(self.render(&post, author)),
+                FeedContent::Excerpt => None,
+            };
+            entries.push(FeedEntry {
+                link: self.config.urls.post(&post.slug),
+                author_name: author.map_or_else(|| post.author_id.clone(), |a| a.name.clone()),
+                published,
+                categories: post
+                    .category_id
+                    .as_ref()
+                    .and_then(|id| category_names.get(id))
+                    .map(|name| vec![name.to_string()])
+                    .unwrap_or_default(),
+                summary: excerpt(&post.content, self.config.excerpt_length),
+                content,
+                title: post.title,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Renders the post the same way its page does.
+    fn render(&self, post: &Post, author: Option<&User>) -> String {
+        let policy = post_sanitization_policy(author);
+        let rendered = self.renderer.render(&post.content);
+        self.sanitizer.sanitize(&rendered.html, policy)
+    }
+
+    fn create_output(feed: &Feed, format: FeedFormat) -> GetFeedOutput {
+        let body = match format {
+            FeedFormat::Rss => write_rss(feed),
+            FeedFormat::Atom => write_atom(feed),
+        };
+        GetFeedOutput {
+            etag: format!("\"{:x}\"", Sha256::digest(body.as_bytes())),
+            last_modified: feed.updated.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+            content_type: format.content_type(),
+            body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use roxmltree::Document;
+
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::make_interactor_setup;
+    use crate::posts::domain::PostId;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::access_management::role_spy::RoleSpy;
+    use crate::test_utils::clock::fake_clock::fixed_now;
+    use crate::test_utils::errors_assertion::assert_not_found_error;
+    use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
+    use crate::utils::{
+        AmmoniaHtmlSanitizer, MarkdownRenderer, SiteUrls, TransliteratingSlugGenerator,
+    };
+
+    use super::*;
+
+    make_interactor_setup!(
+        GetFeedInteractor,
+        [
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&posts()),
+                FakePostsRepository
+            ),
+            (
+                categories_repo,
+                FakeCategoriesRepository::new_with_data(&categories()),
+                FakeCategoriesRepository
+            ),
+            (
+                users_repo,
+                FakeUsersRepository::new_with_data(&[
+                    user("trusted", true),
+                    user("untrusted", false)
+                ]),
+                FakeUsersRepository
+            ),
+            (
+                renderer,
+                MarkdownRenderer::new(Arc::new(TransliteratingSlugGenerator::default())),
+                MarkdownRenderer
+            ),
+            (
+                sanitizer,
+                AmmoniaHtmlSanitizer::new(&[]),
+                AmmoniaHtmlSanitizer
+            ),
+            (config, config(), FeedConfig)
+        ]
+    );
+
+    fn config() -> FeedConfig {
+        FeedConfig {
+            title: "Blog".into(),
+            description: "A blog".into(),
+            urls: SiteUrls::new("https://blog.example.com"),
+            language: "en".into(),
+            max_entries: 10,
+            excerpt_length: 20,
+        }
+    }
+
+    fn category(id: &str, parent_id: Option<&str>) -> Category {
+        Category {
+            id: CategoryId::new(id),
+            name: id.to_uppercase(),
+            description: "".into(),
+            created_at: fixed_now(),
+            slug: id.into(),
+            parent_id: parent_id.map(CategoryId::new),
+        }
+    }
+
+    fn categories() -> Vec<Category> {
+        vec![
+            category("lang", None),
+            category("rust", Some("lang")),
+            category("life", None),
+            category("misc", None),
+        ]
+    }
+
+    fn user(id: &str, trusted: bool) -> User {
+        User {
+            id: id.into(),
+            name: format!("{} author", id),
+            email: format!("{}@example.com", id),
+            password: "".into(),
+            role: Box::new(if trusted {
+                RoleSpy::new_allowed()
+            } else {
+                RoleSpy::new_disallowed()
+            }),
+        }
+    }
+
+    fn post(id: &str, category: &str, author: &str, days_ago: i64) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: format!("Post {}", id),
+            slug: id.into(),
+            content: format!("Content of {} <span class=\"x\">html</span>", id),
+            author_id: author.into(),
+            category_id: Some(CategoryId::new(category)),
+            tag_ids: vec![],
+            status: PostStatus::Published,
+            publish_at: None,
+            published_at: Some(fixed_now() - Duration::days(days_ago)),
+            created_at: fixed_now() - Duration::days(30),
+        }
+    }
+
+    fn posts() -> Vec<Post> {
+        let mut draft = post("draft", "life", "trusted", 0);
+        draft.status = PostStatus::Draft;
+        draft.published_at = None;
+        vec![
+            post("old", "lang", "trusted", 5),
+            post("new", "rust", "untrusted", 1),
+            post("life", "life", "trusted", 3),
+            draft,
+        ]
+    }
+
+    fn input(scope: FeedScope) -> GetFeedInput {
+        GetFeedInput {
+            scope,
+            format: FeedFormat::Rss,
+            content: FeedContent::Full,
+        }
+    }
+
+    fn titles(xml: &str) -> Vec<String> {
+        let doc = Document::parse(xml).unwrap();
+        doc.descendants()
+            .filter(|n| n.tag_name().name() == "item")
+            .filter_map(|item| item.children().find(|n| n.tag_name().name() == "title"))
+            .filter_map(|title| title.text().map(String::from))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_list_published_posts_newest_first() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(input(FeedScope::Site)).await.unwrap();
+
+        assert_eq!(titles(&output.body), ["Post new", "Post life", "Post old"]);
+        assert_eq!(output.content_type, "application/rss+xml; charset=utf-8");
+    }
+
+    #[tokio::test]
+    async fn should_cover_the_category_subtree() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(input(FeedScope::Category("lang".into())))
+            .await
+            .unwrap();
+
+        assert_eq!(titles(&output.body), ["Post new", "Post old"]);
+        assert!(output
+            .body
+            .contains("<atom:link href=\"https://blog.example.com/categories/lang/feed.rss\""));
+    }
+
+    #[tokio::test]
+    async fn should_cover_the_posts_of_an_author() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(input(FeedScope::Author("trusted".into())))
+            .await
+            .unwrap();
+
+        assert_eq!(titles(&output.body), ["Post life", "Post old"]);
+        assert!(output.body.contains("<title>Blog - trusted author</title>"));
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_for_unknown_categories_and_authors() {
+        let c = create_interactor();
+
+        for scope in [
+            FeedScope::Category("nope".into()),
+            FeedScope::Author("nope".into()),
+        ] {
+            let err = c.interactor.execute(input(scope)).await.unwrap_err();
+            assert_not_found_error(err);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_limit_the_number_of_entries() {
+        let c = create_interactor();
+        let mut config = config();
+        config.max_entries = 2;
+        let interactor = GetFeedInteractor::new(
+            c.posts_repo,
+            c.categories_repo,
+            c.users_repo,
+            c.renderer,
+            c.sanitizer,
+            Arc::new(config),
+        );
+
+        let output = interactor.execute(input(FeedScope::Site)).await.unwrap();
+
+        assert_eq!(titles(&output.body), ["Post new", "Post life"]);
+    }
+
+    #[tokio::test]
+    async fn should_sanitize_content_by_the_trust_of_its_author() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(input(FeedScope::Site)).await.unwrap();
+
+        let doc = Document::parse(&output.body).unwrap();
+        let contents: Vec<_> = doc
+            .descendants()
+            .filter(|n| n.tag_name().name() == "encoded")
+            .filter_map(|n| n.text())
+            .collect();
+        assert_eq!(contents[0], "<p>Content of new html</p>\n");
+        assert_eq!(
+            contents[1],
+            "<p>Content of life <span class=\"x\">html</span></p>\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_only_include_the_excerpt_when_asked_to() {
+        let c = create_interactor();
+        let mut input = input(FeedScope::Site);
+        input.content = FeedContent::Excerpt;
+
+        let output = c.interactor.execute(input).await.unwrap();
+
+        assert!(!output.body.contains("content:encoded>"));
+        assert!(output
+            .body
+            .contains("<description>Content of new html</description>"));
+    }
+
+    #[tokio::test]
+    async fn should_write_atom_when_asked_to() {
+        let c = create_interactor();
+        let mut input = input(FeedScope::Site);
+        input.format = FeedFormat::Atom;
+
+        let output = c.interactor.execute(input).await.unwrap();
+
+        let doc = Document::parse(&output.body).unwrap();
+        assert_eq!(doc.root_element().tag_name().name(), "feed");
+        assert_eq!(output.content_type, "application/atom+xml; charset=utf-8");
+    }
+
+    #[tokio::test]
+    async fn should_derive_the_validators_from_the_newest_entry_and_the_body() {
+        let c = create_interactor();
+
+        let first = c.interactor.execute(input(FeedScope::Site)).await.unwrap();
+        let second = c.interactor.execute(input(FeedScope::Site)).await.unwrap();
+        let mut post = posts().remove(0);
+        post.title = "Renamed".into();
+        c.posts_repo.update(&post).await.unwrap();
+        let third = c.interactor.execute(input(FeedScope::Site)).await.unwrap();
+
+        assert_eq!(first.last_modified, "Wed, 31 Dec 2025 12:00:00 GMT");
+        assert_eq!(first.etag, second.etag);
+        assert_ne!(first.etag, third.etag);
+        assert_eq!(first.etag.len(), 66);
+    }
+
+    #[tokio::test]
+    async fn should_date_an_empty_feed_at_the_epoch() {
+        let c = create_interactor();
+
+        let first = c
+            .interactor
+            .execute(input(FeedScope::Category("misc".into())))
+            .await
+            .unwrap();
+        let second = c
+            .interactor
+            .execute(input(FeedScope::Category("misc".into())))
+            .await
+            .unwrap();
+
+        assert!(titles(&first.body).is_empty());
+        assert_eq!(first.last_modified, "Thu, 01 Jan 1970 00:00:00 GMT");
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_feed;
+pub mod utils;
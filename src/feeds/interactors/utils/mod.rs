@@ -0,0 +1,68 @@
+use crate::feeds::domain::FeedFormat;
+use crate::utils::{markdown_to_text, SiteUrls};
+
+#[derive(Debug, Clone)]
+pub struct FeedConfig {
+    pub title: String,
+    pub description: String,
+    pub urls: SiteUrls,
+    pub language: String,
+    pub max_entries: usize,
+    /// In characters.
+    pub excerpt_length: usize,
+}
+
+impl FeedConfig {
+    /// The feed of a page is served under it, e.g. `/categories/rust/feed.atom`.
+    pub fn feed_url(&self, page_url: &str, format: FeedFormat) -> String {
+        format!("{}/feed.{}", page_url, format.extension())
+    }
+}
+
+/// The plain text of a markdown document cut at a word boundary to at most `max_chars`
+/// characters, followed by an ellipsis when cut. A first word longer than that is cut inside.
+pub fn excerpt(markdown: &str, max_chars: usize) -> String {
+    let text = markdown_to_text(markdown);
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    let mut result = String::new();
+    for word in &words {
+        let separator = usize::from(!result.is_empty());
+        if result.chars().count() + separator + word.chars().count() > max_chars {
+            if result.is_empty() {
+                result.extend(word.chars().take(max_chars));
+            }
+            result.push('…');
+            return result;
+        }
+        if separator == 1 {
+            result.push(' ');
+        }
+        result.push_str(word);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_strip_markdown_from_the_excerpt() {
+        assert_eq!(
+            excerpt("# Title\n\nSome *emphasis* and `code`.\n\n- item", 100),
+            "Title Some emphasis and code. item"
+        );
+    }
+
+    #[test]
+    fn should_cut_the_excerpt_at_a_word_boundary() {
+        assert_eq!(excerpt("one two three", 9), "one two…");
+        assert_eq!(excerpt("one two three", 13), "one two three");
+    }
+
+    #[test]
+    fn should_cut_inside_a_first_word_longer_than_the_excerpt() {
+        assert_eq!(excerpt("Donaudampfschifffahrt ahead", 5), "Donau…");
+    }
+}
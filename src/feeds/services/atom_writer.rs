@@ -0,0 +1,160 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::feeds::domain::{Feed, FeedFormat};
+use crate::utils::{escape_xml, push_xml_element};
+
+/// Writes `feed` as an Atom 1.0 (RFC 4287) document.
+///
+/// Posts are not tracked after they go live, so an entry's `updated` is its publish time.
+pub fn write_atom(feed: &Feed) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">",
+        escape_xml(&feed.language)
+    ));
+    push_xml_element(&mut out, "id", &feed.self_link);
+    push_xml_element(&mut out, "title", &feed.title);
+    push_xml_element(&mut out, "subtitle", &feed.description);
+    push_xml_element(&mut out, "updated", &timestamp(feed.updated));
+    push_link(
+        &mut out,
+        "self",
+        &feed.self_link,
+        FeedFormat::Atom.content_type(),
+    );
+    push_link(&mut out, "alternate", &feed.link, "text/html");
+    for entry in &feed.entries {
+        out.push_str("<entry>");
+        push_xml_element(&mut out, "id", &entry.link);
+        push_xml_element(&mut out, "title", &entry.title);
+        push_link(&mut out, "alternate", &entry.link, "text/html");
+        push_xml_element(&mut out, "published", &timestamp(entry.published));
+        push_xml_element(&mut out, "updated", &timestamp(entry.published));
+        out.push_str("<author>");
+        push_xml_element(&mut out, "name", &entry.author_name);
+        out.push_str("</author>");
+        for category in &entry.categories {
+            out.push_str(&format!("<category term=\"{}\"/>", escape_xml(category)));
+        }
+        out.push_str(&format!(
+            "<summary type=\"text\">{}</summary>",
+            escape_xml(&entry.summary)
+        ));
+        if let Some(content) = &entry.content {
+            out.push_str(&format!(
+                "<content type=\"html\">{}</content>",
+                escape_xml(content)
+            ));
+        }
+        out.push_str("</entry>");
+    }
+    out.push_str("</feed>\n");
+    out
+}
+
+fn push_link(out: &mut String, rel: &str, href: &str, content_type: &str) {
+    out.push_str(&format!(
+        "<link rel=\"{}\" href=\"{}\" type=\"{}\"/>",
+        rel,
+        escape_xml(href),
+        escape_xml(content_type)
+    ));
+}
+
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use roxmltree::{Document, Node};
+
+    use crate::feeds::services::sample::sample_feed;
+
+    use super::*;
+
+    const ATOM_NS: &str = "http://www.w3.org/2005/Atom";
+
+    fn children<'a>(node: Node<'a, 'a>, name: &'a str) -> impl Iterator<Item = Node<'a, 'a>> {
+        node.children()
+            .filter(move |n| n.is_element() && n.tag_name().name() == name)
+    }
+
+    fn text_of(node: Node, name: &str) -> String {
+        children(node, name)
+            .next()
+            .and_then(|n| n.text())
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    fn link<'a>(node: Node<'a, 'a>, rel: &str) -> Option<&'a str> {
+        children(node, "link")
+            .find(|n| n.attribute("rel") == Some(rel))
+            .and_then(|n| n.attribute("href"))
+    }
+
+    #[test]
+    fn should_write_the_required_feed_elements() {
+        let xml = write_atom(&sample_feed());
+        let doc = Document::parse(&xml).unwrap();
+
+        let feed = doc.root_element();
+        assert_eq!(feed.tag_name().name(), "feed");
+        assert_eq!(feed.tag_name().namespace(), Some(ATOM_NS));
+        assert_eq!(text_of(feed, "id"), "https://blog.example.com/feed.rss");
+        assert_eq!(text_of(feed, "title"), "Tom & Jerry's blog");
+        assert_eq!(text_of(feed, "updated"), "2026-10-19T05:30:00Z");
+        assert_eq!(
+            link(feed, "self"),
+            Some("https://blog.example.com/feed.rss")
+        );
+        assert_eq!(link(feed, "alternate"), Some("https://blog.example.com"));
+    }
+
+    #[test]
+    fn should_write_the_required_entry_elements() {
+        let xml = write_atom(&sample_feed());
+        let doc = Document::parse(&xml).unwrap();
+
+        let entries: Vec<_> = children(doc.root_element(), "entry").collect();
+        assert_eq!(entries.len(), 2);
+        for entry in &entries {
+            for required in ["id", "title", "updated", "author", "summary"] {
+                assert_eq!(children(*entry, required).count(), 1, "{}", required);
+            }
+            assert!(link(*entry, "alternate").is_some());
+            let author = children(*entry, "author").next().unwrap();
+            assert!(!text_of(author, "name").is_empty());
+        }
+    }
+
+    #[test]
+    fn should_write_the_entry_values() {
+        let xml = write_atom(&sample_feed());
+        let doc = Document::parse(&xml).unwrap();
+
+        let entry = children(doc.root_element(), "entry").next().unwrap();
+        assert_eq!(
+            text_of(entry, "id"),
+            "https://blog.example.com/posts/chasing-mice"
+        );
+        assert_eq!(text_of(entry, "title"), "Chasing <mice>");
+        assert_eq!(text_of(entry, "published"), "2026-10-19T05:30:00Z");
+        let category = children(entry, "category").next().unwrap();
+        assert_eq!(category.attribute("term"), Some("Cats"));
+        let content = children(entry, "content").next().unwrap();
+        assert_eq!(content.attribute("type"), Some("html"));
+        assert_eq!(content.text(), Some("<p>A &amp; B</p>"));
+    }
+
+    #[test]
+    fn should_leave_out_the_content_of_excerpt_only_entries() {
+        let xml = write_atom(&sample_feed());
+        let doc = Document::parse(&xml).unwrap();
+
+        let entry = children(doc.root_element(), "entry").nth(1).unwrap();
+        assert_eq!(children(entry, "content").count(), 0);
+        assert_eq!(text_of(entry, "summary"), "Mice like it");
+    }
+}
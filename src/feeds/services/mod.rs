@@ -0,0 +1,7 @@
+pub use atom_writer::write_atom;
+pub use rss_writer::write_rss;
+
+mod atom_writer;
+mod rss_writer;
+#[cfg(test)]
+mod sample;
@@ -0,0 +1,139 @@
+use crate::feeds::domain::{Feed, FeedFormat};
+use crate::utils::{escape_xml, push_xml_element};
+
+/// Writes `feed` as an RSS 2.0 document.
+///
+/// Authors go to `dc:creator` since `author` must be an email address, and the full content
+/// to `content:encoded`, leaving `description` to the excerpt.
+pub fn write_rss(feed: &Feed) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(concat!(
+        "<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\"",
+        " xmlns:content=\"http://purl.org/rss/1.0/modules/content/\"",
+        " xmlns:dc=\"http://purl.org/dc/elements/1.1/\">",
+    ));
+    out.push_str("<channel>");
+    push_xml_element(&mut out, "title", &feed.title);
+    push_xml_element(&mut out, "link", &feed.link);
+    push_xml_element(&mut out, "description", &feed.description);
+    push_xml_element(&mut out, "language", &feed.language);
+    push_xml_element(&mut out, "lastBuildDate", &feed.updated.to_rfc2822());
+    out.push_str(&format!(
+        "<atom:link href=\"{}\" rel=\"self\" type=\"{}\"/>",
+        escape_xml(&feed.self_link),
+        escape_xml(FeedFormat::Rss.content_type())
+    ));
+    for entry in &feed.entries {
+        out.push_str("<item>");
+        push_xml_element(&mut out, "title", &entry.title);
+        push_xml_element(&mut out, "link", &entry.link);
+        out.push_str(&format!(
+            "<guid isPermaLink=\"true\">{}</guid>",
+            escape_xml(&entry.link)
+        ));
+        push_xml_element(&mut out, "pubDate", &entry.published.to_rfc2822());
+        push_xml_element(&mut out, "dc:creator", &entry.author_name);
+        for category in &entry.categories {
+            push_xml_element(&mut out, "category", category);
+        }
+        push_xml_element(&mut out, "description", &entry.summary);
+        if let Some(content) = &entry.content {
+            push_xml_element(&mut out, "content:encoded", content);
+        }
+        out.push_str("</item>");
+    }
+    out.push_str("</channel></rss>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use roxmltree::{Document, Node};
+
+    use crate::feeds::services::sample::sample_feed;
+
+    use super::*;
+
+    const CONTENT_NS: &str = "http://purl.org/rss/1.0/modules/content/";
+
+    fn child<'a>(node: Node<'a, 'a>, name: &str) -> Option<Node<'a, 'a>> {
+        node.children()
+            .find(|n| n.is_element() && n.tag_name().name() == name)
+    }
+
+    fn text_of(node: Node, name: &str) -> String {
+        child(node, name)
+            .and_then(|n| n.text())
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[test]
+    fn should_write_the_required_channel_elements() {
+        let xml = write_rss(&sample_feed());
+        let doc = Document::parse(&xml).unwrap();
+
+        let rss = doc.root_element();
+        assert_eq!(rss.tag_name().name(), "rss");
+        assert_eq!(rss.attribute("version"), Some("2.0"));
+        let channel = child(rss, "channel").unwrap();
+        assert_eq!(text_of(channel, "title"), "Tom & Jerry's blog");
+        assert_eq!(text_of(channel, "link"), "https://blog.example.com");
+        assert_eq!(text_of(channel, "description"), "Notes on <cats> and mice");
+        assert_eq!(
+            text_of(channel, "lastBuildDate"),
+            "Mon, 19 Oct 2026 05:30:00 +0000"
+        );
+        let self_link = channel
+            .children()
+            .find(|n| n.tag_name().name() == "link" && n.attribute("rel") == Some("self"))
+            .unwrap();
+        assert_eq!(
+            self_link.attribute("href"),
+            Some("https://blog.example.com/feed.rss")
+        );
+    }
+
+    #[test]
+    fn should_write_an_item_per_entry() {
+        let xml = write_rss(&sample_feed());
+        let doc = Document::parse(&xml).unwrap();
+
+        let items: Vec<_> = doc
+            .descendants()
+            .filter(|n| n.tag_name().name() == "item")
+            .collect();
+        assert_eq!(items.len(), 2);
+        let item = items[0];
+        assert_eq!(text_of(item, "title"), "Chasing <mice>");
+        assert_eq!(
+            text_of(item, "guid"),
+            "https://blog.example.com/posts/chasing-mice"
+        );
+        assert_eq!(
+            child(item, "guid").unwrap().attribute("isPermaLink"),
+            Some("true")
+        );
+        assert_eq!(text_of(item, "pubDate"), "Mon, 19 Oct 2026 05:30:00 +0000");
+        assert_eq!(text_of(item, "creator"), "Tom");
+        assert_eq!(text_of(item, "category"), "Cats");
+        assert_eq!(text_of(item, "description"), "A & B");
+        let content = child(item, "encoded").unwrap();
+        assert_eq!(content.tag_name().namespace(), Some(CONTENT_NS));
+        assert_eq!(content.text(), Some("<p>A &amp; B</p>"));
+    }
+
+    #[test]
+    fn should_leave_out_the_content_of_excerpt_only_entries() {
+        let xml = write_rss(&sample_feed());
+        let doc = Document::parse(&xml).unwrap();
+
+        let item = doc
+            .descendants()
+            .filter(|n| n.tag_name().name() == "item")
+            .nth(1)
+            .unwrap();
+        assert!(child(item, "encoded").is_none());
+        assert!(child(item, "category").is_none());
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::{TimeZone, Utc};
+
+use crate::feeds::domain::{Feed, FeedEntry};
+
+pub fn sample_feed() -> Feed {
+    Feed {
+        title: "Tom & Jerry's blog".into(),
+        description: "Notes on <cats> and mice".into(),
+        link: "https://blog.example.com".into(),
+        self_link: "https://blog.example.com/feed.rss".into(),
+        language: "en".into(),
+        updated: Utc.with_ymd_and_hms(2026, 10, 19, 5, 30, 0).unwrap(),
+        entries: vec![
+            FeedEntry {
+                title: "Chasing <mice>".into(),
+                link: "https://blog.example.com/posts/chasing-mice".into(),
+                author_name: "Tom".into(),
+                published: Utc.with_ymd_and_hms(2026, 10, 19, 5, 30, 0).unwrap(),
+                categories: vec!["Cats".into()],
+                summary: "A & B".into(),
+                content: Some("<p>A &amp; B</p>".into()),
+            },
+            FeedEntry {
+                title: "Cheese".into(),
+                link: "https://blog.example.com/posts/cheese".into(),
+                author_name: "Jerry".into(),
+                published: Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap(),
+                categories: vec![],
+                summary: "Mice like it".into(),
+                content: None,
+            },
+        ],
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+pub use wordpress::*;
+
+mod wordpress;
+
+/// What an imported entity became in this blog.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportKind {
+    User,
+    Category,
+    Tag,
+    Post,
+    Comment,
+    Media,
+}
+
+/// Remembers which entity an item of an export was imported as, so running the same import
+/// again skips it instead of creating a copy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportMapping {
+    /// Identifies the exporting site, ids are only unique within one site.
+    pub source: String,
+    pub kind: ImportKind,
+    /// The id the item had in the export.
+    pub original_id: String,
+    pub local_id: String,
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+
+/// The content of a WordPress eXtended RSS (WXR) export, as WordPress wrote it.
+///
+/// Statuses and flags are kept as the raw WordPress values; deciding what they mean here is up
+/// to the importer.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WxrExport {
+    pub site_url: String,
+    pub authors: Vec<WxrAuthor>,
+    pub categories: Vec<WxrCategory>,
+    pub tags: Vec<WxrTag>,
+    pub posts: Vec<WxrPost>,
+    pub attachments: Vec<WxrAttachment>,
+    /// Pages, menu items and the other item types that have no counterpart here.
+    pub skipped_items: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WxrAuthor {
+    pub id: String,
+    pub login: String,
+    pub email: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WxrCategory {
+    pub id: String,
+    /// The slug, percent-encoded when it has non ASCII characters.
+    pub nicename: String,
+    pub name: String,
+    pub description: String,
+    /// The nicename of the parent, `None` for a top-level category.
+    pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WxrTag {
+    pub id: String,
+    pub slug: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WxrPost {
+    pub id: String,
+    pub title: String,
+    /// Empty for drafts that were never given one.
+    pub slug: String,
+    /// HTML, possibly with block editor comments.
+    pub content: String,
+    pub author_login: String,
+    /// `publish`, `future`, `draft`, `pending`, `private`, `trash`, ...
+    pub status: String,
+    pub date: Option<DateTime<Utc>>,
+    /// Category nicenames, in the order of the export.
+    pub categories: Vec<String>,
+    pub tags: Vec<String>,
+    pub comments: Vec<WxrComment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WxrComment {
+    pub id: String,
+    pub parent_id: Option<String>,
+    pub author_name: String,
+    pub author_email: String,
+    /// The WordPress user id of the author, `None` for readers without an account.
+    pub author_user_id: Option<String>,
+    pub content: String,
+    /// `1`, `0`, `spam` or `trash`.
+    pub approved: String,
+    /// Empty for regular comments, `pingback` or `trackback` otherwise.
+    pub comment_type: String,
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WxrAttachment {
+    pub id: String,
+    pub url: String,
+    pub author_login: String,
+}
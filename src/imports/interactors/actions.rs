@@ -0,0 +1 @@
+pub const IMPORT_WORDPRESS_ACTION: &str = "IMPORT_WORDPRESS_ACTION";
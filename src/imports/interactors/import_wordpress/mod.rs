@@ -0,0 +1,1172 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
+use crate::categories::domain::{Category, CategoryId};
+use crate::comments::domain::{Comment, CommentAuthor, CommentId, CommentStatus};
+use crate::errors::validation::ValidationError;
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::imports::domain::{
+    ImportKind, ImportMapping, WxrAttachment, WxrAuthor, WxrCategory, WxrComment, WxrPost, WxrTag,
+};
+use crate::imports::interactors::actions::IMPORT_WORDPRESS_ACTION;
+use crate::imports::interactors::traits::WxrParser;
+use crate::imports::interactors::utils::{
+    decode_wordpress_slug, parents_first, ImportContentRepositories, ImportMediaServices,
+    ImportReport, ImportUserServices, PlannedSlugAvailability, RenamedSlug,
+};
+use crate::media::domain::{MediaId, MediaItem, MediaType};
+use crate::media::interactors::utils::{process_image, store_processed_image};
+use crate::posts::domain::{Post, PostId, PostStatus};
+use crate::tags::domain::{Tag, TagId};
+use crate::users::domain::User;
+use crate::utils::{
+    AuthPayload, Clock, RandomService, RepositorySlugAvailability, SlugAvailability,
+};
+
+#[derive(Debug, Clone)]
+pub struct ImportWordpressInput {
+    /// The WXR file from Tools > Export in the WordPress admin.
+    pub xml: String,
+    /// The role of created users whose login is not in `roles`.
+    pub default_role: String,
+    /// Role names by WordPress login.
+    pub roles: HashMap<String, String>,
+    /// Reports what an import would do without writing anything. Attachments are not read, so
+    /// ones that turn out not to be images still count as created.
+    pub dry_run: bool,
+}
+
+/// Imports a WordPress site from its WXR export.
+///
+/// Every imported item is remembered by its WordPress id, so running the import again only adds
+/// what is new in the export; items deleted here after an import stay deleted. Users with an
+/// email that is already registered and tags with a name that already exists are reused instead
+/// of duplicated. Attachments are read from the `AttachmentSource` and stored as media, and the
+/// links to them in the imported posts are pointed at the new files.
+#[derive(WithDeps)]
+pub struct ImportWordpressInteractor {
+    parser: Arc<dyn WxrParser>,
+    content: Arc<ImportContentRepositories>,
+    users: Arc<ImportUserServices>,
+    media: Arc<ImportMediaServices>,
+    random: Arc<dyn RandomService>,
+    clock: Arc<dyn Clock>,
+    audit: Arc<dyn AuditLog>,
+}
+
+impl ImportWordpressInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ImportWordpressInput,
+    ) -> ApplicationResult<ImportReport> {
+        let target = "wordpress".to_string();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            IMPORT_WORDPRESS_ACTION,
+            &target,
+            self.import(auth, input),
+        )
+        .await
+    }
+
+    async fn import(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ImportWordpressInput,
+    ) -> ApplicationResult<ImportReport> {
+        auth.can_or_fail(IMPORT_WORDPRESS_ACTION)?;
+        self.validate_roles(&input)?;
+
+        let xml_error = |message: String| ValidationError::new("xml".into(), "".into(), message);
+        let export = self
+            .parser
+            .parse(&input.xml)
+            .map_err(|e| xml_error(format!("the export could not be read: {}", e)))?;
+        if export.site_url.is_empty() {
+            return Err(xml_error("the export does not name its site".into()).into());
+        }
+
+        let mut run = ImportRun::new(self, &input, export.site_url.clone(), auth.get_user_id());
+        if export.skipped_items > 0 {
+            run.report.warnings.push(format!(
+                "{} items that are neither posts nor attachments were skipped",
+                export.skipped_items
+            ));
+        }
+        for author in &export.authors {
+            run.import_user(author).await?;
+        }
+        let categories = parents_first(
+            &export.categories,
+            |c| c.nicename.as_str(),
+            |c| c.parent.as_deref(),
+        );
+        for category in categories {
+            run.import_category(category).await?;
+        }
+        for tag in &export.tags {
+            run.import_tag(tag).await?;
+        }
+        for attachment in &export.attachments {
+            run.import_attachment(attachment).await?;
+        }
+        for post in &export.posts {
+            run.import_post(post).await?;
+        }
+        Ok(run.report)
+    }
+
+    fn validate_roles(&self, input: &ImportWordpressInput) -> Result<(), ValidationError> {
+        for role in std::iter::once(&input.default_role).chain(input.roles.values()) {
+            if !self.users.role_factory.is_valid_role_name(role) {
+                return Err(ValidationError::new(
+                    "role".into(),
+                    role.clone(),
+                    format!("Role {} not found", role),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The state of one import: what each WordPress reference resolved to so far.
+struct ImportRun<'a> {
+    i: &'a ImportWordpressInteractor,
+    input: &'a ImportWordpressInput,
+    source: String,
+    importer_id: String,
+    report: ImportReport,
+    users_by_login: HashMap<String, String>,
+    users_by_wordpress_id: HashMap<String, String>,
+    categories_by_nicename: HashMap<String, CategoryId>,
+    tags_by_slug: HashMap<String, TagId>,
+    /// Attachment URLs and the URLs of the stored copies.
+    media_urls: Vec<(String, String)>,
+    /// The comments of the current post by WordPress id, with their depth.
+    comments: HashMap<String, (CommentId, u32)>,
+    planned_slugs: HashMap<ImportKind, HashSet<String>>,
+}
+
+impl<'a> ImportRun<'a> {
+    fn new(
+        i: &'a ImportWordpressInteractor,
+        input: &'a ImportWordpressInput,
+        source: String,
+        importer_id: String,
+    ) -> Self {
+        Self {
+            i,
+            input,
+            source,
+            importer_id,
+            report: ImportReport {
+                dry_run: input.dry_run,
+                ..Default::default()
+            },
+            users_by_login: HashMap::new(),
+            users_by_wordpress_id: HashMap::new(),
+            categories_by_nicename: HashMap::new(),
+            tags_by_slug: HashMap::new(),
+            media_urls: vec![],
+            comments: HashMap::new(),
+            planned_slugs: HashMap::new(),
+        }
+    }
+
+    async fn mapped(&self, kind: ImportKind, original_id: &str) -> UnknownResult<Option<String>> {
+        let mapping = self
+            .i
+            .content
+            .mappings
+            .get(&self.source, kind, original_id)
+            .await?;
+        Ok(mapping.map(|m| m.local_id))
+    }
+
+    async fn remember(
+        &self,
+        kind: ImportKind,
+        original_id: &str,
+        local_id: String,
+    ) -> UnknownResult<()> {
+        if self.input.dry_run {
+            return Ok(());
+        }
+        self.i
+            .content
+            .mappings
+            .save(&ImportMapping {
+                source: self.source.clone(),
+                kind,
+                original_id: original_id.into(),
+                local_id,
+            })
+            .await
+    }
+
+    /// Generates a slug from the first of `candidates` that makes one, keeping it if it is free.
+    async fn slug(
+        &mut self,
+        kind: ImportKind,
+        candidates: &[&str],
+        availability: &(dyn SlugAvailability),
+    ) -> ApplicationResult<String> {
+        let generator = self.i.content.slug_generator.as_ref();
+        let wanted = candidates
+            .iter()
+            .find(|candidate| !generator.slugify(candidate).is_empty())
+            .copied()
+            .unwrap_or_default();
+        let planned = self.planned_slugs.entry(kind).or_default();
+        let slug = generator
+            .generate(
+                wanted,
+                &PlannedSlugAvailability {
+                    inner: availability,
+                    planned,
+                },
+            )
+            .await?;
+        planned.insert(slug.clone());
+        if slug != generator.slugify(wanted) {
+            self.report.renamed_slugs.push(RenamedSlug {
+                kind,
+                original: wanted.into(),
+                slug: slug.clone(),
+            });
+        }
+        Ok(slug)
+    }
+
+    async fn import_user(&mut self, author: &WxrAuthor) -> ApplicationResult<()> {
+        if let Some(id) = self.mapped(ImportKind::User, &author.id).await? {
+            if let Some(user) = self.i.users.users_repo.get_by_id(&id).await? {
+                self.add_user(author, user.id);
+            }
+            self.report.users.existing += 1;
+            return Ok(());
+        }
+        if let Some(user) = self.i.users.users_repo.get_by_email(&author.email).await? {
+            self.remember(ImportKind::User, &author.id, user.id.clone())
+                .await?;
+            self.add_user(author, user.id);
+            self.report.users.existing += 1;
+            return Ok(());
+        }
+        if !validator::validate_email(&author.email) {
+            self.report.warnings.push(format!(
+                "user {} was skipped, {:?} is not a valid email",
+                author.login, author.email
+            ));
+            self.report.users.skipped += 1;
+            return Ok(());
+        }
+
+        let role_name = self
+            .input
+            .roles
+            .get(&author.login)
+            .unwrap_or(&self.input.default_role);
+        let role = self
+            .i
+            .users
+            .role_factory
+            .create_role(role_name)
+            .ok_or_else(|| {
+                ValidationError::new(
+                    "role".into(),
+                    role_name.clone(),
+                    format!("Role {} not found", role_name),
+                )
+            })?;
+        // imported users choose their password through a reset, so a dry run has none to hash
+        let password = match self.input.dry_run {
+            true => String::new(),
+            false => {
+                let password = self.i.random.secure_random_password().await?;
+                self.i.users.crypto.hash(&password).await?
+            }
+        };
+        let user = User {
+            id: self.i.random.random_id().await?,
+            name: match author.display_name.is_empty() {
+                true => author.login.clone(),
+                false => author.display_name.clone(),
+            },
+            email: author.email.clone(),
+            password,
+            role,
+        };
+        if !self.input.dry_run {
+            self.i.users.users_repo.create(&user).await?;
+        }
+        self.remember(ImportKind::User, &author.id, user.id.clone())
+            .await?;
+        self.add_user(author, user.id);
+        self.report.users.created += 1;
+        Ok(())
+    }
+
+    fn add_user(&mut self, author: &WxrAuthor, user_id: String) {
+        self.users_by_login
+            .insert(author.login.clone(), user_id.clone());
+        self.users_by_wordpress_id
+            .insert(author.id.clone(), user_id);
+    }
+
+    async fn import_category(&mut self, category: &WxrCategory) -> ApplicationResult<()> {
+        let i = self.i;
+        if let Some(id) = self.mapped(ImportKind::Category, &category.id).await? {
+            let id = CategoryId::from(id);
+            if i.content.categories_repo.get_by_id(&id).await?.is_some() {
+                self.categories_by_nicename
+                    .insert(category.nicename.clone(), id);
+            }
+            self.report.categories.existing += 1;
+            return Ok(());
+        }
+
+        let availability = RepositorySlugAvailability {
+            repo: i.content.categories_repo.as_ref(),
+            slug_history: i.content.slug_history.as_ref(),
+            owner_id: None,
+        };
+        let slug = self
+            .slug(
+                ImportKind::Category,
+                &[&decode_wordpress_slug(&category.nicename), &category.name],
+                &availability,
+            )
+            .await?;
+        let created = Category {
+            id: CategoryId::new(&i.random.random_id().await?),
+            name: category.name.clone(),
+            description: category.description.clone(),
+            created_at: i.clock.now(),
+            slug,
+            parent_id: category
+                .parent
+                .as_ref()
+                .and_then(|parent| self.categories_by_nicename.get(parent))
+                .cloned(),
+        };
+        if !self.input.dry_run {
+            i.content.categories_repo.create(&created).await?;
+        }
+        self.remember(ImportKind::Category, &category.id, created.id.to_string())
+            .await?;
+        self.categories_by_nicename
+            .insert(category.nicename.clone(), created.id);
+        self.report.categories.created += 1;
+        Ok(())
+    }
+
+    async fn import_tag(&mut self, tag: &WxrTag) -> ApplicationResult<()> {
+        let i = self.i;
+        if let Some(id) = self.mapped(ImportKind::Tag, &tag.id).await? {
+            let id = TagId::from(id);
+            if i.content.tags_repo.get_by_id(&id).await?.is_some() {
+                self.tags_by_slug.insert(tag.slug.clone(), id);
+            }
+            self.report.tags.existing += 1;
+            return Ok(());
+        }
+        if let Some(existing) = i.content.tags_repo.get_by_name(&tag.name).await? {
+            self.remember(ImportKind::Tag, &tag.id, existing.id.to_string())
+                .await?;
+            self.tags_by_slug.insert(tag.slug.clone(), existing.id);
+            self.report.tags.existing += 1;
+            return Ok(());
+        }
+
+        let availability = RepositorySlugAvailability {
+            repo: i.content.tags_repo.as_ref(),
+            slug_history: i.content.slug_history.as_ref(),
+            owner_id: None,
+        };
+        let slug = self
+            .slug(
+                ImportKind::Tag,
+                &[&decode_wordpress_slug(&tag.slug), &tag.name],
+                &availability,
+            )
+            .await?;
+        let created = Tag {
+            id: TagId::new(&i.random.random_id().await?),
+            name: tag.name.clone(),
+            slug,
+            created_at: i.clock.now(),
+        };
+        if !self.input.dry_run {
+            i.content.tags_repo.create(&created).await?;
+        }
+        self.remember(ImportKind::Tag, &tag.id, created.id.to_string())
+            .await?;
+        self.tags_by_slug.insert(tag.slug.clone(), created.id);
+        self.report.tags.created += 1;
+        Ok(())
+    }
+
+    async fn import_attachment(&mut self, attachment: &WxrAttachment) -> ApplicationResult<()> {
+        let i = self.i;
+        if let Some(id) = self.mapped(ImportKind::Media, &attachment.id).await? {
+            if let Some(item) = i.media.media_repo.get_by_id(&MediaId::from(id)).await? {
+                let url = i.media.media_storage.url(&item.key);
+                self.media_urls.push((attachment.url.clone(), url));
+            }
+            self.report.media.existing += 1;
+            return Ok(());
+        }
+
+        // the size is asked first so a file that is too large is never read
+        let max_size = i.media.config.max_size;
+        let problem = match i.media.attachments.size(&attachment.url).await? {
+            None => Some("is not available"),
+            Some(size) if size > max_size => Some("is too large"),
+            Some(_) => None,
+        };
+        if let Some(problem) = problem {
+            self.skip_attachment(attachment, problem);
+            return Ok(());
+        }
+        // a dry run does not read the files, so it can not tell which ones are not images
+        if self.input.dry_run {
+            self.report.media.created += 1;
+            return Ok(());
+        }
+        let bytes = i.media.attachments.fetch(&attachment.url).await?;
+        let checked = match &bytes {
+            None => Err("is not available"),
+            Some(bytes) if bytes.len() > max_size => Err("is too large"),
+            Some(bytes) => MediaType::from_magic_bytes(bytes).ok_or("is not a supported image"),
+        };
+        let media_type = match checked {
+            Ok(media_type) => media_type,
+            Err(problem) => {
+                self.skip_attachment(attachment, problem);
+                return Ok(());
+            }
+        };
+        let bytes = bytes.unwrap_or_default();
+        let Ok(processed) = process_image(
+            &i.media.image_processor,
+            bytes,
+            media_type,
+            &i.media.config.variant_widths,
+        )
+        .await?
+        else {
+            self.skip_attachment(attachment, "could not be read");
+            return Ok(());
+        };
+
+        let id = MediaId::new(&i.random.random_id().await?);
+        let (key, variants) =
+            store_processed_image(i.media.media_storage.as_ref(), &id, media_type, &processed)
+                .await?;
+        let item = MediaItem {
+            id,
+            file_name: attachment
+                .url
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            media_type,
+            key,
+            size: processed.original.len(),
+            width: processed.width,
+            height: processed.height,
+            variants,
+            uploaded_by: self.author_id(&attachment.author_login),
+            created_at: i.clock.now(),
+        };
+        i.media.media_repo.create(&item).await?;
+        self.remember(ImportKind::Media, &attachment.id, item.id.to_string())
+            .await?;
+        self.media_urls
+            .push((attachment.url.clone(), i.media.media_storage.url(&item.key)));
+        self.report.media.created += 1;
+        Ok(())
+    }
+
+    fn skip_attachment(&mut self, attachment: &WxrAttachment, problem: &str) {
+        self.report.warnings.push(format!(
+            "attachment {} was skipped, it {}",
+            attachment.url, problem
+        ));
+        self.report.media.skipped += 1;
+    }
+
+    /// Falls back to the user running the import for authors that were not imported.
+    fn author_id(&self, login: &str) -> String {
+        self.users_by_login
+            .get(login)
+            .cloned()
+            .unwrap_or_else(|| self.importer_id.clone())
+    }
+
+    async fn import_post(&mut self, post: &WxrPost) -> ApplicationResult<()> {
+        let i = self.i;
+        let Some(status) = post_status(post) else {
+            self.report.warnings.push(format!(
+                "post {} was skipped, its status is {:?}",
+                post.id, post.status
+            ));
+            self.report.posts.skipped += 1;
+            return Ok(());
+        };
+        self.comments.clear();
+
+        if let Some(id) = self.mapped(ImportKind::Post, &post.id).await? {
+            let id = PostId::from(id);
+            self.report.posts.existing += 1;
+            // comments written since the last import still get in
+            if i.content.posts_repo.get_by_id(&id).await?.is_some() {
+                self.import_comments(&id, &post.comments).await?;
+            }
+            return Ok(());
+        }
+
+        if post.categories.len() > 1 {
+            self.report.warnings.push(format!(
+                "post {} has {} categories, only the first one was kept",
+                post.id,
+                post.categories.len()
+            ));
+        }
+        if !self.users_by_login.contains_key(&post.author_login) {
+            self.report.warnings.push(format!(
+                "post {} is attributed to you, its author {} was not imported",
+                post.id, post.author_login
+            ));
+        }
+        let availability = RepositorySlugAvailability {
+            repo: i.content.posts_repo.as_ref(),
+            slug_history: i.content.slug_history.as_ref(),
+            owner_id: None,
+        };
+        let fallback = format!("post-{}", post.id);
+        let slug = self
+            .slug(
+                ImportKind::Post,
+                &[&decode_wordpress_slug(&post.slug), &post.title, &fallback],
+                &availability,
+            )
+            .await?;
+
+        let mut content = post.content.clone();
+        for (old, new) in &self.media_urls {
+            content = content.replace(old, new);
+        }
+        let created_at = post.date.unwrap_or_else(|| i.clock.now());
+        let created = Post {
+            id: PostId::new(&i.random.random_id().await?),
+            title: post.title.clone(),
+            slug,
+            content,
+            author_id: self.author_id(&post.author_login),
+            category_id: post
+                .categories
+                .first()
+                .and_then(|nicename| self.categories_by_nicename.get(nicename))
+                .cloned(),
+            tag_ids: post
+                .tags
+                .iter()
+                .filter_map(|slug| self.tags_by_slug.get(slug))
+                .cloned()
+                .collect(),
+            status,
+            publish_at: (status == PostStatus::Scheduled).then_some(created_at),
+            published_at: (status == PostStatus::Published).then_some(created_at),
+            created_at,
+        };
+        if !self.input.dry_run {
+            i.content.posts_repo.create(&created).await?;
+        }
+        self.remember(ImportKind::Post, &post.id, created.id.to_string())
+            .await?;
+        self.report.posts.created += 1;
+        self.import_comments(&created.id, &post.comments).await
+    }
+
+    async fn import_comments(
+        &mut self,
+        post_id: &PostId,
+        comments: &[WxrComment],
+    ) -> ApplicationResult<()> {
+        // WordPress only lets a reply in after its parent, so the ids order parents first
+        let mut comments: Vec<&WxrComment> = comments.iter().collect();
+        comments.sort_by_key(|comment| comment.id.parse::<u64>().unwrap_or(u64::MAX));
+        for comment in comments {
+            self.import_comment(post_id, comment).await?;
+        }
+        Ok(())
+    }
+
+    async fn import_comment(
+        &mut self,
+        post_id: &PostId,
+        comment: &WxrComment,
+    ) -> ApplicationResult<()> {
+        let i = self.i;
+        let Some(status) = comment_status(comment) else {
+            self.report.comments.skipped += 1;
+            return Ok(());
+        };
+        if let Some(id) = self.mapped(ImportKind::Comment, &comment.id).await? {
+            if let Some(existing) = i
+                .content
+                .comments_repo
+                .get_by_id(&CommentId::from(id))
+                .await?
+            {
+                self.comments
+                    .insert(comment.id.clone(), (existing.id, existing.depth));
+            }
+            self.report.comments.existing += 1;
+            return Ok(());
+        }
+
+        let parent = comment
+            .parent_id
+            .as_ref()
+            .and_then(|parent| self.comments.get(parent))
+            .cloned();
+        let author = match comment
+            .author_user_id
+            .as_ref()
+            .and_then(|user_id| self.users_by_wordpress_id.get(user_id))
+        {
+            Some(user_id) => CommentAuthor::User {
+                user_id: user_id.clone(),
+            },
+            None => CommentAuthor::Anonymous {
+                name: comment.author_name.clone(),
+                email: Some(comment.author_email.clone()).filter(|email| !email.is_empty()),
+            },
+        };
+        // replies are kept however deep WordPress let them nest
+        let created = Comment {
+            id: CommentId::new(&i.random.random_id().await?),
+            post_id: post_id.clone(),
+            depth: parent.as_ref().map_or(0, |(_, depth)| depth + 1),
+            parent_id: parent.map(|(id, _)| id),
+            author,
+            content: comment.content.clone(),
+            status,
+            trained_as: None,
+            created_at: comment.date.unwrap_or_else(|| i.clock.now()),
+        };
+        if !self.input.dry_run {
+            i.content.comments_repo.create(&created).await?;
+        }
+        self.remember(ImportKind::Comment, &comment.id, created.id.to_string())
+            .await?;
+        self.comments
+            .insert(comment.id.clone(), (created.id, created.depth));
+        self.report.comments.created += 1;
+        Ok(())
+    }
+}
+
+/// Private posts become drafts so they stay hidden. Trashed posts and the drafts WordPress
+/// saves on its own are not imported.
+fn post_status(post: &WxrPost) -> Option<PostStatus> {
+    match post.status.as_str() {
+        "publish" => Some(PostStatus::Published),
+        "future" if post.date.is_some() => Some(PostStatus::Scheduled),
+        "pending" => Some(PostStatus::InReview),
+        "draft" | "private" | "future" => Some(PostStatus::Draft),
+        _ => None,
+    }
+}
+
+/// Pingbacks and trackbacks are not comments here.
+fn comment_status(comment: &WxrComment) -> Option<CommentStatus> {
+    if !matches!(comment.comment_type.as_str(), "" | "comment") {
+        return None;
+    }
+    match comment.approved.as_str() {
+        "1" => Some(CommentStatus::Approved),
+        "0" => Some(CommentStatus::Pending),
+        "spam" => Some(CommentStatus::Spam),
+        "trash" => Some(CommentStatus::Deleted),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::access_management::variants::Admin;
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::categories::interactors::traits::CategoriesRepository;
+    use crate::comments::interactors::test_doubles::fake_comments_repository::FakeCommentsRepository;
+    use crate::imports::interactors::test_doubles::fake_attachment_source::FakeAttachmentSource;
+    use crate::imports::interactors::test_doubles::fake_import_mappings_repository::FakeImportMappingsRepository;
+    use crate::imports::interactors::test_doubles::sample_wxr::SAMPLE_WXR;
+    use crate::imports::interactors::utils::ImportCounts;
+    use crate::imports::services::RoxmltreeWxrParser;
+    use crate::media::interactors::test_doubles::fake_media_repository::FakeMediaRepository;
+    use crate::media::interactors::test_doubles::fake_media_storage::FakeMediaStorage;
+    use crate::media::interactors::test_doubles::sample_images::png;
+    use crate::media::interactors::traits::MediaStorage;
+    use crate::media::interactors::utils::MediaConfig;
+    use crate::media::services::ImageCrateProcessor;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::posts::interactors::traits::PostsRepository;
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::tags::interactors::traits::TagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::access_management::role_factory_spy::RoleFactorySpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::crypto::crypto_service_spy::{CryptoServiceSpy, HASH_RESULT};
+    use crate::test_utils::crypto::sequential_random_service::SequentialRandomService;
+    use crate::test_utils::errors_assertion::*;
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
+    use crate::users::interactors::traits::UsersRepository;
+    use crate::utils::TransliteratingSlugGenerator;
+
+    use super::*;
+
+    const CAT_URL: &str = "https://old.example.com/wp-content/uploads/2020/05/cat.png";
+
+    struct CreationResult {
+        interactor: ImportWordpressInteractor,
+        content: Arc<ImportContentRepositories>,
+        users: Arc<ImportUserServices>,
+        media: Arc<ImportMediaServices>,
+        mappings: Arc<FakeImportMappingsRepository>,
+        users_repo: Arc<FakeUsersRepository>,
+        role_factory: Arc<RoleFactorySpy>,
+        crypto: Arc<CryptoServiceSpy>,
+        categories_repo: Arc<FakeCategoriesRepository>,
+        tags_repo: Arc<FakeTagsRepository>,
+        posts_repo: Arc<FakePostsRepository>,
+        comments_repo: Arc<FakeCommentsRepository>,
+        media_repo: Arc<FakeMediaRepository>,
+        media_storage: Arc<FakeMediaStorage>,
+        attachments: Arc<FakeAttachmentSource>,
+        audit: Arc<AuditLogSpy>,
+    }
+
+    fn create_interactor() -> CreationResult {
+        let mappings = Arc::new(FakeImportMappingsRepository::new_empty());
+        let categories_repo = Arc::new(FakeCategoriesRepository::new_empty());
+        let tags_repo = Arc::new(FakeTagsRepository::new_empty());
+        let posts_repo = Arc::new(FakePostsRepository::new_empty());
+        let comments_repo = Arc::new(FakeCommentsRepository::new_empty());
+        let content = Arc::new(ImportContentRepositories {
+            mappings: mappings.clone(),
+            categories_repo: categories_repo.clone(),
+            tags_repo: tags_repo.clone(),
+            posts_repo: posts_repo.clone(),
+            comments_repo: comments_repo.clone(),
+            slug_history: Arc::new(FakeSlugHistoryRepository::new_empty()),
+            slug_generator: Arc::new(TransliteratingSlugGenerator::default()),
+        });
+        let users_repo = Arc::new(FakeUsersRepository::new_empty());
+        let role_factory = Arc::new(RoleFactorySpy::new(Some(Box::new(Admin))));
+        let crypto = Arc::new(CryptoServiceSpy::new_verified());
+        let users = Arc::new(ImportUserServices {
+            users_repo: users_repo.clone(),
+            role_factory: role_factory.clone(),
+            crypto: crypto.clone(),
+        });
+        let attachments = Arc::new(FakeAttachmentSource::new_with_files(&[(
+            CAT_URL,
+            png(10, 10),
+        )]));
+        let media_repo = Arc::new(FakeMediaRepository::new_empty());
+        let media_storage = Arc::new(FakeMediaStorage::new_empty());
+        let media = Arc::new(ImportMediaServices {
+            attachments: attachments.clone(),
+            media_repo: media_repo.clone(),
+            media_storage: media_storage.clone(),
+            image_processor: Arc::new(ImageCrateProcessor::default()),
+            config: Arc::new(MediaConfig {
+                max_size: 4096,
+                variant_widths: vec![],
+            }),
+        });
+        let audit = Arc::new(AuditLogSpy::default());
+        let interactor = ImportWordpressInteractor::new(
+            Arc::new(RoxmltreeWxrParser),
+            content.clone(),
+            users.clone(),
+            media.clone(),
+            Arc::new(SequentialRandomService::new()),
+            Arc::new(FakeClock::new_fixed()),
+            audit.clone(),
+        );
+        CreationResult {
+            interactor,
+            content,
+            users,
+            media,
+            mappings,
+            users_repo,
+            role_factory,
+            crypto,
+            categories_repo,
+            tags_repo,
+            posts_repo,
+            comments_repo,
+            media_repo,
+            media_storage,
+            attachments,
+            audit,
+        }
+    }
+
+    fn input(dry_run: bool) -> ImportWordpressInput {
+        ImportWordpressInput {
+            xml: SAMPLE_WXR.into(),
+            default_role: "author".into(),
+            roles: HashMap::from([("jane".to_string(), "editor".to_string())]),
+            dry_run,
+        }
+    }
+
+    fn counts(created: usize, existing: usize, skipped: usize) -> ImportCounts {
+        ImportCounts {
+            created,
+            existing,
+            skipped,
+        }
+    }
+
+    fn post_by_title(c: &CreationResult, title: &str) -> Post {
+        let posts = c.posts_repo.get_posts();
+        posts.into_iter().find(|p| p.title == title).unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input(false)).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [IMPORT_WORDPRESS_ACTION]);
+        assert_forbidden_error(err);
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Denied);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_import_in_the_audit_log() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: IMPORT_WORDPRESS_ACTION.into(),
+                    target: "wordpress".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: IMPORT_WORDPRESS_ACTION.into(),
+                    target: "wordpress".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_unknown_roles() {
+        let mut c = create_interactor();
+        c.interactor.set_users(Arc::new(ImportUserServices {
+            role_factory: Arc::new(RoleFactorySpy::new(None)),
+            ..(*c.users).clone()
+        }));
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input(false)).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "role");
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_unreadable_exports() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        let mut input = input(false);
+        input.xml = "<html><body>not an export</body></html>".into();
+
+        let err = c.interactor.execute(&auth, input).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "xml");
+    }
+
+    #[tokio::test]
+    async fn should_import_the_whole_site() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("importer".into());
+
+        let report = c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        assert_eq!(report.users, counts(2, 0, 0));
+        assert_eq!(report.categories, counts(2, 0, 0));
+        assert_eq!(report.tags, counts(2, 0, 0));
+        assert_eq!(report.media, counts(1, 0, 0));
+        assert_eq!(report.posts, counts(2, 0, 1));
+        assert_eq!(report.comments, counts(3, 0, 1));
+        assert!(report.renamed_slugs.is_empty());
+
+        let users = c.users_repo.get_users();
+        assert_eq!(users[0].name, "Jane Doe");
+        assert_eq!(users[0].password, HASH_RESULT);
+        assert_eq!(c.role_factory.get_create_role_calls(), ["editor", "author"]);
+
+        let categories = c.categories_repo.get_all().await.unwrap();
+        let rust = categories.iter().find(|c| c.slug == "rust").unwrap();
+        let async_category = categories.iter().find(|c| c.slug == "async").unwrap();
+        assert_eq!(rust.description, "All about Rust");
+        assert_eq!(async_category.parent_id, Some(rust.id.clone()));
+
+        let hello = post_by_title(&c, "Hello World");
+        assert_eq!(hello.slug, "hello-world");
+        assert_eq!(hello.author_id, users[0].id);
+        assert_eq!(hello.status, PostStatus::Published);
+        let date = Utc.with_ymd_and_hms(2020, 5, 1, 8, 30, 0).unwrap();
+        assert_eq!((hello.created_at, hello.published_at), (date, Some(date)));
+        assert_eq!(hello.category_id, Some(async_category.id.clone()));
+        assert_eq!(hello.tag_ids.len(), 2);
+        let media = &c.media_repo.get_items()[0];
+        assert!(!hello.content.contains(CAT_URL));
+        assert!(hello.content.contains(&c.media_storage.url(&media.key)));
+        assert_eq!(media.uploaded_by, users[0].id);
+
+        let draft = post_by_title(&c, "Draft idea");
+        assert_eq!(draft.slug, "draft-idea");
+        assert_eq!(draft.status, PostStatus::Draft);
+        assert_eq!(draft.created_at, fixed_now());
+        assert_eq!(draft.author_id, users[1].id);
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_comment_threads() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("importer".into());
+
+        c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        let comments = c.comments_repo.get_comments();
+        let statuses: Vec<CommentStatus> = comments.iter().map(|c| c.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                CommentStatus::Approved,
+                CommentStatus::Approved,
+                CommentStatus::Spam
+            ]
+        );
+        assert_eq!(
+            comments[0].author,
+            CommentAuthor::Anonymous {
+                name: "A reader".into(),
+                email: Some("reader@example.com".into()),
+            }
+        );
+        let jane = c.users_repo.get_users()[0].id.clone();
+        assert_eq!(comments[1].author, CommentAuthor::User { user_id: jane });
+        assert_eq!(comments[1].parent_id, Some(comments[0].id.clone()));
+        assert_eq!(comments[1].depth, 1);
+        assert_eq!(comments[1].post_id, post_by_title(&c, "Hello World").id);
+    }
+
+    #[tokio::test]
+    async fn should_write_nothing_on_a_dry_run() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("importer".into());
+
+        let report = c.interactor.execute(&auth, input(true)).await.unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(report.users, counts(2, 0, 0));
+        assert_eq!(report.posts, counts(2, 0, 1));
+        assert_eq!(report.comments, counts(3, 0, 1));
+        assert_eq!(report.media, counts(1, 0, 0));
+        assert!(c.users_repo.get_users().is_empty());
+        assert!(c.categories_repo.get_all().await.unwrap().is_empty());
+        assert!(c.tags_repo.get_tags().is_empty());
+        assert!(c.posts_repo.get_posts().is_empty());
+        assert!(c.comments_repo.get_comments().is_empty());
+        assert!(c.media_storage.get_keys().is_empty());
+        assert!(c.mappings.get_mappings().is_empty());
+        assert!(c.attachments.get_fetched().is_empty());
+        c.crypto.assert_hash_calls(&[]);
+    }
+
+    #[tokio::test]
+    async fn should_skip_what_an_earlier_run_imported() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("importer".into());
+        c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        let report = c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        assert_eq!(report.users, counts(0, 2, 0));
+        assert_eq!(report.categories, counts(0, 2, 0));
+        assert_eq!(report.tags, counts(0, 2, 0));
+        assert_eq!(report.media, counts(0, 1, 0));
+        assert_eq!(report.posts, counts(0, 2, 1));
+        assert_eq!(report.comments, counts(0, 3, 1));
+        assert_eq!(c.posts_repo.get_posts().len(), 2);
+        assert_eq!(c.comments_repo.get_comments().len(), 3);
+        assert_eq!(c.media_repo.get_items().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_suffix_slugs_that_are_taken() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("importer".into());
+        let taken = Post {
+            slug: "hello-world".into(),
+            ..post_with_id("taken")
+        };
+        c.posts_repo.create(&taken).await.unwrap();
+        c.categories_repo
+            .create(&Category {
+                id: "taken".into(),
+                name: "Rust lang".into(),
+                description: "".into(),
+                created_at: Utc::now(),
+                slug: "rust".into(),
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        let report = c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        assert_eq!(
+            report.renamed_slugs,
+            [
+                RenamedSlug {
+                    kind: ImportKind::Category,
+                    original: "rust".into(),
+                    slug: "rust-2".into(),
+                },
+                RenamedSlug {
+                    kind: ImportKind::Post,
+                    original: "hello-world".into(),
+                    slug: "hello-world-2".into(),
+                }
+            ]
+        );
+        assert_eq!(post_by_title(&c, "Hello World").slug, "hello-world-2");
+    }
+
+    #[tokio::test]
+    async fn should_reuse_users_and_tags_that_already_exist() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("importer".into());
+        c.users_repo
+            .create(&User {
+                id: "existing-jane".into(),
+                name: "Jane".into(),
+                email: "jane@example.com".into(),
+                password: "".into(),
+                role: Box::new(Admin),
+            })
+            .await
+            .unwrap();
+        c.tags_repo
+            .create(&Tag {
+                id: "existing-news".into(),
+                name: "news".into(),
+                slug: "news".into(),
+                created_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        let report = c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        assert_eq!(report.users, counts(1, 1, 0));
+        assert_eq!(report.tags, counts(1, 1, 0));
+        let hello = post_by_title(&c, "Hello World");
+        assert_eq!(hello.author_id, "existing-jane");
+        assert!(hello.tag_ids.contains(&TagId::new("existing-news")));
+    }
+
+    #[tokio::test]
+    async fn should_warn_about_attachments_it_can_not_read() {
+        let mut c = create_interactor();
+        c.interactor.set_media(Arc::new(ImportMediaServices {
+            attachments: Arc::new(FakeAttachmentSource::new_with_files(&[])),
+            ..(*c.media).clone()
+        }));
+        let auth = AuthPayloadSpy::new_allowed("importer".into());
+
+        let report = c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        assert_eq!(report.media, counts(0, 0, 1));
+        assert!(report
+            .warnings
+            .iter()
+            .any(|w| w.contains(CAT_URL) && w.contains("not available")));
+        assert!(post_by_title(&c, "Hello World").content.contains(CAT_URL));
+    }
+
+    #[tokio::test]
+    async fn should_skip_attachments_that_are_too_large_without_reading_them() {
+        let mut c = create_interactor();
+        c.interactor.set_media(Arc::new(ImportMediaServices {
+            config: Arc::new(MediaConfig {
+                max_size: 10,
+                variant_widths: vec![],
+            }),
+            ..(*c.media).clone()
+        }));
+        let auth = AuthPayloadSpy::new_allowed("importer".into());
+
+        let report = c.interactor.execute(&auth, input(false)).await.unwrap();
+
+        assert_eq!(report.media, counts(0, 0, 1));
+        assert!(report
+            .warnings
+            .iter()
+            .any(|w| w.contains(CAT_URL) && w.contains("too large")));
+        assert!(c.attachments.get_fetched().is_empty());
+    }
+
+    fn post_with_id(id: &str) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.into(),
+            slug: id.into(),
+            content: "".into(),
+            author_id: "author".into(),
+            category_id: None,
+            tag_ids: vec![],
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+}
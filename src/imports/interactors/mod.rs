@@ -0,0 +1,5 @@
+pub mod actions;
+pub mod import_wordpress;
+pub mod test_doubles;
+pub mod traits;
+pub mod utils;
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::imports::interactors::traits::AttachmentSource;
+
+pub struct FakeAttachmentSource {
+    files: HashMap<String, Vec<u8>>,
+    fetched: Mutex<Vec<String>>,
+}
+
+impl FakeAttachmentSource {
+    pub fn new_with_files(files: &[(&str, Vec<u8>)]) -> Self {
+        Self {
+            files: files
+                .iter()
+                .map(|(url, bytes)| (url.to_string(), bytes.clone()))
+                .collect(),
+            fetched: Mutex::new(vec![]),
+        }
+    }
+    pub fn get_fetched(&self) -> Vec<String> {
+        self.fetched.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl AttachmentSource for FakeAttachmentSource {
+    async fn size(&self, url: &str) -> UnknownResult<Option<usize>> {
+        Ok(self.files.get(url).map(|bytes| bytes.len()))
+    }
+
+    async fn fetch(&self, url: &str) -> UnknownResult<Option<Vec<u8>>> {
+        self.fetched.lock().unwrap().push(url.into());
+        Ok(self.files.get(url).cloned())
+    }
+}
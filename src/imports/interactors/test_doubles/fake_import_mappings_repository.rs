@@ -0,0 +1,50 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::imports::domain::{ImportKind, ImportMapping};
+use crate::imports::interactors::traits::ImportMappingsRepository;
+
+pub struct FakeImportMappingsRepository {
+    pub mappings: Mutex<Vec<ImportMapping>>,
+}
+
+impl FakeImportMappingsRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            mappings: Mutex::new(Vec::new()),
+        }
+    }
+    pub fn get_mappings(&self) -> Vec<ImportMapping> {
+        self.mappings.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl ImportMappingsRepository for FakeImportMappingsRepository {
+    async fn get(
+        &self,
+        source: &str,
+        kind: ImportKind,
+        original_id: &str,
+    ) -> UnknownResult<Option<ImportMapping>> {
+        let mappings = self.mappings.lock().unwrap();
+        Ok(mappings
+            .iter()
+            .find(|m| m.source == source && m.kind == kind && m.original_id == original_id)
+            .cloned())
+    }
+
+    async fn get_all(&self) -> UnknownResult<Vec<ImportMapping>> {
+        Ok(self.mappings.lock().unwrap().clone())
+    }
+
+    async fn save(&self, mapping: &ImportMapping) -> UnknownResult<()> {
+        let mut mappings = self.mappings.lock().unwrap();
+        mappings.retain(|m| {
+            (&m.source, m.kind, &m.original_id)
+                != (&mapping.source, mapping.kind, &mapping.original_id)
+        });
+        mappings.push(mapping.clone());
+        Ok(())
+    }
+}
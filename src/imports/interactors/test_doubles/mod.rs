@@ -0,0 +1,3 @@
+pub mod fake_attachment_source;
+pub mod fake_import_mappings_repository;
+pub mod sample_wxr;
@@ -0,0 +1,147 @@
+/// A trimmed down export of a WordPress 6 site, with the child category listed before its
+/// parent the way WordPress sometimes orders them.
+pub const SAMPLE_WXR: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<rss version="2.0"
+    xmlns:excerpt="http://wordpress.org/export/1.2/excerpt/"
+    xmlns:content="http://purl.org/rss/1.0/modules/content/"
+    xmlns:wfw="http://wellformedweb.org/CommentAPI/"
+    xmlns:dc="http://purl.org/dc/elements/1.1/"
+    xmlns:wp="http://wordpress.org/export/1.2/">
+<channel>
+    <title>Old Blog</title>
+    <link>https://old.example.com</link>
+    <wp:wxr_version>1.2</wp:wxr_version>
+    <wp:base_site_url>https://old.example.com</wp:base_site_url>
+    <wp:author>
+        <wp:author_id>2</wp:author_id>
+        <wp:author_login><![CDATA[jane]]></wp:author_login>
+        <wp:author_email><![CDATA[jane@example.com]]></wp:author_email>
+        <wp:author_display_name><![CDATA[Jane Doe]]></wp:author_display_name>
+    </wp:author>
+    <wp:author>
+        <wp:author_id>3</wp:author_id>
+        <wp:author_login><![CDATA[bob]]></wp:author_login>
+        <wp:author_email><![CDATA[bob@example.com]]></wp:author_email>
+        <wp:author_display_name><![CDATA[Bob]]></wp:author_display_name>
+    </wp:author>
+    <wp:category>
+        <wp:term_id>11</wp:term_id>
+        <wp:category_nicename><![CDATA[async]]></wp:category_nicename>
+        <wp:category_parent><![CDATA[rust]]></wp:category_parent>
+        <wp:cat_name><![CDATA[Async]]></wp:cat_name>
+    </wp:category>
+    <wp:category>
+        <wp:term_id>10</wp:term_id>
+        <wp:category_nicename><![CDATA[rust]]></wp:category_nicename>
+        <wp:category_parent><![CDATA[]]></wp:category_parent>
+        <wp:cat_name><![CDATA[Rust]]></wp:cat_name>
+        <wp:category_description><![CDATA[All about Rust]]></wp:category_description>
+    </wp:category>
+    <wp:tag>
+        <wp:term_id>20</wp:term_id>
+        <wp:tag_slug><![CDATA[tokio]]></wp:tag_slug>
+        <wp:tag_name><![CDATA[Tokio]]></wp:tag_name>
+    </wp:tag>
+    <wp:tag>
+        <wp:term_id>21</wp:term_id>
+        <wp:tag_slug><![CDATA[news]]></wp:tag_slug>
+        <wp:tag_name><![CDATA[News]]></wp:tag_name>
+    </wp:tag>
+    <item>
+        <title><![CDATA[cat]]></title>
+        <dc:creator><![CDATA[jane]]></dc:creator>
+        <wp:post_id>200</wp:post_id>
+        <wp:post_type><![CDATA[attachment]]></wp:post_type>
+        <wp:status><![CDATA[inherit]]></wp:status>
+        <wp:attachment_url><![CDATA[https://old.example.com/wp-content/uploads/2020/05/cat.png]]></wp:attachment_url>
+    </item>
+    <item>
+        <title><![CDATA[Hello World]]></title>
+        <dc:creator><![CDATA[jane]]></dc:creator>
+        <content:encoded><![CDATA[<p>Hello from WordPress!</p>
+<img src="https://old.example.com/wp-content/uploads/2020/05/cat.png" alt="a cat" />]]></content:encoded>
+        <excerpt:encoded><![CDATA[]]></excerpt:encoded>
+        <wp:post_id>100</wp:post_id>
+        <wp:post_date><![CDATA[2020-05-01 10:30:00]]></wp:post_date>
+        <wp:post_date_gmt><![CDATA[2020-05-01 08:30:00]]></wp:post_date_gmt>
+        <wp:post_name><![CDATA[hello-world]]></wp:post_name>
+        <wp:status><![CDATA[publish]]></wp:status>
+        <wp:post_type><![CDATA[post]]></wp:post_type>
+        <category domain="category" nicename="async"><![CDATA[Async]]></category>
+        <category domain="post_tag" nicename="tokio"><![CDATA[Tokio]]></category>
+        <category domain="post_tag" nicename="news"><![CDATA[News]]></category>
+        <wp:comment>
+            <wp:comment_id>500</wp:comment_id>
+            <wp:comment_author><![CDATA[A reader]]></wp:comment_author>
+            <wp:comment_author_email><![CDATA[reader@example.com]]></wp:comment_author_email>
+            <wp:comment_date_gmt><![CDATA[2020-05-02 09:00:00]]></wp:comment_date_gmt>
+            <wp:comment_content><![CDATA[Nice post]]></wp:comment_content>
+            <wp:comment_approved><![CDATA[1]]></wp:comment_approved>
+            <wp:comment_type><![CDATA[comment]]></wp:comment_type>
+            <wp:comment_parent>0</wp:comment_parent>
+            <wp:comment_user_id>0</wp:comment_user_id>
+        </wp:comment>
+        <wp:comment>
+            <wp:comment_id>501</wp:comment_id>
+            <wp:comment_author><![CDATA[Jane Doe]]></wp:comment_author>
+            <wp:comment_author_email><![CDATA[jane@example.com]]></wp:comment_author_email>
+            <wp:comment_date_gmt><![CDATA[2020-05-02 10:00:00]]></wp:comment_date_gmt>
+            <wp:comment_content><![CDATA[Thanks!]]></wp:comment_content>
+            <wp:comment_approved><![CDATA[1]]></wp:comment_approved>
+            <wp:comment_type><![CDATA[comment]]></wp:comment_type>
+            <wp:comment_parent>500</wp:comment_parent>
+            <wp:comment_user_id>2</wp:comment_user_id>
+        </wp:comment>
+        <wp:comment>
+            <wp:comment_id>502</wp:comment_id>
+            <wp:comment_author><![CDATA[Some blog]]></wp:comment_author>
+            <wp:comment_date_gmt><![CDATA[2020-05-03 10:00:00]]></wp:comment_date_gmt>
+            <wp:comment_content><![CDATA[[...] linked here [...]]]></wp:comment_content>
+            <wp:comment_approved><![CDATA[1]]></wp:comment_approved>
+            <wp:comment_type><![CDATA[pingback]]></wp:comment_type>
+            <wp:comment_parent>0</wp:comment_parent>
+            <wp:comment_user_id>0</wp:comment_user_id>
+        </wp:comment>
+        <wp:comment>
+            <wp:comment_id>503</wp:comment_id>
+            <wp:comment_author><![CDATA[Cheap pills]]></wp:comment_author>
+            <wp:comment_date_gmt><![CDATA[2020-05-04 10:00:00]]></wp:comment_date_gmt>
+            <wp:comment_content><![CDATA[Buy now]]></wp:comment_content>
+            <wp:comment_approved><![CDATA[spam]]></wp:comment_approved>
+            <wp:comment_type><![CDATA[comment]]></wp:comment_type>
+            <wp:comment_parent>0</wp:comment_parent>
+            <wp:comment_user_id>0</wp:comment_user_id>
+        </wp:comment>
+    </item>
+    <item>
+        <title><![CDATA[Draft idea]]></title>
+        <dc:creator><![CDATA[bob]]></dc:creator>
+        <content:encoded><![CDATA[Some thoughts]]></content:encoded>
+        <wp:post_id>101</wp:post_id>
+        <wp:post_date><![CDATA[0000-00-00 00:00:00]]></wp:post_date>
+        <wp:post_date_gmt><![CDATA[0000-00-00 00:00:00]]></wp:post_date_gmt>
+        <wp:post_name><![CDATA[]]></wp:post_name>
+        <wp:status><![CDATA[draft]]></wp:status>
+        <wp:post_type><![CDATA[post]]></wp:post_type>
+    </item>
+    <item>
+        <title><![CDATA[Gone]]></title>
+        <dc:creator><![CDATA[jane]]></dc:creator>
+        <content:encoded><![CDATA[Deleted]]></content:encoded>
+        <wp:post_id>102</wp:post_id>
+        <wp:post_date_gmt><![CDATA[2020-04-01 08:00:00]]></wp:post_date_gmt>
+        <wp:post_name><![CDATA[gone__trashed]]></wp:post_name>
+        <wp:status><![CDATA[trash]]></wp:status>
+        <wp:post_type><![CDATA[post]]></wp:post_type>
+    </item>
+    <item>
+        <title><![CDATA[About]]></title>
+        <dc:creator><![CDATA[jane]]></dc:creator>
+        <wp:post_id>300</wp:post_id>
+        <wp:post_name><![CDATA[about]]></wp:post_name>
+        <wp:status><![CDATA[publish]]></wp:status>
+        <wp:post_type><![CDATA[page]]></wp:post_type>
+    </item>
+</channel>
+</rss>
+"#;
@@ -0,0 +1,29 @@
+use crate::errors::UnknownResult;
+use crate::imports::domain::{ImportKind, ImportMapping, WxrExport};
+
+#[async_trait::async_trait]
+pub trait ImportMappingsRepository: Send + Sync {
+    async fn get(
+        &self,
+        source: &str,
+        kind: ImportKind,
+        original_id: &str,
+    ) -> UnknownResult<Option<ImportMapping>>;
+    async fn get_all(&self) -> UnknownResult<Vec<ImportMapping>>;
+    /// Stores the mapping, replacing one with the same source, kind and original id.
+    async fn save(&self, mapping: &ImportMapping) -> UnknownResult<()>;
+}
+
+pub trait WxrParser: Send + Sync {
+    /// Fails when `xml` is not a WXR document.
+    fn parse(&self, xml: &str) -> UnknownResult<WxrExport>;
+}
+
+/// Reads the files attached to an exported site.
+#[async_trait::async_trait]
+pub trait AttachmentSource: Send + Sync {
+    /// The size of the file behind `url` in bytes, `None` when it is not available.
+    async fn size(&self, url: &str) -> UnknownResult<Option<usize>>;
+    /// Returns `None` when the file behind `url` is not available.
+    async fn fetch(&self, url: &str) -> UnknownResult<Option<Vec<u8>>>;
+}
@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use percent_encoding::percent_decode_str;
+
+use crate::access_management::RoleFactory;
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::comments::interactors::traits::CommentsRepository;
+use crate::errors::UnknownResult;
+use crate::imports::domain::ImportKind;
+use crate::imports::interactors::traits::{AttachmentSource, ImportMappingsRepository};
+use crate::media::interactors::traits::{ImageProcessor, MediaRepository, MediaStorage};
+use crate::media::interactors::utils::MediaConfig;
+use crate::posts::interactors::traits::PostsRepository;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::users::interactors::traits::UsersRepository;
+use crate::utils::{CryptoService, SlugAvailability, SlugGenerator, SlugHistoryRepository};
+
+/// Where an import remembers what it imported, writes the content and finds slugs for it.
+#[derive(Clone)]
+pub struct ImportContentRepositories {
+    pub mappings: Arc<dyn ImportMappingsRepository>,
+    pub categories_repo: Arc<dyn CategoriesRepository>,
+    pub tags_repo: Arc<dyn TagsRepository>,
+    pub posts_repo: Arc<dyn PostsRepository>,
+    pub comments_repo: Arc<dyn CommentsRepository>,
+    pub slug_history: Arc<dyn SlugHistoryRepository>,
+    pub slug_generator: Arc<dyn SlugGenerator>,
+}
+
+/// What an import creates users with.
+#[derive(Clone)]
+pub struct ImportUserServices {
+    pub users_repo: Arc<dyn UsersRepository>,
+    pub role_factory: Arc<dyn RoleFactory>,
+    pub crypto: Arc<dyn CryptoService>,
+}
+
+/// What an import reads attachments from and stores them as media with.
+#[derive(Clone)]
+pub struct ImportMediaServices {
+    pub attachments: Arc<dyn AttachmentSource>,
+    pub media_repo: Arc<dyn MediaRepository>,
+    pub media_storage: Arc<dyn MediaStorage>,
+    pub image_processor: Arc<dyn ImageProcessor>,
+    pub config: Arc<MediaConfig>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ImportCounts {
+    pub created: usize,
+    /// Imported by an earlier run, or matched to an entity that was already here.
+    pub existing: usize,
+    /// Left out because there is nothing to import them as, see the warnings for why.
+    pub skipped: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImportReport {
+    /// When set, the counts tell what an import would do; nothing was written.
+    pub dry_run: bool,
+    pub users: ImportCounts,
+    pub categories: ImportCounts,
+    pub tags: ImportCounts,
+    pub media: ImportCounts,
+    pub posts: ImportCounts,
+    pub comments: ImportCounts,
+    pub renamed_slugs: Vec<RenamedSlug>,
+    pub warnings: Vec<String>,
+}
+
+/// An imported entity whose slug was taken here, so it got a suffixed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenamedSlug {
+    pub kind: ImportKind,
+    pub original: String,
+    pub slug: String,
+}
+
+/// Also refuses the slugs handed out earlier in the same import, which a dry run never stores.
+pub struct PlannedSlugAvailability<'a> {
+    pub inner: &'a dyn SlugAvailability,
+    pub planned: &'a HashSet<String>,
+}
+
+#[async_trait::async_trait]
+impl<'a> SlugAvailability for PlannedSlugAvailability<'a> {
+    async fn is_available(&self, slug: &str) -> UnknownResult<bool> {
+        Ok(!self.planned.contains(slug) && self.inner.is_available(slug).await?)
+    }
+}
+
+/// WordPress stores non ASCII slugs percent-encoded, e.g. `caf%c3%a9` for `café`.
+pub fn decode_wordpress_slug(slug: &str) -> String {
+    percent_decode_str(slug).decode_utf8_lossy().into_owned()
+}
+
+/// Orders categories so every parent comes before its children, `key` and `parent` telling
+/// how a category is referenced and which one it belongs to.
+///
+/// Categories whose parent is not among `items`, or that are part of a cycle, come last.
+pub fn parents_first<T>(
+    items: &[T],
+    key: impl Fn(&T) -> &str,
+    parent: impl Fn(&T) -> Option<&str>,
+) -> Vec<&T> {
+    let mut ordered = vec![];
+    let mut placed: HashSet<&str> = HashSet::new();
+    let mut remaining: Vec<&T> = items.iter().collect();
+    while !remaining.is_empty() {
+        let (ready, rest): (Vec<&T>, Vec<&T>) = remaining
+            .into_iter()
+            .partition(|item| parent(item).is_none_or(|parent| placed.contains(parent)));
+        if ready.is_empty() {
+            ordered.extend(rest);
+            break;
+        }
+        placed.extend(ready.iter().map(|item| key(item)));
+        ordered.extend(ready);
+        remaining = rest;
+    }
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::imports::domain::WxrCategory;
+
+    use super::*;
+
+    fn category(nicename: &str, parent: Option<&str>) -> WxrCategory {
+        WxrCategory {
+            id: nicename.into(),
+            nicename: nicename.into(),
+            name: nicename.into(),
+            description: "".into(),
+            parent: parent.map(String::from),
+        }
+    }
+
+    #[test]
+    fn should_put_parents_before_children_and_orphans_last() {
+        let categories = [
+            category("grandchild", Some("child")),
+            category("orphan", Some("missing")),
+            category("child", Some("root")),
+            category("root", None),
+            category("loop-a", Some("loop-b")),
+            category("loop-b", Some("loop-a")),
+        ];
+
+        let ordered: Vec<&str> = parents_first(
+            &categories,
+            |c| c.nicename.as_str(),
+            |c| c.parent.as_deref(),
+        )
+        .iter()
+        .map(|c| c.nicename.as_str())
+        .collect();
+
+        assert_eq!(
+            ordered,
+            ["root", "child", "grandchild", "orphan", "loop-a", "loop-b"]
+        );
+    }
+
+    #[test]
+    fn should_decode_percent_encoded_slugs() {
+        assert_eq!(decode_wordpress_slug("caf%c3%a9-au-lait"), "café-au-lait");
+        assert_eq!(decode_wordpress_slug("plain"), "plain");
+    }
+}
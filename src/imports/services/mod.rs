@@ -0,0 +1,5 @@
+pub use roxmltree_wxr_parser::RoxmltreeWxrParser;
+pub use uploads_directory_attachment_source::UploadsDirectoryAttachmentSource;
+
+mod roxmltree_wxr_parser;
+mod uploads_directory_attachment_source;
@@ -0,0 +1,219 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use roxmltree::{Document, Node};
+
+use crate::errors::UnknownResult;
+use crate::imports::domain::{
+    WxrAttachment, WxrAuthor, WxrCategory, WxrComment, WxrExport, WxrPost, WxrTag,
+};
+use crate::imports::interactors::traits::WxrParser;
+
+/// Every WXR version so far (1.0 to 1.2) puts its elements under this namespace plus the version.
+const WP_NAMESPACE_PREFIX: &str = "http://wordpress.org/export/";
+const CONTENT_NAMESPACE: &str = "http://purl.org/rss/1.0/modules/content/";
+const DC_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+
+/// Reads WXR exports with `roxmltree`, which refuses DTDs and so entity expansion attacks.
+#[derive(Default)]
+pub struct RoxmltreeWxrParser;
+
+impl WxrParser for RoxmltreeWxrParser {
+    fn parse(&self, xml: &str) -> UnknownResult<WxrExport> {
+        let document = Document::parse(xml)?;
+        let channel = child(document.root_element(), None, "channel")
+            .filter(|_| document.root_element().has_tag_name("rss"))
+            .ok_or("not a WXR export: the rss channel is missing")?;
+
+        let mut export = WxrExport {
+            site_url: wp_text(channel, "base_site_url")
+                .or_else(|| text(channel, None, "link"))
+                .unwrap_or_default(),
+            ..Default::default()
+        };
+        for node in channel.children().filter(|n| is_wp(*n)) {
+            match node.tag_name().name() {
+                "author" => export.authors.push(WxrAuthor {
+                    id: wp_text(node, "author_id").unwrap_or_default(),
+                    login: wp_text(node, "author_login").unwrap_or_default(),
+                    email: wp_text(node, "author_email").unwrap_or_default(),
+                    display_name: wp_text(node, "author_display_name").unwrap_or_default(),
+                }),
+                "category" => export.categories.push(WxrCategory {
+                    id: wp_text(node, "term_id").unwrap_or_default(),
+                    nicename: wp_text(node, "category_nicename").unwrap_or_default(),
+                    name: wp_text(node, "cat_name").unwrap_or_default(),
+                    description: wp_text(node, "category_description").unwrap_or_default(),
+                    parent: wp_text(node, "category_parent").filter(|p| !p.is_empty()),
+                }),
+                "tag" => export.tags.push(WxrTag {
+                    id: wp_text(node, "term_id").unwrap_or_default(),
+                    slug: wp_text(node, "tag_slug").unwrap_or_default(),
+                    name: wp_text(node, "tag_name").unwrap_or_default(),
+                }),
+                _ => {}
+            }
+        }
+
+        for item in channel.children().filter(|n| n.has_tag_name("item")) {
+            let id = wp_text(item, "post_id").unwrap_or_default();
+            let author_login = text(item, Some(DC_NAMESPACE), "creator").unwrap_or_default();
+            match wp_text(item, "post_type").as_deref() {
+                Some("post") => export.posts.push(parse_post(item, id, author_login)),
+                Some("attachment") => export.attachments.push(WxrAttachment {
+                    id,
+                    url: wp_text(item, "attachment_url").unwrap_or_default(),
+                    author_login,
+                }),
+                _ => export.skipped_items += 1,
+            }
+        }
+        Ok(export)
+    }
+}
+
+fn parse_post(item: Node, id: String, author_login: String) -> WxrPost {
+    let terms = |domain: &str| -> Vec<String> {
+        item.children()
+            .filter(|n| n.has_tag_name("category") && n.attribute("domain") == Some(domain))
+            .filter_map(|n| n.attribute("nicename").map(String::from))
+            .collect()
+    };
+    WxrPost {
+        id,
+        title: text(item, None, "title").unwrap_or_default(),
+        slug: wp_text(item, "post_name").unwrap_or_default(),
+        content: text(item, Some(CONTENT_NAMESPACE), "encoded").unwrap_or_default(),
+        author_login,
+        status: wp_text(item, "status").unwrap_or_default(),
+        date: wp_date(item, "post_date_gmt").or_else(|| wp_date(item, "post_date")),
+        categories: terms("category"),
+        tags: terms("post_tag"),
+        comments: item
+            .children()
+            .filter(|n| is_wp(*n) && n.tag_name().name() == "comment")
+            .map(parse_comment)
+            .collect(),
+    }
+}
+
+fn parse_comment(node: Node) -> WxrComment {
+    let non_zero = |value: Option<String>| value.filter(|v| !v.is_empty() && v != "0");
+    WxrComment {
+        id: wp_text(node, "comment_id").unwrap_or_default(),
+        parent_id: non_zero(wp_text(node, "comment_parent")),
+        author_name: wp_text(node, "comment_author").unwrap_or_default(),
+        author_email: wp_text(node, "comment_author_email").unwrap_or_default(),
+        author_user_id: non_zero(wp_text(node, "comment_user_id")),
+        content: wp_text(node, "comment_content").unwrap_or_default(),
+        approved: wp_text(node, "comment_approved").unwrap_or_default(),
+        comment_type: wp_text(node, "comment_type").unwrap_or_default(),
+        date: wp_date(node, "comment_date_gmt").or_else(|| wp_date(node, "comment_date")),
+    }
+}
+
+fn is_wp(node: Node) -> bool {
+    node.is_element()
+        && node
+            .tag_name()
+            .namespace()
+            .is_some_and(|ns| ns.starts_with(WP_NAMESPACE_PREFIX))
+}
+
+fn child<'a, 'input>(
+    node: Node<'a, 'input>,
+    namespace: Option<&str>,
+    name: &str,
+) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| {
+        n.is_element() && n.tag_name().name() == name && n.tag_name().namespace() == namespace
+    })
+}
+
+/// The trimmed text of the child element, CDATA included.
+fn text(node: Node, namespace: Option<&str>, name: &str) -> Option<String> {
+    child(node, namespace, name).map(|n| n.text().unwrap_or_default().trim().to_string())
+}
+
+fn wp_text(node: Node, name: &str) -> Option<String> {
+    node.children()
+        .find(|n| is_wp(*n) && n.tag_name().name() == name)
+        .map(|n| n.text().unwrap_or_default().trim().to_string())
+}
+
+/// WordPress writes `0000-00-00 00:00:00` for dates that were never set, which fails to parse.
+fn wp_date(node: Node, name: &str) -> Option<DateTime<Utc>> {
+    let value = wp_text(node, name)?;
+    NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|date| date.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use crate::imports::interactors::test_doubles::sample_wxr::SAMPLE_WXR;
+
+    use super::*;
+
+    #[test]
+    fn should_read_the_site_and_its_terms() {
+        let export = RoxmltreeWxrParser.parse(SAMPLE_WXR).unwrap();
+
+        assert_eq!(export.site_url, "https://old.example.com");
+        assert_eq!(
+            export.authors[0],
+            WxrAuthor {
+                id: "2".into(),
+                login: "jane".into(),
+                email: "jane@example.com".into(),
+                display_name: "Jane Doe".into(),
+            }
+        );
+        assert_eq!(
+            export.categories[0],
+            WxrCategory {
+                id: "11".into(),
+                nicename: "async".into(),
+                name: "Async".into(),
+                description: "".into(),
+                parent: Some("rust".into()),
+            }
+        );
+        assert_eq!(export.categories[1].parent, None);
+        assert_eq!(export.tags.len(), 2);
+        assert_eq!(export.skipped_items, 1);
+    }
+
+    #[test]
+    fn should_read_posts_with_their_terms_and_comments() {
+        let export = RoxmltreeWxrParser.parse(SAMPLE_WXR).unwrap();
+
+        let post = &export.posts[0];
+        assert_eq!(post.id, "100");
+        assert_eq!(post.slug, "hello-world");
+        assert_eq!(post.author_login, "jane");
+        assert_eq!(post.status, "publish");
+        assert_eq!(
+            post.date,
+            Some(Utc.with_ymd_and_hms(2020, 5, 1, 8, 30, 0).unwrap())
+        );
+        assert!(post.content.starts_with("<p>Hello"));
+        assert_eq!(post.categories, ["async"]);
+        assert_eq!(post.tags, ["tokio", "news"]);
+        assert_eq!(post.comments.len(), 4);
+        assert_eq!(post.comments[1].parent_id.as_deref(), Some("500"));
+        assert_eq!(post.comments[0].author_user_id, None);
+        assert_eq!(export.posts[1].date, None);
+        assert_eq!(
+            export.attachments[0].url,
+            "https://old.example.com/wp-content/uploads/2020/05/cat.png"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_documents_that_are_not_exports() {
+        for xml in ["<html></html>", "not xml", "<rss><item/></rss>"] {
+            assert!(RoxmltreeWxrParser.parse(xml).is_err(), "{}", xml);
+        }
+    }
+}
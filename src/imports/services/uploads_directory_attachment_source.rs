@@ -0,0 +1,110 @@
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+use percent_encoding::percent_decode_str;
+
+use crate::errors::UnknownResult;
+use crate::imports::interactors::traits::AttachmentSource;
+
+const UPLOADS_PATH: &str = "/wp-content/uploads/";
+
+/// Reads attachments from a copy of the site's `wp-content/uploads` directory, so an import
+/// does not depend on the old site still being online.
+pub struct UploadsDirectoryAttachmentSource {
+    root: PathBuf,
+}
+
+impl UploadsDirectoryAttachmentSource {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Maps `https://site/wp-content/uploads/2020/05/a.png` to `<root>/2020/05/a.png`.
+    fn path_of(&self, url: &str) -> Option<PathBuf> {
+        let start = url.find(UPLOADS_PATH)? + UPLOADS_PATH.len();
+        let relative = url[start..].split(['?', '#']).next()?;
+        let relative = percent_decode_str(relative).decode_utf8().ok()?;
+        let relative = Path::new(relative.as_ref());
+        let is_inside_root = relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)));
+        (is_inside_root && !relative.as_os_str().is_empty()).then(|| self.root.join(relative))
+    }
+}
+
+#[async_trait::async_trait]
+impl AttachmentSource for UploadsDirectoryAttachmentSource {
+    async fn size(&self, url: &str) -> UnknownResult<Option<usize>> {
+        let Some(path) = self.path_of(url) else {
+            return Ok(None);
+        };
+        match tokio::fs::metadata(path).await {
+            Ok(metadata) if metadata.is_file() => Ok(Some(metadata.len() as usize)),
+            Ok(_) => Ok(None),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn fetch(&self, url: &str) -> UnknownResult<Option<Vec<u8>>> {
+        let Some(path) = self.path_of(url) else {
+            return Ok(None);
+        };
+        match tokio::fs::read(path).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_read_files_below_the_uploads_directory() {
+        let root = std::env::temp_dir().join(format!("uploads-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("2020/05")).unwrap();
+        std::fs::write(root.join("2020/05/café.png"), b"bytes").unwrap();
+        let source = UploadsDirectoryAttachmentSource::new(&root);
+
+        let found = source
+            .fetch("https://old.example.com/wp-content/uploads/2020/05/caf%C3%A9.png?v=2")
+            .await
+            .unwrap();
+        let missing = source
+            .fetch("https://old.example.com/wp-content/uploads/2020/05/dog.png")
+            .await
+            .unwrap();
+
+        assert_eq!(found, Some(b"bytes".to_vec()));
+        assert_eq!(missing, None);
+        let size = source
+            .size("https://old.example.com/wp-content/uploads/2020/05/caf%C3%A9.png")
+            .await
+            .unwrap();
+        assert_eq!(size, Some(5));
+        let directory = source
+            .size("https://old.example.com/wp-content/uploads/2020/05")
+            .await
+            .unwrap();
+        assert_eq!(directory, None);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_ignore_urls_outside_the_uploads_directory() {
+        let source = UploadsDirectoryAttachmentSource::new(std::env::temp_dir());
+
+        for url in [
+            "https://old.example.com/wp-config.php",
+            "https://old.example.com/wp-content/uploads/../../etc/passwd",
+            "https://old.example.com/wp-content/uploads/%2e%2e/secret",
+            "https://old.example.com/wp-content/uploads/",
+        ] {
+            assert_eq!(source.fetch(url).await.unwrap(), None, "{}", url);
+            assert_eq!(source.size(url).await.unwrap(), None, "{}", url);
+        }
+    }
+}
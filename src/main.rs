@@ -1,7 +1,21 @@
 mod access_management;
+mod audit;
+mod backups;
 mod categories;
+mod comments;
 mod errors;
+mod events;
+mod feeds;
+mod imports;
+mod markdown_sites;
+mod media;
+mod posts;
+mod search;
+mod sitemaps;
+mod static_sites;
+mod tags;
 mod test_utils;
+mod themes;
 mod users;
 mod utils;
 
@@ -0,0 +1,158 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::posts::domain::PostStatus;
+
+/// A post as a Markdown file with front matter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownPost {
+    pub title: String,
+    /// `None` when the front matter leaves it to the file name.
+    pub slug: Option<String>,
+    pub date: Option<DateTime<Utc>>,
+    pub status: PostStatus,
+    pub publish_at: Option<DateTime<Utc>>,
+    /// The author's email.
+    pub author: Option<String>,
+    /// Category names from the top-level category down to the post's own.
+    pub categories: Vec<String>,
+    /// Tag names.
+    pub tags: Vec<String>,
+    /// The Markdown below the front matter.
+    pub content: String,
+}
+
+/// A category as listed in the site's data file, which keeps what posts can not tell: the
+/// description and where a category without posts belongs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownCategory {
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    /// The slug of the parent.
+    pub parent: Option<String>,
+}
+
+/// Where a static site generator expects the files.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SiteLayout {
+    /// `content/posts/<slug>.md`, or `content/posts/<slug>/index.md` for page bundles.
+    Hugo,
+    /// `_posts/<yyyy-mm-dd>-<slug>.md`, and `_drafts/<slug>.md` for anything not published.
+    Jekyll,
+}
+
+impl SiteLayout {
+    pub fn post_path(self, slug: &str, date: DateTime<Utc>, status: PostStatus) -> String {
+        match (self, status) {
+            (SiteLayout::Hugo, _) => format!("content/posts/{}.md", slug),
+            (SiteLayout::Jekyll, PostStatus::Published) => {
+                format!("_posts/{}-{}.md", date.format("%Y-%m-%d"), slug)
+            }
+            (SiteLayout::Jekyll, _) => format!("_drafts/{}.md", slug),
+        }
+    }
+
+    pub fn categories_path(self) -> &'static str {
+        match self {
+            SiteLayout::Hugo => "data/categories.yaml",
+            SiteLayout::Jekyll => "_data/categories.yml",
+        }
+    }
+
+    pub fn is_post_path(self, path: &str) -> bool {
+        let is_markdown = path.ends_with(".md") || path.ends_with(".markdown");
+        let in_posts = match self {
+            SiteLayout::Hugo => path.starts_with("content/posts/"),
+            SiteLayout::Jekyll => path.starts_with("_posts/") || path.starts_with("_drafts/"),
+        };
+        is_markdown && in_posts
+    }
+
+    /// Jekyll keeps everything that is not published under `_drafts`.
+    pub fn is_draft_path(self, path: &str) -> bool {
+        self == SiteLayout::Jekyll && path.starts_with("_drafts/")
+    }
+
+    /// The slug the file name gives, for posts whose front matter has none.
+    pub fn slug_from_path(self, path: &str) -> String {
+        let mut parts: Vec<&str> = path.split('/').collect();
+        let mut name = parts.pop().unwrap_or_default();
+        if self == SiteLayout::Hugo && name.starts_with("index.") {
+            name = parts.pop().unwrap_or_default();
+        }
+        let name = name.split('.').next().unwrap_or_default();
+        match self.date_prefix(name) {
+            Some(_) => name[11..].to_string(),
+            None => name.to_string(),
+        }
+    }
+
+    /// The date Jekyll post file names start with.
+    pub fn date_from_path(self, path: &str) -> Option<DateTime<Utc>> {
+        let name = path.rsplit('/').next().unwrap_or_default();
+        self.date_prefix(name)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date| date.and_utc())
+    }
+
+    fn date_prefix(self, name: &str) -> Option<NaiveDate> {
+        if self != SiteLayout::Jekyll || name.len() < 11 || name.as_bytes()[10] != b'-' {
+            return None;
+        }
+        NaiveDate::parse_from_str(name.get(..10)?, "%Y-%m-%d").ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn should_place_posts_the_way_each_generator_expects() {
+        let date = Utc.with_ymd_and_hms(2020, 5, 1, 8, 30, 0).unwrap();
+
+        assert_eq!(
+            SiteLayout::Hugo.post_path("hello", date, PostStatus::Draft),
+            "content/posts/hello.md"
+        );
+        assert_eq!(
+            SiteLayout::Jekyll.post_path("hello", date, PostStatus::Published),
+            "_posts/2020-05-01-hello.md"
+        );
+        assert_eq!(
+            SiteLayout::Jekyll.post_path("hello", date, PostStatus::Archived),
+            "_drafts/hello.md"
+        );
+    }
+
+    #[test]
+    fn should_read_slugs_and_dates_from_paths() {
+        assert_eq!(
+            SiteLayout::Hugo.slug_from_path("content/posts/hello/index.md"),
+            "hello"
+        );
+        assert_eq!(
+            SiteLayout::Hugo.slug_from_path("content/posts/2020-05-01-hello.md"),
+            "2020-05-01-hello"
+        );
+        assert_eq!(
+            SiteLayout::Jekyll.slug_from_path("_posts/2020-05-01-hello.markdown"),
+            "hello"
+        );
+        assert_eq!(
+            SiteLayout::Jekyll.date_from_path("_posts/2020-05-01-hello.md"),
+            Some(Utc.with_ymd_and_hms(2020, 5, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(SiteLayout::Jekyll.date_from_path("_drafts/hello.md"), None);
+    }
+
+    #[test]
+    fn should_recognize_post_files() {
+        assert!(SiteLayout::Hugo.is_post_path("content/posts/a/index.md"));
+        assert!(!SiteLayout::Hugo.is_post_path("content/about.md"));
+        assert!(SiteLayout::Jekyll.is_post_path("_drafts/a.md"));
+        assert!(!SiteLayout::Jekyll.is_post_path("_posts/image.png"));
+    }
+}
@@ -0,0 +1,2 @@
+pub const IMPORT_MARKDOWN_SITE_ACTION: &str = "IMPORT_MARKDOWN_SITE_ACTION";
+pub const EXPORT_MARKDOWN_SITE_ACTION: &str = "EXPORT_MARKDOWN_SITE_ACTION";
@@ -0,0 +1,395 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::categories::domain::{Category, CategoryId};
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::errors::ApplicationResult;
+use crate::markdown_sites::domain::{MarkdownCategory, MarkdownPost, SiteLayout};
+use crate::markdown_sites::interactors::actions::EXPORT_MARKDOWN_SITE_ACTION;
+use crate::markdown_sites::interactors::traits::{FrontMatterCodec, SiteDirectory};
+use crate::posts::interactors::traits::PostsRepository;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::users::interactors::traits::UsersRepository;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone)]
+pub struct ExportMarkdownSiteInput {
+    pub layout: SiteLayout,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportMarkdownSiteOutput {
+    pub posts: usize,
+    pub categories: usize,
+}
+
+/// Writes every post as a Markdown file with front matter, and the categories to the data file
+/// of the layout, in a form `ImportMarkdownSiteInteractor` reads back.
+///
+/// Files are written in a stable order and shape so that committing an export to git after
+/// each change gives readable diffs. Post files of an earlier export that this one does not
+/// write, because the post was deleted, renamed or published, are removed so that importing the
+/// directory again creates every post once.
+#[derive(WithDeps)]
+pub struct ExportMarkdownSiteInteractor {
+    directory: Arc<dyn SiteDirectory>,
+    codec: Arc<dyn FrontMatterCodec>,
+    posts_repo: Arc<dyn PostsRepository>,
+    categories_repo: Arc<dyn CategoriesRepository>,
+    tags_repo: Arc<dyn TagsRepository>,
+    users_repo: Arc<dyn UsersRepository>,
+}
+
+impl ExportMarkdownSiteInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ExportMarkdownSiteInput,
+    ) -> ApplicationResult<ExportMarkdownSiteOutput> {
+        auth.can_or_fail(EXPORT_MARKDOWN_SITE_ACTION)?;
+
+        let mut categories = self.categories_repo.get_all().await?;
+        categories.sort_by(|a, b| a.slug.cmp(&b.slug));
+        let categories_by_id: HashMap<CategoryId, Category> = categories
+            .iter()
+            .map(|category| (category.id.clone(), category.clone()))
+            .collect();
+        let listed: Vec<MarkdownCategory> = categories
+            .iter()
+            .map(|category| MarkdownCategory {
+                slug: category.slug.clone(),
+                name: category.name.clone(),
+                description: category.description.clone(),
+                parent: category
+                    .parent_id
+                    .as_ref()
+                    .and_then(|id| categories_by_id.get(id))
+                    .map(|parent| parent.slug.clone()),
+            })
+            .collect();
+        self.directory
+            .write(
+                input.layout.categories_path(),
+                &self.codec.write_categories(&listed)?,
+            )
+            .await?;
+
+        let tag_names: HashMap<_, _> = self
+            .tags_repo
+            .get_all()
+            .await?
+            .into_iter()
+            .map(|tag| (tag.id, tag.name))
+            .collect();
+        let mut stale: HashSet<String> = self
+            .directory
+            .list()
+            .await?
+            .into_iter()
+            .filter(|path| input.layout.is_post_path(path))
+            .collect();
+        let mut emails: HashMap<String, Option<String>> = HashMap::new();
+        let mut posts = self.posts_repo.get_all().await?;
+        posts.sort_by(|a, b| a.slug.cmp(&b.slug));
+        for post in &posts {
+            if !emails.contains_key(&post.author_id) {
+                let user = self.users_repo.get_by_id(&post.author_id).await?;
+                emails.insert(post.author_id.clone(), user.map(|user| user.email));
+            }
+            let date = post.published_at.unwrap_or(post.created_at);
+            let markdown = MarkdownPost {
+                title: post.title.clone(),
+                slug: Some(post.slug.clone()),
+                date: Some(date),
+                status: post.status,
+                publish_at: post.publish_at,
+                author: emails[&post.author_id].clone(),
+                categories: category_path(&categories_by_id, post.category_id.as_ref()),
+                tags: post
+                    .tag_ids
+                    .iter()
+                    .filter_map(|id| tag_names.get(id).cloned())
+                    .collect(),
+                content: post.content.clone(),
+            };
+            let path = input.layout.post_path(&post.slug, date, post.status);
+            self.directory
+                .write(&path, &self.codec.write_post(&markdown)?)
+                .await?;
+            stale.remove(&path);
+        }
+        for path in stale {
+            self.directory.delete(&path).await?;
+        }
+
+        Ok(ExportMarkdownSiteOutput {
+            posts: posts.len(),
+            categories: categories.len(),
+        })
+    }
+}
+
+/// The names of the category and its ancestors, top-level first.
+fn category_path(
+    categories: &HashMap<CategoryId, Category>,
+    id: Option<&CategoryId>,
+) -> Vec<String> {
+    let mut names = vec![];
+    let mut visited = HashSet::new();
+    let mut current = id.and_then(|id| categories.get(id));
+    while let Some(category) = current.filter(|c| visited.insert(c.id.clone())) {
+        names.push(category.name.clone());
+        current = category
+            .parent_id
+            .as_ref()
+            .and_then(|id| categories.get(id));
+    }
+    names.reverse();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::access_management::variants::Admin;
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
+    use crate::make_interactor_setup;
+    use crate::markdown_sites::interactors::import_markdown_site::{
+        ImportMarkdownSiteInput, ImportMarkdownSiteInteractor,
+    };
+    use crate::markdown_sites::interactors::test_doubles::fake_site_directory::FakeSiteDirectory;
+    use crate::markdown_sites::interactors::utils::MarkdownImportRepositories;
+    use crate::markdown_sites::services::YamlFrontMatterCodec;
+    use crate::posts::domain::{Post, PostId, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_post_revisions_repository::FakePostRevisionsRepository;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::tags::domain::{Tag, TagId};
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::FakeClock;
+    use crate::test_utils::crypto::sequential_random_service::SequentialRandomService;
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
+    use crate::users::domain::User;
+    use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
+    use crate::utils::TransliteratingSlugGenerator;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ExportMarkdownSiteInteractor,
+        [
+            (directory, FakeSiteDirectory::new_empty(), FakeSiteDirectory),
+            (codec, YamlFrontMatterCodec, YamlFrontMatterCodec),
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&[
+                    post("hello", PostStatus::Published),
+                    post("idea", PostStatus::Draft)
+                ]),
+                FakePostsRepository
+            ),
+            (
+                categories_repo,
+                FakeCategoriesRepository::new_with_data(&[
+                    category("async", "Async", Some("rust")),
+                    category("rust", "Rust", None)
+                ]),
+                FakeCategoriesRepository
+            ),
+            (
+                tags_repo,
+                FakeTagsRepository::new_with_data(&[Tag {
+                    id: TagId::new("tokio"),
+                    name: "Tokio".into(),
+                    slug: "tokio".into(),
+                    created_at: Utc::now(),
+                }]),
+                FakeTagsRepository
+            ),
+            (
+                users_repo,
+                FakeUsersRepository::new_with_data(&[User {
+                    id: "jane".into(),
+                    name: "Jane".into(),
+                    email: "jane@example.com".into(),
+                    password: "".into(),
+                    role: Box::new(Admin),
+                }]),
+                FakeUsersRepository
+            )
+        ]
+    );
+
+    fn category(slug: &str, name: &str, parent: Option<&str>) -> Category {
+        Category {
+            id: CategoryId::new(slug),
+            name: name.into(),
+            description: "".into(),
+            created_at: Utc::now(),
+            slug: slug.into(),
+            parent_id: parent.map(CategoryId::new),
+        }
+    }
+
+    fn post(slug: &str, status: PostStatus) -> Post {
+        let date = Utc.with_ymd_and_hms(2020, 5, 1, 8, 30, 0).unwrap();
+        Post {
+            id: PostId::new(slug),
+            title: slug.into(),
+            slug: slug.into(),
+            content: format!("About {}", slug),
+            author_id: "jane".into(),
+            category_id: Some(CategoryId::new("async")),
+            tag_ids: vec![TagId::new("tokio")],
+            status,
+            publish_at: None,
+            published_at: (status == PostStatus::Published).then_some(date),
+            created_at: date,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(
+                &auth,
+                ExportMarkdownSiteInput {
+                    layout: SiteLayout::Hugo,
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [EXPORT_MARKDOWN_SITE_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_write_posts_and_categories_in_the_jekyll_layout() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c
+            .interactor
+            .execute(
+                &auth,
+                ExportMarkdownSiteInput {
+                    layout: SiteLayout::Jekyll,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            output,
+            ExportMarkdownSiteOutput {
+                posts: 2,
+                categories: 2,
+            }
+        );
+        let paths: Vec<String> = c.directory.list().await.unwrap();
+        assert_eq!(
+            paths,
+            [
+                "_data/categories.yml",
+                "_drafts/idea.md",
+                "_posts/2020-05-01-hello.md"
+            ]
+        );
+        let hello = YamlFrontMatterCodec
+            .parse_post(&c.directory.get_file("_posts/2020-05-01-hello.md").unwrap())
+            .unwrap();
+        assert_eq!(hello.categories, ["Rust", "Async"]);
+        assert_eq!(hello.tags, ["Tokio"]);
+        assert_eq!(hello.author.as_deref(), Some("jane@example.com"));
+        assert_eq!(hello.content, "About hello");
+        let categories = YamlFrontMatterCodec
+            .parse_categories(&c.directory.get_file("_data/categories.yml").unwrap())
+            .unwrap();
+        assert_eq!(categories[0].parent.as_deref(), Some("rust"));
+    }
+
+    #[tokio::test]
+    async fn should_remove_files_an_earlier_export_wrote_for_moved_posts() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        let jekyll = || ExportMarkdownSiteInput {
+            layout: SiteLayout::Jekyll,
+        };
+        c.interactor.execute(&auth, jekyll()).await.unwrap();
+
+        let mut idea = c
+            .posts_repo
+            .get_by_id(&PostId::new("idea"))
+            .await
+            .unwrap()
+            .unwrap();
+        idea.slug = "plan".into();
+        idea.status = PostStatus::Published;
+        idea.published_at = Some(idea.created_at);
+        c.posts_repo.update(&idea).await.unwrap();
+        let mut hello = c
+            .posts_repo
+            .get_by_id(&PostId::new("hello"))
+            .await
+            .unwrap()
+            .unwrap();
+        hello.slug = "hi".into();
+        c.posts_repo.update(&hello).await.unwrap();
+        c.interactor.execute(&auth, jekyll()).await.unwrap();
+
+        assert_eq!(
+            c.directory.list().await.unwrap(),
+            [
+                "_data/categories.yml",
+                "_posts/2020-05-01-hi.md",
+                "_posts/2020-05-01-plan.md"
+            ]
+        );
+        let posts_repo = Arc::new(FakePostsRepository::new_empty());
+        let import = ImportMarkdownSiteInteractor::new(
+            c.directory.clone(),
+            Arc::new(YamlFrontMatterCodec),
+            Arc::new(MarkdownImportRepositories {
+                posts_repo: posts_repo.clone(),
+                revisions_repo: Arc::new(FakePostRevisionsRepository::new_empty()),
+                categories_repo: Arc::new(FakeCategoriesRepository::new_empty()),
+                tags_repo: Arc::new(FakeTagsRepository::new_empty()),
+                users_repo: c.users_repo.clone(),
+                slug_history: Arc::new(FakeSlugHistoryRepository::new_empty()),
+                unit_of_work: Arc::new(UnitOfWorkSpy::default()),
+            }),
+            Arc::new(TransliteratingSlugGenerator::default()),
+            Arc::new(SequentialRandomService::new()),
+            Arc::new(FakeClock::new_fixed()),
+            Arc::new(EventPublisherSpy::default()),
+        );
+        import
+            .execute(
+                &auth,
+                ImportMarkdownSiteInput {
+                    layout: SiteLayout::Jekyll,
+                },
+            )
+            .await
+            .unwrap();
+
+        let mut slugs: Vec<String> = posts_repo
+            .get_posts()
+            .into_iter()
+            .map(|post| post.slug)
+            .collect();
+        slugs.sort();
+        assert_eq!(slugs, ["hi", "plan"]);
+    }
+}
@@ -0,0 +1,657 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::categories::domain::{Category, CategoryId};
+use crate::errors::ApplicationResult;
+use crate::events::domain::CategoryCreated;
+use crate::events::interactors::traits::EventPublisher;
+use crate::imports::domain::ImportKind;
+use crate::imports::interactors::utils::{parents_first, RenamedSlug};
+use crate::markdown_sites::domain::{MarkdownCategory, MarkdownPost, SiteLayout};
+use crate::markdown_sites::interactors::actions::IMPORT_MARKDOWN_SITE_ACTION;
+use crate::markdown_sites::interactors::traits::{FrontMatterCodec, SiteDirectory};
+use crate::markdown_sites::interactors::utils::{MarkdownImportReport, MarkdownImportRepositories};
+use crate::posts::domain::{Post, PostId, PostRevision, PostStatus};
+use crate::tags::domain::{Tag, TagId};
+use crate::utils::{
+    AuthPayload, Clock, RandomService, RepositorySlugAvailability, SlugAvailability, SlugGenerator,
+};
+
+#[derive(Debug, Clone)]
+pub struct ImportMarkdownSiteInput {
+    pub layout: SiteLayout,
+}
+
+/// Reads the posts of a Hugo or Jekyll site into the blog.
+///
+/// The `categories` of a post are read as a path, the first one top-level and each next one a
+/// child of the one before, which is how `ExportMarkdownSiteInteractor` writes them. Categories
+/// and tags are matched to existing ones by name or slug, authors by email; posts whose author
+/// is unknown are attributed to the user running the import. A post whose slug is already used
+/// by a post of the same title is taken to be imported already and is left alone; one whose
+/// slug another post uses is skipped with a warning. Each created post gets its first revision.
+#[derive(WithDeps)]
+pub struct ImportMarkdownSiteInteractor {
+    directory: Arc<dyn SiteDirectory>,
+    codec: Arc<dyn FrontMatterCodec>,
+    repos: Arc<MarkdownImportRepositories>,
+    slug_generator: Arc<dyn SlugGenerator>,
+    random: Arc<dyn RandomService>,
+    clock: Arc<dyn Clock>,
+    events: Arc<dyn EventPublisher>,
+}
+
+impl ImportMarkdownSiteInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ImportMarkdownSiteInput,
+    ) -> ApplicationResult<MarkdownImportReport> {
+        auth.can_or_fail(IMPORT_MARKDOWN_SITE_ACTION)?;
+        let layout = input.layout;
+
+        let mut run = ImportRun {
+            i: self,
+            importer_id: auth.get_user_id(),
+            report: MarkdownImportReport::default(),
+            categories: self.repos.categories_repo.get_all().await?,
+            tags: HashMap::new(),
+        };
+        if let Some(text) = self.directory.read(layout.categories_path()).await? {
+            match self.codec.parse_categories(&text) {
+                Ok(categories) => run.import_categories(&categories).await?,
+                Err(e) => run.report.warnings.push(format!(
+                    "{} was skipped: {}",
+                    layout.categories_path(),
+                    e
+                )),
+            }
+        }
+        for path in self.directory.list().await? {
+            if !layout.is_post_path(&path) {
+                continue;
+            }
+            let text = self.directory.read(&path).await?.unwrap_or_default();
+            match self.codec.parse_post(&text) {
+                Ok(post) => run.import_post(layout, &path, post).await?,
+                Err(e) => {
+                    run.report
+                        .warnings
+                        .push(format!("{} was skipped: {}", path, e));
+                    run.report.posts.skipped += 1;
+                }
+            }
+        }
+        Ok(run.report)
+    }
+}
+
+struct ImportRun<'a> {
+    i: &'a ImportMarkdownSiteInteractor,
+    importer_id: String,
+    report: MarkdownImportReport,
+    /// Every category of the blog, the created ones included.
+    categories: Vec<Category>,
+    /// Tag ids by lowercase name.
+    tags: HashMap<String, TagId>,
+}
+
+impl<'a> ImportRun<'a> {
+    async fn slug(
+        &mut self,
+        kind: ImportKind,
+        wanted: &str,
+        availability: &(dyn SlugAvailability),
+    ) -> ApplicationResult<String> {
+        let generator = self.i.slug_generator.as_ref();
+        let slug = generator.generate(wanted, availability).await?;
+        if slug != generator.slugify(wanted) {
+            self.report.renamed_slugs.push(RenamedSlug {
+                kind,
+                original: wanted.into(),
+                slug: slug.clone(),
+            });
+        }
+        Ok(slug)
+    }
+
+    async fn import_categories(&mut self, listed: &[MarkdownCategory]) -> ApplicationResult<()> {
+        // the data file refers to parents by slug, which may change on a collision
+        let mut ids_by_slug: HashMap<&str, CategoryId> = HashMap::new();
+        for category in parents_first(listed, |c| c.slug.as_str(), |c| c.parent.as_deref()) {
+            let parent_id = category
+                .parent
+                .as_deref()
+                .and_then(|parent| ids_by_slug.get(parent))
+                .cloned();
+            let existing = self.categories.iter().find(|c| c.slug == category.slug);
+            let id = match existing {
+                Some(existing) => {
+                    self.report.categories.existing += 1;
+                    existing.id.clone()
+                }
+                None => {
+                    self.create_category(
+                        &category.name,
+                        &category.slug,
+                        &category.description,
+                        parent_id,
+                    )
+                    .await?
+                    .id
+                }
+            };
+            ids_by_slug.insert(&category.slug, id);
+        }
+        Ok(())
+    }
+
+    async fn create_category(
+        &mut self,
+        name: &str,
+        wanted_slug: &str,
+        description: &str,
+        parent_id: Option<CategoryId>,
+    ) -> ApplicationResult<Category> {
+        let i = self.i;
+        let availability = RepositorySlugAvailability {
+            repo: i.repos.categories_repo.as_ref(),
+            slug_history: i.repos.slug_history.as_ref(),
+            owner_id: None,
+        };
+        let slug = self
+            .slug(ImportKind::Category, wanted_slug, &availability)
+            .await?;
+        let category = Category {
+            id: CategoryId::new(&i.random.random_id().await?),
+            name: name.into(),
+            description: description.into(),
+            created_at: i.clock.now(),
+            slug,
+            parent_id,
+        };
+        i.repos
+            .unit_of_work
+            .run(Box::pin(async {
+                i.repos.categories_repo.create(&category).await?;
+                i.events
+                    .publish(
+                        CategoryCreated {
+                            category: category.clone(),
+                        }
+                        .into(),
+                    )
+                    .await?;
+                Ok(())
+            }))
+            .await?;
+        self.categories.push(category.clone());
+        self.report.categories.created += 1;
+        Ok(category)
+    }
+
+    /// Finds or creates each category of `path` under the one before it.
+    async fn category_id(&mut self, path: &[String]) -> ApplicationResult<Option<CategoryId>> {
+        let mut parent_id: Option<CategoryId> = None;
+        for name in path {
+            let found = self.categories.iter().find(|c| {
+                c.parent_id == parent_id && (c.name.eq_ignore_ascii_case(name) || c.slug == *name)
+            });
+            parent_id = Some(match found {
+                Some(category) => category.id.clone(),
+                None => self.create_category(name, name, "", parent_id).await?.id,
+            });
+        }
+        Ok(parent_id)
+    }
+
+    async fn tag_id(&mut self, name: &str) -> ApplicationResult<TagId> {
+        let i = self.i;
+        if let Some(id) = self.tags.get(&name.to_lowercase()) {
+            return Ok(id.clone());
+        }
+        let id = match i.repos.tags_repo.get_by_name(name).await? {
+            Some(tag) => {
+                self.report.tags.existing += 1;
+                tag.id
+            }
+            None => {
+                let availability = RepositorySlugAvailability {
+                    repo: i.repos.tags_repo.as_ref(),
+                    slug_history: i.repos.slug_history.as_ref(),
+                    owner_id: None,
+                };
+                let tag = Tag {
+                    id: TagId::new(&i.random.random_id().await?),
+                    name: name.into(),
+                    slug: self.slug(ImportKind::Tag, name, &availability).await?,
+                    created_at: i.clock.now(),
+                };
+                i.repos.tags_repo.create(&tag).await?;
+                self.report.tags.created += 1;
+                tag.id
+            }
+        };
+        self.tags.insert(name.to_lowercase(), id.clone());
+        Ok(id)
+    }
+
+    async fn import_post(
+        &mut self,
+        layout: SiteLayout,
+        path: &str,
+        post: MarkdownPost,
+    ) -> ApplicationResult<()> {
+        let i = self.i;
+        let generator = i.slug_generator.as_ref();
+        let wanted = post
+            .slug
+            .clone()
+            .unwrap_or_else(|| layout.slug_from_path(path));
+        let wanted = match generator.slugify(&wanted) {
+            slug if slug.is_empty() => generator.slugify(&post.title),
+            slug => slug,
+        };
+        if let Some(existing) = i.repos.posts_repo.get_by_slug(&wanted).await? {
+            if existing.title == post.title {
+                self.report.posts.existing += 1;
+            } else {
+                self.report.warnings.push(format!(
+                    "{} was skipped: its slug {} is used by the post {:?}",
+                    path, wanted, existing.title
+                ));
+                self.report.posts.skipped += 1;
+            }
+            return Ok(());
+        }
+        let availability = RepositorySlugAvailability {
+            repo: i.repos.posts_repo.as_ref(),
+            slug_history: i.repos.slug_history.as_ref(),
+            owner_id: None,
+        };
+        let slug = match self.slug(ImportKind::Post, &wanted, &availability).await {
+            Ok(slug) => slug,
+            Err(e) => {
+                self.report
+                    .warnings
+                    .push(format!("{} was skipped: {:?}", path, e));
+                self.report.posts.skipped += 1;
+                return Ok(());
+            }
+        };
+
+        let author_id = match &post.author {
+            Some(email) => match i.repos.users_repo.get_by_email(email).await? {
+                Some(user) => user.id,
+                None => {
+                    self.report.warnings.push(format!(
+                        "{} is attributed to you, no user has the email {}",
+                        path, email
+                    ));
+                    self.importer_id.clone()
+                }
+            },
+            None => self.importer_id.clone(),
+        };
+        let status = match post.status {
+            PostStatus::Published if layout.is_draft_path(path) => PostStatus::Draft,
+            status => status,
+        };
+        let date = post
+            .date
+            .or_else(|| layout.date_from_path(path))
+            .unwrap_or_else(|| i.clock.now());
+        let category_id = self.category_id(&post.categories).await?;
+        let mut tag_ids = vec![];
+        for name in &post.tags {
+            let id = self.tag_id(name).await?;
+            if !tag_ids.contains(&id) {
+                tag_ids.push(id);
+            }
+        }
+
+        let created = Post {
+            id: PostId::new(&i.random.random_id().await?),
+            title: post.title,
+            slug,
+            content: post.content,
+            author_id,
+            category_id,
+            tag_ids,
+            status,
+            publish_at: match status {
+                PostStatus::Scheduled => post.publish_at.or(Some(date)),
+                _ => None,
+            },
+            published_at: (status == PostStatus::Published).then_some(date),
+            created_at: date,
+        };
+        i.repos
+            .unit_of_work
+            .run(Box::pin(async {
+                i.repos.posts_repo.create(&created).await?;
+                i.repos
+                    .revisions_repo
+                    .create(&PostRevision {
+                        post_id: created.id.clone(),
+                        number: 1,
+                        title: created.title.clone(),
+                        content: created.content.clone(),
+                        author_id: created.author_id.clone(),
+                        created_at: created.created_at,
+                        restored_from: None,
+                    })
+                    .await?;
+                Ok(())
+            }))
+            .await?;
+        self.report.posts.created += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::access_management::variants::Admin;
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::categories::interactors::traits::CategoriesRepository;
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
+    use crate::imports::interactors::utils::ImportCounts;
+    use crate::markdown_sites::interactors::test_doubles::fake_site_directory::FakeSiteDirectory;
+    use crate::markdown_sites::services::YamlFrontMatterCodec;
+    use crate::posts::interactors::test_doubles::fake_post_revisions_repository::FakePostRevisionsRepository;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::posts::interactors::traits::PostsRepository;
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::crypto::sequential_random_service::SequentialRandomService;
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
+    use crate::users::domain::User;
+    use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
+    use crate::utils::{RetiredSlug, SlugOwnerKind, TransliteratingSlugGenerator};
+
+    use super::*;
+
+    const HELLO: &str = "---\ntitle: Hello\ndate: 2020-05-01T08:30:00Z\nauthor: jane@example.com\n\
+                         categories:\n- Rust\n- Async\ntags:\n- Tokio\n- tokio\n---\n\nHi there\n";
+
+    struct CreationResult {
+        interactor: ImportMarkdownSiteInteractor,
+        repos: Arc<MarkdownImportRepositories>,
+        posts_repo: Arc<FakePostsRepository>,
+        categories_repo: Arc<FakeCategoriesRepository>,
+        tags_repo: Arc<FakeTagsRepository>,
+        revisions_repo: Arc<FakePostRevisionsRepository>,
+        unit_of_work: Arc<UnitOfWorkSpy>,
+        events: Arc<EventPublisherSpy>,
+    }
+
+    fn create_interactor() -> CreationResult {
+        let posts_repo = Arc::new(FakePostsRepository::new_empty());
+        let categories_repo = Arc::new(FakeCategoriesRepository::new_empty());
+        let tags_repo = Arc::new(FakeTagsRepository::new_empty());
+        let revisions_repo = Arc::new(FakePostRevisionsRepository::new_empty());
+        let unit_of_work = Arc::new(UnitOfWorkSpy::default());
+        let events = Arc::new(EventPublisherSpy::default());
+        let repos = Arc::new(MarkdownImportRepositories {
+            posts_repo: posts_repo.clone(),
+            revisions_repo: revisions_repo.clone(),
+            categories_repo: categories_repo.clone(),
+            tags_repo: tags_repo.clone(),
+            users_repo: Arc::new(FakeUsersRepository::new_with_data(&[User {
+                id: "jane".into(),
+                name: "Jane".into(),
+                email: "jane@example.com".into(),
+                password: "".into(),
+                role: Box::new(Admin),
+            }])),
+            slug_history: Arc::new(FakeSlugHistoryRepository::new_empty()),
+            unit_of_work: unit_of_work.clone(),
+        });
+        let interactor = ImportMarkdownSiteInteractor::new(
+            Arc::new(FakeSiteDirectory::new_with_files(&[
+                ("content/posts/hello.md", HELLO),
+                (
+                    "data/categories.yaml",
+                    "- slug: async\n  name: Async\n  parent: rust\n- slug: rust\n  name: Rust\n",
+                ),
+                ("content/posts/broken.md", "# No front matter"),
+                ("content/about.md", "---\ntitle: About\n---\n"),
+            ])),
+            Arc::new(YamlFrontMatterCodec),
+            repos.clone(),
+            Arc::new(TransliteratingSlugGenerator::default()),
+            Arc::new(SequentialRandomService::new()),
+            Arc::new(FakeClock::new_fixed()),
+            events.clone(),
+        );
+        CreationResult {
+            interactor,
+            repos,
+            posts_repo,
+            categories_repo,
+            tags_repo,
+            revisions_repo,
+            unit_of_work,
+            events,
+        }
+    }
+
+    fn input(layout: SiteLayout) -> ImportMarkdownSiteInput {
+        ImportMarkdownSiteInput { layout }
+    }
+
+    fn counts(created: usize, existing: usize, skipped: usize) -> ImportCounts {
+        ImportCounts {
+            created,
+            existing,
+            skipped,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input(SiteLayout::Hugo))
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [IMPORT_MARKDOWN_SITE_ACTION]);
+        assert_forbidden_error(err);
+        assert!(c.posts_repo.get_posts().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_import_posts_with_their_categories_tags_and_authors() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let report = c
+            .interactor
+            .execute(&auth, input(SiteLayout::Hugo))
+            .await
+            .unwrap();
+
+        assert_eq!(report.categories, counts(2, 0, 0));
+        assert_eq!(report.tags, counts(1, 0, 0));
+        assert_eq!(report.posts, counts(1, 0, 1));
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].starts_with("content/posts/broken.md"));
+
+        let categories = c.categories_repo.get_all().await.unwrap();
+        let rust = categories.iter().find(|c| c.slug == "rust").unwrap();
+        let r#async = categories.iter().find(|c| c.slug == "async").unwrap();
+        assert_eq!(r#async.parent_id, Some(rust.id.clone()));
+
+        let posts = c.posts_repo.get_posts();
+        let post = &posts[0];
+        assert_eq!(post.slug, "hello");
+        assert_eq!(post.author_id, "jane");
+        assert_eq!(post.status, PostStatus::Published);
+        assert_eq!(post.content, "Hi there\n");
+        assert_eq!(post.category_id, Some(r#async.id.clone()));
+        assert_eq!(post.tag_ids, [c.tags_repo.get_tags()[0].id.clone()]);
+        assert_eq!(
+            post.published_at,
+            Some(Utc.with_ymd_and_hms(2020, 5, 1, 8, 30, 0).unwrap())
+        );
+
+        let revisions = c.revisions_repo.get_revisions();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(
+            (revisions[0].post_id.clone(), revisions[0].number),
+            (post.id.clone(), 1)
+        );
+        assert_eq!(revisions[0].author_id, "jane");
+        let created: Vec<DomainEvent> = [rust, r#async]
+            .into_iter()
+            .map(|category| {
+                CategoryCreated {
+                    category: category.clone(),
+                }
+                .into()
+            })
+            .collect();
+        assert_eq!(c.events.get_published(), created);
+        assert_eq!(c.unit_of_work.get_runs(), 3);
+    }
+
+    #[tokio::test]
+    async fn should_skip_posts_whose_slug_another_post_uses() {
+        let c = create_interactor();
+        c.posts_repo
+            .create(&Post {
+                id: PostId::new("other"),
+                title: "Another post".into(),
+                slug: "hello".into(),
+                content: "".into(),
+                author_id: "jane".into(),
+                category_id: None,
+                tag_ids: vec![],
+                status: PostStatus::Draft,
+                publish_at: None,
+                published_at: None,
+                created_at: fixed_now(),
+            })
+            .await
+            .unwrap();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let report = c
+            .interactor
+            .execute(&auth, input(SiteLayout::Hugo))
+            .await
+            .unwrap();
+
+        assert_eq!(report.posts, counts(0, 0, 2));
+        assert!(report
+            .warnings
+            .iter()
+            .any(|w| w.starts_with("content/posts/hello.md") && w.contains("Another post")));
+        assert_eq!(c.posts_repo.get_posts().len(), 1);
+        assert!(c.revisions_repo.get_revisions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_leave_already_imported_posts_alone() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        c.interactor
+            .execute(&auth, input(SiteLayout::Hugo))
+            .await
+            .unwrap();
+
+        let report = c
+            .interactor
+            .execute(&auth, input(SiteLayout::Hugo))
+            .await
+            .unwrap();
+
+        assert_eq!(report.categories, counts(0, 2, 0));
+        assert_eq!(report.tags, counts(0, 0, 0));
+        assert_eq!(report.posts, counts(0, 1, 1));
+        assert_eq!(c.posts_repo.get_posts().len(), 1);
+        assert_eq!(c.categories_repo.get_all().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_read_jekyll_drafts_dates_and_unknown_authors() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_directory(Arc::new(FakeSiteDirectory::new_with_files(&[
+                (
+                    "_posts/2019-03-04-old-news.md",
+                    "---\ntitle: Old news\nauthor: nobody@example.com\ncategories: Rust\n---\nOld",
+                ),
+                ("_drafts/idea.md", "---\ntitle: Idea\n---\nLater"),
+            ])));
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let report = c
+            .interactor
+            .execute(&auth, input(SiteLayout::Jekyll))
+            .await
+            .unwrap();
+
+        assert_eq!(report.posts, counts(2, 0, 0));
+        assert_eq!(report.categories, counts(1, 0, 0));
+        assert_eq!(report.warnings.len(), 1);
+        let posts = c.posts_repo.get_posts();
+        let idea = posts.iter().find(|p| p.slug == "idea").unwrap();
+        assert_eq!(idea.status, PostStatus::Draft);
+        assert_eq!(idea.created_at, fixed_now());
+        assert_eq!(idea.published_at, None);
+        let old = posts.iter().find(|p| p.slug == "old-news").unwrap();
+        assert_eq!(old.author_id, "ID");
+        assert_eq!(
+            old.published_at,
+            Some(Utc.with_ymd_and_hms(2019, 3, 4, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_rename_categories_whose_slug_is_taken() {
+        let mut c = create_interactor();
+        c.interactor.set_repos(Arc::new(MarkdownImportRepositories {
+            slug_history: Arc::new(FakeSlugHistoryRepository::new_with_data(&[RetiredSlug {
+                kind: SlugOwnerKind::Category,
+                slug: "rust".into(),
+                owner_id: "someone-else".into(),
+                retired_at: fixed_now(),
+            }])),
+            ..(*c.repos).clone()
+        }));
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let report = c
+            .interactor
+            .execute(&auth, input(SiteLayout::Hugo))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report.renamed_slugs,
+            [RenamedSlug {
+                kind: ImportKind::Category,
+                original: "rust".into(),
+                slug: "rust-2".into(),
+            }]
+        );
+        let categories = c.categories_repo.get_all().await.unwrap();
+        let rust = categories.iter().find(|c| c.slug == "rust-2").unwrap();
+        let r#async = categories.iter().find(|c| c.slug == "async").unwrap();
+        assert_eq!(r#async.parent_id, Some(rust.id.clone()));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod actions;
+pub mod export_markdown_site;
+pub mod import_markdown_site;
+pub mod test_doubles;
+pub mod traits;
+pub mod utils;
@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::markdown_sites::interactors::traits::SiteDirectory;
+
+pub struct FakeSiteDirectory {
+    pub files: Mutex<BTreeMap<String, String>>,
+}
+
+impl FakeSiteDirectory {
+    pub fn new_empty() -> Self {
+        Self::new_with_files(&[])
+    }
+    pub fn new_with_files(files: &[(&str, &str)]) -> Self {
+        Self {
+            files: Mutex::new(
+                files
+                    .iter()
+                    .map(|(path, text)| (path.to_string(), text.to_string()))
+                    .collect(),
+            ),
+        }
+    }
+    pub fn get_file(&self, path: &str) -> Option<String> {
+        self.files.lock().unwrap().get(path).cloned()
+    }
+}
+
+#[async_trait::async_trait]
+impl SiteDirectory for FakeSiteDirectory {
+    async fn list(&self) -> UnknownResult<Vec<String>> {
+        Ok(self.files.lock().unwrap().keys().cloned().collect())
+    }
+
+    async fn read(&self, path: &str) -> UnknownResult<Option<String>> {
+        Ok(self.get_file(path))
+    }
+
+    /// Keeps binary files as lossy text, which is all the tests look at.
+    async fn write_bytes(&self, path: &str, contents: &[u8]) -> UnknownResult<()> {
+        self.files
+            .lock()
+            .unwrap()
+            .insert(path.into(), String::from_utf8_lossy(contents).into_owned());
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> UnknownResult<()> {
+        self.files.lock().unwrap().remove(path);
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::errors::UnknownResult;
+use crate::markdown_sites::domain::{MarkdownCategory, MarkdownPost};
+
+/// The directory a static site lives in, addressed by `/`-separated paths relative to it.
+#[async_trait::async_trait]
+pub trait SiteDirectory: Send + Sync {
+    /// The paths of every file, hidden ones such as `.git` left out.
+    async fn list(&self) -> UnknownResult<Vec<String>>;
+    async fn read(&self, path: &str) -> UnknownResult<Option<String>>;
+    /// Creates the missing parent directories and replaces an existing file.
+    async fn write_bytes(&self, path: &str, contents: &[u8]) -> UnknownResult<()>;
+    /// Does nothing when there is no such file.
+    async fn delete(&self, path: &str) -> UnknownResult<()>;
+
+    async fn write(&self, path: &str, contents: &str) -> UnknownResult<()> {
+        self.write_bytes(path, contents.as_bytes()).await
+    }
+}
+
+pub trait FrontMatterCodec: Send + Sync {
+    /// Fails when the file does not start with front matter or it is malformed.
+    fn parse_post(&self, text: &str) -> UnknownResult<MarkdownPost>;
+    fn write_post(&self, post: &MarkdownPost) -> UnknownResult<String>;
+    fn parse_categories(&self, text: &str) -> UnknownResult<Vec<MarkdownCategory>>;
+    fn write_categories(&self, categories: &[MarkdownCategory]) -> UnknownResult<String>;
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::imports::interactors::utils::{ImportCounts, RenamedSlug};
+use crate::posts::interactors::traits::{PostRevisionsRepository, PostsRepository};
+use crate::tags::interactors::traits::TagsRepository;
+use crate::users::interactors::traits::UsersRepository;
+use crate::utils::{SlugHistoryRepository, UnitOfWork};
+
+/// The repositories an import of a site reads and adds to, and the unit of work it adds in.
+#[derive(Clone)]
+pub struct MarkdownImportRepositories {
+    pub posts_repo: Arc<dyn PostsRepository>,
+    pub revisions_repo: Arc<dyn PostRevisionsRepository>,
+    pub categories_repo: Arc<dyn CategoriesRepository>,
+    pub tags_repo: Arc<dyn TagsRepository>,
+    pub users_repo: Arc<dyn UsersRepository>,
+    pub slug_history: Arc<dyn SlugHistoryRepository>,
+    pub unit_of_work: Arc<dyn UnitOfWork>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MarkdownImportReport {
+    pub categories: ImportCounts,
+    pub tags: ImportCounts,
+    /// Posts whose slug a post of the same title has count as existing, so importing a site
+    /// again adds only the new posts. Those whose slug another post has are skipped.
+    pub posts: ImportCounts,
+    pub renamed_slugs: Vec<RenamedSlug>,
+    pub warnings: Vec<String>,
+}
@@ -0,0 +1,117 @@
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+use crate::errors::UnknownResult;
+use crate::markdown_sites::interactors::traits::SiteDirectory;
+
+pub struct LocalSiteDirectory {
+    root: PathBuf,
+}
+
+impl LocalSiteDirectory {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Refuses paths that would leave the root directory.
+    fn path_of(&self, path: &str) -> UnknownResult<PathBuf> {
+        let relative = Path::new(path);
+        if path.is_empty()
+            || !relative
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(format!("invalid site path {:?}", path).into());
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait::async_trait]
+impl SiteDirectory for LocalSiteDirectory {
+    async fn list(&self) -> UnknownResult<Vec<String>> {
+        let mut paths = vec![];
+        let mut pending = vec![(self.root.clone(), String::new())];
+        while let Some((directory, prefix)) = pending.pop() {
+            let mut entries = match tokio::fs::read_dir(&directory).await {
+                Err(e) if e.kind() == ErrorKind::NotFound && prefix.is_empty() => break,
+                entries => entries?,
+            };
+            while let Some(entry) = entries.next_entry().await? {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if name.starts_with('.') {
+                    continue;
+                }
+                let path = format!("{}{}", prefix, name);
+                if entry.file_type().await?.is_dir() {
+                    pending.push((entry.path(), format!("{}/", path)));
+                } else {
+                    paths.push(path);
+                }
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    async fn read(&self, path: &str) -> UnknownResult<Option<String>> {
+        match tokio::fs::read_to_string(self.path_of(path)?).await {
+            Ok(text) => Ok(Some(text)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn write_bytes(&self, path: &str, contents: &[u8]) -> UnknownResult<()> {
+        let path = self.path_of(path)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(path, contents).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> UnknownResult<()> {
+        match tokio::fs::remove_file(self.path_of(path)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_write_list_and_read_files() {
+        let root = std::env::temp_dir().join(format!("site-{}", std::process::id()));
+        let directory = LocalSiteDirectory::new(&root);
+
+        directory.write("_posts/a.md", "a").await.unwrap();
+        directory.write("_data/categories.yml", "[]").await.unwrap();
+        directory.write(".git/HEAD", "ref").await.unwrap();
+
+        assert_eq!(
+            directory.list().await.unwrap(),
+            ["_data/categories.yml", "_posts/a.md"]
+        );
+        assert_eq!(
+            directory.read("_posts/a.md").await.unwrap(),
+            Some("a".into())
+        );
+        assert_eq!(directory.read("_posts/b.md").await.unwrap(), None);
+        directory.delete("_posts/a.md").await.unwrap();
+        directory.delete("_posts/a.md").await.unwrap();
+        assert_eq!(directory.read("_posts/a.md").await.unwrap(), None);
+        assert!(directory.write("../outside.md", "").await.is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_list_nothing_for_a_missing_directory() {
+        let directory = LocalSiteDirectory::new(std::env::temp_dir().join("no-such-site"));
+
+        assert!(directory.list().await.unwrap().is_empty());
+    }
+}
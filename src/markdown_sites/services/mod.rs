@@ -0,0 +1,5 @@
+pub use local_site_directory::LocalSiteDirectory;
+pub use yaml_front_matter_codec::YamlFrontMatterCodec;
+
+mod local_site_directory;
+mod yaml_front_matter_codec;
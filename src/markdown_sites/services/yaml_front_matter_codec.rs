@@ -0,0 +1,308 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use serde_norway::{Mapping, Value};
+
+use crate::errors::UnknownResult;
+use crate::markdown_sites::domain::{MarkdownCategory, MarkdownPost};
+use crate::markdown_sites::interactors::traits::FrontMatterCodec;
+use crate::posts::domain::PostStatus;
+
+/// Reads and writes the YAML front matter both Hugo and Jekyll understand.
+///
+/// Hugo's `draft` flag carries whether a post is published, Jekyll's `published: false` is read
+/// as well. Statuses neither generator knows go in an extra `status` key they ignore, so they
+/// survive a round trip.
+#[derive(Default)]
+pub struct YamlFrontMatterCodec;
+
+impl FrontMatterCodec for YamlFrontMatterCodec {
+    fn parse_post(&self, text: &str) -> UnknownResult<MarkdownPost> {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let (yaml, body) =
+            split_front_matter(text).ok_or("the file does not start with YAML front matter")?;
+        let fields = match serde_norway::from_str(yaml)? {
+            Value::Mapping(fields) => fields,
+            Value::Null => Mapping::new(),
+            _ => return Err("the front matter is not a mapping".into()),
+        };
+
+        let flag = |key: &str| fields.get(key).and_then(Value::as_bool);
+        let status = match string(&fields, "status").as_deref() {
+            Some("published") => PostStatus::Published,
+            Some("draft") => PostStatus::Draft,
+            Some("in_review") => PostStatus::InReview,
+            Some("scheduled") => PostStatus::Scheduled,
+            Some("archived") => PostStatus::Archived,
+            _ if flag("draft") == Some(true) || flag("published") == Some(false) => {
+                PostStatus::Draft
+            }
+            _ => PostStatus::Published,
+        };
+        let mut categories = list(&fields, "categories");
+        categories.extend(string(&fields, "category"));
+        Ok(MarkdownPost {
+            title: string(&fields, "title").unwrap_or_default(),
+            slug: string(&fields, "slug").filter(|slug| !slug.is_empty()),
+            date: date(&fields, "date")?,
+            status,
+            publish_at: date(&fields, "publishDate")?,
+            author: string(&fields, "author"),
+            categories,
+            tags: list(&fields, "tags"),
+            content: body.to_string(),
+        })
+    }
+
+    fn write_post(&self, post: &MarkdownPost) -> UnknownResult<String> {
+        let mut fields = Mapping::new();
+        fields.insert("title".into(), post.title.as_str().into());
+        if let Some(slug) = &post.slug {
+            fields.insert("slug".into(), slug.as_str().into());
+        }
+        if let Some(date) = post.date {
+            fields.insert("date".into(), format_date(date).into());
+        }
+        fields.insert(
+            "draft".into(),
+            (post.status != PostStatus::Published).into(),
+        );
+        let status = match post.status {
+            PostStatus::InReview => Some("in_review"),
+            PostStatus::Scheduled => Some("scheduled"),
+            PostStatus::Archived => Some("archived"),
+            PostStatus::Draft | PostStatus::Published => None,
+        };
+        if let Some(status) = status {
+            fields.insert("status".into(), status.into());
+        }
+        if let Some(publish_at) = post.publish_at {
+            fields.insert("publishDate".into(), format_date(publish_at).into());
+        }
+        if let Some(author) = &post.author {
+            fields.insert("author".into(), author.as_str().into());
+        }
+        if !post.categories.is_empty() {
+            fields.insert("categories".into(), post.categories.clone().into());
+        }
+        if !post.tags.is_empty() {
+            fields.insert("tags".into(), post.tags.clone().into());
+        }
+        Ok(format!(
+            "---\n{}---\n\n{}",
+            serde_norway::to_string(&fields)?,
+            post.content
+        ))
+    }
+
+    fn parse_categories(&self, text: &str) -> UnknownResult<Vec<MarkdownCategory>> {
+        let entries = match serde_norway::from_str(text)? {
+            Value::Sequence(entries) => entries,
+            Value::Null => vec![],
+            _ => return Err("the categories are not a list".into()),
+        };
+        entries
+            .iter()
+            .map(|entry| {
+                let fields = entry.as_mapping().ok_or("a category is not a mapping")?;
+                let slug = string(fields, "slug").ok_or("a category has no slug")?;
+                Ok(MarkdownCategory {
+                    name: string(fields, "name").unwrap_or_else(|| slug.clone()),
+                    description: string(fields, "description").unwrap_or_default(),
+                    parent: string(fields, "parent"),
+                    slug,
+                })
+            })
+            .collect()
+    }
+
+    fn write_categories(&self, categories: &[MarkdownCategory]) -> UnknownResult<String> {
+        let entries: Vec<Value> = categories
+            .iter()
+            .map(|category| {
+                let mut fields = Mapping::new();
+                fields.insert("slug".into(), category.slug.as_str().into());
+                fields.insert("name".into(), category.name.as_str().into());
+                if !category.description.is_empty() {
+                    fields.insert("description".into(), category.description.as_str().into());
+                }
+                if let Some(parent) = &category.parent {
+                    fields.insert("parent".into(), parent.as_str().into());
+                }
+                Value::Mapping(fields)
+            })
+            .collect();
+        Ok(serde_norway::to_string(&entries)?)
+    }
+}
+
+/// Splits `---\n<yaml>\n---\n<body>`, YAML's `...` also ending the front matter. The blank line
+/// that usually follows the front matter is not part of the body.
+fn split_front_matter(text: &str) -> Option<(&str, &str)> {
+    let rest = text
+        .strip_prefix("---\n")
+        .or_else(|| text.strip_prefix("---\r\n"))?;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if matches!(line.trim_end(), "---" | "...") {
+            let body = &rest[offset + line.len()..];
+            let body = body
+                .strip_prefix("\r\n")
+                .or_else(|| body.strip_prefix('\n'))
+                .unwrap_or(body);
+            return Some((&rest[..offset], body));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Also takes numbers and booleans, a title such as `2024` is read as a number.
+fn string(fields: &Mapping, key: &str) -> Option<String> {
+    match fields.get(key)? {
+        Value::String(value) => Some(value.trim().to_string()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Jekyll also takes a space separated string.
+fn list(fields: &Mapping, key: &str) -> Vec<String> {
+    match fields.get(key) {
+        Some(Value::Sequence(values)) => values
+            .iter()
+            .filter_map(|value| match value {
+                Value::String(value) => Some(value.trim().to_string()),
+                Value::Number(value) => Some(value.to_string()),
+                _ => None,
+            })
+            .collect(),
+        Some(Value::String(value)) => value.split_whitespace().map(String::from).collect(),
+        _ => vec![],
+    }
+}
+
+/// Takes RFC 3339 as Hugo writes it, `2020-05-01 10:30:00 +0200` as Jekyll writes it, and dates
+/// without a time or zone, which are taken as UTC.
+fn date(fields: &Mapping, key: &str) -> UnknownResult<Option<DateTime<Utc>>> {
+    let Some(value) = string(fields, key) else {
+        return Ok(None);
+    };
+    let parsed = DateTime::parse_from_rfc3339(&value)
+        .or_else(|_| DateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S %z"))
+        .map(|date| date.to_utc())
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S").map(|d| d.and_utc())
+        })
+        .or_else(|_| {
+            NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+                .map(|d| d.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+        });
+    match parsed {
+        Ok(date) => Ok(Some(date)),
+        Err(_) => Err(format!("{} {:?} is not a date", key, value).into()),
+    }
+}
+
+fn format_date(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn post() -> MarkdownPost {
+        MarkdownPost {
+            title: "Hello: World".into(),
+            slug: Some("hello-world".into()),
+            date: Some(Utc.with_ymd_and_hms(2020, 5, 1, 8, 30, 0).unwrap()),
+            status: PostStatus::Scheduled,
+            publish_at: Some(Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap()),
+            author: Some("jane@example.com".into()),
+            categories: vec!["Rust".into(), "Async".into()],
+            tags: vec!["tokio".into()],
+            content: "# Hi\n\n---\n\nMore".into(),
+        }
+    }
+
+    #[test]
+    fn should_round_trip_posts() {
+        let codec = YamlFrontMatterCodec;
+
+        let text = codec.write_post(&post()).unwrap();
+
+        assert!(text.starts_with("---\ntitle: 'Hello: World'\nslug: hello-world\n"));
+        assert!(text.contains("\ndraft: true\n"));
+        assert_eq!(codec.parse_post(&text).unwrap(), post());
+    }
+
+    #[test]
+    fn should_read_jekyll_front_matter() {
+        let text = "---\r\nlayout: post\r\ntitle: Old\r\ndate: 2020-05-01 10:30:00 +0200\r\n\
+                    categories: rust async\r\npublished: false\r\n---\r\nBody\r\n";
+
+        let post = YamlFrontMatterCodec.parse_post(text).unwrap();
+
+        assert_eq!(post.title, "Old");
+        assert_eq!(post.slug, None);
+        assert_eq!(
+            post.date,
+            Some(Utc.with_ymd_and_hms(2020, 5, 1, 8, 30, 0).unwrap())
+        );
+        assert_eq!(post.categories, ["rust", "async"]);
+        assert_eq!(post.status, PostStatus::Draft);
+        assert_eq!(post.content, "Body\r\n");
+    }
+
+    #[test]
+    fn should_take_posts_without_flags_as_published() {
+        let post = YamlFrontMatterCodec
+            .parse_post("---\ntitle: 2024\ndate: 2024-01-02\n---\n")
+            .unwrap();
+
+        assert_eq!(post.title, "2024");
+        assert_eq!(post.status, PostStatus::Published);
+        assert_eq!(
+            post.date,
+            Some(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn should_fail_on_files_without_valid_front_matter() {
+        for text in [
+            "# Just Markdown",
+            "+++\ntitle = \"toml\"\n+++\n",
+            "---\ntitle: [unclosed\n---\n",
+            "---\ntitle: no end",
+            "---\ndate: yesterday\n---\n",
+        ] {
+            assert!(YamlFrontMatterCodec.parse_post(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn should_round_trip_categories() {
+        let categories = vec![
+            MarkdownCategory {
+                slug: "rust".into(),
+                name: "Rust".into(),
+                description: "All about Rust".into(),
+                parent: None,
+            },
+            MarkdownCategory {
+                slug: "async".into(),
+                name: "Async".into(),
+                description: "".into(),
+                parent: Some("rust".into()),
+            },
+        ];
+        let codec = YamlFrontMatterCodec;
+
+        let text = codec.write_categories(&categories).unwrap();
+
+        assert_eq!(codec.parse_categories(&text).unwrap(), categories);
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaItem {
+    pub id: MediaId,
+    /// The file name it was uploaded with, for display only.
+    pub file_name: String,
+    pub media_type: MediaType,
+    /// The storage key of the stored original.
+    pub key: String,
+    pub size: usize,
+    pub width: u32,
+    pub height: u32,
+    /// Scaled down copies, narrowest first.
+    pub variants: Vec<MediaVariant>,
+    pub uploaded_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaVariant {
+    pub key: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaType {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+}
+
+impl MediaType {
+    /// Recognizes a file by its first bytes, whatever its name says.
+    pub fn from_magic_bytes(bytes: &[u8]) -> Option<MediaType> {
+        match bytes {
+            [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some(MediaType::Png),
+            [0xFF, 0xD8, 0xFF, ..] => Some(MediaType::Jpeg),
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(MediaType::Gif),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => {
+                Some(MediaType::Webp)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            MediaType::Png => "image/png",
+            MediaType::Jpeg => "image/jpeg",
+            MediaType::Gif => "image/gif",
+            MediaType::Webp => "image/webp",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            MediaType::Png => "png",
+            MediaType::Jpeg => "jpg",
+            MediaType::Gif => "gif",
+            MediaType::Webp => "webp",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MediaId(String);
+
+impl MediaId {
+    pub fn new(id: &str) -> Self {
+        MediaId(id.into())
+    }
+}
+
+impl Display for MediaId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<MediaId> for String {
+    fn from(id: MediaId) -> Self {
+        id.0
+    }
+}
+
+impl From<String> for MediaId {
+    fn from(s: String) -> Self {
+        MediaId(s)
+    }
+}
+
+impl From<&str> for MediaId {
+    fn from(s: &str) -> Self {
+        MediaId(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_recognize_images_by_their_magic_bytes() {
+        let cases: [(&[u8], Option<MediaType>); 6] = [
+            (b"\x89PNG\r\n\x1a\n....", Some(MediaType::Png)),
+            (b"\xFF\xD8\xFF\xE0....", Some(MediaType::Jpeg)),
+            (b"GIF89a....", Some(MediaType::Gif)),
+            (b"RIFF\x10\0\0\0WEBPVP8 ", Some(MediaType::Webp)),
+            (b"RIFF\x10\0\0\0WAVEfmt ", None),
+            (b"<svg xmlns=", None),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(MediaType::from_magic_bytes(bytes), expected);
+        }
+    }
+}
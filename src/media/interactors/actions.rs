@@ -0,0 +1,3 @@
+pub const UPLOAD_MEDIA_ACTION: &str = "UPLOAD_MEDIA_ACTION";
+pub const LIST_MEDIA_ACTION: &str = "LIST_MEDIA_ACTION";
+pub const DELETE_MEDIA_ACTION: &str = "DELETE_MEDIA_ACTION";
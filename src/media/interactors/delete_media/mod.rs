@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
+use crate::errors::ApplicationException::BadRequestException;
+use crate::errors::ApplicationResult;
+use crate::media::domain::MediaId;
+use crate::media::interactors::actions::DELETE_MEDIA_ACTION;
+use crate::media::interactors::traits::{MediaRepository, MediaStorage};
+use crate::media::interactors::utils::media_key_prefix;
+use crate::posts::interactors::traits::PostsRepository;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone)]
+pub struct DeleteMediaInput {
+    pub id: String,
+}
+
+/// Deletes a media item and all of its files, unless a post still links to any of them.
+#[derive(WithDeps)]
+pub struct DeleteMediaInteractor {
+    repo: Arc<dyn MediaRepository>,
+    storage: Arc<dyn MediaStorage>,
+    posts_repo: Arc<dyn PostsRepository>,
+    audit: Arc<dyn AuditLog>,
+}
+
+impl DeleteMediaInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: DeleteMediaInput,
+    ) -> ApplicationResult<()> {
+        let target = input.id.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            DELETE_MEDIA_ACTION,
+            &target,
+            self.delete(auth, input),
+        )
+        .await
+    }
+
+    async fn delete(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: DeleteMediaInput,
+    ) -> ApplicationResult<()> {
+        auth.can_or_fail(DELETE_MEDIA_ACTION)?;
+
+        let id = MediaId::from(input.id);
+        let item = self.repo.get_by_id_or_fail(&id).await?;
+
+        let prefix = media_key_prefix(&id);
+        let mut referencing: Vec<String> = self
+            .posts_repo
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|post| post.content.contains(&prefix))
+            .map(|post| post.slug)
+            .collect();
+        if !referencing.is_empty() {
+            referencing.sort();
+            return Err(BadRequestException(format!(
+                "media {} is still used by posts: {}",
+                id,
+                referencing.join(", ")
+            )));
+        }
+
+        // the record goes last so a failed file delete can be retried
+        for variant in &item.variants {
+            self.storage.delete(&variant.key).await?;
+        }
+        self.storage.delete(&item.key).await?;
+        self.repo.delete(&id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::make_interactor_setup;
+    use crate::media::domain::{MediaItem, MediaType, MediaVariant};
+    use crate::media::interactors::test_doubles::fake_media_repository::FakeMediaRepository;
+    use crate::media::interactors::test_doubles::fake_media_storage::FakeMediaStorage;
+    use crate::posts::domain::{Post, PostId, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::*;
+
+    use super::*;
+
+    make_interactor_setup!(
+        DeleteMediaInteractor,
+        [
+            (
+                repo,
+                FakeMediaRepository::new_with_data(&[item("used"), item("unused")]),
+                FakeMediaRepository
+            ),
+            (storage, FakeMediaStorage::new_empty(), FakeMediaStorage),
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&[post(
+                    "with-image",
+                    "![a cat](https://cdn.example.com/media/used/640w.png)"
+                )]),
+                FakePostsRepository
+            ),
+            (audit, AuditLogSpy::default(), AuditLogSpy)
+        ]
+    );
+
+    fn item(id: &str) -> MediaItem {
+        MediaItem {
+            id: MediaId::new(id),
+            file_name: format!("{}.png", id),
+            media_type: MediaType::Png,
+            key: format!("media/{}/original.png", id),
+            size: 10,
+            width: 1000,
+            height: 1000,
+            variants: vec![MediaVariant {
+                key: format!("media/{}/640w.png", id),
+                width: 640,
+                height: 640,
+            }],
+            uploaded_by: "uploader".into(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn post(slug: &str, content: &str) -> Post {
+        Post {
+            id: PostId::new(slug),
+            title: slug.into(),
+            slug: slug.into(),
+            content: content.into(),
+            author_id: "author".into(),
+            category_id: None,
+            tag_ids: vec![],
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    async fn create_stored_interactor() -> CreationResult {
+        let c = create_interactor();
+        for item in c.repo.get_items() {
+            for key in [item.key.clone(), item.variants[0].key.clone()] {
+                c.storage.put(&key, b"", "image/png").await.unwrap();
+            }
+        }
+        c
+    }
+
+    fn input(id: &str) -> DeleteMediaInput {
+        DeleteMediaInput { id: id.into() }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_stored_interactor().await;
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input("unused"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [DELETE_MEDIA_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_media_does_not_exist() {
+        let c = create_stored_interactor().await;
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input("missing"))
+            .await
+            .unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_while_a_post_references_the_media() {
+        let c = create_stored_interactor().await;
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input("used"))
+            .await
+            .unwrap_err();
+
+        assert_bad_request_error(err);
+        assert_eq!(c.repo.get_items().len(), 2);
+        assert_eq!(c.storage.get_keys().len(), 4);
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Failed);
+    }
+
+    #[tokio::test]
+    async fn should_delete_the_record_and_every_file() {
+        let c = create_stored_interactor().await;
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor.execute(&auth, input("unused")).await.unwrap();
+
+        let ids: Vec<MediaId> = c.repo.get_items().into_iter().map(|i| i.id).collect();
+        assert_eq!(ids, [MediaId::new("used")]);
+        assert_eq!(
+            c.storage.get_keys(),
+            ["media/used/640w.png", "media/used/original.png"]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_record_the_deletion_in_the_audit_log() {
+        let c = create_stored_interactor().await;
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor.execute(&auth, input("unused")).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: DELETE_MEDIA_ACTION.into(),
+                    target: "unused".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: DELETE_MEDIA_ACTION.into(),
+                    target: "unused".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+}
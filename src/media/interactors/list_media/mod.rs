@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::media::interactors::actions::LIST_MEDIA_ACTION;
+use crate::media::interactors::traits::{MediaRepository, MediaStorage};
+use crate::media::interactors::utils::VisibleMedia;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListMediaOutput {
+    /// Newest first.
+    pub items: Vec<VisibleMedia>,
+}
+
+#[derive(WithDeps)]
+pub struct ListMediaInteractor {
+    repo: Arc<dyn MediaRepository>,
+    storage: Arc<dyn MediaStorage>,
+}
+
+impl ListMediaInteractor {
+    pub async fn execute(&self, auth: &(dyn AuthPayload)) -> ApplicationResult<ListMediaOutput> {
+        auth.can_or_fail(LIST_MEDIA_ACTION)?;
+
+        let items = self.repo.get_all().await?;
+        Ok(ListMediaOutput {
+            items: items
+                .into_iter()
+                .map(|item| VisibleMedia::new(item, self.storage.as_ref()))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::make_interactor_setup;
+    use crate::media::domain::{MediaId, MediaItem, MediaType};
+    use crate::media::interactors::test_doubles::fake_media_repository::FakeMediaRepository;
+    use crate::media::interactors::test_doubles::fake_media_storage::FakeMediaStorage;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ListMediaInteractor,
+        [
+            (
+                repo,
+                FakeMediaRepository::new_with_data(&[item("old", 2), item("new", 1)]),
+                FakeMediaRepository
+            ),
+            (storage, FakeMediaStorage::new_empty(), FakeMediaStorage)
+        ]
+    );
+
+    fn item(id: &str, days_ago: i64) -> MediaItem {
+        MediaItem {
+            id: MediaId::new(id),
+            file_name: format!("{}.png", id),
+            media_type: MediaType::Png,
+            key: format!("media/{}/original.png", id),
+            size: 10,
+            width: 1,
+            height: 1,
+            variants: vec![],
+            uploaded_by: "uploader".into(),
+            created_at: Utc::now() - Duration::days(days_ago),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [LIST_MEDIA_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_list_media_newest_first_with_urls() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c.interactor.execute(&auth).await.unwrap();
+
+        let urls: Vec<&str> = output.items.iter().map(|i| i.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://cdn.example.com/media/new/original.png",
+                "https://cdn.example.com/media/old/original.png"
+            ]
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod actions;
+pub mod delete_media;
+pub mod list_media;
+pub mod test_doubles;
+pub mod traits;
+pub mod upload_media;
+pub mod utils;
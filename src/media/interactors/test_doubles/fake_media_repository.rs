@@ -0,0 +1,56 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::media::domain::{MediaId, MediaItem};
+use crate::media::interactors::traits::MediaRepository;
+use crate::utils::DeletionResult;
+
+pub struct FakeMediaRepository {
+    pub items: Mutex<Vec<MediaItem>>,
+}
+
+impl FakeMediaRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            items: Mutex::new(Vec::new()),
+        }
+    }
+    pub fn new_with_data(items: &[MediaItem]) -> Self {
+        Self {
+            items: Mutex::new(items.to_vec()),
+        }
+    }
+    pub fn get_items(&self) -> Vec<MediaItem> {
+        self.items.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaRepository for FakeMediaRepository {
+    async fn get_by_id(&self, id: &MediaId) -> UnknownResult<Option<MediaItem>> {
+        let items = self.items.lock().unwrap();
+        Ok(items.iter().find(|item| item.id == *id).cloned())
+    }
+
+    async fn get_all(&self) -> UnknownResult<Vec<MediaItem>> {
+        let mut items = self.get_items();
+        items.sort_by_key(|item| std::cmp::Reverse(item.created_at));
+        Ok(items)
+    }
+
+    async fn create(&self, item: &MediaItem) -> UnknownResult<MediaItem> {
+        self.items.lock().unwrap().push(item.clone());
+        Ok(item.clone())
+    }
+
+    async fn delete(&self, id: &MediaId) -> UnknownResult<DeletionResult> {
+        let mut items = self.items.lock().unwrap();
+        match items.iter().position(|item| item.id == *id) {
+            Some(index) => {
+                items.remove(index);
+                Ok(DeletionResult::Deleted)
+            }
+            None => Ok(DeletionResult::NotFound),
+        }
+    }
+}
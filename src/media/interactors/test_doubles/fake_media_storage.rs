@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::media::interactors::traits::MediaStorage;
+
+pub const STORAGE_URL: &str = "https://cdn.example.com";
+
+/// Keeps the objects in memory as `(bytes, content type)` by key.
+pub struct FakeMediaStorage {
+    pub objects: Mutex<HashMap<String, (Vec<u8>, String)>>,
+}
+
+impl FakeMediaStorage {
+    pub fn new_empty() -> Self {
+        Self {
+            objects: Mutex::new(HashMap::new()),
+        }
+    }
+    pub fn get_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.objects.lock().unwrap().keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+    pub fn get_object(&self, key: &str) -> Option<(Vec<u8>, String)> {
+        self.objects.lock().unwrap().get(key).cloned()
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaStorage for FakeMediaStorage {
+    async fn put(&self, key: &str, bytes: &[u8], content_type: &str) -> UnknownResult<()> {
+        self.objects
+            .lock()
+            .unwrap()
+            .insert(key.into(), (bytes.to_vec(), content_type.into()));
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> UnknownResult<Option<Vec<u8>>> {
+        Ok(self.get_object(key).map(|(bytes, _)| bytes))
+    }
+
+    async fn delete(&self, key: &str) -> UnknownResult<()> {
+        self.objects.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", STORAGE_URL, key)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod fake_media_repository;
+pub mod fake_media_storage;
+pub mod sample_images;
@@ -0,0 +1,70 @@
+use std::io::Cursor;
+
+use image::codecs::gif::GifEncoder;
+use image::{DynamicImage, Frame, ImageFormat, RgbImage, RgbaImage};
+
+fn encode(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+    let image = RgbImage::from_fn(width, height, |x, y| {
+        image::Rgb([(x * 7) as u8, (y * 13) as u8, 128])
+    });
+    let mut out = Cursor::new(vec![]);
+    DynamicImage::ImageRgb8(image)
+        .write_to(&mut out, format)
+        .unwrap();
+    out.into_inner()
+}
+
+pub fn png(width: u32, height: u32) -> Vec<u8> {
+    encode(width, height, ImageFormat::Png)
+}
+
+/// A JPEG carrying an EXIF block that says to turn it a quarter clockwise (orientation 6).
+pub fn jpeg_with_exif(width: u32, height: u32) -> Vec<u8> {
+    let jpeg = encode(width, height, ImageFormat::Jpeg);
+
+    let mut tiff = b"II*\0\x08\0\0\0".to_vec();
+    tiff.extend([1, 0]); // one entry
+    tiff.extend([0x12, 0x01, 3, 0, 1, 0, 0, 0, 6, 0, 0, 0]); // orientation, short, 6
+    tiff.extend([0, 0, 0, 0]); // no next directory
+    let mut app1 = b"Exif\0\0".to_vec();
+    app1.extend(tiff);
+
+    let mut out = jpeg[..2].to_vec();
+    out.extend([0xFF, 0xE1]);
+    out.extend(((app1.len() + 2) as u16).to_be_bytes());
+    out.extend(app1);
+    out.extend(&jpeg[2..]);
+    out
+}
+
+/// A GIF whose frames go from black to red.
+pub fn animated_gif(width: u32, height: u32, frames: u32) -> Vec<u8> {
+    let mut gif = vec![];
+    let mut encoder = GifEncoder::new(&mut gif);
+    for i in 0..frames {
+        let shade = (i * 255 / frames.saturating_sub(1).max(1)) as u8;
+        let frame = RgbaImage::from_pixel(width, height, image::Rgba([shade, 0, 0, 255]));
+        encoder.encode_frame(Frame::new(frame)).unwrap();
+    }
+    drop(encoder);
+    gif
+}
+
+/// A two frame GIF carrying a comment and an XMP block, both saying "secret".
+pub fn animated_gif_with_extensions(width: u32, height: u32) -> Vec<u8> {
+    let mut gif = animated_gif(width, height, 2);
+
+    let mut extensions = vec![0x21, 0xFE, 14]; // comment
+    extensions.extend(b"secret comment");
+    extensions.push(0);
+    extensions.extend([0x21, 0xFF, 11]); // application, XMP
+    extensions.extend(b"XMP DataXMP");
+    extensions.push(10);
+    extensions.extend(b"secret xmp");
+    extensions.push(0);
+
+    // right before the trailer
+    let trailer = gif.len() - 1;
+    gif.splice(trailer..trailer, extensions);
+    gif
+}
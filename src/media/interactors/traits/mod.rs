@@ -0,0 +1,60 @@
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::media::domain::{MediaId, MediaItem, MediaType};
+use crate::utils::DeletionResult;
+
+#[async_trait::async_trait]
+pub trait MediaRepository: Send + Sync {
+    async fn get_by_id(&self, id: &MediaId) -> UnknownResult<Option<MediaItem>>;
+    /// Newest first.
+    async fn get_all(&self) -> UnknownResult<Vec<MediaItem>>;
+    async fn create(&self, item: &MediaItem) -> UnknownResult<MediaItem>;
+    async fn delete(&self, id: &MediaId) -> UnknownResult<DeletionResult>;
+
+    async fn get_by_id_or_fail(&self, id: &MediaId) -> ApplicationResult<MediaItem> {
+        let item = self.get_by_id(id).await?;
+        item.ok_or_else(|| NotFoundException(format!("Media with id {} not found", id)))
+    }
+}
+
+/// Where the uploaded files live, addressed by `/`-separated keys such as
+/// `media/<id>/original.png`, the way an S3 bucket addresses objects.
+#[async_trait::async_trait]
+pub trait MediaStorage: Send + Sync {
+    async fn put(&self, key: &str, bytes: &[u8], content_type: &str) -> UnknownResult<()>;
+    async fn get(&self, key: &str) -> UnknownResult<Option<Vec<u8>>>;
+    /// Deleting a missing key is not an error.
+    async fn delete(&self, key: &str) -> UnknownResult<()>;
+    /// The public URL the object is served from.
+    fn url(&self, key: &str) -> String;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessedImage {
+    /// The bytes to store as the original, with the metadata removed.
+    pub original: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    /// Narrowest first.
+    pub variants: Vec<EncodedVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodedVariant {
+    pub bytes: Vec<u8>,
+    pub media_type: MediaType,
+    pub width: u32,
+    pub height: u32,
+}
+
+pub trait ImageProcessor: Send + Sync {
+    /// Fails when `bytes` is not a readable `media_type` image.
+    ///
+    /// Makes a variant for each of `widths` narrower than the image.
+    fn process(
+        &self,
+        bytes: &[u8],
+        media_type: MediaType,
+        widths: &[u32],
+    ) -> UnknownResult<ProcessedImage>;
+}
@@ -0,0 +1,234 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationResult;
+use crate::media::domain::{MediaId, MediaItem, MediaType};
+use crate::media::interactors::actions::UPLOAD_MEDIA_ACTION;
+use crate::media::interactors::traits::{ImageProcessor, MediaRepository, MediaStorage};
+use crate::media::interactors::utils::{
+    process_image, store_processed_image, MediaConfig, VisibleMedia,
+};
+use crate::utils::{AuthPayload, Clock, RandomService};
+
+#[derive(Debug, Clone)]
+pub struct UploadMediaInput {
+    pub file_name: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Stores an uploaded image along with its scaled down variants.
+///
+/// The type comes from the magic bytes, never from the file name or the client, and the
+/// image is decoded and re-encoded before it is stored so no metadata gets through, GIF
+/// comments and extensions included.
+#[derive(WithDeps)]
+pub struct UploadMediaInteractor {
+    repo: Arc<dyn MediaRepository>,
+    storage: Arc<dyn MediaStorage>,
+    processor: Arc<dyn ImageProcessor>,
+    random: Arc<dyn RandomService>,
+    clock: Arc<dyn Clock>,
+    config: Arc<MediaConfig>,
+}
+
+impl UploadMediaInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: UploadMediaInput,
+    ) -> ApplicationResult<VisibleMedia> {
+        auth.can_or_fail(UPLOAD_MEDIA_ACTION)?;
+        let media_type = self.validate(&input)?;
+
+        let processed = process_image(
+            &self.processor,
+            input.bytes,
+            media_type,
+            &self.config.variant_widths,
+        )
+        .await?
+        .map_err(|e| {
+            ValidationError::new(
+                "bytes".into(),
+                input.file_name.clone(),
+                format!("the image could not be read: {}", e),
+            )
+        })?;
+
+        let id = MediaId::new(&self.random.random_id().await?);
+        let (key, variants) =
+            store_processed_image(self.storage.as_ref(), &id, media_type, &processed).await?;
+
+        let item = MediaItem {
+            id,
+            file_name: input.file_name.trim().to_string(),
+            media_type,
+            key,
+            size: processed.original.len(),
+            width: processed.width,
+            height: processed.height,
+            variants,
+            uploaded_by: auth.get_user_id(),
+            created_at: self.clock.now(),
+        };
+        self.repo.create(&item).await?;
+        Ok(VisibleMedia::new(item, self.storage.as_ref()))
+    }
+
+    fn validate(&self, input: &UploadMediaInput) -> Result<MediaType, ValidationError> {
+        let error = |message: String| {
+            ValidationError::new("bytes".into(), input.file_name.clone(), message)
+        };
+        if input.bytes.is_empty() {
+            return Err(error("the file is empty".into()));
+        }
+        if input.bytes.len() > self.config.max_size {
+            return Err(error(format!(
+                "the file is larger than {} bytes",
+                self.config.max_size
+            )));
+        }
+        MediaType::from_magic_bytes(&input.bytes)
+            .ok_or_else(|| error("only PNG, JPEG, GIF and WebP images are accepted".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::make_interactor_setup;
+    use crate::media::interactors::test_doubles::fake_media_repository::FakeMediaRepository;
+    use crate::media::interactors::test_doubles::fake_media_storage::FakeMediaStorage;
+    use crate::media::interactors::test_doubles::sample_images::{jpeg_with_exif, png};
+    use crate::media::interactors::utils::VisibleMediaVariant;
+    use crate::media::services::ImageCrateProcessor;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::crypto::random_service_spy::{RandomServiceSpy, RANDOM_ID};
+    use crate::test_utils::errors_assertion::*;
+
+    use super::*;
+
+    make_interactor_setup!(
+        UploadMediaInteractor,
+        [
+            (repo, FakeMediaRepository::new_empty(), FakeMediaRepository),
+            (storage, FakeMediaStorage::new_empty(), FakeMediaStorage),
+            (
+                processor,
+                ImageCrateProcessor::default(),
+                ImageCrateProcessor
+            ),
+            (random, RandomServiceSpy::new(), RandomServiceSpy),
+            (clock, FakeClock::new_fixed(), FakeClock),
+            (
+                config,
+                MediaConfig {
+                    max_size: 4096,
+                    variant_widths: vec![20, 40],
+                },
+                MediaConfig
+            )
+        ]
+    );
+
+    fn input(bytes: Vec<u8>) -> UploadMediaInput {
+        UploadMediaInput {
+            file_name: "photo.png".into(),
+            bytes,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input(png(30, 30)))
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [UPLOAD_MEDIA_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_rejected_files() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        let mut corrupt = png(30, 30);
+        corrupt.truncate(30);
+
+        for bytes in [
+            vec![],
+            b"<svg onload=alert(1)>".to_vec(),
+            vec![0x89; 5000],
+            corrupt,
+        ] {
+            let err = c.interactor.execute(&auth, input(bytes)).await.unwrap_err();
+            assert_validation_error_with_key(err, "bytes");
+        }
+        assert!(c.storage.get_keys().is_empty());
+        assert!(c.repo.get_items().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_store_the_original_and_its_variants() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("uploader".into());
+
+        let output = c
+            .interactor
+            .execute(&auth, input(png(30, 15)))
+            .await
+            .unwrap();
+
+        let original = format!("media/{}/original.png", RANDOM_ID);
+        let variant = format!("media/{}/20w.png", RANDOM_ID);
+        assert_eq!(c.storage.get_keys(), [variant.clone(), original.clone()]);
+        assert_eq!(c.storage.get_object(&original).unwrap().1, "image/png");
+        assert_eq!(
+            output,
+            VisibleMedia {
+                id: RANDOM_ID.into(),
+                file_name: "photo.png".into(),
+                content_type: "image/png".into(),
+                url: format!("https://cdn.example.com/{}", original),
+                size: c.storage.get_object(&original).unwrap().0.len(),
+                width: 30,
+                height: 15,
+                variants: vec![VisibleMediaVariant {
+                    url: format!("https://cdn.example.com/{}", variant),
+                    width: 20,
+                    height: 10,
+                }],
+                created_at: fixed_now().to_rfc2822(),
+            }
+        );
+        let item = &c.repo.get_items()[0];
+        assert_eq!(item.uploaded_by, "uploader");
+        assert_eq!(item.key, original);
+    }
+
+    #[tokio::test]
+    async fn should_detect_the_type_regardless_of_the_file_name() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c
+            .interactor
+            .execute(&auth, input(jpeg_with_exif(10, 10)))
+            .await
+            .unwrap();
+
+        assert_eq!(output.content_type, "image/jpeg");
+        let (stored, _) = c
+            .storage
+            .get_object(&format!("media/{}/original.jpg", RANDOM_ID))
+            .unwrap();
+        assert!(!stored.windows(4).any(|w| w == b"Exif"));
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use crate::errors::{ApplicationResult, UnknownException, UnknownResult};
+use crate::media::domain::{MediaId, MediaItem, MediaType, MediaVariant};
+use crate::media::interactors::traits::{ImageProcessor, MediaStorage, ProcessedImage};
+
+#[derive(Debug, Clone)]
+pub struct MediaConfig {
+    /// In bytes.
+    pub max_size: usize,
+    /// Widths of the scaled down copies made for responsive images.
+    pub variant_widths: Vec<u32>,
+}
+
+impl Default for MediaConfig {
+    fn default() -> Self {
+        Self {
+            max_size: 10 * 1024 * 1024,
+            variant_widths: vec![320, 640, 1280],
+        }
+    }
+}
+
+/// Every object of a media item is stored under this prefix, so a post referencing any of
+/// them contains it.
+pub fn media_key_prefix(id: &MediaId) -> String {
+    format!("media/{}/", id)
+}
+
+pub fn original_key(id: &MediaId, media_type: MediaType) -> String {
+    format!(
+        "{}original.{}",
+        media_key_prefix(id),
+        media_type.extension()
+    )
+}
+
+pub fn variant_key(id: &MediaId, width: u32, media_type: MediaType) -> String {
+    format!(
+        "{}{}w.{}",
+        media_key_prefix(id),
+        width,
+        media_type.extension()
+    )
+}
+
+/// Runs `processor` on a blocking thread, since decoding and resizing keep the CPU busy for a
+/// while. The inner result is the processor's own, failing when the image can not be read.
+pub async fn process_image(
+    processor: &Arc<dyn ImageProcessor>,
+    bytes: Vec<u8>,
+    media_type: MediaType,
+    widths: &[u32],
+) -> ApplicationResult<UnknownResult<ProcessedImage>> {
+    let processor = processor.clone();
+    let widths = widths.to_vec();
+    let processed =
+        tokio::task::spawn_blocking(move || processor.process(&bytes, media_type, &widths))
+            .await
+            .map_err(UnknownException::from)?;
+    Ok(processed)
+}
+
+/// Puts the original and the variants of `processed` in the storage, returning the key of the
+/// original and the stored variants.
+pub async fn store_processed_image(
+    storage: &dyn MediaStorage,
+    id: &MediaId,
+    media_type: MediaType,
+    processed: &ProcessedImage,
+) -> UnknownResult<(String, Vec<MediaVariant>)> {
+    let key = original_key(id, media_type);
+    storage
+        .put(&key, &processed.original, media_type.content_type())
+        .await?;
+    let mut variants = vec![];
+    for variant in &processed.variants {
+        let key = variant_key(id, variant.width, variant.media_type);
+        storage
+            .put(&key, &variant.bytes, variant.media_type.content_type())
+            .await?;
+        variants.push(MediaVariant {
+            key,
+            width: variant.width,
+            height: variant.height,
+        });
+    }
+    Ok((key, variants))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct VisibleMediaVariant {
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct VisibleMedia {
+    pub id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub url: String,
+    pub size: usize,
+    pub width: u32,
+    pub height: u32,
+    /// Narrowest first, ready for a `srcset`.
+    pub variants: Vec<VisibleMediaVariant>,
+    pub created_at: String,
+}
+
+impl VisibleMedia {
+    pub fn new(item: MediaItem, storage: &dyn MediaStorage) -> Self {
+        VisibleMedia {
+            id: item.id.to_string(),
+            file_name: item.file_name,
+            content_type: item.media_type.content_type().to_string(),
+            url: storage.url(&item.key),
+            size: item.size,
+            width: item.width,
+            height: item.height,
+            variants: item
+                .variants
+                .iter()
+                .map(|variant| VisibleMediaVariant {
+                    url: storage.url(&variant.key),
+                    width: variant.width,
+                    height: variant.height,
+                })
+                .collect(),
+            created_at: item.created_at.to_rfc2822(),
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::io::Cursor;
+
+use image::codecs::gif::{GifDecoder, GifEncoder, Repeat};
+use image::imageops::FilterType;
+use image::{AnimationDecoder, DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits};
+
+use crate::errors::UnknownResult;
+use crate::media::domain::MediaType;
+use crate::media::interactors::traits::{EncodedVariant, ImageProcessor, ProcessedImage};
+
+pub const MAX_GIF_FRAMES: usize = 500;
+
+/// Decodes and re-encodes images with the `image` crate.
+///
+/// Re-encoding is what strips EXIF and other metadata, since the encoders write none; the
+/// EXIF orientation is applied to the pixels first so photos keep facing the right way.
+/// GIFs are rebuilt frame by frame to keep their animation while leaving comments, XMP and
+/// other extension blocks behind; their variants are PNGs of the first frame.
+pub struct ImageCrateProcessor {
+    /// Guards against decompression bombs: a few kilobytes can claim gigapixels.
+    max_dimension: u32,
+    /// Frames are re-encoded one at a time, but a tiny GIF can still repeat one frame endlessly.
+    max_frames: usize,
+}
+
+impl ImageCrateProcessor {
+    pub fn new(max_dimension: u32, max_frames: usize) -> Self {
+        Self {
+            max_dimension,
+            max_frames,
+        }
+    }
+
+    /// `Limits::default()` also caps every buffer the decoder allocates at 512 MiB.
+    fn limits(&self) -> Limits {
+        let mut limits = Limits::default();
+        limits.max_image_width = Some(self.max_dimension);
+        limits.max_image_height = Some(self.max_dimension);
+        limits
+    }
+
+    fn decode(&self, bytes: &[u8], media_type: MediaType) -> UnknownResult<DynamicImage> {
+        let mut reader = ImageReader::with_format(Cursor::new(bytes), format_of(media_type));
+        reader.limits(self.limits());
+
+        let mut decoder = reader.into_decoder()?;
+        let orientation = decoder.orientation()?;
+        let mut image = DynamicImage::from_decoder(decoder)?;
+        image.apply_orientation(orientation);
+        Ok(image)
+    }
+
+    /// Loops forever whatever the upload said, the encoder writing no other extension.
+    fn reencode_gif(&self, bytes: &[u8]) -> UnknownResult<Vec<u8>> {
+        let mut decoder = GifDecoder::new(Cursor::new(bytes))?;
+        decoder.set_limits(self.limits())?;
+        let mut out = vec![];
+        let mut encoder = GifEncoder::new(&mut out);
+        encoder.set_repeat(Repeat::Infinite)?;
+        for (count, frame) in decoder.into_frames().enumerate() {
+            if count == self.max_frames {
+                return Err(format!("GIF has more than {} frames", self.max_frames).into());
+            }
+            encoder.encode_frame(frame?)?;
+        }
+        drop(encoder);
+        Ok(out)
+    }
+}
+
+impl Default for ImageCrateProcessor {
+    fn default() -> Self {
+        Self::new(10_000, MAX_GIF_FRAMES)
+    }
+}
+
+impl ImageProcessor for ImageCrateProcessor {
+    fn process(
+        &self,
+        bytes: &[u8],
+        media_type: MediaType,
+        widths: &[u32],
+    ) -> UnknownResult<ProcessedImage> {
+        let image = self.decode(bytes, media_type)?;
+        let original = match media_type {
+            MediaType::Gif => self.reencode_gif(bytes)?,
+            _ => encode(&image, media_type)?,
+        };
+        let variant_type = match media_type {
+            MediaType::Gif => MediaType::Png,
+            other => other,
+        };
+
+        let mut widths: Vec<u32> = widths
+            .iter()
+            .copied()
+            .filter(|&width| width > 0 && width < image.width())
+            .collect();
+        widths.sort_unstable();
+        widths.dedup();
+        let mut variants = vec![];
+        for width in widths {
+            let scaled = image.resize(width, u32::MAX, FilterType::Lanczos3);
+            variants.push(EncodedVariant {
+                bytes: encode(&scaled, variant_type)?,
+                media_type: variant_type,
+                width: scaled.width(),
+                height: scaled.height(),
+            });
+        }
+
+        Ok(ProcessedImage {
+            original,
+            width: image.width(),
+            height: image.height(),
+            variants,
+        })
+    }
+}
+
+fn format_of(media_type: MediaType) -> ImageFormat {
+    match media_type {
+        MediaType::Png => ImageFormat::Png,
+        MediaType::Jpeg => ImageFormat::Jpeg,
+        MediaType::Gif => ImageFormat::Gif,
+        MediaType::Webp => ImageFormat::WebP,
+    }
+}
+
+fn encode(image: &DynamicImage, media_type: MediaType) -> UnknownResult<Vec<u8>> {
+    let image = match media_type {
+        // the JPEG encoder takes no alpha channel
+        MediaType::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8()),
+        _ => image.clone(),
+    };
+    let mut out = Cursor::new(vec![]);
+    image.write_to(&mut out, format_of(media_type))?;
+    Ok(out.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::media::interactors::test_doubles::sample_images::{
+        animated_gif, animated_gif_with_extensions, jpeg_with_exif, png,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_make_variants_narrower_than_the_image_only() {
+        let processed = ImageCrateProcessor::default()
+            .process(&png(100, 50), MediaType::Png, &[80, 40, 200, 40])
+            .unwrap();
+
+        let sizes: Vec<_> = processed
+            .variants
+            .iter()
+            .map(|v| (v.width, v.height, v.media_type))
+            .collect();
+        assert_eq!(sizes, [(40, 20, MediaType::Png), (80, 40, MediaType::Png)]);
+        assert_eq!((processed.width, processed.height), (100, 50));
+        let variant = image::load_from_memory(&processed.variants[0].bytes).unwrap();
+        assert_eq!((variant.width(), variant.height()), (40, 20));
+    }
+
+    #[test]
+    fn should_strip_exif_and_apply_its_orientation() {
+        let bytes = jpeg_with_exif(40, 20);
+        assert!(bytes.windows(4).any(|w| w == b"Exif"));
+
+        let processed = ImageCrateProcessor::default()
+            .process(&bytes, MediaType::Jpeg, &[])
+            .unwrap();
+
+        assert!(!processed.original.windows(4).any(|w| w == b"Exif"));
+        // orientation 6 turns the picture a quarter clockwise
+        assert_eq!((processed.width, processed.height), (20, 40));
+        assert_eq!(
+            MediaType::from_magic_bytes(&processed.original),
+            Some(MediaType::Jpeg)
+        );
+    }
+
+    #[test]
+    fn should_strip_gif_extensions_and_keep_the_animation() {
+        let bytes = animated_gif_with_extensions(20, 10);
+        assert!(bytes.windows(6).any(|w| w == b"secret"));
+
+        let processed = ImageCrateProcessor::default()
+            .process(&bytes, MediaType::Gif, &[10])
+            .unwrap();
+
+        assert!(!processed.original.windows(6).any(|w| w == b"secret"));
+        assert!(!processed.original.windows(3).any(|w| w == b"XMP"));
+        let frames = GifDecoder::new(Cursor::new(&processed.original))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(processed.variants[0].media_type, MediaType::Png);
+    }
+
+    #[test]
+    fn should_fail_on_unreadable_images() {
+        let mut bytes = png(10, 10);
+        bytes.truncate(20);
+
+        let result = ImageCrateProcessor::default().process(&bytes, MediaType::Png, &[]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_refuse_images_past_the_dimension_limit() {
+        let result =
+            ImageCrateProcessor::new(50, MAX_GIF_FRAMES).process(&png(60, 10), MediaType::Png, &[]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_refuse_animations_past_the_frame_limit() {
+        let processor = ImageCrateProcessor::new(10_000, 3);
+
+        assert!(processor
+            .process(&animated_gif(20, 10, 3), MediaType::Gif, &[])
+            .is_ok());
+        assert!(processor
+            .process(&animated_gif(20, 10, 4), MediaType::Gif, &[])
+            .is_err());
+    }
+
+    #[test]
+    fn should_refuse_animations_past_the_dimension_limit() {
+        let result = ImageCrateProcessor::new(50, MAX_GIF_FRAMES).process(
+            &animated_gif(60, 10, 2),
+            MediaType::Gif,
+            &[],
+        );
+
+        assert!(result.is_err());
+    }
+}
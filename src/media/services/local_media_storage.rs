@@ -0,0 +1,114 @@
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+use crate::errors::UnknownResult;
+use crate::media::interactors::traits::MediaStorage;
+
+/// Keeps media as files under a directory that a web server serves at `base_url`.
+///
+/// The content type is not stored; the web server derives it from the extension.
+pub struct LocalMediaStorage {
+    root: PathBuf,
+    base_url: String,
+}
+
+impl LocalMediaStorage {
+    pub fn new(root: impl Into<PathBuf>, base_url: &str) -> Self {
+        Self {
+            root: root.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Refuses keys that would leave the root directory.
+    fn path_of(&self, key: &str) -> UnknownResult<PathBuf> {
+        let relative = Path::new(key);
+        if key.is_empty()
+            || !relative
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(format!("invalid media key {:?}", key).into());
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaStorage for LocalMediaStorage {
+    async fn put(&self, key: &str, bytes: &[u8], _content_type: &str) -> UnknownResult<()> {
+        let path = self.path_of(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(path, bytes).await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> UnknownResult<Option<Vec<u8>>> {
+        match tokio::fs::read(self.path_of(key)?).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> UnknownResult<()> {
+        match tokio::fs::remove_file(self.path_of(key)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.base_url, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_storage(name: &str) -> LocalMediaStorage {
+        let root = std::env::temp_dir().join(format!("media-{}-{}", name, std::process::id()));
+        LocalMediaStorage::new(root, "https://cdn.example.com/")
+    }
+
+    #[tokio::test]
+    async fn should_store_read_and_delete_files() {
+        let storage = create_storage("roundtrip");
+
+        storage
+            .put("media/1/original.png", b"bytes", "image/png")
+            .await
+            .unwrap();
+        assert_eq!(
+            storage.get("media/1/original.png").await.unwrap(),
+            Some(b"bytes".to_vec())
+        );
+
+        storage.delete("media/1/original.png").await.unwrap();
+        storage.delete("media/1/original.png").await.unwrap();
+        assert_eq!(storage.get("media/1/original.png").await.unwrap(), None);
+        std::fs::remove_dir_all(&storage.root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_refuse_keys_leaving_the_root() {
+        let storage = create_storage("escape");
+
+        for key in ["../secret", "/etc/passwd", "media/../../secret", ""] {
+            assert!(storage.put(key, b"", "image/png").await.is_err(), "{}", key);
+        }
+    }
+
+    #[test]
+    fn should_serve_keys_under_the_base_url() {
+        let storage = create_storage("url");
+
+        assert_eq!(
+            storage.url("media/1/original.png"),
+            "https://cdn.example.com/media/1/original.png"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub use image_crate_processor::ImageCrateProcessor;
+pub use local_media_storage::LocalMediaStorage;
+
+mod image_crate_processor;
+mod local_media_storage;
@@ -0,0 +1,74 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::categories::domain::CategoryId;
+use crate::tags::domain::TagId;
+
+pub use workflow::*;
+
+mod workflow;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Post {
+    pub id: PostId,
+    pub title: String,
+    pub slug: String,
+    pub content: String,
+    pub author_id: String,
+    pub category_id: Option<CategoryId>,
+    pub tag_ids: Vec<TagId>,
+    pub status: PostStatus,
+    /// When a scheduled post goes live.
+    pub publish_at: Option<DateTime<Utc>>,
+    /// When the post first went live; kept when it is archived or published again.
+    pub published_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A snapshot of a post taken whenever it is updated. Numbers start at 1 for every post.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostRevision {
+    pub post_id: PostId,
+    pub number: u32,
+    pub title: String,
+    pub content: String,
+    pub author_id: String,
+    pub created_at: DateTime<Utc>,
+    pub restored_from: Option<u32>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PostId(String);
+
+impl PostId {
+    pub fn new(id: &str) -> Self {
+        PostId(id.into())
+    }
+}
+
+impl Display for PostId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<PostId> for String {
+    fn from(id: PostId) -> Self {
+        id.0
+    }
+}
+
+impl From<String> for PostId {
+    fn from(s: String) -> Self {
+        PostId(s)
+    }
+}
+
+impl From<&str> for PostId {
+    fn from(s: &str) -> Self {
+        PostId(s.to_string())
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::posts::domain::PostId;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostStatus {
+    Draft,
+    InReview,
+    Scheduled,
+    Published,
+    Archived,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostTransition {
+    /// An author hands a draft over to the editors.
+    Submit,
+    /// An editor sends a post in review back to its author.
+    Reject,
+    /// An editor publishes a post in review right away.
+    Approve,
+    /// An editor sets or moves the time a post in review (or already scheduled) goes live.
+    Schedule(DateTime<Utc>),
+    Unschedule,
+    /// A scheduled post goes live.
+    Publish,
+    Archive,
+    /// Brings an archived post back as a draft.
+    Unarchive,
+}
+
+impl PostStatus {
+    /// Returns the status `transition` leads to, or `None` if it is not allowed from `self`.
+    pub fn apply(self, transition: PostTransition) -> Option<PostStatus> {
+        use PostStatus::*;
+        use PostTransition::*;
+
+        let next = match (self, transition) {
+            (Draft, Submit) => InReview,
+            (InReview, Reject) => Draft,
+            (InReview, Approve) => Published,
+            (InReview | Scheduled, Schedule(_)) => Scheduled,
+            (Scheduled, Unschedule) => InReview,
+            (Scheduled, Publish) => Published,
+            (Published, Archive) => Archived,
+            (Archived, Unarchive) => Draft,
+            _ => return None,
+        };
+        Some(next)
+    }
+}
+
+/// A performed transition, kept as the post's workflow history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostStatusChange {
+    pub post_id: PostId,
+    pub from: PostStatus,
+    pub to: PostStatus,
+    pub transition: PostTransition,
+    pub performed_by: String,
+    pub performed_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PostStatus::*;
+    use super::PostTransition::*;
+    use super::*;
+
+    fn all_transitions() -> Vec<PostTransition> {
+        vec![
+            Submit,
+            Reject,
+            Approve,
+            Schedule(Utc::now()),
+            Unschedule,
+            Publish,
+            Archive,
+            Unarchive,
+        ]
+    }
+
+    #[test]
+    fn should_follow_the_editorial_workflow() {
+        let status = Draft.apply(Submit).unwrap();
+        assert_eq!(status, InReview);
+        let status = status.apply(Schedule(Utc::now())).unwrap();
+        assert_eq!(status, Scheduled);
+        let status = status.apply(Publish).unwrap();
+        assert_eq!(status, Published);
+        let status = status.apply(Archive).unwrap();
+        assert_eq!(status, Archived);
+        assert_eq!(status.apply(Unarchive), Some(Draft));
+    }
+
+    #[test]
+    fn should_allow_exactly_the_listed_transitions() {
+        let allowed = [
+            (Draft, vec![Submit]),
+            (InReview, vec![Reject, Approve, Schedule(Utc::now())]),
+            (Scheduled, vec![Schedule(Utc::now()), Unschedule, Publish]),
+            (Published, vec![Archive]),
+            (Archived, vec![Unarchive]),
+        ];
+        for (status, transitions) in allowed {
+            for transition in all_transitions() {
+                let expected = transitions
+                    .iter()
+                    .any(|t| std::mem::discriminant(t) == std::mem::discriminant(&transition));
+                assert_eq!(
+                    status.apply(transition).is_some(),
+                    expected,
+                    "{:?} -> {:?}",
+                    status,
+                    transition
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub const UPDATE_POST_ACTION: &str = "UPDATE_POST_ACTION";
+pub const VIEW_POST_REVISIONS_ACTION: &str = "VIEW_POST_REVISIONS_ACTION";
+pub const RESTORE_POST_REVISION_ACTION: &str = "RESTORE_POST_REVISION_ACTION";
+pub const SUBMIT_POST_ACTION: &str = "SUBMIT_POST_ACTION";
+pub const REVIEW_POST_ACTION: &str = "REVIEW_POST_ACTION";
+pub const PUBLISH_POST_ACTION: &str = "PUBLISH_POST_ACTION";
+pub const ARCHIVE_POST_ACTION: &str = "ARCHIVE_POST_ACTION";
+/// Lets an author publish posts with the relaxed sanitization policy.
+pub const USE_TRUSTED_HTML_ACTION: &str = "USE_TRUSTED_HTML_ACTION";
@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use similar::{ChangeTag, TextDiff};
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::posts::domain::PostId;
+use crate::posts::interactors::actions::VIEW_POST_REVISIONS_ACTION;
+use crate::posts::interactors::traits::PostRevisionsRepository;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone)]
+pub struct DiffPostRevisionsInput {
+    pub post_id: String,
+    pub from: u32,
+    pub to: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// Line number in the `from` revision, starting at 1; `None` for added lines.
+    pub old_line: Option<usize>,
+    /// Line number in the `to` revision, starting at 1; `None` for removed lines.
+    pub new_line: Option<usize>,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffPostRevisionsOutput {
+    pub from: u32,
+    pub to: u32,
+    pub title: Vec<DiffLine>,
+    pub content: Vec<DiffLine>,
+}
+
+#[derive(WithDeps)]
+pub struct DiffPostRevisionsInteractor {
+    revisions: Arc<dyn PostRevisionsRepository>,
+}
+
+impl DiffPostRevisionsInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: DiffPostRevisionsInput,
+    ) -> ApplicationResult<DiffPostRevisionsOutput> {
+        auth.can_or_fail(VIEW_POST_REVISIONS_ACTION)?;
+
+        let post_id = PostId::from(input.post_id);
+        let from = self.revisions.get_or_fail(&post_id, input.from).await?;
+        let to = self.revisions.get_or_fail(&post_id, input.to).await?;
+
+        Ok(DiffPostRevisionsOutput {
+            from: from.number,
+            to: to.number,
+            title: Self::diff_lines(&from.title, &to.title),
+            content: Self::diff_lines(&from.content, &to.content),
+        })
+    }
+
+    fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+        TextDiff::from_lines(old, new)
+            .iter_all_changes()
+            .map(|change| DiffLine {
+                kind: match change.tag() {
+                    ChangeTag::Equal => DiffLineKind::Unchanged,
+                    ChangeTag::Insert => DiffLineKind::Added,
+                    ChangeTag::Delete => DiffLineKind::Removed,
+                },
+                old_line: change.old_index().map(|i| i + 1),
+                new_line: change.new_index().map(|i| i + 1),
+                content: change.value().trim_end_matches(['\n', '\r']).to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::posts::domain::PostRevision;
+    use crate::posts::interactors::test_doubles::fake_post_revisions_repository::FakePostRevisionsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::{assert_forbidden_error, assert_not_found_error};
+
+    use super::*;
+    use DiffLineKind::*;
+
+    make_interactor_setup!(
+        DiffPostRevisionsInteractor,
+        [(
+            revisions,
+            FakePostRevisionsRepository::new_with_data(&[
+                revision(1, "title", "first\nsecond\nthird\n"),
+                revision(2, "title", "first\n2nd\nthird\nfourth\n"),
+            ]),
+            FakePostRevisionsRepository
+        )]
+    );
+
+    fn revision(number: u32, title: &str, content: &str) -> PostRevision {
+        PostRevision {
+            post_id: "post".into(),
+            number,
+            title: title.to_string(),
+            content: content.to_string(),
+            author_id: "author".to_string(),
+            created_at: Utc::now(),
+            restored_from: None,
+        }
+    }
+
+    fn input(from: u32, to: u32) -> DiffPostRevisionsInput {
+        DiffPostRevisionsInput {
+            post_id: "post".into(),
+            from,
+            to,
+        }
+    }
+
+    fn line(kind: DiffLineKind, old: Option<usize>, new: Option<usize>, content: &str) -> DiffLine {
+        DiffLine {
+            kind,
+            old_line: old,
+            new_line: new,
+            content: content.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input(1, 2)).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [VIEW_POST_REVISIONS_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_a_revision_does_not_exist() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input(1, 3)).await.unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_diff_the_content_line_by_line() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c.interactor.execute(&auth, input(1, 2)).await.unwrap();
+
+        assert_eq!(
+            output.content,
+            [
+                line(Unchanged, Some(1), Some(1), "first"),
+                line(Removed, Some(2), None, "second"),
+                line(Added, None, Some(2), "2nd"),
+                line(Unchanged, Some(3), Some(3), "third"),
+                line(Added, None, Some(4), "fourth"),
+            ]
+        );
+        assert_eq!(output.title, [line(Unchanged, Some(1), Some(1), "title")]);
+    }
+
+    #[tokio::test]
+    async fn should_diff_in_either_direction() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c.interactor.execute(&auth, input(2, 1)).await.unwrap();
+
+        assert_eq!((output.from, output.to), (2, 1));
+        assert_eq!(output.content[4], line(Removed, Some(4), None, "fourth"));
+    }
+}
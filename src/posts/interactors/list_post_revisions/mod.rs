@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::posts::domain::PostId;
+use crate::posts::interactors::actions::VIEW_POST_REVISIONS_ACTION;
+use crate::posts::interactors::traits::{PostRevisionsRepository, PostsRepository};
+use crate::posts::interactors::utils::VisibleRevision;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone)]
+pub struct ListPostRevisionsInput {
+    pub post_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListPostRevisionsOutput {
+    /// Newest first.
+    pub revisions: Vec<VisibleRevision>,
+}
+
+#[derive(WithDeps)]
+pub struct ListPostRevisionsInteractor {
+    repo: Arc<dyn PostsRepository>,
+    revisions: Arc<dyn PostRevisionsRepository>,
+}
+
+impl ListPostRevisionsInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ListPostRevisionsInput,
+    ) -> ApplicationResult<ListPostRevisionsOutput> {
+        auth.can_or_fail(VIEW_POST_REVISIONS_ACTION)?;
+
+        let post_id = PostId::from(input.post_id);
+        self.repo.get_by_id_or_fail(&post_id).await?;
+
+        let revisions = self.revisions.get_by_post_id(&post_id).await?;
+        Ok(ListPostRevisionsOutput {
+            revisions: revisions.into_iter().rev().map(|r| r.into()).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::posts::domain::{Post, PostRevision, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_post_revisions_repository::FakePostRevisionsRepository;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::{assert_forbidden_error, assert_not_found_error};
+
+    use super::*;
+
+    make_interactor_setup!(
+        ListPostRevisionsInteractor,
+        [
+            (
+                repo,
+                FakePostsRepository::new_with_data(&[post("post"), post("other")]),
+                FakePostsRepository
+            ),
+            (
+                revisions,
+                FakePostRevisionsRepository::new_with_data(&[
+                    revision("post", 2),
+                    revision("other", 1),
+                    revision("post", 1),
+                ]),
+                FakePostRevisionsRepository
+            )
+        ]
+    );
+
+    fn post(id: &str) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.to_string(),
+            slug: id.to_string(),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            category_id: None,
+            tag_ids: vec![],
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn revision(post_id: &str, number: u32) -> PostRevision {
+        PostRevision {
+            post_id: post_id.into(),
+            number,
+            title: format!("title {}", number),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            created_at: Utc::now(),
+            restored_from: None,
+        }
+    }
+
+    fn input() -> ListPostRevisionsInput {
+        ListPostRevisionsInput {
+            post_id: "post".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth, input()).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [VIEW_POST_REVISIONS_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_post_does_not_exist() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(
+                &auth,
+                ListPostRevisionsInput {
+                    post_id: "missing".into(),
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_list_revisions_of_the_post_newest_first() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c.interactor.execute(&auth, input()).await.unwrap();
+
+        let numbers: Vec<u32> = output.revisions.iter().map(|r| r.number).collect();
+        assert_eq!(numbers, [2, 1]);
+    }
+}
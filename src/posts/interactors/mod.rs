@@ -0,0 +1,9 @@
+pub mod actions;
+pub mod diff_post_revisions;
+pub mod list_post_revisions;
+pub mod restore_post_revision;
+pub mod test_doubles;
+pub mod traits;
+pub mod transition_post;
+pub mod update_post;
+pub mod utils;
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::posts::domain::PostId;
+use crate::posts::interactors::actions::RESTORE_POST_REVISION_ACTION;
+use crate::posts::interactors::traits::{PostRevisionsRepository, PostsRepository};
+use crate::posts::interactors::utils::{record_revision, VisibleRevision};
+use crate::utils::{AuthPayload, Clock};
+
+#[derive(Debug, Clone)]
+pub struct RestorePostRevisionInput {
+    pub post_id: String,
+    pub number: u32,
+}
+
+#[derive(WithDeps)]
+pub struct RestorePostRevisionInteractor {
+    repo: Arc<dyn PostsRepository>,
+    revisions: Arc<dyn PostRevisionsRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl RestorePostRevisionInteractor {
+    /// Brings back the title and content of an old revision, recorded as a new revision so the
+    /// history is never rewritten.
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: RestorePostRevisionInput,
+    ) -> ApplicationResult<VisibleRevision> {
+        auth.can_or_fail(RESTORE_POST_REVISION_ACTION)?;
+
+        let post_id = PostId::from(input.post_id);
+        let original = self.repo.get_by_id_or_fail(&post_id).await?;
+        let restored = self.revisions.get_or_fail(&post_id, input.number).await?;
+
+        let mut updated = original.clone();
+        updated.title = restored.title;
+        updated.content = restored.content;
+        self.repo.update(&updated).await?;
+
+        let revision = record_revision(
+            self.revisions.as_ref(),
+            &original,
+            &updated,
+            &auth.get_user_id(),
+            Some(restored.number),
+            self.clock.now(),
+        )
+        .await?;
+        Ok(revision.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::posts::domain::{Post, PostRevision, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_post_revisions_repository::FakePostRevisionsRepository;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::errors_assertion::{assert_forbidden_error, assert_not_found_error};
+
+    use super::*;
+
+    make_interactor_setup!(
+        RestorePostRevisionInteractor,
+        [
+            (
+                repo,
+                FakePostsRepository::new_with_data(&[current_post()]),
+                FakePostsRepository
+            ),
+            (
+                revisions,
+                FakePostRevisionsRepository::new_with_data(&[
+                    revision(1, "old title", "old content"),
+                    revision(2, "new title", "new content"),
+                ]),
+                FakePostRevisionsRepository
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock)
+        ]
+    );
+
+    fn current_post() -> Post {
+        Post {
+            id: PostId::new("post"),
+            title: "new title".to_string(),
+            slug: "post".to_string(),
+            content: "new content".to_string(),
+            author_id: "author".to_string(),
+            category_id: None,
+            tag_ids: vec![],
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn revision(number: u32, title: &str, content: &str) -> PostRevision {
+        PostRevision {
+            post_id: "post".into(),
+            number,
+            title: title.to_string(),
+            content: content.to_string(),
+            author_id: "author".to_string(),
+            created_at: Utc::now(),
+            restored_from: None,
+        }
+    }
+
+    fn input(number: u32) -> RestorePostRevisionInput {
+        RestorePostRevisionInput {
+            post_id: "post".into(),
+            number,
+        }
+    }
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("editor".into())
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("editor".into());
+
+        let err = c.interactor.execute(&auth, input(1)).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [RESTORE_POST_REVISION_ACTION]);
+        assert_forbidden_error(err);
+        assert_eq!(c.revisions.get_revisions().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_the_revision_does_not_exist() {
+        let c = create_interactor();
+
+        let err = c.interactor.execute(&auth(), input(7)).await.unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_restore_the_post_to_the_old_revision() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), input(1)).await.unwrap();
+
+        let post = c.repo.get_by_id(&"post".into()).await.unwrap().unwrap();
+        assert_eq!(post.title, "old title");
+        assert_eq!(post.content, "old content");
+    }
+
+    #[tokio::test]
+    async fn should_record_the_restore_as_a_new_revision() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(&auth(), input(1)).await.unwrap();
+
+        assert_eq!(output.number, 3);
+        assert_eq!(output.restored_from, Some(1));
+        assert_eq!(output.author_id, "editor");
+        let latest = c.revisions.get_revisions().pop().unwrap();
+        assert_eq!(latest.content, "old content");
+        assert_eq!(latest.created_at, fixed_now());
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::posts::domain::{PostId, PostRevision};
+use crate::posts::interactors::traits::PostRevisionsRepository;
+
+pub struct FakePostRevisionsRepository {
+    pub revisions: Mutex<Vec<PostRevision>>,
+}
+
+impl FakePostRevisionsRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            revisions: Mutex::new(Vec::new()),
+        }
+    }
+    pub fn new_with_data(revisions: &[PostRevision]) -> Self {
+        Self {
+            revisions: Mutex::new(revisions.to_vec()),
+        }
+    }
+    pub fn get_revisions(&self) -> Vec<PostRevision> {
+        self.revisions.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl PostRevisionsRepository for FakePostRevisionsRepository {
+    async fn get_by_post_id(&self, post_id: &PostId) -> UnknownResult<Vec<PostRevision>> {
+        let revisions = self.revisions.lock().unwrap();
+        let mut result: Vec<PostRevision> = revisions
+            .iter()
+            .filter(|revision| revision.post_id == *post_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|revision| revision.number);
+        Ok(result)
+    }
+
+    async fn get(&self, post_id: &PostId, number: u32) -> UnknownResult<Option<PostRevision>> {
+        let revisions = self.revisions.lock().unwrap();
+        Ok(revisions
+            .iter()
+            .find(|revision| revision.post_id == *post_id && revision.number == number)
+            .cloned())
+    }
+
+    async fn get_latest(&self, post_id: &PostId) -> UnknownResult<Option<PostRevision>> {
+        let revisions = self.revisions.lock().unwrap();
+        Ok(revisions
+            .iter()
+            .filter(|revision| revision.post_id == *post_id)
+            .max_by_key(|revision| revision.number)
+            .cloned())
+    }
+
+    async fn create(&self, revision: &PostRevision) -> UnknownResult<PostRevision> {
+        self.revisions.lock().unwrap().push(revision.clone());
+        Ok(revision.clone())
+    }
+}
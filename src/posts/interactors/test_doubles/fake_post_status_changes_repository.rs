@@ -0,0 +1,37 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::posts::domain::{PostId, PostStatusChange};
+use crate::posts::interactors::traits::PostStatusChangesRepository;
+
+pub struct FakePostStatusChangesRepository {
+    pub changes: Mutex<Vec<PostStatusChange>>,
+}
+
+impl FakePostStatusChangesRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            changes: Mutex::new(Vec::new()),
+        }
+    }
+    pub fn get_changes(&self) -> Vec<PostStatusChange> {
+        self.changes.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl PostStatusChangesRepository for FakePostStatusChangesRepository {
+    async fn get_by_post_id(&self, post_id: &PostId) -> UnknownResult<Vec<PostStatusChange>> {
+        let changes = self.changes.lock().unwrap();
+        Ok(changes
+            .iter()
+            .filter(|change| change.post_id == *post_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, change: &PostStatusChange) -> UnknownResult<PostStatusChange> {
+        self.changes.lock().unwrap().push(change.clone());
+        Ok(change.clone())
+    }
+}
@@ -0,0 +1,118 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+use crate::categories::domain::CategoryId;
+use crate::errors::UnknownResult;
+use crate::posts::domain::{Post, PostId, PostStatus};
+use crate::posts::interactors::traits::PostsRepository;
+use crate::tags::domain::TagId;
+use crate::utils::DeletionResult;
+
+pub struct FakePostsRepository {
+    pub posts: Mutex<Vec<Post>>,
+}
+
+impl FakePostsRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            posts: Mutex::new(Vec::new()),
+        }
+    }
+    pub fn new_with_data(posts: &[Post]) -> Self {
+        Self {
+            posts: Mutex::new(posts.to_vec()),
+        }
+    }
+    pub fn get_posts(&self) -> Vec<Post> {
+        self.posts.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl PostsRepository for FakePostsRepository {
+    async fn get_by_id(&self, id: &PostId) -> UnknownResult<Option<Post>> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts.iter().find(|post| post.id == *id).cloned())
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Post>> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts.iter().find(|post| post.slug == slug).cloned())
+    }
+
+    async fn get_all(&self) -> UnknownResult<Vec<Post>> {
+        Ok(self.posts.lock().unwrap().clone())
+    }
+
+    async fn get_by_category_id(&self, id: &CategoryId) -> UnknownResult<Vec<Post>> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts
+            .iter()
+            .filter(|post| post.category_id.as_ref() == Some(id))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_by_tag_id(&self, id: &TagId) -> UnknownResult<Vec<Post>> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts
+            .iter()
+            .filter(|post| post.tag_ids.contains(id))
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, post: &Post) -> UnknownResult<Post> {
+        self.posts.lock().unwrap().push(post.clone());
+        Ok(post.clone())
+    }
+
+    async fn update(&self, post: &Post) -> UnknownResult<Post> {
+        let mut posts = self.posts.lock().unwrap();
+        let index = posts.iter().position(|p| p.id == post.id).unwrap();
+        posts[index] = post.clone();
+        Ok(post.clone())
+    }
+
+    async fn publish_if_scheduled_for(
+        &self,
+        id: &PostId,
+        publish_at: DateTime<Utc>,
+    ) -> UnknownResult<Option<Post>> {
+        let mut posts = self.posts.lock().unwrap();
+        match posts.iter_mut().find(|p| &p.id == id) {
+            Some(stored)
+                if stored.status == PostStatus::Scheduled
+                    && stored.publish_at == Some(publish_at) =>
+            {
+                stored.status = PostStatus::Published;
+                stored.publish_at = None;
+                stored.published_at.get_or_insert(publish_at);
+                Ok(Some(stored.clone()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    async fn get_scheduled_until(&self, time: DateTime<Utc>) -> UnknownResult<Vec<Post>> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts
+            .iter()
+            .filter(|post| post.status == PostStatus::Scheduled)
+            .filter(|post| post.publish_at.is_some_and(|publish_at| publish_at <= time))
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, id: &PostId) -> UnknownResult<DeletionResult> {
+        let mut posts = self.posts.lock().unwrap();
+        match posts.iter().position(|post| post.id == *id) {
+            Some(index) => {
+                posts.remove(index);
+                Ok(DeletionResult::Deleted)
+            }
+            None => Ok(DeletionResult::NotFound),
+        }
+    }
+}
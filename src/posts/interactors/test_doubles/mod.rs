@@ -0,0 +1,3 @@
+pub mod fake_post_revisions_repository;
+pub mod fake_post_status_changes_repository;
+pub mod fake_posts_repository;
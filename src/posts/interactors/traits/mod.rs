@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+
+use crate::categories::domain::CategoryId;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::posts::domain::{Post, PostId, PostRevision, PostStatusChange};
+use crate::tags::domain::TagId;
+use crate::utils::DeletionResult;
+
+#[async_trait::async_trait]
+pub trait PostsRepository: Send + Sync {
+    async fn get_by_id(&self, id: &PostId) -> UnknownResult<Option<Post>>;
+    async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Post>>;
+    async fn get_all(&self) -> UnknownResult<Vec<Post>>;
+    async fn get_by_category_id(&self, id: &CategoryId) -> UnknownResult<Vec<Post>>;
+    async fn get_by_tag_id(&self, id: &TagId) -> UnknownResult<Vec<Post>>;
+    async fn create(&self, post: &Post) -> UnknownResult<Post>;
+    async fn update(&self, post: &Post) -> UnknownResult<Post>;
+    /// Publishes the post only if it is still scheduled for `publish_at` and returns the stored
+    /// copy if it did. Only the status and the publish times are written: `published_at` is set
+    /// to `publish_at` unless the post was published before, so edits made since the post was
+    /// read are kept. The check and the write must be atomic (e.g. `UPDATE ... WHERE status =
+    /// 'scheduled' AND publish_at = ?`), it is what keeps several app instances from publishing
+    /// a post twice, or publishing one an editor has just moved to a later time.
+    async fn publish_if_scheduled_for(
+        &self,
+        id: &PostId,
+        publish_at: DateTime<Utc>,
+    ) -> UnknownResult<Option<Post>>;
+    /// Returns the scheduled posts whose publish time is at or before `time`.
+    async fn get_scheduled_until(&self, time: DateTime<Utc>) -> UnknownResult<Vec<Post>>;
+    async fn delete(&self, id: &PostId) -> UnknownResult<DeletionResult>;
+
+    async fn get_by_id_or_fail(&self, id: &PostId) -> ApplicationResult<Post> {
+        let post = self.get_by_id(id).await?;
+        post.ok_or_else(|| NotFoundException(format!("Post with id {} not found", id)))
+    }
+
+    async fn get_by_slug_or_fail(&self, slug: &str) -> ApplicationResult<Post> {
+        let post = self.get_by_slug(slug).await?;
+        post.ok_or_else(|| NotFoundException(format!("Post with slug {} not found", slug)))
+    }
+}
+
+#[async_trait::async_trait]
+pub trait PostRevisionsRepository: Send + Sync {
+    /// Returns the revisions of the post ordered by number.
+    async fn get_by_post_id(&self, post_id: &PostId) -> UnknownResult<Vec<PostRevision>>;
+    async fn get(&self, post_id: &PostId, number: u32) -> UnknownResult<Option<PostRevision>>;
+    async fn get_latest(&self, post_id: &PostId) -> UnknownResult<Option<PostRevision>>;
+    async fn create(&self, revision: &PostRevision) -> UnknownResult<PostRevision>;
+
+    async fn get_or_fail(&self, post_id: &PostId, number: u32) -> ApplicationResult<PostRevision> {
+        let revision = self.get(post_id, number).await?;
+        revision.ok_or_else(|| {
+            NotFoundException(format!(
+                "Revision {} of post with id {} not found",
+                number, post_id
+            ))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+pub trait PostStatusChangesRepository: Send + Sync {
+    /// Returns the status changes of the post, oldest first.
+    async fn get_by_post_id(&self, post_id: &PostId) -> UnknownResult<Vec<PostStatusChange>>;
+    async fn create(&self, change: &PostStatusChange) -> UnknownResult<PostStatusChange>;
+}
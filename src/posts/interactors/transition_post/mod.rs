@@ -0,0 +1,362 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationException::BadRequestException;
+use crate::errors::ApplicationResult;
+use crate::posts::domain::{PostId, PostStatus, PostStatusChange, PostTransition};
+use crate::posts::interactors::actions::{
+    ARCHIVE_POST_ACTION, PUBLISH_POST_ACTION, REVIEW_POST_ACTION, SUBMIT_POST_ACTION,
+};
+use crate::posts::interactors::traits::{PostStatusChangesRepository, PostsRepository};
+use crate::utils::{AuthPayload, BlogTimezone, Clock};
+
+#[derive(Debug, Clone)]
+pub struct TransitionPostInput {
+    pub post_id: String,
+    pub transition: RequestedTransition,
+}
+
+/// A `PostTransition` as callers ask for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestedTransition {
+    Submit,
+    Reject,
+    Approve,
+    /// A wall-clock time of the blog's timezone, e.g. Monday 09:00.
+    Schedule(NaiveDateTime),
+    Unschedule,
+    Publish,
+    Archive,
+    Unarchive,
+}
+
+impl RequestedTransition {
+    fn resolve(self, timezone: &BlogTimezone) -> ApplicationResult<PostTransition> {
+        Ok(match self {
+            RequestedTransition::Submit => PostTransition::Submit,
+            RequestedTransition::Reject => PostTransition::Reject,
+            RequestedTransition::Approve => PostTransition::Approve,
+            RequestedTransition::Schedule(local) => {
+                PostTransition::Schedule(timezone.to_utc(local)?)
+            }
+            RequestedTransition::Unschedule => PostTransition::Unschedule,
+            RequestedTransition::Publish => PostTransition::Publish,
+            RequestedTransition::Archive => PostTransition::Archive,
+            RequestedTransition::Unarchive => PostTransition::Unarchive,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionPostOutput {
+    pub status: PostStatus,
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+#[derive(WithDeps)]
+pub struct TransitionPostInteractor {
+    repo: Arc<dyn PostsRepository>,
+    status_changes: Arc<dyn PostStatusChangesRepository>,
+    clock: Arc<dyn Clock>,
+    timezone: Arc<BlogTimezone>,
+}
+
+impl TransitionPostInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: TransitionPostInput,
+    ) -> ApplicationResult<TransitionPostOutput> {
+        auth.can_or_fail(Self::required_action(input.transition))?;
+        let transition = input.transition.resolve(&self.timezone)?;
+
+        let mut post = self
+            .repo
+            .get_by_id_or_fail(&PostId::from(input.post_id))
+            .await?;
+        let from = post.status;
+        let to = from.apply(transition).ok_or_else(|| {
+            BadRequestException(format!(
+                "can not {:?} a post that is {:?}",
+                transition, from
+            ))
+        })?;
+
+        let now = self.clock.now();
+        post.status = to;
+        post.publish_at = match transition {
+            PostTransition::Schedule(publish_at) => Some(publish_at),
+            _ => None,
+        };
+        if to == PostStatus::Published {
+            post.published_at.get_or_insert(now);
+        }
+        self.repo.update(&post).await?;
+
+        self.status_changes
+            .create(&PostStatusChange {
+                post_id: post.id.clone(),
+                from,
+                to,
+                transition,
+                performed_by: auth.get_user_id(),
+                performed_at: now,
+            })
+            .await?;
+
+        Ok(TransitionPostOutput {
+            status: post.status,
+            publish_at: post.publish_at,
+        })
+    }
+
+    fn required_action(transition: RequestedTransition) -> &'static str {
+        match transition {
+            RequestedTransition::Submit => SUBMIT_POST_ACTION,
+            RequestedTransition::Reject
+            | RequestedTransition::Approve
+            | RequestedTransition::Schedule(_)
+            | RequestedTransition::Unschedule => REVIEW_POST_ACTION,
+            RequestedTransition::Publish => PUBLISH_POST_ACTION,
+            RequestedTransition::Archive | RequestedTransition::Unarchive => ARCHIVE_POST_ACTION,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate, TimeZone};
+
+    use crate::make_interactor_setup;
+    use crate::posts::domain::Post;
+    use crate::posts::interactors::test_doubles::fake_post_status_changes_repository::FakePostStatusChangesRepository;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::errors_assertion::*;
+
+    use super::*;
+
+    make_interactor_setup!(
+        TransitionPostInteractor,
+        [
+            (
+                repo,
+                FakePostsRepository::new_with_data(&[
+                    post("draft", PostStatus::Draft),
+                    post("review", PostStatus::InReview),
+                ]),
+                FakePostsRepository
+            ),
+            (
+                status_changes,
+                FakePostStatusChangesRepository::new_empty(),
+                FakePostStatusChangesRepository
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock),
+            (
+                timezone,
+                BlogTimezone::new(chrono_tz::Asia::Tehran),
+                BlogTimezone
+            )
+        ]
+    );
+
+    fn post(id: &str, status: PostStatus) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.to_string(),
+            slug: id.to_string(),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            category_id: None,
+            tag_ids: vec![],
+            status,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn input(post_id: &str, transition: RequestedTransition) -> TransitionPostInput {
+        TransitionPostInput {
+            post_id: post_id.into(),
+            transition,
+        }
+    }
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("editor".into())
+    }
+
+    #[tokio::test]
+    async fn should_check_the_action_of_the_transition() {
+        let cases = [
+            (RequestedTransition::Submit, SUBMIT_POST_ACTION),
+            (RequestedTransition::Approve, REVIEW_POST_ACTION),
+            (
+                RequestedTransition::Schedule(fixed_now().naive_utc()),
+                REVIEW_POST_ACTION,
+            ),
+            (RequestedTransition::Publish, PUBLISH_POST_ACTION),
+            (RequestedTransition::Unarchive, ARCHIVE_POST_ACTION),
+        ];
+        for (transition, action) in cases {
+            let c = create_interactor();
+            let auth = AuthPayloadSpy::new_disallowed("author".into());
+
+            let err = c
+                .interactor
+                .execute(&auth, input("draft", transition))
+                .await
+                .unwrap_err();
+
+            assert_eq!(auth.get_called(), [action]);
+            assert_forbidden_error(err);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_post_does_not_exist() {
+        let c = create_interactor();
+
+        let err = c
+            .interactor
+            .execute(&auth(), input("missing", RequestedTransition::Submit))
+            .await
+            .unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_bad_request_for_an_illegal_transition() {
+        let c = create_interactor();
+
+        let err = c
+            .interactor
+            .execute(&auth(), input("draft", RequestedTransition::Approve))
+            .await
+            .unwrap_err();
+
+        assert_bad_request_error(err);
+        assert!(c.status_changes.get_changes().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_move_the_post_to_the_next_status() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(&auth(), input("draft", RequestedTransition::Submit))
+            .await
+            .unwrap();
+
+        assert_eq!(output.status, PostStatus::InReview);
+        let stored = c.repo.get_by_id(&"draft".into()).await.unwrap().unwrap();
+        assert_eq!(stored.status, PostStatus::InReview);
+    }
+
+    #[tokio::test]
+    async fn should_schedule_at_a_wall_clock_time_of_the_blog() {
+        let c = create_interactor();
+        let monday_morning = NaiveDate::from_ymd_opt(2026, 10, 19)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+
+        let output = c
+            .interactor
+            .execute(
+                &auth(),
+                input("review", RequestedTransition::Schedule(monday_morning)),
+            )
+            .await
+            .unwrap();
+
+        let in_utc = Utc.with_ymd_and_hms(2026, 10, 19, 5, 30, 0).unwrap();
+        assert_eq!(output.status, PostStatus::Scheduled);
+        assert_eq!(output.publish_at, Some(in_utc));
+        assert_eq!(
+            c.status_changes.get_changes()[0].transition,
+            PostTransition::Schedule(in_utc)
+        );
+
+        let output = c
+            .interactor
+            .execute(&auth(), input("review", RequestedTransition::Unschedule))
+            .await
+            .unwrap();
+        assert_eq!(output.publish_at, None);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_a_time_skipped_by_daylight_saving() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_timezone(Arc::new(BlogTimezone::new(chrono_tz::Europe::Berlin)));
+        let skipped = NaiveDate::from_ymd_opt(2026, 3, 29)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+
+        let err = c
+            .interactor
+            .execute(
+                &auth(),
+                input("review", RequestedTransition::Schedule(skipped)),
+            )
+            .await
+            .unwrap_err();
+
+        assert_validation_error_with_key(err, "publish_at");
+        assert!(c.status_changes.get_changes().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_record_who_performed_the_transition_and_when() {
+        let c = create_interactor();
+
+        c.interactor
+            .execute(&auth(), input("review", RequestedTransition::Reject))
+            .await
+            .unwrap();
+
+        let changes = c.status_changes.get_changes();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].post_id, PostId::new("review"));
+        assert_eq!(changes[0].from, PostStatus::InReview);
+        assert_eq!(changes[0].to, PostStatus::Draft);
+        assert_eq!(changes[0].transition, PostTransition::Reject);
+        assert_eq!(changes[0].performed_by, "editor");
+        assert_eq!(changes[0].performed_at, fixed_now());
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_first_publish_time_when_published_again() {
+        let c = create_interactor();
+        c.interactor
+            .execute(&auth(), input("review", RequestedTransition::Approve))
+            .await
+            .unwrap();
+
+        c.clock.advance(Duration::days(1));
+        for transition in [
+            RequestedTransition::Archive,
+            RequestedTransition::Unarchive,
+            RequestedTransition::Submit,
+            RequestedTransition::Approve,
+        ] {
+            c.interactor
+                .execute(&auth(), input("review", transition))
+                .await
+                .unwrap();
+        }
+
+        let stored = c.repo.get_by_id(&"review".into()).await.unwrap().unwrap();
+        assert_eq!(stored.published_at, Some(fixed_now()));
+    }
+}
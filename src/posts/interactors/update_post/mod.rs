@@ -0,0 +1,241 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationResult;
+use crate::posts::domain::PostId;
+use crate::posts::interactors::actions::UPDATE_POST_ACTION;
+use crate::posts::interactors::traits::{PostRevisionsRepository, PostsRepository};
+use crate::posts::interactors::utils::record_revision;
+use crate::utils::{AuthPayload, Clock, Validatable};
+
+#[derive(Debug, Clone)]
+pub struct UpdatePostInput {
+    pub id: String,
+    pub title: String,
+    pub content: String,
+}
+
+impl Validatable for UpdatePostInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.title.trim().is_empty() {
+            return Err(ValidationError::new(
+                "title".into(),
+                self.title.clone(),
+                "title is empty".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdatePostOutput {
+    /// The revision recorded for this update, `None` when nothing changed.
+    pub revision: Option<u32>,
+}
+
+#[derive(WithDeps)]
+pub struct UpdatePostInteractor {
+    repo: Arc<dyn PostsRepository>,
+    revisions: Arc<dyn PostRevisionsRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl UpdatePostInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: UpdatePostInput,
+    ) -> ApplicationResult<UpdatePostOutput> {
+        auth.can_or_fail(UPDATE_POST_ACTION)?;
+        input.validate()?;
+
+        let original = self.repo.get_by_id_or_fail(&PostId::from(input.id)).await?;
+        if original.title == input.title && original.content == input.content {
+            return Ok(UpdatePostOutput { revision: None });
+        }
+
+        let mut updated = original.clone();
+        updated.title = input.title;
+        updated.content = input.content;
+        self.repo.update(&updated).await?;
+
+        let revision = record_revision(
+            self.revisions.as_ref(),
+            &original,
+            &updated,
+            &auth.get_user_id(),
+            None,
+            self.clock.now(),
+        )
+        .await?;
+        Ok(UpdatePostOutput {
+            revision: Some(revision.number),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::posts::domain::{Post, PostRevision, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_post_revisions_repository::FakePostRevisionsRepository;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::errors_assertion::*;
+
+    use super::*;
+
+    make_interactor_setup!(
+        UpdatePostInteractor,
+        [
+            (
+                repo,
+                FakePostsRepository::new_with_data(&[existing_post()]),
+                FakePostsRepository
+            ),
+            (
+                revisions,
+                FakePostRevisionsRepository::new_empty(),
+                FakePostRevisionsRepository
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock)
+        ]
+    );
+
+    fn existing_post() -> Post {
+        Post {
+            id: PostId::new("post"),
+            title: "first title".to_string(),
+            slug: "first-title".to_string(),
+            content: "first content".to_string(),
+            author_id: "author".to_string(),
+            category_id: None,
+            tag_ids: vec![],
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn valid_input() -> UpdatePostInput {
+        UpdatePostInput {
+            id: "post".into(),
+            title: "second title".into(),
+            content: "second content".into(),
+        }
+    }
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("editor".into())
+    }
+
+    fn snapshot(revision: &PostRevision) -> (u32, &str, &str, &str) {
+        (
+            revision.number,
+            revision.title.as_str(),
+            revision.content.as_str(),
+            revision.author_id.as_str(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("editor".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, valid_input())
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [UPDATE_POST_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_if_title_is_empty() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.title = " ".into();
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "title");
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_post_does_not_exist() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.id = "missing".into();
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_update_the_post() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let post = c.repo.get_by_id(&"post".into()).await.unwrap().unwrap();
+        assert_eq!(post.title, "second title");
+        assert_eq!(post.content, "second content");
+    }
+
+    #[tokio::test]
+    async fn should_snapshot_the_original_before_the_first_revision() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(output.revision, Some(2));
+        let revisions = c.revisions.get_revisions();
+        assert_eq!(
+            revisions.iter().map(snapshot).collect::<Vec<_>>(),
+            [
+                (1, "first title", "first content", "author"),
+                (2, "second title", "second content", "editor"),
+            ]
+        );
+        assert_eq!(revisions[1].created_at, fixed_now());
+    }
+
+    #[tokio::test]
+    async fn should_append_a_revision_for_every_update() {
+        let c = create_interactor();
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let mut input = valid_input();
+        input.content = "third content".into();
+        let output = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(output.revision, Some(3));
+        assert_eq!(c.revisions.get_revisions().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn should_not_record_a_revision_when_nothing_changed() {
+        let c = create_interactor();
+        let input = UpdatePostInput {
+            id: "post".into(),
+            title: existing_post().title,
+            content: existing_post().content,
+        };
+
+        let output = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(output.revision, None);
+        assert!(c.revisions.get_revisions().is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+
+use crate::errors::UnknownResult;
+use crate::posts::domain::{Post, PostRevision};
+use crate::posts::interactors::actions::USE_TRUSTED_HTML_ACTION;
+use crate::posts::interactors::traits::{PostRevisionsRepository, PostsRepository};
+use crate::users::domain::User;
+use crate::utils::{ContentKind, SanitizationPolicy, SlugOwnerKind, SlugOwners};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct VisibleRevision {
+    pub number: u32,
+    pub title: String,
+    pub author_id: String,
+    pub created_at: String,
+    pub restored_from: Option<u32>,
+}
+
+impl From<PostRevision> for VisibleRevision {
+    fn from(revision: PostRevision) -> Self {
+        VisibleRevision {
+            number: revision.number,
+            title: revision.title,
+            author_id: revision.author_id,
+            created_at: revision.created_at.to_rfc2822(),
+            restored_from: revision.restored_from,
+        }
+    }
+}
+
+/// Records `updated` as the next revision of the post.
+///
+/// Posts written before revisions existed have none, so their `original` state is stored
+/// first as revision 1, attributed to the post author, to keep it restorable.
+pub async fn record_revision(
+    revisions: &dyn PostRevisionsRepository,
+    original: &Post,
+    updated: &Post,
+    author_id: &str,
+    restored_from: Option<u32>,
+    created_at: DateTime<Utc>,
+) -> UnknownResult<PostRevision> {
+    let latest = match revisions.get_latest(&original.id).await? {
+        Some(latest) => latest,
+        None => {
+            revisions
+                .create(&PostRevision {
+                    post_id: original.id.clone(),
+                    number: 1,
+                    title: original.title.clone(),
+                    content: original.content.clone(),
+                    author_id: original.author_id.clone(),
+                    created_at: original.created_at,
+                    restored_from: None,
+                })
+                .await?
+        }
+    };
+    revisions
+        .create(&PostRevision {
+            post_id: updated.id.clone(),
+            number: latest.number + 1,
+            title: updated.title.clone(),
+            content: updated.content.clone(),
+            author_id: author_id.into(),
+            created_at,
+            restored_from,
+        })
+        .await
+}
+
+/// How the content of a post by `author` is sanitized; posts of deleted authors are strict.
+pub fn post_sanitization_policy(author: Option<&User>) -> SanitizationPolicy {
+    let trusted = author.is_some_and(|author| author.role.can(USE_TRUSTED_HTML_ACTION));
+    SanitizationPolicy::for_content(ContentKind::Post, trusted)
+}
+
+#[async_trait::async_trait]
+impl SlugOwners for dyn PostsRepository {
+    const KIND: SlugOwnerKind = SlugOwnerKind::Post;
+
+    async fn get_owner_of(&self, slug: &str) -> UnknownResult<Option<String>> {
+        Ok(self
+            .get_by_slug(slug)
+            .await?
+            .map(|post| post.id.to_string()))
+    }
+}
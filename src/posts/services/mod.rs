@@ -0,0 +1,3 @@
+pub use scheduled_publisher::ScheduledPublisher;
+
+mod scheduled_publisher;
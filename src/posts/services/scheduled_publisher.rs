@@ -0,0 +1,447 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::errors::{UnknownException, UnknownResult};
+use crate::posts::domain::{PostId, PostStatus, PostStatusChange, PostTransition};
+use crate::posts::interactors::traits::{PostStatusChangesRepository, PostsRepository};
+use crate::utils::{run_every, Clock};
+
+/// Recorded as the performer of the transitions the publisher makes.
+pub const SCHEDULER_USER_ID: &str = "scheduler";
+
+/// Publishes scheduled posts once their publish time has passed.
+///
+/// Every instance of the app may run one; a post is only published by the instance whose
+/// `publish_if_scheduled_for` wins, so running it concurrently or repeatedly publishes each post once.
+pub struct ScheduledPublisher {
+    posts_repo: Arc<dyn PostsRepository>,
+    status_changes: Arc<dyn PostStatusChangesRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ScheduledPublisher {
+    pub fn new(
+        posts_repo: Arc<dyn PostsRepository>,
+        status_changes: Arc<dyn PostStatusChangesRepository>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            posts_repo,
+            status_changes,
+            clock,
+        }
+    }
+
+    /// Publishes every due post and returns the ids this call published.
+    pub async fn publish_due(&self) -> UnknownResult<Vec<PostId>> {
+        let now = self.clock.now();
+        let mut published = vec![];
+        for post in self.posts_repo.get_scheduled_until(now).await? {
+            let (to, publish_at) =
+                match (post.status.apply(PostTransition::Publish), post.publish_at) {
+                    (Some(to), Some(publish_at)) => (to, publish_at),
+                    _ => continue,
+                };
+            if self
+                .posts_repo
+                .publish_if_scheduled_for(&post.id, publish_at)
+                .await?
+                .is_none()
+            {
+                // another instance published it, or an editor moved or unscheduled it in the meantime
+                continue;
+            }
+            self.status_changes
+                .create(&PostStatusChange {
+                    post_id: post.id.clone(),
+                    from: PostStatus::Scheduled,
+                    to,
+                    transition: PostTransition::Publish,
+                    performed_by: SCHEDULER_USER_ID.into(),
+                    performed_at: now,
+                })
+                .await?;
+            published.push(post.id);
+        }
+        Ok(published)
+    }
+
+    /// Runs `publish_due` with `run_every`.
+    pub async fn run_until(
+        &self,
+        interval: Duration,
+        shutdown: impl Future<Output = ()>,
+        on_error: impl Fn(UnknownException),
+    ) {
+        run_every(interval, shutdown, on_error, || self.publish_due()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+
+    use crate::categories::domain::CategoryId;
+    use crate::posts::domain::Post;
+    use crate::posts::interactors::test_doubles::fake_post_status_changes_repository::FakePostStatusChangesRepository;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::tags::domain::TagId;
+    use crate::test_utils::clock::fake_clock::FakeClock;
+    use crate::utils::DeletionResult;
+
+    use super::*;
+
+    struct CreationResult {
+        publisher: ScheduledPublisher,
+        posts_repo: Arc<FakePostsRepository>,
+        status_changes: Arc<FakePostStatusChangesRepository>,
+        clock: Arc<FakeClock>,
+    }
+
+    fn create_publisher() -> CreationResult {
+        let posts_repo = Arc::new(FakePostsRepository::new_with_data(&posts()));
+        let status_changes = Arc::new(FakePostStatusChangesRepository::new_empty());
+        let clock = Arc::new(FakeClock::new(now()));
+        let publisher =
+            ScheduledPublisher::new(posts_repo.clone(), status_changes.clone(), clock.clone());
+        CreationResult {
+            publisher,
+            posts_repo,
+            status_changes,
+            clock,
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 19, 5, 30, 0).unwrap()
+    }
+
+    fn post(id: &str, status: PostStatus, publish_at: Option<DateTime<Utc>>) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.to_string(),
+            slug: id.to_string(),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            category_id: None,
+            tag_ids: vec![],
+            status,
+            publish_at,
+            published_at: None,
+            created_at: now() - ChronoDuration::days(7),
+        }
+    }
+
+    fn posts() -> Vec<Post> {
+        vec![
+            post(
+                "past",
+                PostStatus::Scheduled,
+                Some(now() - ChronoDuration::hours(1)),
+            ),
+            post("now", PostStatus::Scheduled, Some(now())),
+            post(
+                "future",
+                PostStatus::Scheduled,
+                Some(now() + ChronoDuration::minutes(1)),
+            ),
+            post("draft", PostStatus::Draft, None),
+        ]
+    }
+
+    fn status_of(repo: &FakePostsRepository, id: &str) -> PostStatus {
+        repo.get_posts()
+            .into_iter()
+            .find(|post| post.id == PostId::new(id))
+            .unwrap()
+            .status
+    }
+
+    #[tokio::test]
+    async fn should_publish_posts_whose_publish_time_has_passed() {
+        let c = create_publisher();
+
+        let published = c.publisher.publish_due().await.unwrap();
+
+        assert_eq!(published, [PostId::new("past"), PostId::new("now")]);
+        assert_eq!(status_of(&c.posts_repo, "past"), PostStatus::Published);
+        assert_eq!(status_of(&c.posts_repo, "future"), PostStatus::Scheduled);
+        assert_eq!(status_of(&c.posts_repo, "draft"), PostStatus::Draft);
+        let past = c
+            .posts_repo
+            .get_by_id(&"past".into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(past.published_at, Some(now() - ChronoDuration::hours(1)));
+    }
+
+    #[tokio::test]
+    async fn should_not_publish_a_post_rescheduled_after_it_was_read() {
+        let c = create_publisher();
+        let later = now() + ChronoDuration::days(1);
+        let mut rescheduled = c
+            .posts_repo
+            .get_by_id(&"past".into())
+            .await
+            .unwrap()
+            .unwrap();
+        rescheduled.publish_at = Some(later);
+        rescheduled.title = "Edited".into();
+        let publisher = ScheduledPublisher::new(
+            Arc::new(ReschedulingPostsRepository {
+                inner: c.posts_repo.clone(),
+                rescheduled,
+            }),
+            c.status_changes.clone(),
+            c.clock.clone(),
+        );
+
+        let published = publisher.publish_due().await.unwrap();
+
+        assert_eq!(published, [PostId::new("now")]);
+        let past = c
+            .posts_repo
+            .get_by_id(&"past".into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(past.status, PostStatus::Scheduled);
+        assert_eq!(past.publish_at, Some(later));
+        assert_eq!(past.title, "Edited");
+    }
+
+    #[tokio::test]
+    async fn should_keep_edits_made_after_the_post_was_read() {
+        let c = create_publisher();
+        let mut edited = c
+            .posts_repo
+            .get_by_id(&"past".into())
+            .await
+            .unwrap()
+            .unwrap();
+        edited.title = "Edited".into();
+        let publisher = ScheduledPublisher::new(
+            Arc::new(ReschedulingPostsRepository {
+                inner: c.posts_repo.clone(),
+                rescheduled: edited,
+            }),
+            c.status_changes.clone(),
+            c.clock.clone(),
+        );
+
+        publisher.publish_due().await.unwrap();
+
+        let past = c
+            .posts_repo
+            .get_by_id(&"past".into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(past.status, PostStatus::Published);
+        assert_eq!(past.title, "Edited");
+    }
+
+    #[tokio::test]
+    async fn should_publish_later_posts_once_the_clock_reaches_them() {
+        let c = create_publisher();
+        c.publisher.publish_due().await.unwrap();
+
+        c.clock.advance(ChronoDuration::minutes(1));
+        let published = c.publisher.publish_due().await.unwrap();
+
+        assert_eq!(published, [PostId::new("future")]);
+    }
+
+    #[tokio::test]
+    async fn should_be_idempotent() {
+        let c = create_publisher();
+
+        c.publisher.publish_due().await.unwrap();
+        let published = c.publisher.publish_due().await.unwrap();
+
+        assert!(published.is_empty());
+        assert_eq!(c.status_changes.get_changes().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_transition_as_the_scheduler() {
+        let c = create_publisher();
+
+        c.publisher.publish_due().await.unwrap();
+
+        let change = &c.status_changes.get_changes()[0];
+        assert_eq!(change.from, PostStatus::Scheduled);
+        assert_eq!(change.to, PostStatus::Published);
+        assert_eq!(change.performed_by, SCHEDULER_USER_ID);
+        assert_eq!(change.performed_at, now());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn should_publish_each_post_once_across_concurrent_instances() {
+        let c = create_publisher();
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let publisher = ScheduledPublisher::new(
+                    c.posts_repo.clone(),
+                    c.status_changes.clone(),
+                    c.clock.clone(),
+                );
+                tokio::spawn(async move { publisher.publish_due().await.unwrap() })
+            })
+            .collect();
+
+        let mut published = vec![];
+        for handle in handles {
+            published.extend(handle.await.unwrap());
+        }
+
+        assert_eq!(published.len(), 2);
+        assert_eq!(c.status_changes.get_changes().len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_keep_polling_until_shutdown() {
+        let c = create_publisher();
+        let (stop, stopped) = tokio::sync::oneshot::channel();
+
+        tokio::join!(
+            c.publisher.run_until(
+                Duration::from_secs(10),
+                async { stopped.await.unwrap() },
+                |e| panic!("{}", e),
+            ),
+            async {
+                tokio::time::advance(Duration::from_secs(30)).await;
+                assert_eq!(status_of(&c.posts_repo, "future"), PostStatus::Scheduled);
+                c.clock.advance(ChronoDuration::minutes(1));
+                for _ in 0..3 {
+                    if status_of(&c.posts_repo, "future") == PostStatus::Published {
+                        break;
+                    }
+                    tokio::time::advance(Duration::from_secs(10)).await;
+                }
+                stop.send(()).unwrap();
+            }
+        );
+
+        assert_eq!(status_of(&c.posts_repo, "future"), PostStatus::Published);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_hand_failed_polls_to_the_caller() {
+        let c = create_publisher();
+        let publisher = ScheduledPublisher::new(
+            Arc::new(FailingPostsRepository),
+            c.status_changes.clone(),
+            c.clock.clone(),
+        );
+        let errors = std::sync::Mutex::new(vec![]);
+        let (stop, stopped) = tokio::sync::oneshot::channel();
+
+        tokio::join!(
+            publisher.run_until(
+                Duration::from_secs(10),
+                async { stopped.await.unwrap() },
+                |e| errors.lock().unwrap().push(e.to_string()),
+            ),
+            async {
+                for _ in 0..3 {
+                    tokio::time::advance(Duration::from_secs(10)).await;
+                }
+                stop.send(()).unwrap();
+            }
+        );
+
+        let errors = errors.into_inner().unwrap();
+        assert!(errors.len() >= 2);
+        assert!(errors.iter().all(|e| e == "database is down"));
+    }
+
+    /// Lets an editor save `rescheduled` right after the publisher read the due posts.
+    struct ReschedulingPostsRepository {
+        inner: Arc<FakePostsRepository>,
+        rescheduled: Post,
+    }
+
+    #[async_trait::async_trait]
+    impl PostsRepository for ReschedulingPostsRepository {
+        async fn get_by_id(&self, id: &PostId) -> UnknownResult<Option<Post>> {
+            self.inner.get_by_id(id).await
+        }
+        async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Post>> {
+            self.inner.get_by_slug(slug).await
+        }
+        async fn get_all(&self) -> UnknownResult<Vec<Post>> {
+            self.inner.get_all().await
+        }
+        async fn get_by_category_id(&self, id: &CategoryId) -> UnknownResult<Vec<Post>> {
+            self.inner.get_by_category_id(id).await
+        }
+        async fn get_by_tag_id(&self, id: &TagId) -> UnknownResult<Vec<Post>> {
+            self.inner.get_by_tag_id(id).await
+        }
+        async fn create(&self, post: &Post) -> UnknownResult<Post> {
+            self.inner.create(post).await
+        }
+        async fn update(&self, post: &Post) -> UnknownResult<Post> {
+            self.inner.update(post).await
+        }
+        async fn publish_if_scheduled_for(
+            &self,
+            id: &PostId,
+            publish_at: DateTime<Utc>,
+        ) -> UnknownResult<Option<Post>> {
+            self.inner.publish_if_scheduled_for(id, publish_at).await
+        }
+        async fn get_scheduled_until(&self, time: DateTime<Utc>) -> UnknownResult<Vec<Post>> {
+            let due = self.inner.get_scheduled_until(time).await?;
+            self.inner.update(&self.rescheduled).await?;
+            Ok(due)
+        }
+        async fn delete(&self, id: &PostId) -> UnknownResult<DeletionResult> {
+            self.inner.delete(id).await
+        }
+    }
+
+    struct FailingPostsRepository;
+
+    #[async_trait::async_trait]
+    impl PostsRepository for FailingPostsRepository {
+        async fn get_by_id(&self, _: &PostId) -> UnknownResult<Option<Post>> {
+            Err("database is down".into())
+        }
+        async fn get_by_slug(&self, _: &str) -> UnknownResult<Option<Post>> {
+            Err("database is down".into())
+        }
+        async fn get_all(&self) -> UnknownResult<Vec<Post>> {
+            Err("database is down".into())
+        }
+        async fn get_by_category_id(&self, _: &CategoryId) -> UnknownResult<Vec<Post>> {
+            Err("database is down".into())
+        }
+        async fn get_by_tag_id(&self, _: &TagId) -> UnknownResult<Vec<Post>> {
+            Err("database is down".into())
+        }
+        async fn create(&self, _: &Post) -> UnknownResult<Post> {
+            Err("database is down".into())
+        }
+        async fn update(&self, _: &Post) -> UnknownResult<Post> {
+            Err("database is down".into())
+        }
+        async fn publish_if_scheduled_for(
+            &self,
+            _: &PostId,
+            _: DateTime<Utc>,
+        ) -> UnknownResult<Option<Post>> {
+            Err("database is down".into())
+        }
+        async fn get_scheduled_until(&self, _: DateTime<Utc>) -> UnknownResult<Vec<Post>> {
+            Err("database is down".into())
+        }
+        async fn delete(&self, _: &PostId) -> UnknownResult<DeletionResult> {
+            Err("database is down".into())
+        }
+    }
+}
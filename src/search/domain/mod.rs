@@ -0,0 +1,22 @@
+use crate::categories::domain::CategoryId;
+use crate::posts::domain::PostId;
+use crate::tags::domain::TagId;
+
+/// Narrows a search down; every set criterion has to match.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchFilter {
+    /// Matches posts in any of these categories; empty matches every category.
+    pub category_ids: Vec<CategoryId>,
+    pub tag_id: Option<TagId>,
+    pub author_id: Option<String>,
+    pub published_only: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub post_id: PostId,
+    pub score: f32,
+    pub title: String,
+    /// An HTML fragment of the content with the matched words wrapped in `<b>`.
+    pub snippet: String,
+}
@@ -0,0 +1,2 @@
+pub const SEARCH_ALL_POSTS_ACTION: &str = "SEARCH_ALL_POSTS_ACTION";
+pub const REINDEX_POSTS_ACTION: &str = "REINDEX_POSTS_ACTION";
@@ -0,0 +1,4 @@
+pub mod actions;
+pub mod reindex_posts;
+pub mod search_posts;
+pub mod traits;
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::posts::interactors::traits::PostsRepository;
+use crate::search::interactors::actions::REINDEX_POSTS_ACTION;
+use crate::search::interactors::traits::SearchIndex;
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReindexPostsOutput {
+    pub indexed: usize,
+}
+
+/// Rebuilds the search index from the stored posts, for a new index or one that fell behind.
+#[derive(WithDeps)]
+pub struct ReindexPostsInteractor {
+    posts_repo: Arc<dyn PostsRepository>,
+    index: Arc<dyn SearchIndex>,
+}
+
+impl ReindexPostsInteractor {
+    pub async fn execute(&self, auth: &(dyn AuthPayload)) -> ApplicationResult<ReindexPostsOutput> {
+        auth.can_or_fail(REINDEX_POSTS_ACTION)?;
+
+        let posts = self.posts_repo.get_all().await?;
+        self.index.rebuild(&posts).await?;
+        Ok(ReindexPostsOutput {
+            indexed: posts.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::posts::domain::{Post, PostId, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::search::domain::SearchFilter;
+    use crate::search::services::TantivySearchIndex;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ReindexPostsInteractor,
+        [
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&[post("fresh")]),
+                FakePostsRepository
+            ),
+            (
+                index,
+                TantivySearchIndex::open_in_ram().unwrap(),
+                TantivySearchIndex
+            )
+        ]
+    );
+
+    fn post(id: &str) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.into(),
+            slug: id.into(),
+            content: "".into(),
+            author_id: "author".into(),
+            category_id: None,
+            tag_ids: vec![],
+            status: PostStatus::Published,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    async fn titles(index: &TantivySearchIndex, query: &str) -> Vec<String> {
+        index
+            .search(query, &SearchFilter::default(), 10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|hit| hit.title)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [REINDEX_POSTS_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_replace_the_index_with_the_stored_posts() {
+        let c = create_interactor();
+        c.index.index(&post("stale")).await.unwrap();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c.interactor.execute(&auth).await.unwrap();
+
+        assert_eq!(output, ReindexPostsOutput { indexed: 1 });
+        assert!(titles(&c.index, "stale").await.is_empty());
+        assert_eq!(titles(&c.index, "fresh").await, ["fresh"]);
+    }
+}
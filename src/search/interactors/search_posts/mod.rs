@@ -0,0 +1,381 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::categories::interactors::utils::category_subtree;
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::ApplicationResult;
+use crate::search::domain::{SearchFilter, SearchHit};
+use crate::search::interactors::actions::SEARCH_ALL_POSTS_ACTION;
+use crate::search::interactors::traits::SearchIndex;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::utils::{AuthPayload, Validatable};
+
+pub const MAX_SEARCH_LIMIT: usize = 100;
+/// Paging deeper than this makes the index rank `offset + limit` hits for a single page.
+pub const MAX_SEARCH_OFFSET: usize = 10_000;
+
+#[derive(Debug, Clone)]
+pub struct SearchPostsInput {
+    pub query: String,
+    /// Narrows the search to this category and everything under it.
+    pub category_slug: Option<String>,
+    pub tag_slug: Option<String>,
+    pub author_id: Option<String>,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+impl Validatable for SearchPostsInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.limit == 0 || self.limit > MAX_SEARCH_LIMIT {
+            return Err(ValidationError::new(
+                "limit".into(),
+                self.limit.to_string(),
+                format!("limit must be between 1 and {}", MAX_SEARCH_LIMIT),
+            ));
+        }
+        if self.offset > MAX_SEARCH_OFFSET {
+            return Err(ValidationError::new(
+                "offset".into(),
+                self.offset.to_string(),
+                format!("offset must be at most {}", MAX_SEARCH_OFFSET),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchPostsOutput {
+    /// Best match first.
+    pub hits: Vec<SearchHit>,
+}
+
+#[derive(WithDeps)]
+pub struct SearchPostsInteractor {
+    index: Arc<dyn SearchIndex>,
+    categories_repo: Arc<dyn CategoriesRepository>,
+    tags_repo: Arc<dyn TagsRepository>,
+}
+
+impl SearchPostsInteractor {
+    /// Searches the published posts; open to everyone.
+    pub async fn execute(&self, input: SearchPostsInput) -> ApplicationResult<SearchPostsOutput> {
+        self.search(input, true).await
+    }
+
+    /// Searches every post whatever its status, for editors looking through drafts.
+    pub async fn execute_as_editor(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: SearchPostsInput,
+    ) -> ApplicationResult<SearchPostsOutput> {
+        auth.can_or_fail(SEARCH_ALL_POSTS_ACTION)?;
+        self.search(input, false).await
+    }
+
+    async fn search(
+        &self,
+        input: SearchPostsInput,
+        published_only: bool,
+    ) -> ApplicationResult<SearchPostsOutput> {
+        input.validate()?;
+
+        let filter = self.filter(&input, published_only).await?;
+        if input.query.trim().is_empty() {
+            return Ok(SearchPostsOutput { hits: vec![] });
+        }
+
+        let hits = self
+            .index
+            .search(&input.query, &filter, input.limit, input.offset)
+            .await?;
+        Ok(SearchPostsOutput { hits })
+    }
+
+    async fn filter(
+        &self,
+        input: &SearchPostsInput,
+        published_only: bool,
+    ) -> ApplicationResult<SearchFilter> {
+        let category_ids = match &input.category_slug {
+            Some(slug) => {
+                let category = self.categories_repo.get_by_slug_or_fail(slug).await?;
+                category_subtree(&self.categories_repo.get_all().await?, &category.id)
+            }
+            None => vec![],
+        };
+        let tag_id = match &input.tag_slug {
+            Some(slug) => Some(
+                self.tags_repo
+                    .get_by_slug(slug)
+                    .await?
+                    .ok_or_else(|| NotFoundException(format!("Tag {} not found", slug)))?
+                    .id,
+            ),
+            None => None,
+        };
+        Ok(SearchFilter {
+            category_ids,
+            tag_id,
+            author_id: input.author_id.clone(),
+            published_only,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::categories::domain::{Category, CategoryId};
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::make_interactor_setup;
+    use crate::posts::domain::{Post, PostId, PostStatus};
+    use crate::search::services::TantivySearchIndex;
+    use crate::tags::domain::{Tag, TagId};
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::{
+        assert_forbidden_error, assert_not_found_error, assert_validation_error_with_key,
+    };
+
+    use super::*;
+
+    make_interactor_setup!(
+        SearchPostsInteractor,
+        [
+            (
+                index,
+                TantivySearchIndex::open_in_ram().unwrap(),
+                TantivySearchIndex
+            ),
+            (
+                categories_repo,
+                FakeCategoriesRepository::new_with_data(&[
+                    category("programming", None),
+                    category("rust", Some("programming")),
+                    category("cooking", None),
+                ]),
+                FakeCategoriesRepository
+            ),
+            (
+                tags_repo,
+                FakeTagsRepository::new_with_data(&[tag("async")]),
+                FakeTagsRepository
+            )
+        ]
+    );
+
+    fn category(slug: &str, parent: Option<&str>) -> Category {
+        Category {
+            id: CategoryId::new(slug),
+            name: slug.into(),
+            description: "".into(),
+            created_at: Utc::now(),
+            slug: slug.into(),
+            parent_id: parent.map(CategoryId::new),
+        }
+    }
+
+    fn tag(slug: &str) -> Tag {
+        Tag {
+            id: TagId::new(slug),
+            name: slug.into(),
+            slug: slug.into(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn post(id: &str, category: &str, tags: &[&str], status: PostStatus) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: format!("Testing {}", id),
+            slug: id.into(),
+            content: "Notes about testing code".into(),
+            author_id: "author".into(),
+            category_id: Some(CategoryId::new(category)),
+            tag_ids: tags.iter().map(|t| TagId::new(t)).collect(),
+            status,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    async fn create_indexed_interactor() -> CreationResult {
+        let c = create_interactor();
+        c.index
+            .rebuild(&[
+                post("programming", "programming", &[], PostStatus::Published),
+                post("rust", "rust", &["async"], PostStatus::Published),
+                post("cooking", "cooking", &[], PostStatus::Published),
+                post("draft", "rust", &["async"], PostStatus::Draft),
+            ])
+            .await
+            .unwrap();
+        c
+    }
+
+    fn input(query: &str) -> SearchPostsInput {
+        SearchPostsInput {
+            query: query.into(),
+            category_slug: None,
+            tag_slug: None,
+            author_id: None,
+            limit: 10,
+            offset: 0,
+        }
+    }
+
+    fn ids(output: SearchPostsOutput) -> Vec<String> {
+        let mut ids: Vec<String> = output
+            .hits
+            .into_iter()
+            .map(|hit| hit.post_id.to_string())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test]
+    async fn should_only_search_published_posts() {
+        let c = create_indexed_interactor().await;
+
+        let output = c.interactor.execute(input("tests")).await.unwrap();
+
+        assert_eq!(ids(output), ["cooking", "programming", "rust"]);
+    }
+
+    #[tokio::test]
+    async fn should_filter_by_category_subtree() {
+        let c = create_indexed_interactor().await;
+
+        let output = c
+            .interactor
+            .execute(SearchPostsInput {
+                category_slug: Some("programming".into()),
+                ..input("testing")
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(ids(output), ["programming", "rust"]);
+    }
+
+    #[tokio::test]
+    async fn should_filter_by_tag() {
+        let c = create_indexed_interactor().await;
+
+        let output = c
+            .interactor
+            .execute(SearchPostsInput {
+                tag_slug: Some("async".into()),
+                ..input("testing")
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(ids(output), ["rust"]);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_for_unknown_category_or_tag() {
+        let c = create_indexed_interactor().await;
+
+        for input in [
+            SearchPostsInput {
+                category_slug: Some("missing".into()),
+                ..input("testing")
+            },
+            SearchPostsInput {
+                tag_slug: Some("missing".into()),
+                ..input("testing")
+            },
+        ] {
+            let err = c.interactor.execute(input).await.unwrap_err();
+            assert_not_found_error(err);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_return_nothing_for_a_blank_query() {
+        let c = create_indexed_interactor().await;
+
+        let output = c.interactor.execute(input("  ")).await.unwrap();
+
+        assert!(output.hits.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_out_of_range_limits() {
+        let c = create_indexed_interactor().await;
+
+        for limit in [0, MAX_SEARCH_LIMIT + 1] {
+            let err = c
+                .interactor
+                .execute(SearchPostsInput {
+                    limit,
+                    ..input("testing")
+                })
+                .await
+                .unwrap_err();
+            assert_validation_error_with_key(err, "limit");
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_too_deep_offsets() {
+        let c = create_indexed_interactor().await;
+
+        for offset in [MAX_SEARCH_OFFSET + 1, usize::MAX] {
+            let err = c
+                .interactor
+                .execute(SearchPostsInput {
+                    offset,
+                    ..input("testing")
+                })
+                .await
+                .unwrap_err();
+            assert_validation_error_with_key(err, "offset");
+        }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_editor_search_is_not_allowed() {
+        let c = create_indexed_interactor().await;
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute_as_editor(&auth, input("testing"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [SEARCH_ALL_POSTS_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_include_unpublished_posts_for_editors() {
+        let c = create_indexed_interactor().await;
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c
+            .interactor
+            .execute_as_editor(
+                &auth,
+                SearchPostsInput {
+                    tag_slug: Some("async".into()),
+                    ..input("testing")
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ids(output), ["draft", "rust"]);
+    }
+}
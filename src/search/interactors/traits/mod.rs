@@ -0,0 +1,20 @@
+use crate::errors::UnknownResult;
+use crate::posts::domain::{Post, PostId};
+use crate::search::domain::{SearchFilter, SearchHit};
+
+#[async_trait::async_trait]
+pub trait SearchIndex: Send + Sync {
+    /// Adds the post, replacing the version indexed before.
+    async fn index(&self, post: &Post) -> UnknownResult<()>;
+    async fn remove(&self, id: &PostId) -> UnknownResult<()>;
+    /// Replaces everything in the index with `posts`.
+    async fn rebuild(&self, posts: &[Post]) -> UnknownResult<()>;
+    /// Returns the hits best first, skipping the first `offset`.
+    async fn search(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        limit: usize,
+        offset: usize,
+    ) -> UnknownResult<Vec<SearchHit>>;
+}
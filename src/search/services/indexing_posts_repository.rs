@@ -0,0 +1,342 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::categories::domain::CategoryId;
+use crate::errors::{UnknownException, UnknownResult};
+use crate::posts::domain::{Post, PostId};
+use crate::posts::interactors::traits::PostsRepository;
+use crate::search::interactors::traits::SearchIndex;
+use crate::tags::domain::TagId;
+use crate::utils::{run_every, DeletionResult};
+
+/// Keeps the search index in step with the posts by indexing every write that goes through
+/// this repository.
+///
+/// Wiring it in place of the plain repository covers every interactor and service that
+/// writes posts. The index is written after the repository and a failed index write does not
+/// fail the stored one: the post is remembered and indexed again by `retry_pending`.
+pub struct IndexingPostsRepository {
+    inner: Arc<dyn PostsRepository>,
+    index: Arc<dyn SearchIndex>,
+    /// Posts whose last index write failed.
+    pending: Mutex<HashSet<PostId>>,
+}
+
+impl IndexingPostsRepository {
+    pub fn new(inner: Arc<dyn PostsRepository>, index: Arc<dyn SearchIndex>) -> Self {
+        Self {
+            inner,
+            index,
+            pending: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Indexes the stored version of every post whose index write failed, removing the ones
+    /// deleted since. Those that fail again stay pending.
+    pub async fn retry_pending(&self) -> UnknownResult<()> {
+        let pending: Vec<PostId> = self.pending.lock().unwrap().iter().cloned().collect();
+        for id in pending {
+            match self.inner.get_by_id(&id).await? {
+                Some(post) => self.index.index(&post).await?,
+                None => self.index.remove(&id).await?,
+            }
+            self.pending.lock().unwrap().remove(&id);
+        }
+        Ok(())
+    }
+
+    /// Runs `retry_pending` with `run_every`.
+    pub async fn run_until(
+        &self,
+        interval: Duration,
+        shutdown: impl Future<Output = ()>,
+        on_error: impl Fn(UnknownException),
+    ) {
+        run_every(interval, shutdown, on_error, || self.retry_pending()).await
+    }
+
+    async fn index(&self, post: &Post) {
+        let indexed = self.index.index(post).await;
+        self.settle(&post.id, indexed);
+    }
+
+    async fn remove(&self, id: &PostId) {
+        let removed = self.index.remove(id).await;
+        self.settle(id, removed);
+    }
+
+    fn settle(&self, id: &PostId, result: UnknownResult<()>) {
+        let mut pending = self.pending.lock().unwrap();
+        match result {
+            Ok(()) => pending.remove(id),
+            Err(_) => pending.insert(id.clone()),
+        };
+    }
+}
+
+#[async_trait::async_trait]
+impl PostsRepository for IndexingPostsRepository {
+    async fn get_by_id(&self, id: &PostId) -> UnknownResult<Option<Post>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Post>> {
+        self.inner.get_by_slug(slug).await
+    }
+
+    async fn get_all(&self) -> UnknownResult<Vec<Post>> {
+        self.inner.get_all().await
+    }
+
+    async fn get_by_category_id(&self, id: &CategoryId) -> UnknownResult<Vec<Post>> {
+        self.inner.get_by_category_id(id).await
+    }
+
+    async fn get_by_tag_id(&self, id: &TagId) -> UnknownResult<Vec<Post>> {
+        self.inner.get_by_tag_id(id).await
+    }
+
+    async fn create(&self, post: &Post) -> UnknownResult<Post> {
+        let created = self.inner.create(post).await?;
+        self.index(&created).await;
+        Ok(created)
+    }
+
+    async fn update(&self, post: &Post) -> UnknownResult<Post> {
+        let updated = self.inner.update(post).await?;
+        self.index(&updated).await;
+        Ok(updated)
+    }
+
+    async fn publish_if_scheduled_for(
+        &self,
+        id: &PostId,
+        publish_at: DateTime<Utc>,
+    ) -> UnknownResult<Option<Post>> {
+        let published = self.inner.publish_if_scheduled_for(id, publish_at).await?;
+        if let Some(post) = &published {
+            self.index(post).await;
+        }
+        Ok(published)
+    }
+
+    async fn get_scheduled_until(&self, time: DateTime<Utc>) -> UnknownResult<Vec<Post>> {
+        self.inner.get_scheduled_until(time).await
+    }
+
+    async fn delete(&self, id: &PostId) -> UnknownResult<DeletionResult> {
+        let result = self.inner.delete(id).await?;
+        if result == DeletionResult::Deleted {
+            self.remove(id).await;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::posts::domain::PostStatus;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::search::domain::{SearchFilter, SearchHit};
+    use crate::search::services::TantivySearchIndex;
+
+    use super::*;
+
+    /// Fails every write while `down` is set.
+    struct FlakySearchIndex {
+        inner: TantivySearchIndex,
+        down: AtomicBool,
+    }
+
+    impl FlakySearchIndex {
+        fn check(&self) -> UnknownResult<()> {
+            if self.down.load(Ordering::SeqCst) {
+                return Err("the index is down".into());
+            }
+            Ok(())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SearchIndex for FlakySearchIndex {
+        async fn index(&self, post: &Post) -> UnknownResult<()> {
+            self.check()?;
+            self.inner.index(post).await
+        }
+
+        async fn remove(&self, id: &PostId) -> UnknownResult<()> {
+            self.check()?;
+            self.inner.remove(id).await
+        }
+
+        async fn rebuild(&self, posts: &[Post]) -> UnknownResult<()> {
+            self.check()?;
+            self.inner.rebuild(posts).await
+        }
+
+        async fn search(
+            &self,
+            query: &str,
+            filter: &SearchFilter,
+            limit: usize,
+            offset: usize,
+        ) -> UnknownResult<Vec<SearchHit>> {
+            self.inner.search(query, filter, limit, offset).await
+        }
+    }
+
+    struct CreationResult {
+        repo: IndexingPostsRepository,
+        index: Arc<TantivySearchIndex>,
+    }
+
+    fn create_repo() -> CreationResult {
+        let index = Arc::new(TantivySearchIndex::open_in_ram().unwrap());
+        let repo =
+            IndexingPostsRepository::new(Arc::new(FakePostsRepository::new_empty()), index.clone());
+        CreationResult { repo, index }
+    }
+
+    fn post(title: &str, status: PostStatus) -> Post {
+        Post {
+            id: PostId::new("post"),
+            title: title.into(),
+            slug: "post".into(),
+            content: "".into(),
+            author_id: "author".into(),
+            category_id: None,
+            tag_ids: vec![],
+            status,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    async fn titles(index: &TantivySearchIndex, query: &str) -> Vec<String> {
+        index
+            .search(query, &SearchFilter::default(), 10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|hit| hit.title)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_index_created_and_updated_posts() {
+        let c = create_repo();
+
+        c.repo
+            .create(&post("First draft", PostStatus::Draft))
+            .await
+            .unwrap();
+        c.repo
+            .update(&post("Final words", PostStatus::Draft))
+            .await
+            .unwrap();
+
+        assert!(titles(&c.index, "draft").await.is_empty());
+        assert_eq!(titles(&c.index, "words").await, ["Final words"]);
+    }
+
+    #[tokio::test]
+    async fn should_only_index_conditional_publishes_that_were_applied() {
+        let c = create_repo();
+        let mut scheduled = post("Scheduled", PostStatus::Scheduled);
+        scheduled.publish_at = Some(Utc::now());
+        c.repo.create(&scheduled).await.unwrap();
+
+        let published = c
+            .repo
+            .publish_if_scheduled_for(&scheduled.id, Utc::now() + chrono::Duration::hours(1))
+            .await
+            .unwrap();
+
+        assert!(published.is_none());
+        let published_only = SearchFilter {
+            published_only: true,
+            ..SearchFilter::default()
+        };
+        let hits = c
+            .index
+            .search("scheduled", &published_only, 10, 0)
+            .await
+            .unwrap();
+        assert!(hits.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_remove_deleted_posts() {
+        let c = create_repo();
+        c.repo
+            .create(&post("Guide", PostStatus::Draft))
+            .await
+            .unwrap();
+
+        c.repo.delete(&PostId::new("post")).await.unwrap();
+
+        assert!(titles(&c.index, "guide").await.is_empty());
+    }
+
+    fn create_flaky_repo() -> (IndexingPostsRepository, Arc<FlakySearchIndex>) {
+        let index = Arc::new(FlakySearchIndex {
+            inner: TantivySearchIndex::open_in_ram().unwrap(),
+            down: AtomicBool::new(true),
+        });
+        let repo =
+            IndexingPostsRepository::new(Arc::new(FakePostsRepository::new_empty()), index.clone());
+        (repo, index)
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_write_and_index_it_later_when_the_index_fails() {
+        let (repo, index) = create_flaky_repo();
+
+        let created = repo.create(&post("Guide", PostStatus::Draft)).await;
+
+        assert!(created.is_ok());
+        assert!(repo
+            .get_by_id(&PostId::new("post"))
+            .await
+            .unwrap()
+            .is_some());
+        assert!(repo.retry_pending().await.is_err());
+
+        index.down.store(false, Ordering::SeqCst);
+        repo.retry_pending().await.unwrap();
+
+        let hits = index
+            .search("guide", &SearchFilter::default(), 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert!(repo.pending.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_remove_posts_deleted_while_pending() {
+        let (repo, index) = create_flaky_repo();
+        index.down.store(false, Ordering::SeqCst);
+        repo.create(&post("Guide", PostStatus::Draft))
+            .await
+            .unwrap();
+        index.down.store(true, Ordering::SeqCst);
+
+        repo.delete(&PostId::new("post")).await.unwrap();
+        index.down.store(false, Ordering::SeqCst);
+        repo.retry_pending().await.unwrap();
+
+        let hits = index
+            .search("guide", &SearchFilter::default(), 10, 0)
+            .await
+            .unwrap();
+        assert!(hits.is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+pub use indexing_posts_repository::IndexingPostsRepository;
+pub use tantivy_search_index::TantivySearchIndex;
+
+mod indexing_posts_repository;
+mod tantivy_search_index;
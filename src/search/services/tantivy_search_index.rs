@@ -0,0 +1,468 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use tantivy::collector::TopDocs;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{BooleanQuery, Occur, Query, QueryParser, TermQuery};
+use tantivy::schema::{
+    Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, Value, STORED, STRING,
+};
+use tantivy::snippet::SnippetGenerator;
+use tantivy::{Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term};
+
+use crate::errors::UnknownResult;
+use crate::posts::domain::{Post, PostId, PostStatus};
+use crate::search::domain::{SearchFilter, SearchHit};
+use crate::search::interactors::traits::SearchIndex;
+use crate::utils::{escape_xml, markdown_to_text};
+
+const WRITER_MEMORY_BYTES: usize = 50_000_000;
+const SNIPPET_MAX_CHARS: usize = 200;
+const TITLE_BOOST: f32 = 2.0;
+
+/// A search index embedded with tantivy, either in memory or in a directory.
+///
+/// Titles and the plain text of the content are stemmed for English, so "running" finds
+/// "runs". Every change is committed right away, which keeps the index in step with the
+/// repository at the cost of write throughput; commits run on a blocking thread.
+pub struct TantivySearchIndex {
+    index: Index,
+    reader: IndexReader,
+    writer: Arc<Mutex<IndexWriter>>,
+    fields: Fields,
+}
+
+#[derive(Clone, Copy)]
+struct Fields {
+    id: Field,
+    title: Field,
+    content: Field,
+    category_id: Field,
+    tag_id: Field,
+    author_id: Field,
+    published: Field,
+}
+
+impl TantivySearchIndex {
+    pub fn open_in_ram() -> UnknownResult<Self> {
+        let (schema, fields) = Self::schema();
+        Self::open(Index::create_in_ram(schema), fields)
+    }
+
+    /// Opens the index stored in `path`, creating it when the directory is empty.
+    pub fn open_in_dir(path: &Path) -> UnknownResult<Self> {
+        let (schema, fields) = Self::schema();
+        Self::open(
+            Index::open_or_create(MmapDirectory::open(path)?, schema)?,
+            fields,
+        )
+    }
+
+    fn open(index: Index, fields: Fields) -> UnknownResult<Self> {
+        let writer = index.writer_with_num_threads(1, WRITER_MEMORY_BYTES)?;
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()?;
+        Ok(Self {
+            index,
+            reader,
+            writer: Arc::new(Mutex::new(writer)),
+            fields,
+        })
+    }
+
+    fn schema() -> (Schema, Fields) {
+        let text = TextOptions::default()
+            .set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer("en_stem")
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            )
+            .set_stored();
+        let mut builder = Schema::builder();
+        let fields = Fields {
+            id: builder.add_text_field("id", STRING | STORED),
+            title: builder.add_text_field("title", text.clone()),
+            content: builder.add_text_field("content", text),
+            category_id: builder.add_text_field("category_id", STRING),
+            tag_id: builder.add_text_field("tag_id", STRING),
+            author_id: builder.add_text_field("author_id", STRING),
+            published: builder.add_bool_field("published", tantivy::schema::INDEXED),
+        };
+        (builder.build(), fields)
+    }
+
+    fn document(&self, post: &Post) -> TantivyDocument {
+        let f = self.fields;
+        let mut doc = TantivyDocument::default();
+        doc.add_text(f.id, post.id.to_string());
+        doc.add_text(f.title, &post.title);
+        doc.add_text(f.content, markdown_to_text(&post.content));
+        if let Some(category_id) = &post.category_id {
+            doc.add_text(f.category_id, category_id.to_string());
+        }
+        for tag_id in &post.tag_ids {
+            doc.add_text(f.tag_id, tag_id.to_string());
+        }
+        doc.add_text(f.author_id, &post.author_id);
+        doc.add_bool(f.published, post.status == PostStatus::Published);
+        doc
+    }
+
+    fn id_term(&self, id: &PostId) -> Term {
+        Term::from_field_text(self.fields.id, &id.to_string())
+    }
+
+    async fn write(
+        &self,
+        change: impl FnOnce(&mut IndexWriter) -> UnknownResult<()> + Send + 'static,
+    ) -> UnknownResult<()> {
+        let writer = self.writer.clone();
+        let reader = self.reader.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut writer = writer.lock().unwrap();
+            change(&mut writer)?;
+            writer.commit()?;
+            reader.reload()?;
+            Ok(())
+        })
+        .await?
+    }
+
+    fn filter_clauses(&self, filter: &SearchFilter) -> Vec<(Occur, Box<dyn Query>)> {
+        let f = self.fields;
+        let term = |field: Field, value: &str| -> Box<dyn Query> {
+            Box::new(TermQuery::new(
+                Term::from_field_text(field, value),
+                IndexRecordOption::Basic,
+            ))
+        };
+        let mut clauses = vec![];
+        if !filter.category_ids.is_empty() {
+            let any_category = filter
+                .category_ids
+                .iter()
+                .map(|id| (Occur::Should, term(f.category_id, &id.to_string())))
+                .collect();
+            clauses.push((
+                Occur::Must,
+                Box::new(BooleanQuery::new(any_category)) as Box<dyn Query>,
+            ));
+        }
+        if let Some(tag_id) = &filter.tag_id {
+            clauses.push((Occur::Must, term(f.tag_id, &tag_id.to_string())));
+        }
+        if let Some(author_id) = &filter.author_id {
+            clauses.push((Occur::Must, term(f.author_id, author_id)));
+        }
+        if filter.published_only {
+            clauses.push((
+                Occur::Must,
+                Box::new(TermQuery::new(
+                    Term::from_field_bool(f.published, true),
+                    IndexRecordOption::Basic,
+                )),
+            ));
+        }
+        clauses
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchIndex for TantivySearchIndex {
+    async fn index(&self, post: &Post) -> UnknownResult<()> {
+        let (term, doc) = (self.id_term(&post.id), self.document(post));
+        self.write(move |writer| {
+            writer.delete_term(term);
+            writer.add_document(doc)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn remove(&self, id: &PostId) -> UnknownResult<()> {
+        let term = self.id_term(id);
+        self.write(move |writer| {
+            writer.delete_term(term);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn rebuild(&self, posts: &[Post]) -> UnknownResult<()> {
+        let docs: Vec<_> = posts.iter().map(|post| self.document(post)).collect();
+        self.write(move |writer| {
+            writer.delete_all_documents()?;
+            for doc in docs {
+                writer.add_document(doc)?;
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        limit: usize,
+        offset: usize,
+    ) -> UnknownResult<Vec<SearchHit>> {
+        let f = self.fields;
+        let mut parser = QueryParser::for_index(&self.index, vec![f.title, f.content]);
+        parser.set_conjunction_by_default();
+        parser.set_field_boost(f.title, TITLE_BOOST);
+        // user input: a stray quote or colon should not fail the search
+        let (text_query, _) = parser.parse_query_lenient(query);
+
+        let searcher = self.reader.searcher();
+        let mut snippets = SnippetGenerator::create(&searcher, &*text_query, f.content)?;
+        snippets.set_max_num_chars(SNIPPET_MAX_CHARS);
+
+        let mut clauses = vec![(Occur::Must, text_query)];
+        clauses.extend(self.filter_clauses(filter));
+        let top = searcher.search(
+            &BooleanQuery::new(clauses),
+            &TopDocs::with_limit(limit.max(1)).and_offset(offset),
+        )?;
+
+        let mut hits = vec![];
+        for (score, address) in top.into_iter().take(limit) {
+            let doc: TantivyDocument = searcher.doc(address)?;
+            let text = |field: Field| {
+                doc.get_first(field)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let snippet = snippets.snippet_from_doc(&doc);
+            let snippet = if snippet.fragment().is_empty() {
+                // only the title matched
+                escape_xml(
+                    &text(f.content)
+                        .chars()
+                        .take(SNIPPET_MAX_CHARS)
+                        .collect::<String>(),
+                )
+            } else {
+                snippet.to_html()
+            };
+            hits.push(SearchHit {
+                post_id: PostId::from(text(f.id)),
+                score,
+                title: text(f.title),
+                snippet,
+            });
+        }
+        Ok(hits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::categories::domain::CategoryId;
+    use crate::tags::domain::TagId;
+
+    use super::*;
+
+    fn post(id: &str, title: &str, content: &str) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: title.into(),
+            slug: id.into(),
+            content: content.into(),
+            author_id: "author".into(),
+            category_id: None,
+            tag_ids: vec![],
+            status: PostStatus::Published,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    async fn index_with(posts: &[Post]) -> TantivySearchIndex {
+        let index = TantivySearchIndex::open_in_ram().unwrap();
+        for post in posts {
+            index.index(post).await.unwrap();
+        }
+        index
+    }
+
+    async fn ids(index: &TantivySearchIndex, query: &str, filter: &SearchFilter) -> Vec<String> {
+        index
+            .search(query, filter, 10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|hit| hit.post_id.to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_match_stemmed_words() {
+        let index = index_with(&[
+            post("1", "Cooking", "She runs every morning."),
+            post("2", "Walking", "I walked."),
+        ])
+        .await;
+
+        assert_eq!(
+            ids(&index, "running", &SearchFilter::default()).await,
+            ["1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_rank_title_matches_first() {
+        let index = index_with(&[
+            post("body", "Other", "A long text that mentions tantivy once."),
+            post("title", "Tantivy", "Nothing else here."),
+        ])
+        .await;
+
+        assert_eq!(
+            ids(&index, "tantivy", &SearchFilter::default()).await,
+            ["title", "body"]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_highlight_the_matches_in_the_snippet() {
+        let index = index_with(&[post("1", "Post", "Rust **borrowing** rules & more")]).await;
+
+        let hits = index
+            .search("borrow", &SearchFilter::default(), 10, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(hits[0].snippet, "Rust <b>borrowing</b> rules &amp; more");
+    }
+
+    #[tokio::test]
+    async fn should_fall_back_to_the_start_of_the_content_for_title_matches() {
+        let index = index_with(&[post("1", "Tantivy", "Plain & simple")]).await;
+
+        let hits = index
+            .search("tantivy", &SearchFilter::default(), 10, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(hits[0].snippet, "Plain &amp; simple");
+    }
+
+    #[tokio::test]
+    async fn should_replace_and_remove_posts() {
+        let index = index_with(&[post("1", "Old title", "")]).await;
+
+        index.index(&post("1", "New title", "")).await.unwrap();
+        assert_eq!(ids(&index, "title", &SearchFilter::default()).await, ["1"]);
+        assert!(ids(&index, "old", &SearchFilter::default())
+            .await
+            .is_empty());
+
+        index.remove(&PostId::new("1")).await.unwrap();
+        assert!(ids(&index, "title", &SearchFilter::default())
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_apply_every_filter() {
+        let mut in_rust = post("rust", "Guide", "");
+        in_rust.category_id = Some(CategoryId::new("rust"));
+        in_rust.tag_ids = vec![TagId::new("async")];
+        let mut in_go = post("go", "Guide", "");
+        in_go.category_id = Some(CategoryId::new("go"));
+        in_go.author_id = "other".into();
+        let mut draft = post("draft", "Guide", "");
+        draft.status = PostStatus::Draft;
+        let index = index_with(&[in_rust, in_go, draft]).await;
+
+        let filter = |f: fn(&mut SearchFilter)| {
+            let mut filter = SearchFilter::default();
+            f(&mut filter);
+            filter
+        };
+        assert_eq!(
+            ids(
+                &index,
+                "guide",
+                &filter(|f| f.category_ids = vec![CategoryId::new("rust"), CategoryId::new("go")])
+            )
+            .await
+            .len(),
+            2
+        );
+        assert_eq!(
+            ids(
+                &index,
+                "guide",
+                &filter(|f| f.tag_id = Some(TagId::new("async")))
+            )
+            .await,
+            ["rust"]
+        );
+        assert_eq!(
+            ids(
+                &index,
+                "guide",
+                &filter(|f| f.author_id = Some("other".into()))
+            )
+            .await,
+            ["go"]
+        );
+        assert_eq!(
+            ids(&index, "guide", &filter(|f| f.published_only = true))
+                .await
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn should_tolerate_malformed_queries_and_paginate() {
+        let index = index_with(&[post("1", "Guide", ""), post("2", "Guide", "")]).await;
+
+        let first = index
+            .search("guide \"", &SearchFilter::default(), 1, 0)
+            .await
+            .unwrap();
+        let second = index
+            .search("guide", &SearchFilter::default(), 1, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+        assert_ne!(first[0].post_id, second[0].post_id);
+    }
+
+    #[tokio::test]
+    async fn should_rebuild_from_scratch() {
+        let index = index_with(&[post("1", "Guide", "")]).await;
+
+        index.rebuild(&[post("2", "Guide", "")]).await.unwrap();
+
+        assert_eq!(ids(&index, "guide", &SearchFilter::default()).await, ["2"]);
+    }
+
+    #[tokio::test]
+    async fn should_persist_in_a_directory() {
+        let dir = std::env::temp_dir().join(format!("search-index-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        {
+            let index = TantivySearchIndex::open_in_dir(&dir).unwrap();
+            index.rebuild(&[post("1", "Guide", "")]).await.unwrap();
+        }
+
+        let reopened = TantivySearchIndex::open_in_dir(&dir).unwrap();
+        let found = ids(&reopened, "guide", &SearchFilter::default()).await;
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found, ["1"]);
+    }
+}
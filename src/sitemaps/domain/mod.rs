@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+
+/// The sitemaps protocol caps a single sitemap at this many URLs.
+pub const MAX_SITEMAP_URLS: usize = 50_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapUrl {
+    pub loc: String,
+    pub lastmod: Option<DateTime<Utc>>,
+}
+
+/// The rules robots.txt gives to a set of crawlers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RobotsGroup {
+    pub user_agents: Vec<String>,
+    pub allow: Vec<String>,
+    pub disallow: Vec<String>,
+}
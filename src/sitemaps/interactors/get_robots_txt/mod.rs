@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::sitemaps::interactors::utils::{RobotsConfig, SitemapConfig};
+use crate::sitemaps::services::write_robots_txt;
+
+#[derive(WithDeps)]
+pub struct GetRobotsTxtInteractor {
+    config: Arc<RobotsConfig>,
+    sitemap_config: Arc<SitemapConfig>,
+}
+
+impl GetRobotsTxtInteractor {
+    pub fn execute(&self) -> String {
+        write_robots_txt(&self.config.groups, &self.sitemap_config.sitemap_url(None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::make_interactor_setup;
+    use crate::sitemaps::domain::RobotsGroup;
+    use crate::utils::SiteUrls;
+
+    use super::*;
+
+    make_interactor_setup!(
+        GetRobotsTxtInteractor,
+        [
+            (
+                config,
+                RobotsConfig {
+                    groups: vec![RobotsGroup {
+                        user_agents: vec!["*".into()],
+                        allow: vec![],
+                        disallow: vec!["/admin".into()],
+                    }],
+                },
+                RobotsConfig
+            ),
+            (
+                sitemap_config,
+                SitemapConfig::new(SiteUrls::new("https://blog.example.com")),
+                SitemapConfig
+            )
+        ]
+    );
+
+    #[test]
+    fn should_serve_the_configured_rules_and_point_to_the_sitemap() {
+        let c = create_interactor();
+
+        assert_eq!(
+            c.interactor.execute(),
+            "User-agent: *\nDisallow: /admin\n\nSitemap: https://blog.example.com/sitemap.xml\n"
+        );
+    }
+
+    #[test]
+    fn should_allow_everything_by_default() {
+        let interactor = GetRobotsTxtInteractor::new(
+            Arc::new(RobotsConfig::default()),
+            create_interactor().sitemap_config,
+        );
+
+        assert!(interactor
+            .execute()
+            .starts_with("User-agent: *\nDisallow:\n\n"));
+    }
+}
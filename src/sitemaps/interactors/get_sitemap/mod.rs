@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use with_deps_proc_macro::WithDeps;
+
+use crate::categories::domain::CategoryId;
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::ApplicationResult;
+use crate::posts::domain::PostStatus;
+use crate::posts::interactors::traits::PostsRepository;
+use crate::sitemaps::domain::SitemapUrl;
+use crate::sitemaps::interactors::utils::SitemapConfig;
+use crate::sitemaps::services::{write_sitemap_index, write_urlset};
+
+pub const SITEMAP_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+#[derive(Debug, Clone)]
+pub struct GetSitemapInput {
+    /// `None` for `/sitemap.xml`, the 1-based part number for `/sitemap-<part>.xml`.
+    pub part: Option<usize>,
+}
+
+/// Serves the sitemap of the home page, every category and every published post.
+///
+/// Once there are more URLs than fit in one sitemap, `/sitemap.xml` becomes an index of parts.
+/// The home page comes first, then the categories by slug, then the posts oldest first. Publishing
+/// a post therefore usually only changes the last part, while adding a category shifts every URL
+/// after it into the next part.
+#[derive(WithDeps)]
+pub struct GetSitemapInteractor {
+    categories_repo: Arc<dyn CategoriesRepository>,
+    posts_repo: Arc<dyn PostsRepository>,
+    config: Arc<SitemapConfig>,
+}
+
+impl GetSitemapInteractor {
+    pub async fn execute(&self, input: GetSitemapInput) -> ApplicationResult<String> {
+        let urls = self.collect_urls().await?;
+        let parts: Vec<&[SitemapUrl]> = urls
+            .chunks(self.config.max_urls_per_sitemap.max(1))
+            .collect();
+
+        match input.part {
+            None if parts.len() <= 1 => Ok(write_urlset(&urls)),
+            None => Ok(write_sitemap_index(&self.index(&parts))),
+            Some(part) if parts.len() > 1 && (1..=parts.len()).contains(&part) => {
+                Ok(write_urlset(parts[part - 1]))
+            }
+            Some(part) => Err(NotFoundException(format!(
+                "Sitemap part {} not found",
+                part
+            ))),
+        }
+    }
+
+    async fn collect_urls(&self) -> ApplicationResult<Vec<SitemapUrl>> {
+        let mut posts: Vec<_> = self
+            .posts_repo
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|post| post.status == PostStatus::Published)
+            .collect();
+        posts.sort_by_key(|post| (post.published_at, post.created_at));
+
+        let mut latest_post_of: HashMap<&CategoryId, DateTime<Utc>> = HashMap::new();
+        for post in &posts {
+            if let (Some(category_id), Some(published_at)) = (&post.category_id, post.published_at)
+            {
+                latest_post_of.insert(category_id, published_at);
+            }
+        }
+
+        let mut categories = self.categories_repo.get_all().await?;
+        categories.sort_by(|a, b| a.slug.cmp(&b.slug));
+
+        let mut urls = vec![SitemapUrl {
+            loc: self.config.urls.home(),
+            lastmod: posts.iter().filter_map(|post| post.published_at).max(),
+        }];
+        urls.extend(categories.iter().map(|category| {
+            SitemapUrl {
+                loc: self.config.urls.category(&category.slug),
+                lastmod: Some(
+                    latest_post_of
+                        .get(&category.id)
+                        .map_or(category.created_at, |&latest| {
+                            latest.max(category.created_at)
+                        }),
+                ),
+            }
+        }));
+        urls.extend(posts.iter().map(|post| SitemapUrl {
+            loc: self.config.urls.post(&post.slug),
+            lastmod: post.published_at,
+        }));
+        Ok(urls)
+    }
+
+    fn index(&self, parts: &[&[SitemapUrl]]) -> Vec<SitemapUrl> {
+        parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| SitemapUrl {
+                loc: self.config.sitemap_url(Some(i + 1)),
+                lastmod: part.iter().filter_map(|url| url.lastmod).max(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use roxmltree::Document;
+
+    use crate::categories::domain::Category;
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::make_interactor_setup;
+    use crate::posts::domain::{Post, PostId};
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::test_utils::clock::fake_clock::fixed_now;
+    use crate::test_utils::errors_assertion::assert_not_found_error;
+    use crate::utils::SiteUrls;
+
+    use super::*;
+
+    make_interactor_setup!(
+        GetSitemapInteractor,
+        [
+            (
+                categories_repo,
+                FakeCategoriesRepository::new_with_data(&[category("rust"), category("go")]),
+                FakeCategoriesRepository
+            ),
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&posts()),
+                FakePostsRepository
+            ),
+            (config, config(2), SitemapConfig)
+        ]
+    );
+
+    fn config(max_urls_per_sitemap: usize) -> SitemapConfig {
+        SitemapConfig {
+            urls: SiteUrls::new("https://blog.example.com"),
+            max_urls_per_sitemap,
+        }
+    }
+
+    fn category(slug: &str) -> Category {
+        Category {
+            id: CategoryId::new(slug),
+            name: slug.into(),
+            description: "".into(),
+            created_at: fixed_now() - Duration::days(10),
+            slug: slug.into(),
+            parent_id: None,
+        }
+    }
+
+    fn post(slug: &str, status: PostStatus, days_ago: i64) -> Post {
+        Post {
+            id: PostId::new(slug),
+            title: slug.into(),
+            slug: slug.into(),
+            content: "".into(),
+            author_id: "author".into(),
+            category_id: Some(CategoryId::new("rust")),
+            tag_ids: vec![],
+            status,
+            publish_at: None,
+            published_at: (status == PostStatus::Published)
+                .then(|| fixed_now() - Duration::days(days_ago)),
+            created_at: fixed_now() - Duration::days(20),
+        }
+    }
+
+    fn posts() -> Vec<Post> {
+        vec![
+            post("new", PostStatus::Published, 1),
+            post("old", PostStatus::Published, 5),
+            post("draft", PostStatus::Draft, 0),
+        ]
+    }
+
+    fn urls(xml: &str) -> Vec<(String, String)> {
+        let doc = Document::parse(xml).unwrap();
+        doc.root_element()
+            .children()
+            .filter(|n| n.is_element())
+            .map(|n| {
+                let text = |name: &str| {
+                    n.children()
+                        .find(|c| c.tag_name().name() == name)
+                        .and_then(|c| c.text())
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                (text("loc"), text("lastmod"))
+            })
+            .collect()
+    }
+
+    fn days_ago(days: i64) -> String {
+        (fixed_now() - Duration::days(days)).to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    }
+
+    #[tokio::test]
+    async fn should_list_the_home_page_categories_and_published_posts() {
+        let c = create_interactor();
+        let interactor =
+            GetSitemapInteractor::new(c.categories_repo, c.posts_repo, Arc::new(config(100)));
+
+        let xml = interactor
+            .execute(GetSitemapInput { part: None })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            urls(&xml),
+            [
+                ("https://blog.example.com".to_string(), days_ago(1)),
+                (
+                    "https://blog.example.com/categories/go".to_string(),
+                    days_ago(10)
+                ),
+                (
+                    "https://blog.example.com/categories/rust".to_string(),
+                    days_ago(1)
+                ),
+                (
+                    "https://blog.example.com/posts/old".to_string(),
+                    days_ago(5)
+                ),
+                (
+                    "https://blog.example.com/posts/new".to_string(),
+                    days_ago(1)
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_split_into_an_index_past_the_url_limit() {
+        let c = create_interactor();
+
+        let index = c
+            .interactor
+            .execute(GetSitemapInput { part: None })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Document::parse(&index)
+                .unwrap()
+                .root_element()
+                .tag_name()
+                .name(),
+            "sitemapindex"
+        );
+        assert_eq!(
+            urls(&index),
+            [
+                (
+                    "https://blog.example.com/sitemap-1.xml".to_string(),
+                    days_ago(1)
+                ),
+                (
+                    "https://blog.example.com/sitemap-2.xml".to_string(),
+                    days_ago(1)
+                ),
+                (
+                    "https://blog.example.com/sitemap-3.xml".to_string(),
+                    days_ago(1)
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_serve_each_part_of_the_index() {
+        let c = create_interactor();
+
+        let mut locations = vec![];
+        for part in 1..=3 {
+            let xml = c
+                .interactor
+                .execute(GetSitemapInput { part: Some(part) })
+                .await
+                .unwrap();
+            locations.extend(urls(&xml).into_iter().map(|(loc, _)| loc));
+        }
+
+        assert_eq!(locations.len(), 5);
+        assert_eq!(locations[4], "https://blog.example.com/posts/new");
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_for_missing_parts() {
+        let c = create_interactor();
+        let single = GetSitemapInteractor::new(
+            c.categories_repo.clone(),
+            c.posts_repo.clone(),
+            Arc::new(config(100)),
+        );
+
+        for (interactor, part) in [(&c.interactor, 0), (&c.interactor, 4), (&single, 1)] {
+            let err = interactor
+                .execute(GetSitemapInput { part: Some(part) })
+                .await
+                .unwrap_err();
+            assert_not_found_error(err);
+        }
+    }
+}
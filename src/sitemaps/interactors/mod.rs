@@ -0,0 +1,3 @@
+pub mod get_robots_txt;
+pub mod get_sitemap;
+pub mod utils;
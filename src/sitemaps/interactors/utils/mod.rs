@@ -0,0 +1,44 @@
+use crate::sitemaps::domain::{RobotsGroup, MAX_SITEMAP_URLS};
+use crate::utils::SiteUrls;
+
+#[derive(Debug, Clone)]
+pub struct SitemapConfig {
+    pub urls: SiteUrls,
+    /// Past this many URLs the sitemap becomes an index of numbered parts.
+    pub max_urls_per_sitemap: usize,
+}
+
+impl SitemapConfig {
+    pub fn new(urls: SiteUrls) -> Self {
+        Self {
+            urls,
+            max_urls_per_sitemap: MAX_SITEMAP_URLS,
+        }
+    }
+
+    /// `/sitemap.xml`, or `/sitemap-<part>.xml` for a part of the index.
+    pub fn sitemap_url(&self, part: Option<usize>) -> String {
+        match part {
+            None => self.urls.page("sitemap.xml"),
+            Some(part) => self.urls.page(&format!("sitemap-{}.xml", part)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RobotsConfig {
+    pub groups: Vec<RobotsGroup>,
+}
+
+impl Default for RobotsConfig {
+    /// Lets every crawler in.
+    fn default() -> Self {
+        Self {
+            groups: vec![RobotsGroup {
+                user_agents: vec!["*".into()],
+                allow: vec![],
+                disallow: vec![],
+            }],
+        }
+    }
+}
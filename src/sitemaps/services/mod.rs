@@ -0,0 +1,5 @@
+pub use robots_txt_writer::write_robots_txt;
+pub use sitemap_writer::{write_sitemap_index, write_urlset};
+
+mod robots_txt_writer;
+mod sitemap_writer;
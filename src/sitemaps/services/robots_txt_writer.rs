@@ -0,0 +1,61 @@
+use crate::sitemaps::domain::RobotsGroup;
+
+/// Writes robots.txt with a record per group followed by the sitemap location.
+///
+/// A group without rules gets an empty `Disallow`, which allows everything.
+pub fn write_robots_txt(groups: &[RobotsGroup], sitemap_url: &str) -> String {
+    let mut out = String::new();
+    for group in groups {
+        for user_agent in &group.user_agents {
+            out.push_str(&format!("User-agent: {}\n", user_agent));
+        }
+        for path in &group.allow {
+            out.push_str(&format!("Allow: {}\n", path));
+        }
+        for path in &group.disallow {
+            out.push_str(&format!("Disallow: {}\n", path));
+        }
+        if group.allow.is_empty() && group.disallow.is_empty() {
+            out.push_str("Disallow:\n");
+        }
+        out.push('\n');
+    }
+    out.push_str(&format!("Sitemap: {}\n", sitemap_url));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_write_a_record_per_group_and_the_sitemap() {
+        let groups = [
+            RobotsGroup {
+                user_agents: vec!["*".into()],
+                allow: vec!["/admin/public".into()],
+                disallow: vec!["/admin".into()],
+            },
+            RobotsGroup {
+                user_agents: vec!["GPTBot".into(), "CCBot".into()],
+                allow: vec![],
+                disallow: vec!["/".into()],
+            },
+            RobotsGroup {
+                user_agents: vec!["Googlebot".into()],
+                allow: vec![],
+                disallow: vec![],
+            },
+        ];
+
+        let robots = write_robots_txt(&groups, "https://blog.example.com/sitemap.xml");
+
+        assert_eq!(
+            robots,
+            "User-agent: *\nAllow: /admin/public\nDisallow: /admin\n\n\
+             User-agent: GPTBot\nUser-agent: CCBot\nDisallow: /\n\n\
+             User-agent: Googlebot\nDisallow:\n\n\
+             Sitemap: https://blog.example.com/sitemap.xml\n"
+        );
+    }
+}
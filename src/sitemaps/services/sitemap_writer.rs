@@ -0,0 +1,102 @@
+use chrono::SecondsFormat;
+
+use crate::sitemaps::domain::SitemapUrl;
+use crate::utils::{escape_xml, push_xml_element};
+
+const SITEMAP_NS: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+/// Writes a sitemap listing `urls`.
+pub fn write_urlset(urls: &[SitemapUrl]) -> String {
+    write_document("urlset", "url", urls)
+}
+
+/// Writes a sitemap index; `sitemaps` are the locations of the sitemaps it points to.
+pub fn write_sitemap_index(sitemaps: &[SitemapUrl]) -> String {
+    write_document("sitemapindex", "sitemap", sitemaps)
+}
+
+fn write_document(root: &str, item: &str, urls: &[SitemapUrl]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!("<{} xmlns=\"{}\">", root, escape_xml(SITEMAP_NS)));
+    for url in urls {
+        out.push_str(&format!("<{}>", item));
+        push_xml_element(&mut out, "loc", &url.loc);
+        if let Some(lastmod) = url.lastmod {
+            push_xml_element(
+                &mut out,
+                "lastmod",
+                &lastmod.to_rfc3339_opts(SecondsFormat::Secs, true),
+            );
+        }
+        out.push_str(&format!("</{}>", item));
+    }
+    out.push_str(&format!("</{}>\n", root));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use roxmltree::Document;
+
+    use super::*;
+
+    fn urls() -> Vec<SitemapUrl> {
+        vec![
+            SitemapUrl {
+                loc: "https://blog.example.com/posts/a?x=1&y=2".into(),
+                lastmod: Some(Utc.with_ymd_and_hms(2026, 10, 19, 5, 30, 0).unwrap()),
+            },
+            SitemapUrl {
+                loc: "https://blog.example.com".into(),
+                lastmod: None,
+            },
+        ]
+    }
+
+    fn entries(xml: &str, item: &str) -> Vec<(String, Option<String>)> {
+        let doc = Document::parse(xml).unwrap();
+        doc.root_element()
+            .children()
+            .filter(|n| n.tag_name().name() == item)
+            .map(|n| {
+                let text = |name: &str| {
+                    n.children()
+                        .find(|c| c.tag_name().name() == name)
+                        .and_then(|c| c.text())
+                        .map(String::from)
+                };
+                (text("loc").unwrap(), text("lastmod"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_write_a_urlset_in_the_sitemap_namespace() {
+        let xml = write_urlset(&urls());
+
+        let doc = Document::parse(&xml).unwrap();
+        assert_eq!(doc.root_element().tag_name().name(), "urlset");
+        assert_eq!(doc.root_element().tag_name().namespace(), Some(SITEMAP_NS));
+        assert_eq!(
+            entries(&xml, "url"),
+            [
+                (
+                    "https://blog.example.com/posts/a?x=1&y=2".to_string(),
+                    Some("2026-10-19T05:30:00Z".to_string())
+                ),
+                ("https://blog.example.com".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_write_a_sitemap_index() {
+        let xml = write_sitemap_index(&urls());
+
+        let doc = Document::parse(&xml).unwrap();
+        assert_eq!(doc.root_element().tag_name().name(), "sitemapindex");
+        assert_eq!(doc.root_element().tag_name().namespace(), Some(SITEMAP_NS));
+        assert_eq!(entries(&xml, "sitemap").len(), 2);
+    }
+}
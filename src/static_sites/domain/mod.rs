@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Where the manifest of the last build is kept in the output directory.
+pub const BUILD_MANIFEST_PATH: &str = ".static-site.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Link {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PostSummary {
+    pub title: String,
+    pub url: String,
+    pub published: DateTime<Utc>,
+    /// Plain text.
+    pub excerpt: String,
+}
+
+/// What every page knows about the site it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SiteInfo {
+    pub title: String,
+    pub description: String,
+    pub url: String,
+}
+
+/// Everything the page of a published post is rendered from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PostPage {
+    pub site: SiteInfo,
+    pub post: PublishedPost,
+    /// The category of the post and its ancestors, top-level first.
+    pub breadcrumb: Vec<Link>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PublishedPost {
+    pub title: String,
+    pub url: String,
+    pub published: DateTime<Utc>,
+    /// `None` once the author is deleted.
+    pub author: Option<Link>,
+    pub tags: Vec<Link>,
+    /// Sanitized HTML.
+    pub html: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListingKind {
+    Home,
+    Category,
+    Tag,
+    Author,
+}
+
+/// Everything one page of a listing of posts is rendered from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListingPage {
+    pub site: SiteInfo,
+    pub listing: Listing,
+    /// The ancestors of a category, top-level first.
+    pub breadcrumb: Vec<Link>,
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Listing {
+    pub kind: ListingKind,
+    pub title: String,
+    pub description: String,
+    /// The URL of the first page.
+    pub url: String,
+    /// The Atom feed of the same posts.
+    pub feed_url: Option<String>,
+    /// The direct children of a category.
+    pub children: Vec<Link>,
+    /// The posts on this page, newest first.
+    pub posts: Vec<PostSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Pagination {
+    /// 1-based.
+    pub page: usize,
+    pub pages: usize,
+    pub previous_url: Option<String>,
+    pub next_url: Option<String>,
+}
+
+/// The fingerprint of every file a build wrote, by path.
+///
+/// A page is only rendered again when its fingerprint changes, and files missing from a newer
+/// manifest are deleted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildManifest {
+    pub files: BTreeMap<String, String>,
+}
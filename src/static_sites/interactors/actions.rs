@@ -0,0 +1 @@
+pub const BUILD_STATIC_SITE_ACTION: &str = "BUILD_STATIC_SITE_ACTION";
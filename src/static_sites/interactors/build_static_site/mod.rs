@@ -0,0 +1,932 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use with_deps_proc_macro::WithDeps;
+
+use crate::categories::domain::{Category, CategoryId};
+use crate::categories::interactors::utils::category_subtree;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::feeds::domain::{FeedContent, FeedFormat, FeedScope};
+use crate::feeds::interactors::get_feed::GetFeedInput;
+use crate::feeds::interactors::utils::excerpt;
+use crate::markdown_sites::interactors::traits::SiteDirectory;
+use crate::posts::domain::{Post, PostStatus};
+use crate::posts::interactors::utils::post_sanitization_policy;
+use crate::sitemaps::interactors::get_sitemap::GetSitemapInput;
+use crate::static_sites::domain::{
+    BuildManifest, Link, Listing, ListingKind, ListingPage, Pagination, PostPage, PostSummary,
+    PublishedPost, SiteInfo, BUILD_MANIFEST_PATH,
+};
+use crate::static_sites::interactors::actions::BUILD_STATIC_SITE_ACTION;
+use crate::static_sites::interactors::traits::SiteTemplates;
+use crate::static_sites::interactors::utils::{page_file, StaticSiteConfig, StaticSiteSources};
+use crate::users::domain::User;
+use crate::utils::{AuthPayload, ContentRenderer, HtmlSanitizer};
+
+#[derive(Debug, Clone, Default)]
+pub struct BuildStaticSiteInput {
+    /// Renders every page again, whatever the last build left behind.
+    pub full: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuildStaticSiteOutput {
+    /// The files written, in the order they were.
+    pub written: Vec<String>,
+    pub unchanged: usize,
+    /// The files of the last build this one no longer produces, which are deleted.
+    pub removed: Vec<String>,
+}
+
+/// Renders the published blog into a directory that can be served as it is.
+///
+/// Every published post gets a page and every category, tag with posts and author with posts
+/// gets a listing split into pages. The site, categories and authors get their Atom and RSS
+/// feeds next to the sitemap, and the assets of the templates are copied. A manifest of
+/// what each file was made from is kept in the directory, so a later build only renders and
+/// writes the pages whose posts, names or templates changed. Feeds and sitemaps are cheap
+/// enough to be built every time and are only written when they differ.
+#[derive(WithDeps)]
+pub struct BuildStaticSiteInteractor {
+    directory: Arc<dyn SiteDirectory>,
+    templates: Arc<dyn SiteTemplates>,
+    renderer: Arc<dyn ContentRenderer>,
+    sanitizer: Arc<dyn HtmlSanitizer>,
+    sources: Arc<StaticSiteSources>,
+    config: Arc<StaticSiteConfig>,
+}
+
+impl BuildStaticSiteInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: BuildStaticSiteInput,
+    ) -> ApplicationResult<BuildStaticSiteOutput> {
+        auth.can_or_fail(BUILD_STATIC_SITE_ACTION)?;
+
+        let mut build = Build {
+            directory: self.directory.as_ref(),
+            templates: self.templates.as_ref(),
+            version: self.templates.version(),
+            previous: if input.full {
+                BuildManifest::default()
+            } else {
+                self.read_manifest().await?
+            },
+            manifest: BuildManifest::default(),
+            output: BuildStaticSiteOutput::default(),
+        };
+
+        let posts = self.latest_published().await?;
+        let categories = self.sources.categories_repo.get_all().await?;
+        let categories_by_id: HashMap<&CategoryId, &Category> = categories
+            .iter()
+            .map(|category| (&category.id, category))
+            .collect();
+        let mut tags = self.sources.tags_repo.get_all().await?;
+        tags.sort_by(|a, b| a.slug.cmp(&b.slug));
+        let mut authors: HashMap<String, Option<User>> = HashMap::new();
+        for (post, _) in &posts {
+            if !authors.contains_key(&post.author_id) {
+                let author = self.sources.users_repo.get_by_id(&post.author_id).await?;
+                authors.insert(post.author_id.clone(), author);
+            }
+        }
+
+        for (post, published) in &posts {
+            let author = authors[&post.author_id].as_ref();
+            let page = PostPage {
+                site: self.site(),
+                post: PublishedPost {
+                    title: post.title.clone(),
+                    url: self.config.urls.post(&post.slug),
+                    published: *published,
+                    author: author.map(|author| self.author_link(author)),
+                    tags: tags
+                        .iter()
+                        .filter(|tag| post.tag_ids.contains(&tag.id))
+                        .map(|tag| Link {
+                            name: tag.name.clone(),
+                            url: self.config.urls.tag(&tag.slug),
+                        })
+                        .collect(),
+                    html: String::new(),
+                },
+                breadcrumb: category_trail(&categories_by_id, post.category_id.as_ref())
+                    .into_iter()
+                    .map(|category| self.category_link(category))
+                    .collect(),
+            };
+            let policy = post_sanitization_policy(author);
+            build
+                .post(
+                    page_file(&format!("posts/{}", post.slug)),
+                    page,
+                    &[&post.content, &format!("{:?}", policy)],
+                    || {
+                        let rendered = self.renderer.render(&post.content);
+                        self.sanitizer.sanitize(&rendered.html, policy)
+                    },
+                )
+                .await?;
+        }
+
+        let home = self.listing(
+            ListingKind::Home,
+            &self.config.title,
+            &self.config.description,
+            self.config.urls.home(),
+            true,
+            posts.iter(),
+        );
+        self.paginate(&mut build, "", home, vec![]).await?;
+
+        let mut sorted_categories: Vec<&Category> = categories.iter().collect();
+        sorted_categories.sort_by(|a, b| a.slug.cmp(&b.slug));
+        for category in &sorted_categories {
+            let subtree: HashSet<CategoryId> = category_subtree(&categories, &category.id)
+                .into_iter()
+                .collect();
+            let mut listing = self.listing(
+                ListingKind::Category,
+                &category.name,
+                &category.description,
+                self.config.urls.category(&category.slug),
+                true,
+                posts.iter().filter(|(post, _)| {
+                    post.category_id
+                        .as_ref()
+                        .is_some_and(|id| subtree.contains(id))
+                }),
+            );
+            let mut trail = category_trail(&categories_by_id, Some(&category.id));
+            trail.pop();
+            let breadcrumb = trail
+                .into_iter()
+                .map(|category| self.category_link(category))
+                .collect();
+            let mut children: Vec<&&Category> = sorted_categories
+                .iter()
+                .filter(|child| child.parent_id.as_ref() == Some(&category.id))
+                .collect();
+            children.sort_by(|a, b| a.name.cmp(&b.name));
+            listing.children = children
+                .into_iter()
+                .map(|child| self.category_link(child))
+                .collect();
+            let path = format!("categories/{}", category.slug);
+            self.paginate(&mut build, &path, listing, breadcrumb)
+                .await?;
+        }
+
+        for tag in &tags {
+            let tagged: Vec<_> = posts
+                .iter()
+                .filter(|(post, _)| post.tag_ids.contains(&tag.id))
+                .collect();
+            if tagged.is_empty() {
+                continue;
+            }
+            let listing = self.listing(
+                ListingKind::Tag,
+                &tag.name,
+                "",
+                self.config.urls.tag(&tag.slug),
+                false,
+                tagged.into_iter(),
+            );
+            let path = format!("tags/{}", tag.slug);
+            self.paginate(&mut build, &path, listing, vec![]).await?;
+        }
+
+        let mut existing_authors: Vec<&User> = authors.values().flatten().collect();
+        existing_authors.sort_by(|a, b| a.id.cmp(&b.id));
+        for author in &existing_authors {
+            let listing = self.listing(
+                ListingKind::Author,
+                &author.name,
+                "",
+                self.config.urls.author(&author.id),
+                true,
+                posts.iter().filter(|(post, _)| post.author_id == author.id),
+            );
+            let path = format!("authors/{}", author.id);
+            self.paginate(&mut build, &path, listing, vec![]).await?;
+        }
+
+        let mut feeds = vec![("".to_string(), FeedScope::Site)];
+        feeds.extend(sorted_categories.iter().map(|category| {
+            (
+                format!("categories/{}/", category.slug),
+                FeedScope::Category(category.slug.clone()),
+            )
+        }));
+        feeds.extend(existing_authors.iter().map(|author| {
+            (
+                format!("authors/{}/", author.id),
+                FeedScope::Author(author.id.clone()),
+            )
+        }));
+        for (prefix, scope) in feeds {
+            for format in [FeedFormat::Atom, FeedFormat::Rss] {
+                let feed = self
+                    .sources
+                    .feeds
+                    .execute(GetFeedInput {
+                        scope: scope.clone(),
+                        format,
+                        content: FeedContent::Full,
+                    })
+                    .await?;
+                build
+                    .file(format!("{}feed.{}", prefix, format.extension()), feed.body)
+                    .await?;
+            }
+        }
+
+        let sitemap = self
+            .sources
+            .sitemaps
+            .execute(GetSitemapInput { part: None })
+            .await?;
+        build.file("sitemap.xml".into(), sitemap).await?;
+        for part in 1.. {
+            match self
+                .sources
+                .sitemaps
+                .execute(GetSitemapInput { part: Some(part) })
+                .await
+            {
+                Ok(sitemap) => build.file(format!("sitemap-{}.xml", part), sitemap).await?,
+                Err(NotFoundException(_)) => break,
+                Err(e) => return Err(e),
+            }
+        }
+
+        for (path, contents) in self.templates.assets() {
+            build.asset(format!("assets/{}", path), contents).await?;
+        }
+
+        Ok(build.finish().await?)
+    }
+
+    /// A missing or unreadable manifest makes every page count as changed.
+    async fn read_manifest(&self) -> UnknownResult<BuildManifest> {
+        Ok(self
+            .directory
+            .read(BUILD_MANIFEST_PATH)
+            .await?
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default())
+    }
+
+    async fn latest_published(&self) -> UnknownResult<Vec<(Post, DateTime<Utc>)>> {
+        let mut published: Vec<_> = self
+            .sources
+            .posts_repo
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|post| post.status == PostStatus::Published)
+            .filter_map(|post| post.published_at.map(|at| (post, at)))
+            .collect();
+        published.sort_by(|(a, a_at), (b, b_at)| b_at.cmp(a_at).then_with(|| a.slug.cmp(&b.slug)));
+        Ok(published)
+    }
+
+    /// Writes the listing as pages of at most `posts_per_page` posts; the first one at `path`,
+    /// the others at `<path>/page/<number>`.
+    async fn paginate(
+        &self,
+        build: &mut Build<'_>,
+        path: &str,
+        listing: Listing,
+        breadcrumb: Vec<Link>,
+    ) -> UnknownResult<()> {
+        let mut chunks: Vec<Vec<PostSummary>> = listing
+            .posts
+            .chunks(self.config.posts_per_page.max(1))
+            .map(<[PostSummary]>::to_vec)
+            .collect();
+        if chunks.is_empty() {
+            chunks.push(vec![]);
+        }
+        let pages = chunks.len();
+        let url_of = |page: usize| match page {
+            1 => listing.url.clone(),
+            _ => format!("{}/page/{}", listing.url, page),
+        };
+        for (i, posts) in chunks.into_iter().enumerate() {
+            let page = i + 1;
+            let file = match page {
+                1 => page_file(path),
+                _ => page_file(&format!("{}/page/{}", path, page)),
+            };
+            let context = ListingPage {
+                site: self.site(),
+                listing: Listing {
+                    posts,
+                    ..listing.clone()
+                },
+                breadcrumb: breadcrumb.clone(),
+                pagination: Pagination {
+                    page,
+                    pages,
+                    previous_url: (page > 1).then(|| url_of(page - 1)),
+                    next_url: (page < pages).then(|| url_of(page + 1)),
+                },
+            };
+            build.listing(file, context).await?;
+        }
+        Ok(())
+    }
+
+    /// All of the posts of a listing, before they are split into pages.
+    fn listing<'a>(
+        &self,
+        kind: ListingKind,
+        title: &str,
+        description: &str,
+        url: String,
+        has_feed: bool,
+        posts: impl Iterator<Item = &'a (Post, DateTime<Utc>)>,
+    ) -> Listing {
+        Listing {
+            kind,
+            title: title.into(),
+            description: description.into(),
+            feed_url: has_feed.then(|| format!("{}/feed.{}", url, FeedFormat::Atom.extension())),
+            url,
+            children: vec![],
+            posts: posts
+                .map(|(post, published)| PostSummary {
+                    title: post.title.clone(),
+                    url: self.config.urls.post(&post.slug),
+                    published: *published,
+                    excerpt: excerpt(&post.content, self.config.excerpt_length),
+                })
+                .collect(),
+        }
+    }
+
+    fn site(&self) -> SiteInfo {
+        SiteInfo {
+            title: self.config.title.clone(),
+            description: self.config.description.clone(),
+            url: self.config.urls.home(),
+        }
+    }
+
+    fn category_link(&self, category: &Category) -> Link {
+        Link {
+            name: category.name.clone(),
+            url: self.config.urls.category(&category.slug),
+        }
+    }
+
+    fn author_link(&self, author: &User) -> Link {
+        Link {
+            name: author.name.clone(),
+            url: self.config.urls.author(&author.id),
+        }
+    }
+}
+
+/// The category and its ancestors, top-level first.
+fn category_trail<'a>(
+    categories: &HashMap<&CategoryId, &'a Category>,
+    id: Option<&CategoryId>,
+) -> Vec<&'a Category> {
+    let mut trail = vec![];
+    let mut visited = HashSet::new();
+    let mut current = id.and_then(|id| categories.get(id).copied());
+    while let Some(category) = current.filter(|c| visited.insert(&c.id)) {
+        trail.push(category);
+        current = category
+            .parent_id
+            .as_ref()
+            .and_then(|id| categories.get(id).copied());
+    }
+    trail.reverse();
+    trail
+}
+
+fn fingerprint(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+struct Build<'a> {
+    directory: &'a dyn SiteDirectory,
+    templates: &'a dyn SiteTemplates,
+    version: String,
+    previous: BuildManifest,
+    manifest: BuildManifest,
+    output: BuildStaticSiteOutput,
+}
+
+impl Build<'_> {
+    /// The page is fingerprinted before its HTML is rendered, from the post it is made of.
+    async fn post(
+        &mut self,
+        path: String,
+        mut page: PostPage,
+        sources: &[&str],
+        html: impl FnOnce() -> String,
+    ) -> UnknownResult<()> {
+        let context = serde_json::to_string(&page)?;
+        let mut parts = vec![self.version.as_str(), context.as_str()];
+        parts.extend_from_slice(sources);
+        let templates = self.templates;
+        self.write_if_changed(path, fingerprint(&parts), || {
+            page.post.html = html();
+            templates.render_post(&page)
+        })
+        .await
+    }
+
+    async fn listing(&mut self, path: String, page: ListingPage) -> UnknownResult<()> {
+        let context = serde_json::to_string(&page)?;
+        let templates = self.templates;
+        self.write_if_changed(path, fingerprint(&[&self.version, &context]), || {
+            templates.render_listing(&page)
+        })
+        .await
+    }
+
+    async fn file(&mut self, path: String, body: String) -> UnknownResult<()> {
+        self.write_if_changed(path, fingerprint(&[&body]), || Ok(body))
+            .await
+    }
+
+    async fn asset(&mut self, path: String, contents: Vec<u8>) -> UnknownResult<()> {
+        let fingerprint = format!("{:x}", Sha256::digest(&contents));
+        self.write_if_changed(path, fingerprint, || Ok(contents))
+            .await
+    }
+
+    async fn write_if_changed<B: AsRef<[u8]>>(
+        &mut self,
+        path: String,
+        fingerprint: String,
+        render: impl FnOnce() -> UnknownResult<B>,
+    ) -> UnknownResult<()> {
+        if self.previous.files.get(&path) == Some(&fingerprint) {
+            self.output.unchanged += 1;
+        } else {
+            self.directory
+                .write_bytes(&path, render()?.as_ref())
+                .await?;
+            self.output.written.push(path.clone());
+        }
+        self.manifest.files.insert(path, fingerprint);
+        Ok(())
+    }
+
+    async fn finish(mut self) -> UnknownResult<BuildStaticSiteOutput> {
+        for path in self.previous.files.keys() {
+            if !self.manifest.files.contains_key(path) {
+                self.directory.delete(path).await?;
+                self.output.removed.push(path.clone());
+            }
+        }
+        self.directory
+            .write(
+                BUILD_MANIFEST_PATH,
+                &serde_json::to_string_pretty(&self.manifest)?,
+            )
+            .await?;
+        Ok(self.output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::categories::interactors::test_doubles::fake_categories_repository::FakeCategoriesRepository;
+    use crate::categories::interactors::traits::CategoriesRepository;
+    use crate::feeds::interactors::get_feed::GetFeedInteractor;
+    use crate::feeds::interactors::utils::FeedConfig;
+    use crate::make_interactor_setup;
+    use crate::markdown_sites::interactors::test_doubles::fake_site_directory::FakeSiteDirectory;
+    use crate::posts::domain::PostId;
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::posts::interactors::traits::PostsRepository;
+    use crate::sitemaps::interactors::get_sitemap::GetSitemapInteractor;
+    use crate::sitemaps::interactors::utils::SitemapConfig;
+    use crate::tags::domain::{Tag, TagId};
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::access_management::role_spy::RoleSpy;
+    use crate::test_utils::clock::fake_clock::fixed_now;
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+    use crate::themes::services::MiniJinjaThemeEngine;
+    use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
+    use crate::users::interactors::traits::UsersRepository;
+    use crate::utils::{
+        AmmoniaHtmlSanitizer, MarkdownRenderer, SiteUrls, TransliteratingSlugGenerator,
+    };
+
+    use super::*;
+
+    make_interactor_setup!(
+        BuildStaticSiteInteractor,
+        [
+            (directory, FakeSiteDirectory::new_empty(), FakeSiteDirectory),
+            (
+                templates,
+                MiniJinjaThemeEngine::default(),
+                MiniJinjaThemeEngine
+            ),
+            (
+                renderer,
+                MarkdownRenderer::new(Arc::new(TransliteratingSlugGenerator::default())),
+                MarkdownRenderer
+            ),
+            (
+                sanitizer,
+                AmmoniaHtmlSanitizer::new(&[]),
+                AmmoniaHtmlSanitizer
+            ),
+            (
+                sources,
+                {
+                    let posts_repo: Arc<dyn PostsRepository> =
+                        Arc::new(FakePostsRepository::new_with_data(&posts()));
+                    let categories_repo: Arc<dyn CategoriesRepository> =
+                        Arc::new(FakeCategoriesRepository::new_with_data(&categories()));
+                    let users_repo: Arc<dyn UsersRepository> =
+                        Arc::new(FakeUsersRepository::new_with_data(&[
+                            user("trusted", true),
+                            user("untrusted", false),
+                        ]));
+                    StaticSiteSources {
+                        posts_repo: posts_repo.clone(),
+                        categories_repo: categories_repo.clone(),
+                        tags_repo: Arc::new(FakeTagsRepository::new_with_data(&[tag("async")])),
+                        users_repo: users_repo.clone(),
+                        feeds: Arc::new(GetFeedInteractor::new(
+                            posts_repo.clone(),
+                            categories_repo.clone(),
+                            users_repo,
+                            renderer.clone(),
+                            sanitizer.clone(),
+                            Arc::new(FeedConfig {
+                                title: "Blog".into(),
+                                description: "A blog".into(),
+                                urls: urls(),
+                                language: "en".into(),
+                                max_entries: 10,
+                                excerpt_length: 20,
+                            }),
+                        )),
+                        sitemaps: Arc::new(GetSitemapInteractor::new(
+                            categories_repo,
+                            posts_repo,
+                            Arc::new(SitemapConfig::new(urls())),
+                        )),
+                    }
+                },
+                StaticSiteSources
+            ),
+            (
+                config,
+                StaticSiteConfig {
+                    title: "Blog".into(),
+                    description: "A blog".into(),
+                    urls: urls(),
+                    excerpt_length: 20,
+                    posts_per_page: 10,
+                },
+                StaticSiteConfig
+            )
+        ]
+    );
+
+    fn urls() -> SiteUrls {
+        SiteUrls::new("https://blog.example.com")
+    }
+
+    fn category(id: &str, parent_id: Option<&str>) -> Category {
+        Category {
+            id: CategoryId::new(id),
+            name: id.to_uppercase(),
+            description: format!("All about {}", id),
+            created_at: fixed_now(),
+            slug: id.into(),
+            parent_id: parent_id.map(CategoryId::new),
+        }
+    }
+
+    fn categories() -> Vec<Category> {
+        vec![
+            category("lang", None),
+            category("rust", Some("lang")),
+            category("life", None),
+        ]
+    }
+
+    fn tag(id: &str) -> Tag {
+        Tag {
+            id: TagId::new(id),
+            name: id.to_uppercase(),
+            slug: id.into(),
+            created_at: fixed_now(),
+        }
+    }
+
+    fn user(id: &str, trusted: bool) -> User {
+        User {
+            id: id.into(),
+            name: format!("{} author", id),
+            email: format!("{}@example.com", id),
+            password: "".into(),
+            role: Box::new(if trusted {
+                RoleSpy::new_allowed()
+            } else {
+                RoleSpy::new_disallowed()
+            }),
+        }
+    }
+
+    fn post(id: &str, category: &str, author: &str, days_ago: i64) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: format!("Post {}", id),
+            slug: id.into(),
+            content: format!("Content of {} <span class=\"x\">html</span>", id),
+            author_id: author.into(),
+            category_id: Some(CategoryId::new(category)),
+            tag_ids: vec![],
+            status: PostStatus::Published,
+            publish_at: None,
+            published_at: Some(fixed_now() - Duration::days(days_ago)),
+            created_at: fixed_now() - Duration::days(30),
+        }
+    }
+
+    fn posts() -> Vec<Post> {
+        let mut new = post("new", "rust", "untrusted", 1);
+        new.tag_ids = vec![TagId::new("async")];
+        let mut draft = post("draft", "life", "trusted", 0);
+        draft.status = PostStatus::Draft;
+        draft.published_at = None;
+        vec![post("old", "lang", "trusted", 5), new, draft]
+    }
+
+    fn sorted(mut paths: Vec<String>) -> Vec<String> {
+        paths.sort();
+        paths
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [BUILD_STATIC_SITE_ACTION]);
+        assert_forbidden_error(err);
+        assert!(c.directory.files.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_write_every_page_feed_and_the_sitemap() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c
+            .interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sorted(output.written),
+            [
+                "assets/style.css",
+                "authors/trusted/feed.atom",
+                "authors/trusted/feed.rss",
+                "authors/trusted/index.html",
+                "authors/untrusted/feed.atom",
+                "authors/untrusted/feed.rss",
+                "authors/untrusted/index.html",
+                "categories/lang/feed.atom",
+                "categories/lang/feed.rss",
+                "categories/lang/index.html",
+                "categories/life/feed.atom",
+                "categories/life/feed.rss",
+                "categories/life/index.html",
+                "categories/rust/feed.atom",
+                "categories/rust/feed.rss",
+                "categories/rust/index.html",
+                "feed.atom",
+                "feed.rss",
+                "index.html",
+                "posts/new/index.html",
+                "posts/old/index.html",
+                "sitemap.xml",
+                "tags/async/index.html",
+            ]
+        );
+        assert!(c.directory.get_file(BUILD_MANIFEST_PATH).is_some());
+        let lang = c.directory.get_file("categories/lang/index.html").unwrap();
+        assert!(lang.contains("Post new") && lang.contains("Post old"));
+        assert!(lang.contains("href=\"https://blog.example.com/categories/rust\""));
+        let rust = c.directory.get_file("categories/rust/index.html").unwrap();
+        assert!(rust.contains("<ul class=\"breadcrumb\"><li><a href=\"https://blog.example.com/categories/lang\">LANG</a></li></ul>"));
+        assert!(!rust.contains("Post old"));
+        let new = c.directory.get_file("posts/new/index.html").unwrap();
+        assert!(new.contains("<p>Content of new html</p>"));
+        assert!(new.contains(">untrusted author</a>"));
+        assert!(new.contains("href=\"https://blog.example.com/tags/async\""));
+        let old = c.directory.get_file("posts/old/index.html").unwrap();
+        assert!(old.contains("<p>Content of old <span class=\"x\">html</span></p>"));
+    }
+
+    #[tokio::test]
+    async fn should_only_rewrite_the_files_a_change_affects() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        c.interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+        let mut old = c
+            .sources
+            .posts_repo
+            .get_by_slug("old")
+            .await
+            .unwrap()
+            .unwrap();
+        old.title = "Old, edited".into();
+        c.sources.posts_repo.update(&old).await.unwrap();
+
+        let output = c
+            .interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sorted(output.written),
+            [
+                "authors/trusted/feed.atom",
+                "authors/trusted/feed.rss",
+                "authors/trusted/index.html",
+                "categories/lang/feed.atom",
+                "categories/lang/feed.rss",
+                "categories/lang/index.html",
+                "feed.atom",
+                "feed.rss",
+                "index.html",
+                "posts/old/index.html",
+            ]
+        );
+        assert_eq!(output.unchanged, 13);
+        assert!(output.removed.is_empty());
+        assert!(c
+            .directory
+            .get_file("posts/old/index.html")
+            .unwrap()
+            .contains("<h1>Old, edited</h1>"));
+    }
+
+    #[tokio::test]
+    async fn should_rebuild_the_pages_of_a_renamed_category() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        c.interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+        let mut lang = category("lang", None);
+        lang.name = "Languages".into();
+        c.sources.categories_repo.update(&lang).await.unwrap();
+
+        let output = c
+            .interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+
+        let written = sorted(output.written);
+        for page in [
+            "categories/lang/index.html",
+            "categories/rust/index.html",
+            "posts/new/index.html",
+            "posts/old/index.html",
+        ] {
+            assert!(written.iter().any(|path| path == page), "{}", page);
+        }
+        assert!(!written
+            .iter()
+            .any(|path| path == "categories/life/index.html"));
+        assert!(!written.iter().any(|path| path == "tags/async/index.html"));
+    }
+
+    #[tokio::test]
+    async fn should_delete_the_files_no_longer_built() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        c.interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+        let mut new = c
+            .sources
+            .posts_repo
+            .get_by_slug("new")
+            .await
+            .unwrap()
+            .unwrap();
+        new.status = PostStatus::Draft;
+        c.sources.posts_repo.update(&new).await.unwrap();
+
+        let output = c
+            .interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sorted(output.removed),
+            [
+                "authors/untrusted/feed.atom",
+                "authors/untrusted/feed.rss",
+                "authors/untrusted/index.html",
+                "posts/new/index.html",
+                "tags/async/index.html",
+            ]
+        );
+        assert_eq!(c.directory.get_file("posts/new/index.html"), None);
+        assert!(c.directory.get_file("posts/old/index.html").is_some());
+    }
+
+    #[tokio::test]
+    async fn should_render_every_page_again_on_a_full_build() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+        let first = c
+            .interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+
+        let output = c
+            .interactor
+            .execute(&auth, BuildStaticSiteInput { full: true })
+            .await
+            .unwrap();
+
+        assert_eq!(output.written, first.written);
+        assert_eq!(output.unchanged, 0);
+        assert!(output.removed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_split_listings_into_pages() {
+        let mut c = create_interactor();
+        c.interactor.set_config(Arc::new(StaticSiteConfig {
+            title: "Blog".into(),
+            description: "A blog".into(),
+            urls: urls(),
+            excerpt_length: 20,
+            posts_per_page: 1,
+        }));
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor
+            .execute(&auth, BuildStaticSiteInput::default())
+            .await
+            .unwrap();
+
+        let first = c.directory.get_file("index.html").unwrap();
+        let second = c.directory.get_file("page/2/index.html").unwrap();
+        assert!(first.contains("Post new") && !first.contains("Post old"));
+        assert!(first.contains("href=\"https://blog.example.com/page/2\""));
+        assert!(second.contains("Post old") && !second.contains("Post new"));
+        assert!(second.contains("<a rel=\"prev\" href=\"https://blog.example.com\">"));
+        assert!(c
+            .directory
+            .get_file("categories/lang/page/2/index.html")
+            .is_some());
+        assert_eq!(
+            c.directory.get_file("categories/rust/page/2/index.html"),
+            None
+        );
+    }
+}
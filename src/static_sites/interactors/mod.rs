@@ -0,0 +1,4 @@
+pub mod actions;
+pub mod build_static_site;
+pub mod traits;
+pub mod utils;
@@ -0,0 +1,13 @@
+use std::collections::BTreeMap;
+
+use crate::errors::UnknownResult;
+use crate::static_sites::domain::{ListingPage, PostPage};
+
+pub trait SiteTemplates: Send + Sync {
+    /// Changes whenever the output of the templates may, so every page is rendered again.
+    fn version(&self) -> String;
+    fn render_post(&self, page: &PostPage) -> UnknownResult<String>;
+    fn render_listing(&self, page: &ListingPage) -> UnknownResult<String>;
+    /// Files such as stylesheets the pages refer to, by their path below `assets/`.
+    fn assets(&self) -> BTreeMap<String, Vec<u8>>;
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::categories::interactors::traits::CategoriesRepository;
+use crate::feeds::interactors::get_feed::GetFeedInteractor;
+use crate::posts::interactors::traits::PostsRepository;
+use crate::sitemaps::interactors::get_sitemap::GetSitemapInteractor;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::users::interactors::traits::UsersRepository;
+use crate::utils::SiteUrls;
+
+#[derive(Debug, Clone)]
+pub struct StaticSiteConfig {
+    pub title: String,
+    pub description: String,
+    pub urls: SiteUrls,
+    /// In characters.
+    pub excerpt_length: usize,
+    pub posts_per_page: usize,
+}
+
+/// What a build reads the published blog from.
+#[derive(Clone)]
+pub struct StaticSiteSources {
+    pub posts_repo: Arc<dyn PostsRepository>,
+    pub categories_repo: Arc<dyn CategoriesRepository>,
+    pub tags_repo: Arc<dyn TagsRepository>,
+    pub users_repo: Arc<dyn UsersRepository>,
+    pub feeds: Arc<GetFeedInteractor>,
+    pub sitemaps: Arc<GetSitemapInteractor>,
+}
+
+/// The file a page URL path is served from, e.g. `posts/hello/index.html`.
+pub fn page_file(path: &str) -> String {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        "index.html".into()
+    } else {
+        format!("{}/index.html", path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serve_every_page_from_an_index_file() {
+        assert_eq!(page_file(""), "index.html");
+        assert_eq!(page_file("/posts/hello/"), "posts/hello/index.html");
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub id: TagId,
+    pub name: String,
+    pub slug: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TagId(String);
+
+impl TagId {
+    pub fn new(id: &str) -> Self {
+        TagId(id.into())
+    }
+}
+
+impl Display for TagId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<TagId> for String {
+    fn from(id: TagId) -> Self {
+        id.0
+    }
+}
+
+impl From<String> for TagId {
+    fn from(s: String) -> Self {
+        TagId(s)
+    }
+}
+
+impl From<&str> for TagId {
+    fn from(s: &str) -> Self {
+        TagId(s.to_string())
+    }
+}
@@ -0,0 +1,3 @@
+pub const CREATE_TAG_ACTION: &str = "CREATE_TAG_ACTION";
+pub const RENAME_TAG_ACTION: &str = "RENAME_TAG_ACTION";
+pub const DELETE_TAG_ACTION: &str = "DELETE_TAG_ACTION";
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::tags::interactors::utils::VisibleTag;
+
+pub const DEFAULT_AUTOCOMPLETE_LIMIT: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct AutocompleteTagsInput {
+    pub prefix: String,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AutocompleteTagsOutput {
+    pub tags: Vec<VisibleTag>,
+}
+
+#[derive(WithDeps)]
+pub struct AutocompleteTagsInteractor {
+    repo: Arc<dyn TagsRepository>,
+}
+
+impl AutocompleteTagsInteractor {
+    pub async fn execute(
+        &self,
+        input: AutocompleteTagsInput,
+    ) -> ApplicationResult<AutocompleteTagsOutput> {
+        let prefix = input.prefix.trim_start();
+        if prefix.is_empty() {
+            return Ok(AutocompleteTagsOutput { tags: vec![] });
+        }
+        let limit = input.limit.unwrap_or(DEFAULT_AUTOCOMPLETE_LIMIT);
+        let tags = self.repo.get_by_prefix(prefix, limit).await?;
+        Ok(AutocompleteTagsOutput {
+            tags: tags.into_iter().map(|tag| tag.into()).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::tags::domain::Tag;
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+
+    use super::*;
+
+    make_interactor_setup!(
+        AutocompleteTagsInteractor,
+        [(
+            repo,
+            FakeTagsRepository::new_with_data(&[
+                tag("Rustacean"),
+                tag("Go"),
+                tag("rust"),
+                tag("Ruby"),
+            ]),
+            FakeTagsRepository
+        )]
+    );
+
+    fn tag(name: &str) -> Tag {
+        Tag {
+            id: name.into(),
+            name: name.to_string(),
+            slug: name.to_lowercase(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn names(output: AutocompleteTagsOutput) -> Vec<String> {
+        output.tags.into_iter().map(|tag| tag.name).collect()
+    }
+
+    #[tokio::test]
+    async fn should_return_nothing_for_a_blank_prefix() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(AutocompleteTagsInput {
+                prefix: " ".into(),
+                limit: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(output.tags.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_match_the_prefix_ignoring_case() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(AutocompleteTagsInput {
+                prefix: "RUS".into(),
+                limit: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(names(output), ["rust", "Rustacean"]);
+    }
+
+    #[tokio::test]
+    async fn should_respect_the_limit() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(AutocompleteTagsInput {
+                prefix: "ru".into(),
+                limit: Some(2),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(names(output), ["Ruby", "rust"]);
+    }
+}
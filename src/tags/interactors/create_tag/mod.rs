@@ -0,0 +1,241 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationException::DuplicationException;
+use crate::errors::ApplicationResult;
+use crate::tags::domain::{Tag, TagId};
+use crate::tags::interactors::actions::CREATE_TAG_ACTION;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::tags::interactors::utils::{validate_tag_name, VisibleTag};
+use crate::utils::{
+    AuthPayload, Clock, RandomService, RepositorySlugAvailability, SlugGenerator,
+    SlugHistoryRepository, Validatable,
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateTagInput {
+    pub name: String,
+}
+
+impl Validatable for CreateTagInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_tag_name(&self.name)
+    }
+}
+
+#[derive(WithDeps)]
+pub struct CreateTagInteractor {
+    repo: Arc<dyn TagsRepository>,
+    random: Arc<dyn RandomService>,
+    slug_history: Arc<dyn SlugHistoryRepository>,
+    slug_generator: Arc<dyn SlugGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CreateTagInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: CreateTagInput,
+    ) -> ApplicationResult<VisibleTag> {
+        auth.can_or_fail(CREATE_TAG_ACTION)?;
+        input.validate()?;
+
+        let name = input.name.trim().to_string();
+        if self.repo.get_by_name(&name).await?.is_some() {
+            return Err(DuplicationException {
+                key: "name".into(),
+                value: name,
+            });
+        }
+
+        let availability = RepositorySlugAvailability {
+            repo: self.repo.as_ref(),
+            slug_history: self.slug_history.as_ref(),
+            owner_id: None,
+        };
+        let slug = self.slug_generator.generate(&name, &availability).await?;
+
+        let tag = Tag {
+            id: TagId::new(&self.random.random_id().await?),
+            name,
+            slug,
+            created_at: self.clock.now(),
+        };
+        self.repo.create(&tag).await?;
+        Ok(tag.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::crypto::random_service_spy::{RandomServiceSpy, RANDOM_ID};
+    use crate::test_utils::errors_assertion::*;
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::utils::{RetiredSlug, SlugOwnerKind, TransliteratingSlugGenerator};
+
+    use super::*;
+
+    make_interactor_setup!(
+        CreateTagInteractor,
+        [
+            (
+                repo,
+                FakeTagsRepository::new_with_data(&[existing_tag()]),
+                FakeTagsRepository
+            ),
+            (random, RandomServiceSpy::new(), RandomServiceSpy),
+            (
+                slug_history,
+                FakeSlugHistoryRepository::new_with_data(&[retired_slug()]),
+                FakeSlugHistoryRepository
+            ),
+            (
+                slug_generator,
+                TransliteratingSlugGenerator::default(),
+                TransliteratingSlugGenerator
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock)
+        ]
+    );
+
+    fn existing_tag() -> Tag {
+        Tag {
+            id: "existing".into(),
+            name: "Rust".to_string(),
+            slug: "rust".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn retired_slug() -> RetiredSlug {
+        RetiredSlug {
+            kind: SlugOwnerKind::Tag,
+            slug: "async-rust".to_string(),
+            owner_id: existing_tag().id.to_string(),
+            retired_at: Utc::now(),
+        }
+    }
+
+    fn valid_input() -> CreateTagInput {
+        CreateTagInput {
+            name: "Web Assembly".to_string(),
+        }
+    }
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("ID".into())
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, valid_input())
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [CREATE_TAG_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_if_name_is_blank() {
+        let c = create_interactor();
+
+        let err = c
+            .interactor
+            .execute(&auth(), CreateTagInput { name: "  ".into() })
+            .await
+            .unwrap_err();
+
+        assert_validation_error_with_key(err, "name");
+    }
+
+    #[tokio::test]
+    async fn should_throw_duplication_error_if_name_exists_in_another_case() {
+        let c = create_interactor();
+
+        let err = c
+            .interactor
+            .execute(
+                &auth(),
+                CreateTagInput {
+                    name: "rUST".into(),
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_duplication_error(err, "name");
+    }
+
+    #[tokio::test]
+    async fn should_store_the_trimmed_name_and_generated_slug() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(
+                &auth(),
+                CreateTagInput {
+                    name: " Web Assembly ".into(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(output.id, RANDOM_ID);
+        assert_eq!(output.name, "Web Assembly");
+        assert_eq!(output.slug, "web-assembly");
+        assert_eq!(output.created_at, fixed_now().to_rfc2822());
+        assert_eq!(c.repo.get_tags().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_skip_slugs_retired_by_other_tags() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(
+                &auth(),
+                CreateTagInput {
+                    name: "Async Rust".into(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(output.slug, "async-rust-2");
+    }
+
+    #[tokio::test]
+    async fn should_suffix_the_slug_of_names_that_only_differ_in_punctuation() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(
+                &auth(),
+                CreateTagInput {
+                    name: "Rust!".into(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(output.slug, "rust-2");
+    }
+}
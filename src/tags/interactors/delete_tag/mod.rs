@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::ApplicationResult;
+use crate::posts::interactors::traits::PostsRepository;
+use crate::tags::domain::TagId;
+use crate::tags::interactors::actions::DELETE_TAG_ACTION;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::utils::{AuthPayload, DeletionResult, SlugHistoryRepository, SlugOwnerKind, UnitOfWork};
+
+#[derive(Debug, Clone)]
+pub struct DeleteTagInput {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteTagOutput {
+    pub detached_posts: i32,
+}
+
+/// Deletes a tag, taking it off its posts and releasing the slugs it retired so new tags can
+/// use them.
+#[derive(WithDeps)]
+pub struct DeleteTagInteractor {
+    repo: Arc<dyn TagsRepository>,
+    posts_repo: Arc<dyn PostsRepository>,
+    slug_history: Arc<dyn SlugHistoryRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    audit: Arc<dyn AuditLog>,
+}
+
+impl DeleteTagInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: DeleteTagInput,
+    ) -> ApplicationResult<DeleteTagOutput> {
+        let target = input.id.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            DELETE_TAG_ACTION,
+            &target,
+            self.delete(auth, input),
+        )
+        .await
+    }
+
+    async fn delete(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: DeleteTagInput,
+    ) -> ApplicationResult<DeleteTagOutput> {
+        auth.can_or_fail(DELETE_TAG_ACTION)?;
+
+        let id: TagId = input.id.into();
+        let posts = self.posts_repo.get_by_tag_id(&id).await?;
+        self.unit_of_work
+            .run(Box::pin(async {
+                if self.repo.delete(&id).await? == DeletionResult::NotFound {
+                    return Err(NotFoundException(format!("Tag with id {} not found", id)));
+                }
+                let owner_id = id.to_string();
+                for retired in self.slug_history.get_all().await? {
+                    if retired.kind == SlugOwnerKind::Tag && retired.owner_id == owner_id {
+                        self.slug_history
+                            .release(SlugOwnerKind::Tag, &retired.slug)
+                            .await?;
+                    }
+                }
+                for mut post in posts.iter().cloned() {
+                    post.tag_ids.retain(|tag_id| *tag_id != id);
+                    self.posts_repo.update(&post).await?;
+                }
+                Ok(())
+            }))
+            .await?;
+
+        Ok(DeleteTagOutput {
+            detached_posts: posts.len() as i32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::make_interactor_setup;
+    use crate::posts::domain::{Post, PostId, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::tags::domain::Tag;
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::{assert_forbidden_error, assert_not_found_error};
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
+    use crate::utils::RetiredSlug;
+
+    use super::*;
+
+    make_interactor_setup!(
+        DeleteTagInteractor,
+        [
+            (
+                repo,
+                FakeTagsRepository::new_with_data(&[tag("rust"), tag("go")]),
+                FakeTagsRepository
+            ),
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&[
+                    post("1", &["rust", "go"]),
+                    post("2", &["rust"]),
+                    post("3", &["go"]),
+                ]),
+                FakePostsRepository
+            ),
+            (
+                slug_history,
+                FakeSlugHistoryRepository::new_with_data(&[
+                    retired("rustlang", "rust", SlugOwnerKind::Tag),
+                    retired("golang", "go", SlugOwnerKind::Tag),
+                    retired("rustacean", "rust", SlugOwnerKind::Category)
+                ]),
+                FakeSlugHistoryRepository
+            ),
+            (unit_of_work, UnitOfWorkSpy::default(), UnitOfWorkSpy),
+            (audit, AuditLogSpy::default(), AuditLogSpy)
+        ]
+    );
+
+    fn retired(slug: &str, owner_id: &str, kind: SlugOwnerKind) -> RetiredSlug {
+        RetiredSlug {
+            kind,
+            slug: slug.into(),
+            owner_id: owner_id.into(),
+            retired_at: Utc::now(),
+        }
+    }
+
+    fn tag(id: &str) -> Tag {
+        Tag {
+            id: id.into(),
+            name: id.to_string(),
+            slug: id.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn post(id: &str, tag_ids: &[&str]) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.to_string(),
+            slug: id.to_string(),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            category_id: None,
+            tag_ids: tag_ids.iter().map(|id| (*id).into()).collect(),
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("ID".into())
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, DeleteTagInput { id: "rust".into() })
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [DELETE_TAG_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_tag_does_not_exist() {
+        let c = create_interactor();
+
+        let err = c
+            .interactor
+            .execute(
+                &auth(),
+                DeleteTagInput {
+                    id: "missing".into(),
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_not_found_error(err);
+        assert_eq!(c.repo.get_tags().len(), 2);
+        assert_eq!(c.slug_history.get_retired().len(), 3);
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Failed);
+    }
+
+    #[tokio::test]
+    async fn should_delete_the_tag_and_detach_it_from_posts() {
+        let c = create_interactor();
+
+        let output = c
+            .interactor
+            .execute(&auth(), DeleteTagInput { id: "rust".into() })
+            .await
+            .unwrap();
+
+        assert_eq!(output.detached_posts, 2);
+        let tag_ids: Vec<Vec<TagId>> = c
+            .posts_repo
+            .get_posts()
+            .into_iter()
+            .map(|post| post.tag_ids)
+            .collect();
+        assert_eq!(tag_ids, [vec!["go".into()], vec![], vec![TagId::new("go")]]);
+        assert_eq!(c.repo.get_tags().len(), 1);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_release_the_slugs_the_tag_retired() {
+        let c = create_interactor();
+
+        c.interactor
+            .execute(&auth(), DeleteTagInput { id: "rust".into() })
+            .await
+            .unwrap();
+
+        let slugs: Vec<String> = c
+            .slug_history
+            .get_retired()
+            .into_iter()
+            .map(|retired| retired.slug)
+            .collect();
+        assert_eq!(slugs, ["golang", "rustacean"]);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_deletion_in_the_audit_log() {
+        let c = create_interactor();
+
+        c.interactor
+            .execute(&auth(), DeleteTagInput { id: "rust".into() })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: DELETE_TAG_ACTION.into(),
+                    target: "rust".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: DELETE_TAG_ACTION.into(),
+                    target: "rust".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::posts::interactors::traits::PostsRepository;
+use crate::tags::domain::TagId;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::tags::interactors::utils::VisibleTag;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TagWithCount {
+    pub tag: VisibleTag,
+    pub posts_count: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListTagsOutput {
+    pub tags: Vec<TagWithCount>,
+}
+
+#[derive(WithDeps)]
+pub struct ListTagsInteractor {
+    repo: Arc<dyn TagsRepository>,
+    posts_repo: Arc<dyn PostsRepository>,
+}
+
+impl ListTagsInteractor {
+    /// Lists every tag with the number of posts using it, most used first.
+    pub async fn execute(&self) -> ApplicationResult<ListTagsOutput> {
+        let mut counts: HashMap<TagId, i32> = HashMap::new();
+        for post in self.posts_repo.get_all().await? {
+            for tag_id in post.tag_ids {
+                *counts.entry(tag_id).or_default() += 1;
+            }
+        }
+
+        let mut tags: Vec<TagWithCount> = self
+            .repo
+            .get_all()
+            .await?
+            .into_iter()
+            .map(|tag| TagWithCount {
+                posts_count: counts.get(&tag.id).copied().unwrap_or_default(),
+                tag: tag.into(),
+            })
+            .collect();
+        tags.sort_by(|a, b| {
+            b.posts_count
+                .cmp(&a.posts_count)
+                .then_with(|| a.tag.name.to_lowercase().cmp(&b.tag.name.to_lowercase()))
+        });
+        Ok(ListTagsOutput { tags })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::posts::domain::{Post, PostId, PostStatus};
+    use crate::posts::interactors::test_doubles::fake_posts_repository::FakePostsRepository;
+    use crate::tags::domain::Tag;
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ListTagsInteractor,
+        [
+            (
+                repo,
+                FakeTagsRepository::new_with_data(&[tag("rust"), tag("go"), tag("c")]),
+                FakeTagsRepository
+            ),
+            (
+                posts_repo,
+                FakePostsRepository::new_with_data(&[
+                    post("1", &["rust", "go"]),
+                    post("2", &["rust"]),
+                    post("3", &[]),
+                ]),
+                FakePostsRepository
+            )
+        ]
+    );
+
+    fn tag(id: &str) -> Tag {
+        Tag {
+            id: id.into(),
+            name: id.to_string(),
+            slug: id.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn post(id: &str, tag_ids: &[&str]) -> Post {
+        Post {
+            id: PostId::new(id),
+            title: id.to_string(),
+            slug: id.to_string(),
+            content: "".to_string(),
+            author_id: "author".to_string(),
+            category_id: None,
+            tag_ids: tag_ids.iter().map(|id| (*id).into()).collect(),
+            status: PostStatus::Draft,
+            publish_at: None,
+            published_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_return_empty_if_no_tags() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_repo(Arc::new(FakeTagsRepository::new_empty()));
+
+        let output = c.interactor.execute().await.unwrap();
+
+        assert!(output.tags.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_count_posts_and_order_by_usage_then_name() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute().await.unwrap();
+
+        let counts: Vec<(String, i32)> = output
+            .tags
+            .into_iter()
+            .map(|t| (t.tag.name, t.posts_count))
+            .collect();
+        assert_eq!(
+            counts,
+            [
+                ("rust".to_string(), 2),
+                ("go".to_string(), 1),
+                ("c".to_string(), 0)
+            ]
+        );
+    }
+}
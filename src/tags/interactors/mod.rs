@@ -0,0 +1,9 @@
+pub mod actions;
+pub mod autocomplete_tags;
+pub mod create_tag;
+pub mod delete_tag;
+pub mod list_tags;
+pub mod rename_tag;
+pub mod test_doubles;
+pub mod traits;
+pub mod utils;
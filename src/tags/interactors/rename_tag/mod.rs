@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationException::DuplicationException;
+use crate::errors::ApplicationResult;
+use crate::tags::domain::TagId;
+use crate::tags::interactors::actions::RENAME_TAG_ACTION;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::tags::interactors::utils::{validate_tag_name, VisibleTag};
+use crate::utils::{
+    AuthPayload, Clock, RepositorySlugAvailability, RetiredSlug, SlugGenerator,
+    SlugHistoryRepository, SlugOwnerKind, Validatable,
+};
+
+#[derive(Debug, Clone)]
+pub struct RenameTagInput {
+    pub id: String,
+    pub name: String,
+}
+
+impl Validatable for RenameTagInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_tag_name(&self.name)
+    }
+}
+
+#[derive(WithDeps)]
+pub struct RenameTagInteractor {
+    repo: Arc<dyn TagsRepository>,
+    slug_history: Arc<dyn SlugHistoryRepository>,
+    slug_generator: Arc<dyn SlugGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl RenameTagInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: RenameTagInput,
+    ) -> ApplicationResult<VisibleTag> {
+        auth.can_or_fail(RENAME_TAG_ACTION)?;
+        input.validate()?;
+
+        let id: TagId = input.id.into();
+        let mut tag = self.repo.get_by_id_or_fail(&id).await?;
+
+        let name = input.name.trim().to_string();
+        if let Some(other) = self.repo.get_by_name(&name).await? {
+            if other.id != id {
+                return Err(DuplicationException {
+                    key: "name".into(),
+                    value: name,
+                });
+            }
+        }
+
+        let availability = RepositorySlugAvailability {
+            repo: self.repo.as_ref(),
+            slug_history: self.slug_history.as_ref(),
+            owner_id: Some(id.to_string()),
+        };
+        let slug = self.slug_generator.generate(&name, &availability).await?;
+
+        if slug != tag.slug {
+            let owner_id = id.to_string();
+            self.slug_history
+                .claim(SlugOwnerKind::Tag, &slug, Some(&owner_id), false)
+                .await?;
+            self.slug_history
+                .retire(&RetiredSlug {
+                    kind: SlugOwnerKind::Tag,
+                    slug: tag.slug.clone(),
+                    owner_id,
+                    retired_at: self.clock.now(),
+                })
+                .await?;
+        }
+
+        tag.name = name;
+        tag.slug = slug;
+        self.repo.update(&tag).await?;
+        Ok(tag.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::make_interactor_setup;
+    use crate::tags::domain::Tag;
+    use crate::tags::interactors::test_doubles::fake_tags_repository::FakeTagsRepository;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::clock::fake_clock::{fixed_now, FakeClock};
+    use crate::test_utils::errors_assertion::*;
+    use crate::test_utils::slugs::fake_slug_history_repository::FakeSlugHistoryRepository;
+    use crate::utils::TransliteratingSlugGenerator;
+
+    use super::*;
+
+    make_interactor_setup!(
+        RenameTagInteractor,
+        [
+            (
+                repo,
+                FakeTagsRepository::new_with_data(&[tag("1", "Rust"), tag("2", "Go")]),
+                FakeTagsRepository
+            ),
+            (
+                slug_history,
+                FakeSlugHistoryRepository::new_empty(),
+                FakeSlugHistoryRepository
+            ),
+            (
+                slug_generator,
+                TransliteratingSlugGenerator::default(),
+                TransliteratingSlugGenerator
+            ),
+            (clock, FakeClock::new_fixed(), FakeClock)
+        ]
+    );
+
+    fn tag(id: &str, name: &str) -> Tag {
+        Tag {
+            id: id.into(),
+            name: name.to_string(),
+            slug: name.to_lowercase(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn valid_input() -> RenameTagInput {
+        RenameTagInput {
+            id: "1".into(),
+            name: "Rust Lang".into(),
+        }
+    }
+
+    fn auth() -> AuthPayloadSpy {
+        AuthPayloadSpy::new_allowed("ID".into())
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, valid_input())
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [RENAME_TAG_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_if_tag_does_not_exist() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.id = "missing".into();
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_not_found_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_throw_duplication_error_if_another_tag_has_the_name() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.name = "go".into();
+
+        let err = c.interactor.execute(&auth(), input).await.unwrap_err();
+
+        assert_duplication_error(err, "name");
+    }
+
+    #[tokio::test]
+    async fn should_allow_changing_the_case_of_its_own_name() {
+        let c = create_interactor();
+        let mut input = valid_input();
+        input.name = "RUST".into();
+
+        let output = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(output.name, "RUST");
+        assert_eq!(output.slug, "rust");
+        assert!(c.slug_history.get_retired().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_update_the_slug_and_retire_the_old_one() {
+        let c = create_interactor();
+
+        let output = c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(output.slug, "rust-lang");
+        let stored = c.repo.get_by_id(&"1".into()).await.unwrap().unwrap();
+        assert_eq!(stored.name, "Rust Lang");
+        let retired = c
+            .slug_history
+            .get(SlugOwnerKind::Tag, "rust")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(retired.owner_id, "1");
+        assert_eq!(retired.retired_at, fixed_now());
+    }
+
+    #[tokio::test]
+    async fn should_take_back_its_own_retired_slug() {
+        let c = create_interactor();
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let mut input = valid_input();
+        input.name = "Rust".into();
+        let output = c.interactor.execute(&auth(), input).await.unwrap();
+
+        assert_eq!(output.slug, "rust");
+        let retired = c.slug_history.get_retired();
+        assert_eq!(retired.len(), 1);
+        assert_eq!(retired[0].slug, "rust-lang");
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::tags::domain::{Tag, TagId};
+use crate::tags::interactors::traits::TagsRepository;
+use crate::utils::DeletionResult;
+
+pub struct FakeTagsRepository {
+    pub tags: Mutex<Vec<Tag>>,
+}
+
+impl FakeTagsRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            tags: Mutex::new(Vec::new()),
+        }
+    }
+    pub fn new_with_data(tags: &[Tag]) -> Self {
+        Self {
+            tags: Mutex::new(tags.to_vec()),
+        }
+    }
+    pub fn get_tags(&self) -> Vec<Tag> {
+        self.tags.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl TagsRepository for FakeTagsRepository {
+    async fn get_by_id(&self, id: &TagId) -> UnknownResult<Option<Tag>> {
+        let tags = self.tags.lock().unwrap();
+        Ok(tags.iter().find(|tag| tag.id == *id).cloned())
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Tag>> {
+        let tags = self.tags.lock().unwrap();
+        Ok(tags.iter().find(|tag| tag.slug == slug).cloned())
+    }
+
+    async fn get_by_name(&self, name: &str) -> UnknownResult<Option<Tag>> {
+        let tags = self.tags.lock().unwrap();
+        let name = name.to_lowercase();
+        Ok(tags
+            .iter()
+            .find(|tag| tag.name.to_lowercase() == name)
+            .cloned())
+    }
+
+    async fn get_all(&self) -> UnknownResult<Vec<Tag>> {
+        Ok(self.tags.lock().unwrap().clone())
+    }
+
+    async fn get_by_prefix(&self, prefix: &str, limit: usize) -> UnknownResult<Vec<Tag>> {
+        let prefix = prefix.to_lowercase();
+        let mut tags: Vec<Tag> = self
+            .tags
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|tag| tag.name.to_lowercase().starts_with(&prefix))
+            .cloned()
+            .collect();
+        tags.sort_by_key(|tag| tag.name.to_lowercase());
+        tags.truncate(limit);
+        Ok(tags)
+    }
+
+    async fn create(&self, tag: &Tag) -> UnknownResult<Tag> {
+        self.tags.lock().unwrap().push(tag.clone());
+        Ok(tag.clone())
+    }
+
+    async fn update(&self, tag: &Tag) -> UnknownResult<Tag> {
+        let mut tags = self.tags.lock().unwrap();
+        let index = tags.iter().position(|t| t.id == tag.id).unwrap();
+        tags[index] = tag.clone();
+        Ok(tag.clone())
+    }
+
+    async fn delete(&self, id: &TagId) -> UnknownResult<DeletionResult> {
+        let mut tags = self.tags.lock().unwrap();
+        match tags.iter().position(|tag| tag.id == *id) {
+            Some(index) => {
+                tags.remove(index);
+                Ok(DeletionResult::Deleted)
+            }
+            None => Ok(DeletionResult::NotFound),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::tags::domain::{Tag, TagId};
+use crate::utils::DeletionResult;
+
+#[async_trait::async_trait]
+pub trait TagsRepository: Send + Sync {
+    async fn get_by_id(&self, id: &TagId) -> UnknownResult<Option<Tag>>;
+    async fn get_by_slug(&self, slug: &str) -> UnknownResult<Option<Tag>>;
+    /// Tag names are unique regardless of case, so the lookup ignores case.
+    async fn get_by_name(&self, name: &str) -> UnknownResult<Option<Tag>>;
+    async fn get_all(&self) -> UnknownResult<Vec<Tag>>;
+    /// Returns at most `limit` tags whose name starts with `prefix`, ignoring case, ordered by name.
+    async fn get_by_prefix(&self, prefix: &str, limit: usize) -> UnknownResult<Vec<Tag>>;
+    async fn create(&self, tag: &Tag) -> UnknownResult<Tag>;
+    async fn update(&self, tag: &Tag) -> UnknownResult<Tag>;
+    async fn delete(&self, id: &TagId) -> UnknownResult<DeletionResult>;
+
+    async fn get_by_id_or_fail(&self, id: &TagId) -> ApplicationResult<Tag> {
+        let tag = self.get_by_id(id).await?;
+        tag.ok_or_else(|| NotFoundException(format!("Tag with id {} not found", id)))
+    }
+}
@@ -0,0 +1,44 @@
+use crate::errors::validation::ValidationError;
+use crate::errors::UnknownResult;
+use crate::tags::domain::Tag;
+use crate::tags::interactors::traits::TagsRepository;
+use crate::utils::{SlugOwnerKind, SlugOwners};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct VisibleTag {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub created_at: String,
+}
+
+impl From<Tag> for VisibleTag {
+    fn from(tag: Tag) -> Self {
+        VisibleTag {
+            id: tag.id.to_string(),
+            name: tag.name,
+            slug: tag.slug,
+            created_at: tag.created_at.to_rfc2822(),
+        }
+    }
+}
+
+pub fn validate_tag_name(name: &str) -> Result<(), ValidationError> {
+    if name.trim().is_empty() {
+        return Err(ValidationError::new(
+            "name".into(),
+            name.into(),
+            "name is empty".into(),
+        ));
+    }
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl SlugOwners for dyn TagsRepository {
+    const KIND: SlugOwnerKind = SlugOwnerKind::Tag;
+
+    async fn get_owner_of(&self, slug: &str) -> UnknownResult<Option<String>> {
+        Ok(self.get_by_slug(slug).await?.map(|tag| tag.id.to_string()))
+    }
+}
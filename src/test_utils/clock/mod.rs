@@ -0,0 +1 @@
+pub mod fake_clock;
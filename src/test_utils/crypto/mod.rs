@@ -1,3 +1,4 @@
 pub mod authorizer_spy;
 pub mod crypto_service_spy;
 pub mod random_service_spy;
+pub mod sequential_random_service;
@@ -0,0 +1,34 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::utils::RandomService;
+
+/// Hands out `id-1`, `id-2`, ... for interactors that create several entities in one call.
+pub struct SequentialRandomService {
+    counter: Mutex<usize>,
+}
+
+#[async_trait::async_trait]
+impl RandomService for SequentialRandomService {
+    async fn secure_random_password(&self) -> UnknownResult<String> {
+        Ok(format!("password-{}", self.next()))
+    }
+
+    async fn random_id(&self) -> UnknownResult<String> {
+        Ok(format!("id-{}", self.next()))
+    }
+}
+
+impl SequentialRandomService {
+    pub fn new() -> Self {
+        Self {
+            counter: Mutex::new(0),
+        }
+    }
+
+    fn next(&self) -> usize {
+        let mut counter = self.counter.lock().unwrap();
+        *counter += 1;
+        *counter
+    }
+}
@@ -1,5 +1,8 @@
 pub mod access_management;
+pub mod clock;
 pub mod crypto;
 pub mod errors_assertion;
 #[macro_use]
 pub mod interactor_macro;
+pub mod slugs;
+pub mod unit_of_work;
@@ -0,0 +1,64 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::utils::{RetiredSlug, SlugHistoryRepository, SlugOwnerKind};
+
+pub struct FakeSlugHistoryRepository {
+    pub retired: Mutex<Vec<RetiredSlug>>,
+}
+
+#[async_trait::async_trait]
+impl SlugHistoryRepository for FakeSlugHistoryRepository {
+    async fn get(&self, kind: SlugOwnerKind, slug: &str) -> UnknownResult<Option<RetiredSlug>> {
+        let retired = self.retired.lock().unwrap();
+        Ok(retired
+            .iter()
+            .find(|r| r.kind == kind && r.slug == slug)
+            .cloned())
+    }
+
+    async fn get_all(&self) -> UnknownResult<Vec<RetiredSlug>> {
+        Ok(self.retired.lock().unwrap().clone())
+    }
+
+    async fn retire(&self, retired: &RetiredSlug) -> UnknownResult<()> {
+        let mut all = self.retired.lock().unwrap();
+        all.retain(|r| !(r.kind == retired.kind && r.slug == retired.slug));
+        all.push(retired.clone());
+        Ok(())
+    }
+
+    async fn release(&self, kind: SlugOwnerKind, slug: &str) -> UnknownResult<()> {
+        let mut all = self.retired.lock().unwrap();
+        all.retain(|r| !(r.kind == kind && r.slug == slug));
+        Ok(())
+    }
+
+    async fn retarget(&self, kind: SlugOwnerKind, from_id: &str, to_id: &str) -> UnknownResult<()> {
+        let mut all = self.retired.lock().unwrap();
+        for retired in all
+            .iter_mut()
+            .filter(|r| r.kind == kind && r.owner_id == from_id)
+        {
+            retired.owner_id = to_id.into();
+        }
+        Ok(())
+    }
+}
+
+#[allow(unused)]
+impl FakeSlugHistoryRepository {
+    pub fn new_empty() -> Self {
+        Self {
+            retired: Mutex::new(Vec::new()),
+        }
+    }
+    pub fn new_with_data(retired: &[RetiredSlug]) -> Self {
+        Self {
+            retired: Mutex::new(retired.to_vec()),
+        }
+    }
+    pub fn get_retired(&self) -> Vec<RetiredSlug> {
+        self.retired.lock().unwrap().clone()
+    }
+}
@@ -0,0 +1 @@
+pub mod fake_slug_history_repository;
@@ -0,0 +1,24 @@
+use std::sync::Mutex;
+
+use crate::errors::ApplicationResult;
+use crate::utils::{UnitOfWork, Work};
+
+/// Runs the work as it is and counts how often it was asked to.
+#[derive(Default)]
+pub struct UnitOfWorkSpy {
+    runs: Mutex<usize>,
+}
+
+impl UnitOfWorkSpy {
+    pub fn get_runs(&self) -> usize {
+        *self.runs.lock().unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfWork for UnitOfWorkSpy {
+    async fn run<'a>(&'a self, work: Work<'a>) -> ApplicationResult<()> {
+        *self.runs.lock().unwrap() += 1;
+        work.await
+    }
+}
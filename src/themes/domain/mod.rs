@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+/// The built-in theme, which also fills in every template another theme leaves out.
+pub const DEFAULT_THEME: &str = "default";
+
+/// The templates every theme is rendered through; others are partials they include.
+pub const POST_LAYOUT: &str = "layouts/post.html";
+pub const LISTING_LAYOUT: &str = "layouts/listing.html";
+
+/// Whether `name` can name a theme: not empty, and only ASCII letters, digits, `-` and `_`.
+pub fn is_valid_theme_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+}
+
+/// A theme as it is stored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    /// By path, such as `layouts/post.html` or `partials/pagination.html`.
+    pub templates: BTreeMap<String, String>,
+    /// Files copied to the site as they are, by their path below `assets/`.
+    pub assets: BTreeMap<String, Vec<u8>>,
+}
+
+impl Theme {
+    /// A theme without files of its own, rendered entirely by the default theme.
+    pub fn empty(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
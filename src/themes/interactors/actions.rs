@@ -0,0 +1,2 @@
+pub const LIST_THEMES_ACTION: &str = "LIST_THEMES_ACTION";
+pub const SWITCH_THEME_ACTION: &str = "SWITCH_THEME_ACTION";
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::themes::interactors::traits::{ThemeEngine, ThemeStore};
+use crate::themes::interactors::utils::activate_theme;
+
+/// Activates the theme last switched to, meant to run once at start-up.
+///
+/// Nothing happens if no theme was ever chosen. If the saved theme was removed or no longer
+/// compiles, the error is returned and the default theme stays active.
+#[derive(WithDeps)]
+pub struct ActivateSavedThemeInteractor {
+    store: Arc<dyn ThemeStore>,
+    engine: Arc<dyn ThemeEngine>,
+}
+
+impl ActivateSavedThemeInteractor {
+    pub async fn execute(&self) -> ApplicationResult<()> {
+        match self.store.get_active().await? {
+            Some(name) => activate_theme(&*self.store, &*self.engine, &name).await,
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::make_interactor_setup;
+    use crate::test_utils::errors_assertion::assert_not_found_error;
+    use crate::themes::domain::{Theme, DEFAULT_THEME};
+    use crate::themes::interactors::test_doubles::fake_theme_store::FakeThemeStore;
+    use crate::themes::interactors::test_doubles::theme_engine_spy::ThemeEngineSpy;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ActivateSavedThemeInteractor,
+        [
+            (
+                store,
+                FakeThemeStore::new_with_themes(&[Theme::empty("dark")]).with_active("dark"),
+                FakeThemeStore
+            ),
+            (
+                engine,
+                ThemeEngineSpy::new_active(DEFAULT_THEME),
+                ThemeEngineSpy
+            )
+        ]
+    );
+
+    #[tokio::test]
+    async fn should_activate_the_saved_theme() {
+        let c = create_interactor();
+
+        c.interactor.execute().await.unwrap();
+
+        assert_eq!(c.engine.get_activated(), [Theme::empty("dark")]);
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_default_theme_when_none_was_saved() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_store(Arc::new(FakeThemeStore::new_with_themes(&[Theme::empty(
+                "dark",
+            )])));
+
+        c.interactor.execute().await.unwrap();
+
+        assert!(c.engine.get_activated().is_empty());
+        assert_eq!(c.engine.active(), DEFAULT_THEME);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_error_when_the_saved_theme_was_removed() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_store(Arc::new(FakeThemeStore::new_empty().with_active("dark")));
+
+        let err = c.interactor.execute().await.unwrap_err();
+
+        assert_not_found_error(err);
+        assert_eq!(c.engine.active(), DEFAULT_THEME);
+    }
+}
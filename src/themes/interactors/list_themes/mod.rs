@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::errors::ApplicationResult;
+use crate::themes::domain::DEFAULT_THEME;
+use crate::themes::interactors::actions::LIST_THEMES_ACTION;
+use crate::themes::interactors::traits::{ThemeEngine, ThemeStore};
+use crate::utils::AuthPayload;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListThemesOutput {
+    pub active: String,
+    /// The default theme first, then the stored ones by name.
+    pub available: Vec<String>,
+}
+
+#[derive(WithDeps)]
+pub struct ListThemesInteractor {
+    store: Arc<dyn ThemeStore>,
+    engine: Arc<dyn ThemeEngine>,
+}
+
+impl ListThemesInteractor {
+    pub async fn execute(&self, auth: &(dyn AuthPayload)) -> ApplicationResult<ListThemesOutput> {
+        auth.can_or_fail(LIST_THEMES_ACTION)?;
+
+        let mut stored = self.store.list().await?;
+        stored.sort();
+        let mut available = vec![DEFAULT_THEME.to_string()];
+        available.extend(stored.into_iter().filter(|name| name != DEFAULT_THEME));
+        Ok(ListThemesOutput {
+            active: self.engine.active(),
+            available,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::make_interactor_setup;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::assert_forbidden_error;
+    use crate::themes::domain::Theme;
+    use crate::themes::interactors::test_doubles::fake_theme_store::FakeThemeStore;
+    use crate::themes::interactors::test_doubles::theme_engine_spy::ThemeEngineSpy;
+
+    use super::*;
+
+    make_interactor_setup!(
+        ListThemesInteractor,
+        [
+            (
+                store,
+                FakeThemeStore::new_with_themes(&[Theme::empty("light"), Theme::empty("dark")]),
+                FakeThemeStore
+            ),
+            (engine, ThemeEngineSpy::new_active("dark"), ThemeEngineSpy)
+        ]
+    );
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c.interactor.execute(&auth).await.unwrap_err();
+
+        assert_eq!(auth.get_called(), [LIST_THEMES_ACTION]);
+        assert_forbidden_error(err);
+    }
+
+    #[tokio::test]
+    async fn should_list_the_default_theme_first() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let output = c.interactor.execute(&auth).await.unwrap();
+
+        assert_eq!(
+            output,
+            ListThemesOutput {
+                active: "dark".into(),
+                available: vec!["default".into(), "dark".into(), "light".into()],
+            }
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod actions;
+pub mod activate_saved_theme;
+pub mod list_themes;
+pub mod switch_theme;
+pub mod test_doubles;
+pub mod traits;
+pub mod utils;
@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use with_deps_proc_macro::WithDeps;
+
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationResult;
+use crate::themes::domain::is_valid_theme_name;
+use crate::themes::interactors::actions::SWITCH_THEME_ACTION;
+use crate::themes::interactors::traits::{ThemeEngine, ThemeStore};
+use crate::themes::interactors::utils::activate_theme;
+use crate::utils::{AuthPayload, Validatable};
+
+#[derive(Debug, Clone)]
+pub struct SwitchThemeInput {
+    pub name: String,
+}
+
+impl Validatable for SwitchThemeInput {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if !is_valid_theme_name(&self.name) {
+            return Err(ValidationError::new(
+                "name".into(),
+                self.name.clone(),
+                "only letters, digits, - and _ are allowed".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Renders the public pages with another theme from now on.
+///
+/// The theme is loaded from the store again, so edits made to it since it was last active are
+/// picked up. The default theme is built in and can always be switched back to. The choice is
+/// saved in the store once the theme is active, so it survives a restart.
+#[derive(WithDeps)]
+pub struct SwitchThemeInteractor {
+    store: Arc<dyn ThemeStore>,
+    engine: Arc<dyn ThemeEngine>,
+    audit: Arc<dyn AuditLog>,
+}
+
+impl SwitchThemeInteractor {
+    pub async fn execute(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: SwitchThemeInput,
+    ) -> ApplicationResult<()> {
+        let target = input.name.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            SWITCH_THEME_ACTION,
+            &target,
+            self.switch(auth, input),
+        )
+        .await
+    }
+
+    async fn switch(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: SwitchThemeInput,
+    ) -> ApplicationResult<()> {
+        auth.can_or_fail(SWITCH_THEME_ACTION)?;
+        input.validate()?;
+
+        activate_theme(&*self.store, &*self.engine, &input.name).await?;
+        Ok(self.store.save_active(&input.name).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::make_interactor_setup;
+    use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
+    use crate::test_utils::errors_assertion::{
+        assert_forbidden_error, assert_not_found_error, assert_validation_error_with_key,
+    };
+    use crate::themes::domain::{Theme, DEFAULT_THEME};
+    use crate::themes::interactors::test_doubles::fake_theme_store::FakeThemeStore;
+    use crate::themes::interactors::test_doubles::theme_engine_spy::ThemeEngineSpy;
+
+    use super::*;
+
+    make_interactor_setup!(
+        SwitchThemeInteractor,
+        [
+            (
+                store,
+                FakeThemeStore::new_with_themes(&[Theme::empty("dark")]),
+                FakeThemeStore
+            ),
+            (
+                engine,
+                ThemeEngineSpy::new_active(DEFAULT_THEME),
+                ThemeEngineSpy
+            ),
+            (audit, AuditLogSpy::default(), AuditLogSpy)
+        ]
+    );
+
+    fn input(name: &str) -> SwitchThemeInput {
+        SwitchThemeInput { name: name.into() }
+    }
+
+    #[tokio::test]
+    async fn should_throw_forbidden_error_if_user_is_not_allowed() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input("dark"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(auth.get_called(), [SWITCH_THEME_ACTION]);
+        assert_forbidden_error(err);
+        assert!(c.engine.get_activated().is_empty());
+        assert_eq!(c.audit.get_recorded()[1].outcome, AuditOutcome::Denied);
+    }
+
+    #[tokio::test]
+    async fn should_activate_the_stored_theme() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor.execute(&auth, input("dark")).await.unwrap();
+
+        assert_eq!(c.engine.get_activated(), [Theme::empty("dark")]);
+        assert_eq!(c.engine.active(), "dark");
+        assert_eq!(c.store.get_saved_active(), Some("dark".into()));
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_for_an_invalid_name() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input("../dark"))
+            .await
+            .unwrap_err();
+
+        assert_validation_error_with_key(err, "name");
+        assert!(c.engine.get_activated().is_empty());
+        assert_eq!(c.store.get_saved_active(), None);
+    }
+
+    #[tokio::test]
+    async fn should_always_be_able_to_switch_back_to_the_default_theme() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_store(Arc::new(FakeThemeStore::new_empty()));
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor
+            .execute(&auth, input(DEFAULT_THEME))
+            .await
+            .unwrap();
+
+        assert_eq!(c.engine.get_activated(), [Theme::empty(DEFAULT_THEME)]);
+    }
+
+    #[tokio::test]
+    async fn should_throw_not_found_error_for_an_unknown_theme() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input("light"))
+            .await
+            .unwrap_err();
+
+        assert_not_found_error(err);
+        assert!(c.engine.get_activated().is_empty());
+        assert_eq!(c.store.get_saved_active(), None);
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_when_the_theme_does_not_compile() {
+        let mut c = create_interactor();
+        let engine = Arc::new(ThemeEngineSpy::new_failing(DEFAULT_THEME));
+        c.interactor.set_engine(engine.clone());
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        let err = c
+            .interactor
+            .execute(&auth, input("dark"))
+            .await
+            .unwrap_err();
+
+        assert_validation_error_with_key(err, "name");
+        assert_eq!(engine.active(), DEFAULT_THEME);
+        assert_eq!(c.store.get_saved_active(), None);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_switch_in_the_audit_log() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_allowed("ID".into());
+
+        c.interactor.execute(&auth, input("dark")).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: SWITCH_THEME_ACTION.into(),
+                    target: "dark".into(),
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: "ID".into(),
+                    action: SWITCH_THEME_ACTION.into(),
+                    target: "dark".into(),
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::themes::domain::Theme;
+use crate::themes::interactors::traits::ThemeStore;
+
+pub struct FakeThemeStore {
+    pub themes: BTreeMap<String, Theme>,
+    pub active: Mutex<Option<String>>,
+}
+
+#[allow(unused)]
+impl FakeThemeStore {
+    pub fn new_empty() -> Self {
+        Self::new_with_themes(&[])
+    }
+    pub fn new_with_themes(themes: &[Theme]) -> Self {
+        Self {
+            themes: themes
+                .iter()
+                .map(|theme| (theme.name.clone(), theme.clone()))
+                .collect(),
+            active: Mutex::new(None),
+        }
+    }
+    pub fn with_active(self, name: &str) -> Self {
+        *self.active.lock().unwrap() = Some(name.into());
+        self
+    }
+    pub fn get_saved_active(&self) -> Option<String> {
+        self.active.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl ThemeStore for FakeThemeStore {
+    async fn list(&self) -> UnknownResult<Vec<String>> {
+        Ok(self.themes.keys().cloned().collect())
+    }
+
+    async fn load(&self, name: &str) -> UnknownResult<Option<Theme>> {
+        Ok(self.themes.get(name).cloned())
+    }
+
+    async fn get_active(&self) -> UnknownResult<Option<String>> {
+        Ok(self.get_saved_active())
+    }
+
+    async fn save_active(&self, name: &str) -> UnknownResult<()> {
+        *self.active.lock().unwrap() = Some(name.into());
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod fake_theme_store;
+pub mod theme_engine_spy;
@@ -0,0 +1,47 @@
+use std::sync::Mutex;
+
+use crate::errors::UnknownResult;
+use crate::themes::domain::Theme;
+use crate::themes::interactors::traits::ThemeEngine;
+
+pub struct ThemeEngineSpy {
+    pub active: Mutex<String>,
+    pub activated: Mutex<Vec<Theme>>,
+    pub failing: bool,
+}
+
+#[allow(unused)]
+impl ThemeEngineSpy {
+    pub fn new_active(name: &str) -> Self {
+        Self {
+            active: Mutex::new(name.into()),
+            activated: Mutex::new(vec![]),
+            failing: false,
+        }
+    }
+    /// Refuses every theme as if its templates did not compile.
+    pub fn new_failing(name: &str) -> Self {
+        Self {
+            failing: true,
+            ..Self::new_active(name)
+        }
+    }
+    pub fn get_activated(&self) -> Vec<Theme> {
+        self.activated.lock().unwrap().clone()
+    }
+}
+
+impl ThemeEngine for ThemeEngineSpy {
+    fn active(&self) -> String {
+        self.active.lock().unwrap().clone()
+    }
+
+    fn activate(&self, theme: Theme) -> UnknownResult<()> {
+        if self.failing {
+            return Err("syntax error in layouts/post.html".into());
+        }
+        *self.active.lock().unwrap() = theme.name.clone();
+        self.activated.lock().unwrap().push(theme);
+        Ok(())
+    }
+}
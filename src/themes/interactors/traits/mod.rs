@@ -0,0 +1,20 @@
+use crate::errors::UnknownResult;
+use crate::themes::domain::Theme;
+
+/// Where themes are kept, each under its name.
+#[async_trait::async_trait]
+pub trait ThemeStore: Send + Sync {
+    async fn list(&self) -> UnknownResult<Vec<String>>;
+    /// `None` if there is no theme with that name.
+    async fn load(&self, name: &str) -> UnknownResult<Option<Theme>>;
+    /// The theme last switched to, `None` if there was none.
+    async fn get_active(&self) -> UnknownResult<Option<String>>;
+    async fn save_active(&self, name: &str) -> UnknownResult<()>;
+}
+
+/// Renders the public pages with one theme at a time, which can be replaced while the blog runs.
+pub trait ThemeEngine: Send + Sync {
+    fn active(&self) -> String;
+    /// Fails with the first template that does not compile and keeps the active theme then.
+    fn activate(&self, theme: Theme) -> UnknownResult<()>;
+}
@@ -0,0 +1,25 @@
+use crate::errors::validation::ValidationError;
+use crate::errors::ApplicationException::NotFoundException;
+use crate::errors::ApplicationResult;
+use crate::themes::domain::{Theme, DEFAULT_THEME};
+use crate::themes::interactors::traits::{ThemeEngine, ThemeStore};
+
+/// Loads the theme called `name` and hands it to the engine, which keeps the previous theme
+/// if this one does not compile.
+pub async fn activate_theme(
+    store: &dyn ThemeStore,
+    engine: &dyn ThemeEngine,
+    name: &str,
+) -> ApplicationResult<()> {
+    let theme = if name == DEFAULT_THEME {
+        Theme::empty(DEFAULT_THEME)
+    } else {
+        store
+            .load(name)
+            .await?
+            .ok_or_else(|| NotFoundException(format!("Theme with name {} not found", name)))?
+    };
+    engine
+        .activate(theme)
+        .map_err(|e| ValidationError::new("name".into(), name.into(), e.to_string()).into())
+}
@@ -0,0 +1,52 @@
+use crate::themes::domain::{Theme, DEFAULT_THEME};
+
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "layouts/base.html",
+        include_str!("default_theme/layouts/base.html"),
+    ),
+    (
+        "layouts/listing.html",
+        include_str!("default_theme/layouts/listing.html"),
+    ),
+    (
+        "layouts/post.html",
+        include_str!("default_theme/layouts/post.html"),
+    ),
+    (
+        "partials/breadcrumb.html",
+        include_str!("default_theme/partials/breadcrumb.html"),
+    ),
+    (
+        "partials/header.html",
+        include_str!("default_theme/partials/header.html"),
+    ),
+    (
+        "partials/pagination.html",
+        include_str!("default_theme/partials/pagination.html"),
+    ),
+    (
+        "partials/post_summary.html",
+        include_str!("default_theme/partials/post_summary.html"),
+    ),
+];
+
+const ASSETS: &[(&str, &[u8])] = &[(
+    "style.css",
+    include_bytes!("default_theme/assets/style.css"),
+)];
+
+/// The built-in theme, which ships inside the binary.
+pub fn default_theme() -> Theme {
+    Theme {
+        name: DEFAULT_THEME.into(),
+        templates: TEMPLATES
+            .iter()
+            .map(|(path, source)| (path.to_string(), source.to_string()))
+            .collect(),
+        assets: ASSETS
+            .iter()
+            .map(|(path, contents)| (path.to_string(), contents.to_vec()))
+            .collect(),
+    }
+}
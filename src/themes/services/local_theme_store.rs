@@ -0,0 +1,137 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use crate::errors::UnknownResult;
+use crate::markdown_sites::interactors::traits::SiteDirectory;
+use crate::markdown_sites::services::LocalSiteDirectory;
+use crate::themes::domain::{is_valid_theme_name, Theme, DEFAULT_THEME};
+use crate::themes::interactors::traits::ThemeStore;
+
+const ACTIVE_FILE: &str = ".active";
+
+/// Keeps every theme as a directory of its own:
+///
+/// ```text
+/// <root>/<theme>/layouts/post.html
+/// <root>/<theme>/partials/header.html
+/// <root>/<theme>/assets/logo.png
+/// ```
+///
+/// Files outside of these three directories, such as a README, are left out. No theme may be
+/// called like the default one, which is built in. The name of the active theme is kept in
+/// `<root>/.active`, which no theme can be called.
+pub struct LocalThemeStore {
+    root: PathBuf,
+}
+
+impl LocalThemeStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_of(&self, name: &str) -> UnknownResult<PathBuf> {
+        if !is_valid_theme_name(name) {
+            return Err(format!("invalid theme name {:?}", name).into());
+        }
+        Ok(self.root.join(name))
+    }
+}
+
+#[async_trait::async_trait]
+impl ThemeStore for LocalThemeStore {
+    async fn list(&self) -> UnknownResult<Vec<String>> {
+        let mut entries = match tokio::fs::read_dir(&self.root).await {
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            entries => entries?,
+        };
+        let mut names = vec![];
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if entry.file_type().await?.is_dir()
+                && name != DEFAULT_THEME
+                && self.path_of(&name).is_ok()
+            {
+                names.push(name);
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    async fn load(&self, name: &str) -> UnknownResult<Option<Theme>> {
+        let root = self.path_of(name)?;
+        if name == DEFAULT_THEME || !tokio::fs::metadata(&root).await.is_ok_and(|m| m.is_dir()) {
+            return Ok(None);
+        }
+        let mut theme = Theme::empty(name);
+        for path in LocalSiteDirectory::new(&root).list().await? {
+            if path.starts_with("layouts/") || path.starts_with("partials/") {
+                let source = tokio::fs::read_to_string(root.join(&path)).await?;
+                theme.templates.insert(path, source);
+            } else if let Some(asset) = path.strip_prefix("assets/") {
+                let contents = tokio::fs::read(root.join(&path)).await?;
+                theme.assets.insert(asset.into(), contents);
+            }
+        }
+        Ok(Some(theme))
+    }
+
+    async fn get_active(&self) -> UnknownResult<Option<String>> {
+        match tokio::fs::read_to_string(self.root.join(ACTIVE_FILE)).await {
+            Ok(name) => Ok(Some(name.trim().to_string())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn save_active(&self, name: &str) -> UnknownResult<()> {
+        tokio::fs::create_dir_all(&self.root).await?;
+        Ok(tokio::fs::write(self.root.join(ACTIVE_FILE), name).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_load_the_templates_and_assets_of_a_theme() {
+        let root = std::env::temp_dir().join(format!("themes-{}", std::process::id()));
+        let dark = root.join("dark");
+        for directory in ["layouts", "partials", "assets/img"] {
+            std::fs::create_dir_all(dark.join(directory)).unwrap();
+        }
+        std::fs::write(dark.join("layouts/post.html"), "{{ post.title }}").unwrap();
+        std::fs::write(dark.join("partials/header.html"), "<header>").unwrap();
+        std::fs::write(dark.join("assets/img/logo.png"), [0x89, 0x50, 0xff]).unwrap();
+        std::fs::write(dark.join("README.md"), "# Dark").unwrap();
+        std::fs::create_dir_all(root.join("default")).unwrap();
+        let store = LocalThemeStore::new(&root);
+
+        let theme = store.load("dark").await.unwrap().unwrap();
+
+        assert_eq!(store.list().await.unwrap(), ["dark"]);
+        assert_eq!(
+            theme.templates.keys().collect::<Vec<_>>(),
+            ["layouts/post.html", "partials/header.html"]
+        );
+        assert_eq!(theme.assets["img/logo.png"], [0x89, 0x50, 0xff]);
+        assert_eq!(store.load("light").await.unwrap(), None);
+        assert_eq!(store.load("default").await.unwrap(), None);
+        assert!(store.load("../dark").await.is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_active_theme() {
+        let root = std::env::temp_dir().join(format!("active-theme-{}", std::process::id()));
+        let store = LocalThemeStore::new(&root);
+
+        assert_eq!(store.get_active().await.unwrap(), None);
+        store.save_active("dark").await.unwrap();
+
+        assert_eq!(store.get_active().await.unwrap(), Some("dark".into()));
+        assert!(store.list().await.unwrap().is_empty());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use minijinja::{escape_formatter, AutoEscape, Environment, Error, Output, State, Value};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::errors::UnknownResult;
+use crate::static_sites::domain::{ListingPage, PostPage};
+use crate::static_sites::interactors::traits::SiteTemplates;
+use crate::themes::domain::{Theme, DEFAULT_THEME, LISTING_LAYOUT, POST_LAYOUT};
+use crate::themes::interactors::traits::ThemeEngine;
+use crate::themes::services::default_theme::default_theme;
+use crate::utils::escape_xml;
+
+/// Renders pages with Jinja templates.
+///
+/// A theme only has to bring the templates and assets it changes; everything else comes from
+/// the default theme, which is also the one active at first. Templates ending in `.html` are
+/// escaped automatically, so trusted HTML such as `post.html` has to be marked `|safe`.
+pub struct MiniJinjaThemeEngine {
+    active: RwLock<Arc<CompiledTheme>>,
+}
+
+struct CompiledTheme {
+    name: String,
+    environment: Environment<'static>,
+    /// A hash of every template, so pages are rendered again whenever one changes.
+    version: String,
+    assets: BTreeMap<String, Vec<u8>>,
+}
+
+impl Default for MiniJinjaThemeEngine {
+    fn default() -> Self {
+        let theme = Self::compile(Theme::empty(DEFAULT_THEME)).expect("default theme compiles");
+        Self {
+            active: RwLock::new(Arc::new(theme)),
+        }
+    }
+}
+
+impl MiniJinjaThemeEngine {
+    fn compile(theme: Theme) -> UnknownResult<CompiledTheme> {
+        let fallback = default_theme();
+        let mut templates = fallback.templates;
+        templates.extend(theme.templates);
+        let mut assets = fallback.assets;
+        assets.extend(theme.assets);
+
+        let mut environment = Environment::new();
+        environment.set_formatter(format_value);
+        let mut hasher = Sha256::new();
+        for (path, source) in templates {
+            hasher.update(path.as_bytes());
+            hasher.update([0]);
+            hasher.update(source.as_bytes());
+            hasher.update([0]);
+            environment.add_template_owned(path, source)?;
+        }
+        Ok(CompiledTheme {
+            version: format!("{}-{:x}", theme.name, hasher.finalize()),
+            name: theme.name,
+            environment,
+            assets,
+        })
+    }
+
+    fn current(&self) -> Arc<CompiledTheme> {
+        self.active.read().unwrap().clone()
+    }
+
+    fn render(&self, layout: &str, context: impl Serialize) -> UnknownResult<String> {
+        let theme = self.current();
+        Ok(theme.environment.get_template(layout)?.render(context)?)
+    }
+}
+
+/// Escapes strings the way the rest of the blog does, which leaves the slashes of URLs alone.
+fn format_value(out: &mut Output, state: &State, value: &Value) -> Result<(), Error> {
+    match value.as_str() {
+        Some(text) if !value.is_safe() && matches!(state.auto_escape(), AutoEscape::Html) => {
+            Ok(out.write_str(&escape_xml(text))?)
+        }
+        _ => escape_formatter(out, state, value),
+    }
+}
+
+impl ThemeEngine for MiniJinjaThemeEngine {
+    fn active(&self) -> String {
+        self.current().name.clone()
+    }
+
+    fn activate(&self, theme: Theme) -> UnknownResult<()> {
+        let compiled = Self::compile(theme)?;
+        *self.active.write().unwrap() = Arc::new(compiled);
+        Ok(())
+    }
+}
+
+impl SiteTemplates for MiniJinjaThemeEngine {
+    fn version(&self) -> String {
+        self.current().version.clone()
+    }
+
+    fn render_post(&self, page: &PostPage) -> UnknownResult<String> {
+        self.render(POST_LAYOUT, page)
+    }
+
+    fn render_listing(&self, page: &ListingPage) -> UnknownResult<String> {
+        self.render(LISTING_LAYOUT, page)
+    }
+
+    fn assets(&self) -> BTreeMap<String, Vec<u8>> {
+        self.current().assets.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::static_sites::domain::{
+        Link, Listing, ListingKind, Pagination, PostSummary, PublishedPost, SiteInfo,
+    };
+    use crate::test_utils::clock::fake_clock::fixed_now;
+
+    use super::*;
+
+    fn site() -> SiteInfo {
+        SiteInfo {
+            title: "Blog".into(),
+            description: "A blog".into(),
+            url: "https://blog.example.com".into(),
+        }
+    }
+
+    fn post_page() -> PostPage {
+        PostPage {
+            site: site(),
+            post: PublishedPost {
+                title: "Fish & <Chips>".into(),
+                url: "https://blog.example.com/posts/fish".into(),
+                published: fixed_now(),
+                author: Some(Link {
+                    name: "Jane".into(),
+                    url: "https://blog.example.com/authors/jane".into(),
+                }),
+                tags: vec![],
+                html: "<p>Hello</p>".into(),
+            },
+            breadcrumb: vec![Link {
+                name: "Food".into(),
+                url: "https://blog.example.com/categories/food".into(),
+            }],
+        }
+    }
+
+    fn listing_page(page: usize, pages: usize) -> ListingPage {
+        ListingPage {
+            site: site(),
+            listing: Listing {
+                kind: ListingKind::Category,
+                title: "Food".into(),
+                description: "".into(),
+                url: "https://blog.example.com/categories/food".into(),
+                feed_url: Some("https://blog.example.com/categories/food/feed.atom".into()),
+                children: vec![],
+                posts: vec![PostSummary {
+                    title: "Fish".into(),
+                    url: "https://blog.example.com/posts/fish".into(),
+                    published: fixed_now(),
+                    excerpt: "Fish and chips".into(),
+                }],
+            },
+            breadcrumb: vec![],
+            pagination: Pagination {
+                page,
+                pages,
+                previous_url: None,
+                next_url: (page < pages)
+                    .then(|| "https://blog.example.com/categories/food/page/2".into()),
+            },
+        }
+    }
+
+    fn theme(name: &str, templates: &[(&str, &str)]) -> Theme {
+        Theme {
+            name: name.into(),
+            templates: templates
+                .iter()
+                .map(|(path, source)| (path.to_string(), source.to_string()))
+                .collect(),
+            assets: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn should_render_with_the_default_theme_at_first() {
+        let engine = MiniJinjaThemeEngine::default();
+
+        let html = engine.render_post(&post_page()).unwrap();
+
+        assert_eq!(engine.active(), DEFAULT_THEME);
+        assert!(html.contains("<title>Fish &amp; &lt;Chips&gt; - Blog</title>"));
+        assert!(html.contains("<p>Hello</p>"));
+        assert!(html.contains(
+            "<ul class=\"breadcrumb\"><li><a href=\"https://blog.example.com/categories/food\">Food</a></li></ul>"
+        ));
+        assert!(html.contains(" by <a href=\"https://blog.example.com/authors/jane\">Jane</a>"));
+        assert!(engine.assets().contains_key("style.css"));
+    }
+
+    #[test]
+    fn should_only_link_the_next_page_of_a_listing_when_there_is_one() {
+        let engine = MiniJinjaThemeEngine::default();
+
+        let first = engine.render_listing(&listing_page(1, 2)).unwrap();
+        let only = engine.render_listing(&listing_page(1, 1)).unwrap();
+
+        assert!(first.contains(
+            "<a rel=\"next\" href=\"https://blog.example.com/categories/food/page/2\">Older</a>"
+        ));
+        assert!(first.contains("Page 1 of 2"));
+        assert!(!only.contains("class=\"pagination\""));
+        assert!(only.contains("<title>Food - Blog</title>"));
+        assert!(only.contains("href=\"https://blog.example.com/categories/food/feed.atom\""));
+    }
+
+    #[test]
+    fn should_fall_back_to_the_default_theme_for_missing_templates() {
+        let engine = MiniJinjaThemeEngine::default();
+        let default_version = engine.version();
+        let mut dark = theme(
+            "dark",
+            &[(
+                "partials/header.html",
+                "<header class=\"dark\">{{ site.title }}</header>",
+            )],
+        );
+        dark.assets.insert("style.css".into(), b"body{}".to_vec());
+
+        engine.activate(dark).unwrap();
+
+        let html = engine.render_post(&post_page()).unwrap();
+        assert_eq!(engine.active(), "dark");
+        assert!(html.contains("<header class=\"dark\">Blog</header>"));
+        assert!(html.contains("<h1>Fish &amp; &lt;Chips&gt;</h1>"));
+        assert_ne!(engine.version(), default_version);
+        assert_eq!(engine.assets()["style.css"], b"body{}");
+    }
+
+    #[test]
+    fn should_keep_the_active_theme_when_a_template_does_not_compile() {
+        let engine = MiniJinjaThemeEngine::default();
+        engine
+            .activate(theme("dark", &[("partials/header.html", "dark")]))
+            .unwrap();
+
+        let result = engine.activate(theme(
+            "broken",
+            &[("layouts/post.html", "{% if post.title %}unclosed")],
+        ));
+
+        assert!(result.is_err());
+        assert_eq!(engine.active(), "dark");
+        assert!(engine.render_post(&post_page()).unwrap().contains("dark"));
+    }
+}
@@ -0,0 +1,6 @@
+pub use local_theme_store::LocalThemeStore;
+pub use minijinja_theme_engine::MiniJinjaThemeEngine;
+
+mod default_theme;
+mod local_theme_store;
+mod minijinja_theme_engine;
@@ -4,8 +4,12 @@ use with_deps_proc_macro::WithDeps;
 
 use crate::errors::validation::ValidationError;
 use crate::errors::ApplicationResult;
+use crate::events::domain::PasswordChanged;
+use crate::events::interactors::traits::EventPublisher;
 use crate::users::interactors::traits::UsersRepository;
-use crate::utils::{AuthPayload, AuthPayloadResolver, Authorizer, CryptoService, Validatable};
+use crate::utils::{
+    AuthPayload, AuthPayloadResolver, Authorizer, CryptoService, UnitOfWork, Validatable,
+};
 
 pub struct ChangeMyPasswordInput {
     pub old_password: String,
@@ -30,6 +34,8 @@ pub struct ChangeMyPasswordInteractor {
     crypto: Arc<dyn CryptoService>,
     authorizer: Arc<dyn Authorizer>,
     auth_payload_resolver: Arc<dyn AuthPayloadResolver>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
 }
 
 impl ChangeMyPasswordInteractor {
@@ -48,13 +54,28 @@ impl ChangeMyPasswordInteractor {
         let password = self.crypto.hash(&input.new_password).await?;
 
         user.password = password;
-        self.repo.update(&user).await?;
-        Ok(())
+        self.unit_of_work
+            .run(Box::pin(async {
+                self.repo.update(&user).await?;
+                self.events
+                    .publish(
+                        PasswordChanged {
+                            user_id: user.id.clone(),
+                            changed_by: user.id,
+                        }
+                        .into(),
+                    )
+                    .await?;
+                Ok(())
+            }))
+            .await
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
     use crate::make_interactor_setup;
     use crate::test_utils::access_management::auth_payload_resolver_spy::AuthPayloadResolverSpy;
     use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
@@ -64,6 +85,7 @@ mod tests {
     use crate::test_utils::errors_assertion::{
         assert_bad_request_error, assert_validation_error_with_key,
     };
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
     use crate::users::domain::User;
     use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
 
@@ -89,7 +111,9 @@ mod tests {
                 auth_resolver,
                 AuthPayloadResolverSpy::new_returning(resolved_user()),
                 AuthPayloadResolverSpy
-            )
+            ),
+            (events, EventPublisherSpy::default(), EventPublisherSpy),
+            (unit_of_work, UnitOfWorkSpy::default(), UnitOfWorkSpy)
         ]
     );
 
@@ -159,6 +183,20 @@ mod tests {
 
         assert_eq!(c.repo.get_users()[0].password, HASH_RESULT);
     }
+    #[tokio::test]
+    async fn should_publish_password_changed_by_the_user_themselves() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let expected: DomainEvent = PasswordChanged {
+            user_id: resolved_user().id,
+            changed_by: resolved_user().id,
+        }
+        .into();
+        assert_eq!(c.events.get_published(), [expected]);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
     fn valid_input() -> ChangeMyPasswordInput {
         ChangeMyPasswordInput {
             old_password: "old_password".into(),
@@ -2,16 +2,23 @@ use std::sync::Arc;
 
 use with_deps_proc_macro::WithDeps;
 
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
 use crate::errors::ApplicationResult;
+use crate::events::domain::PasswordChanged;
+use crate::events::interactors::traits::EventPublisher;
 use crate::users::interactors::actions::CHANGE_OTHERS_PASSWORD_ACTION;
 use crate::users::interactors::traits::UsersRepository;
-use crate::utils::{AuthPayload, AuthWithPasswordValidator, CryptoService};
+use crate::utils::{AuthPayload, AuthWithPasswordValidator, CryptoService, UnitOfWork};
 
 #[derive(WithDeps)]
 pub struct ChangeUsersPasswordInteractor {
     repo: Arc<dyn UsersRepository>,
     crypto: Arc<dyn CryptoService>,
     auth_with_password_validator: Arc<dyn AuthWithPasswordValidator>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    audit: Arc<dyn AuditLog>,
 }
 
 pub struct ChangeUsersPasswordInput {
@@ -24,6 +31,22 @@ impl ChangeUsersPasswordInteractor {
         &self,
         auth: &(dyn AuthPayload),
         input: ChangeUsersPasswordInput,
+    ) -> ApplicationResult<()> {
+        let target = input.user_id.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            CHANGE_OTHERS_PASSWORD_ACTION,
+            &target,
+            self.change(auth, input),
+        )
+        .await
+    }
+
+    async fn change(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: ChangeUsersPasswordInput,
     ) -> ApplicationResult<()> {
         auth.can_or_fail(CHANGE_OTHERS_PASSWORD_ACTION)?;
 
@@ -33,13 +56,30 @@ impl ChangeUsersPasswordInteractor {
 
         let mut user = self.repo.get_by_id_or_fail(&input.user_id).await?;
         user.password = self.crypto.hash(&input.new_password).await?;
-        self.repo.update(&user).await?;
-        Ok(())
+        self.unit_of_work
+            .run(Box::pin(async {
+                self.repo.update(&user).await?;
+                self.events
+                    .publish(
+                        PasswordChanged {
+                            user_id: user.id,
+                            changed_by: auth.get_user_id(),
+                        }
+                        .into(),
+                    )
+                    .await?;
+                Ok(())
+            }))
+            .await
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
     use crate::make_interactor_setup;
     use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
     use crate::test_utils::access_management::auth_with_password_validator_spy::AuthWithPasswordValidatorSpy;
@@ -48,6 +88,7 @@ mod tests {
     use crate::test_utils::errors_assertion::{
         assert_bad_request_error, assert_forbidden_error, assert_not_found_error,
     };
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
     use crate::users::domain::User;
     use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
 
@@ -95,7 +136,10 @@ mod tests {
                 auth_with_password_validator,
                 AuthWithPasswordValidatorSpy::new_verified(),
                 AuthWithPasswordValidatorSpy
-            )
+            ),
+            (events, EventPublisherSpy::default(), EventPublisherSpy),
+            (unit_of_work, UnitOfWorkSpy::default(), UnitOfWorkSpy),
+            (audit, AuditLogSpy::default(), AuditLogSpy)
         ]
     );
     #[tokio::test]
@@ -202,4 +246,64 @@ mod tests {
             .unwrap();
         assert_eq!(user.password, HASH_RESULT);
     }
+
+    #[tokio::test]
+    async fn should_publish_password_changed_by_the_modifier() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let expected: DomainEvent = PasswordChanged {
+            user_id: modifying_user().id,
+            changed_by: auth().get_user_id(),
+        }
+        .into();
+        assert_eq!(c.events.get_published(), [expected]);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_change_in_the_audit_log() {
+        let c = create_interactor();
+
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: auth().get_user_id(),
+                    action: CHANGE_OTHERS_PASSWORD_ACTION.into(),
+                    target: modifying_user().id,
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: auth().get_user_id(),
+                    action: CHANGE_OTHERS_PASSWORD_ACTION.into(),
+                    target: modifying_user().id,
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_record_a_failed_attempt_in_the_audit_log() {
+        let mut c = create_interactor();
+        c.interactor.set_auth_with_password_validator(Arc::from(
+            AuthWithPasswordValidatorSpy::new_unverified(),
+        ));
+
+        c.interactor
+            .execute(&auth(), valid_input())
+            .await
+            .unwrap_err();
+
+        let recorded = c.audit.get_recorded();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[1].outcome, AuditOutcome::Failed);
+        assert!(recorded[1].detail.is_some());
+    }
 }
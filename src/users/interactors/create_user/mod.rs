@@ -7,11 +7,13 @@ use ApplicationException::*;
 use crate::access_management::RoleFactory;
 use crate::errors::validation::ValidationError;
 use crate::errors::{ApplicationException, ApplicationResult};
+use crate::events::domain::UserCreated;
+use crate::events::interactors::traits::EventPublisher;
 use crate::users::domain::User;
 use crate::users::interactors::actions::CREATE_USER_ACTION;
 use crate::users::interactors::traits::UsersRepository;
 use crate::utils::AuthPayload;
-use crate::utils::{CryptoService, RandomService, Validatable};
+use crate::utils::{CryptoService, RandomService, UnitOfWork, Validatable};
 
 #[derive(WithDeps)]
 pub struct CreateUserInteractor {
@@ -19,6 +21,8 @@ pub struct CreateUserInteractor {
     crypto_service: Arc<dyn CryptoService>,
     repo: Arc<dyn UsersRepository>,
     role_factory: Arc<dyn RoleFactory>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
 }
 
 impl CreateUserInteractor {
@@ -36,14 +40,31 @@ impl CreateUserInteractor {
 
         let role = self.role_factory.create_role(&input.role).unwrap();
 
-        self.repo
-            .create(&User {
-                email: input.email.clone(),
-                name: input.name.clone(),
-                password: password_hash,
-                role,
-                id: self.random_service.random_id().await?,
-            })
+        let id = self.random_service.random_id().await?;
+        self.unit_of_work
+            .run(Box::pin(async {
+                self.repo
+                    .create(&User {
+                        email: input.email.clone(),
+                        name: input.name.clone(),
+                        password: password_hash,
+                        role,
+                        id: id.clone(),
+                    })
+                    .await?;
+                self.events
+                    .publish(
+                        UserCreated {
+                            user_id: id,
+                            name: input.name.clone(),
+                            email: input.email.clone(),
+                            role: input.role.clone(),
+                        }
+                        .into(),
+                    )
+                    .await?;
+                Ok(())
+            }))
             .await?;
         Ok(CreateUserOutput {
             password: random_password,
@@ -128,6 +149,8 @@ pub struct CreateUserOutput {
 mod tests {
     use utils::*;
 
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
     use crate::make_interactor_setup;
     use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
     use crate::test_utils::access_management::role_factory_spy::RoleFactorySpy;
@@ -140,6 +163,7 @@ mod tests {
         assert_duplication_error, assert_forbidden_error, assert_validation_error,
         assert_validation_error_with_key,
     };
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
     use crate::users::domain::User;
     use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
 
@@ -159,7 +183,9 @@ mod tests {
                 role_factory,
                 RoleFactorySpy::new(Some(Box::from(RoleSpy::new_allowed()))),
                 RoleFactorySpy
-            )
+            ),
+            (events, EventPublisherSpy::default(), EventPublisherSpy),
+            (unit_of_work, UnitOfWorkSpy::default(), UnitOfWorkSpy)
         ]
     );
 
@@ -266,6 +292,23 @@ mod tests {
         assert_eq!(stored_user.id, RANDOM_ID);
     }
     #[tokio::test]
+    async fn should_publish_user_created_event() {
+        let c = create_interactor();
+
+        c.interactor.execute(valid_input(), &auth()).await.unwrap();
+
+        let input = valid_input();
+        let expected: DomainEvent = UserCreated {
+            user_id: RANDOM_ID.into(),
+            name: input.name,
+            email: input.email,
+            role: input.role,
+        }
+        .into();
+        assert_eq!(c.events.get_published(), [expected]);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
+    #[tokio::test]
     async fn should_return_random_password_on_result() {
         let c = create_interactor();
         let result = c.interactor.execute(valid_input(), &auth()).await.unwrap();
@@ -2,16 +2,23 @@ use std::sync::Arc;
 
 use with_deps_proc_macro::WithDeps;
 
+use crate::audit::interactors::traits::AuditLog;
+use crate::audit::interactors::utils::audited;
 use crate::errors::ApplicationResult;
+use crate::events::domain::UserDeleted;
+use crate::events::interactors::traits::EventPublisher;
 use crate::users::interactors::actions::DELETE_USER_ACTION;
 use crate::users::interactors::traits::UsersRepository;
-use crate::utils::{AuthPayload, AuthRevoker, AuthWithPasswordValidator};
+use crate::utils::{AuthPayload, AuthRevoker, AuthWithPasswordValidator, UnitOfWork};
 
 #[derive(WithDeps)]
 pub struct DeleteUserInteractor {
     repo: Arc<dyn UsersRepository>,
     auth_with_password_validator: Arc<dyn AuthWithPasswordValidator>,
     revoker: Arc<dyn AuthRevoker>,
+    events: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    audit: Arc<dyn AuditLog>,
 }
 
 pub struct DeleteUserInput {
@@ -24,6 +31,22 @@ impl DeleteUserInteractor {
         &self,
         auth: &(dyn AuthPayload),
         input: DeleteUserInput,
+    ) -> ApplicationResult<()> {
+        let target = input.id.clone();
+        audited(
+            self.audit.as_ref(),
+            auth,
+            DELETE_USER_ACTION,
+            &target,
+            self.delete(auth, input),
+        )
+        .await
+    }
+
+    async fn delete(
+        &self,
+        auth: &(dyn AuthPayload),
+        input: DeleteUserInput,
     ) -> ApplicationResult<()> {
         auth.can_or_fail(DELETE_USER_ACTION)?;
 
@@ -31,14 +54,25 @@ impl DeleteUserInteractor {
             .validate_or_fail(auth, &input.password)
             .await?;
         let user = self.repo.get_by_id_or_fail(&input.id).await?;
-        self.revoker.revoke_all_with_id(&user.id).await?;
-        self.repo.delete(&user.id).await?;
-        Ok(())
+        self.unit_of_work
+            .run(Box::pin(async {
+                self.revoker.revoke_all_with_id(&user.id).await?;
+                self.repo.delete(&user.id).await?;
+                self.events
+                    .publish(UserDeleted { user_id: user.id }.into())
+                    .await?;
+                Ok(())
+            }))
+            .await
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::audit::domain::{AuditOutcome, AuditRecord};
+    use crate::audit::interactors::test_doubles::audit_log_spy::AuditLogSpy;
+    use crate::events::domain::DomainEvent;
+    use crate::events::interactors::test_doubles::event_publisher_spy::EventPublisherSpy;
     use crate::make_interactor_setup;
     use crate::test_utils::access_management::auth_payload_revoker_spy::AuthRevokerSpy;
     use crate::test_utils::access_management::auth_payload_spy::AuthPayloadSpy;
@@ -47,6 +81,7 @@ mod tests {
     use crate::test_utils::errors_assertion::{
         assert_bad_request_error, assert_forbidden_error, assert_not_found_error,
     };
+    use crate::test_utils::unit_of_work::unit_of_work_spy::UnitOfWorkSpy;
     use crate::users::domain::User;
     use crate::users::interactors::mocks::fake_users_repository::FakeUsersRepository;
 
@@ -75,7 +110,10 @@ mod tests {
                 AuthWithPasswordValidatorSpy::new_verified(),
                 AuthWithPasswordValidatorSpy
             ),
-            (revoker, AuthRevokerSpy::new(), AuthRevokerSpy)
+            (revoker, AuthRevokerSpy::new(), AuthRevokerSpy),
+            (events, EventPublisherSpy::default(), EventPublisherSpy),
+            (unit_of_work, UnitOfWorkSpy::default(), UnitOfWorkSpy),
+            (audit, AuditLogSpy::default(), AuditLogSpy)
         ]
     );
     #[tokio::test]
@@ -143,6 +181,74 @@ mod tests {
         assert_eq!(c.repo.get_users().len(), 0);
     }
 
+    #[tokio::test]
+    async fn should_publish_user_deleted_event() {
+        let c = create_interactor();
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        let expected: DomainEvent = UserDeleted { user_id: user().id }.into();
+        assert_eq!(c.events.get_published(), [expected]);
+        assert_eq!(c.unit_of_work.get_runs(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_record_the_deletion_in_the_audit_log() {
+        let c = create_interactor();
+        c.interactor.execute(&auth(), valid_input()).await.unwrap();
+
+        assert_eq!(
+            c.audit.get_recorded(),
+            [
+                AuditRecord {
+                    actor_id: auth().get_user_id(),
+                    action: DELETE_USER_ACTION.into(),
+                    target: user().id,
+                    outcome: AuditOutcome::Started,
+                    detail: None,
+                },
+                AuditRecord {
+                    actor_id: auth().get_user_id(),
+                    action: DELETE_USER_ACTION.into(),
+                    target: user().id,
+                    outcome: AuditOutcome::Succeeded,
+                    detail: None,
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_record_a_denied_attempt_in_the_audit_log() {
+        let c = create_interactor();
+        let auth = AuthPayloadSpy::new_disallowed("intruder".to_string());
+
+        c.interactor
+            .execute(&auth, valid_input())
+            .await
+            .unwrap_err();
+
+        let recorded = c.audit.get_recorded();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[1].actor_id, "intruder");
+        assert_eq!(recorded[1].outcome, AuditOutcome::Denied);
+        assert_eq!(c.repo.get_users().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_delete_when_the_attempt_can_not_be_recorded() {
+        let mut c = create_interactor();
+        c.interactor
+            .set_audit(Arc::new(AuditLogSpy::new_failing_after(0)));
+
+        c.interactor
+            .execute(&auth(), valid_input())
+            .await
+            .unwrap_err();
+
+        assert_eq!(c.repo.get_users().len(), 1);
+        assert_eq!(c.unit_of_work.get_runs(), 0);
+    }
+
     pub fn valid_input() -> DeleteUserInput {
         DeleteUserInput {
             id: user().id,
@@ -1,5 +1,7 @@
 pub use enums::*;
+pub use services::*;
 pub use traits::*;
 
 mod enums;
+mod services;
 mod traits;
@@ -0,0 +1,346 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use ammonia::Builder;
+use regex::{Captures, Regex};
+
+use crate::utils::{HtmlSanitizer, SanitizationPolicy};
+
+const EXTERNAL_LINK_REL: &str = "nofollow noopener";
+
+/// Allowlist based sanitizer built on `ammonia`.
+///
+/// Scripts, styles, event handler attributes and non-http(s)/mailto urls are removed by both
+/// policies. Links pointing outside of `internal_hosts` get `rel="nofollow noopener"`; any
+/// `rel` the author wrote is dropped.
+pub struct AmmoniaHtmlSanitizer {
+    strict: Builder<'static>,
+    relaxed: Builder<'static>,
+    internal_hosts: HashSet<String>,
+    anchor_tag: Regex,
+    href: Regex,
+}
+
+impl AmmoniaHtmlSanitizer {
+    pub fn new(internal_hosts: &[&str]) -> Self {
+        Self {
+            strict: Self::strict_builder(),
+            relaxed: Self::relaxed_builder(),
+            internal_hosts: internal_hosts.iter().map(|h| h.to_lowercase()).collect(),
+            // ammonia serializes every attribute value double quoted with `"` escaped
+            anchor_tag: Regex::new(r#"<a((?:\s+[^\s="]+="[^"]*")*)>"#).unwrap(),
+            href: Regex::new(r#"\shref="([^"]*)""#).unwrap(),
+        }
+    }
+
+    fn strict_builder() -> Builder<'static> {
+        let mut builder = Builder::empty();
+        builder
+            .tags(HashSet::from([
+                "a",
+                "b",
+                "blockquote",
+                "br",
+                "code",
+                "del",
+                "em",
+                "i",
+                "li",
+                "ol",
+                "p",
+                "pre",
+                "s",
+                "strong",
+                "ul",
+            ]))
+            .clean_content_tags(HashSet::from(["script", "style"]))
+            .add_tag_attributes("a", ["href"])
+            .url_schemes(HashSet::from(["http", "https", "mailto"]))
+            .link_rel(None);
+        builder
+    }
+
+    fn relaxed_builder() -> Builder<'static> {
+        let mut builder = Builder::default();
+        builder
+            .url_schemes(HashSet::from(["http", "https", "mailto"]))
+            .add_generic_attributes(["class", "id"])
+            .add_tags(["input"])
+            .add_tag_attributes("input", ["type", "checked", "disabled"])
+            .attribute_filter(|element, attribute, value| match (element, attribute) {
+                // task list checkboxes are the only inputs the renderer emits
+                ("input", "type") if value != "checkbox" => None,
+                _ => Some(Cow::Borrowed(value)),
+            })
+            .link_rel(None);
+        builder
+    }
+
+    fn is_external(&self, href: &str) -> bool {
+        // browsers read `\` as `/`, skip tabs and newlines and take any number of slashes after
+        // an http(s) scheme, so `\\host` and `https:/host` lead off the site too
+        let href: String = href
+            .trim()
+            .chars()
+            .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+            .map(|c| if c == '\\' { '/' } else { c })
+            .collect();
+        let rest = match href.split_once(':') {
+            Some((scheme, rest))
+                if scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https") =>
+            {
+                rest
+            }
+            _ if href.starts_with("//") => &href,
+            _ => return false,
+        };
+        let rest = rest.trim_start_matches('/');
+        let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        let host = authority.rsplit('@').next().unwrap_or_default();
+        let host = host.split(':').next().unwrap_or_default().to_lowercase();
+        !self.internal_hosts.contains(&host)
+    }
+
+    fn mark_external_links(&self, html: &str) -> String {
+        self.anchor_tag
+            .replace_all(html, |caps: &Captures| {
+                let attributes = &caps[1];
+                let external = self
+                    .href
+                    .captures(attributes)
+                    .map(|href| self.is_external(&href[1].replace("&amp;", "&")))
+                    .unwrap_or(false);
+                if external {
+                    format!("<a{} rel=\"{}\">", attributes, EXTERNAL_LINK_REL)
+                } else {
+                    caps[0].to_string()
+                }
+            })
+            .into_owned()
+    }
+}
+
+impl HtmlSanitizer for AmmoniaHtmlSanitizer {
+    fn sanitize(&self, html: &str, policy: SanitizationPolicy) -> String {
+        let builder = match policy {
+            SanitizationPolicy::Strict => &self.strict,
+            SanitizationPolicy::Relaxed => &self.relaxed,
+        };
+        let cleaned = builder.clean(html).to_string();
+        self.mark_external_links(&cleaned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::ContentKind;
+
+    use super::*;
+
+    const POLICIES: [SanitizationPolicy; 2] =
+        [SanitizationPolicy::Strict, SanitizationPolicy::Relaxed];
+
+    fn sanitizer() -> AmmoniaHtmlSanitizer {
+        AmmoniaHtmlSanitizer::new(&["blog.example.com"])
+    }
+
+    fn known_vectors() -> Vec<&'static str> {
+        vec![
+            "<script>alert(1)</script>",
+            "<SCRIPT SRC=//evil.example/xss.js></SCRIPT>",
+            "<scr<script>ipt>alert(1)</script>",
+            "\"><script>alert(1)</script>",
+            "<img src=x onerror=alert(1)>",
+            "<img src=\"javascript:alert(1)\">",
+            "<svg/onload=alert(1)>",
+            "<svg><script>alert(1)</script></svg>",
+            "<math><mi xlink:href=\"javascript:alert(1)\">x</mi></math>",
+            "<body onload=alert(1)>",
+            "<iframe src=\"javascript:alert(1)\"></iframe>",
+            "<iframe srcdoc=\"<script>alert(1)</script>\"></iframe>",
+            "<object data=\"javascript:alert(1)\"></object>",
+            "<embed src=\"javascript:alert(1)\">",
+            "<a href=\"javascript:alert(1)\">x</a>",
+            "<a href=\"JaVaScRiPt:alert(1)\">x</a>",
+            "<a href=\" javascript:alert(1)\">x</a>",
+            "<a href=\"&#106;avascript:alert(1)\">x</a>",
+            "<a href=\"java&#x09;script:alert(1)\">x</a>",
+            "<a href=\"vbscript:msgbox(1)\">x</a>",
+            "<a href=\"data:text/html;base64,PHNjcmlwdD5hbGVydCgxKTwvc2NyaXB0Pg==\">x</a>",
+            "<form action=\"javascript:alert(1)\"><button>x</button></form>",
+            "<input onfocus=alert(1) autofocus>",
+            "<input type=\"image\" src=\"x\" onerror=\"alert(1)\">",
+            "<details open ontoggle=alert(1)>",
+            "<div style=\"background:url(javascript:alert(1))\">x</div>",
+            "<style>@import 'javascript:alert(1)';</style>",
+            "<meta http-equiv=\"refresh\" content=\"0;url=javascript:alert(1)\">",
+            "<base href=\"javascript:alert(1)//\">",
+            "<link rel=stylesheet href=\"javascript:alert(1)\">",
+            "<table background=\"javascript:alert(1)\"><tr><td>x</td></tr></table>",
+            "<!--<img src=\"--><img src=x onerror=alert(1)//\">",
+            "<noscript><p title=\"</noscript><img src=x onerror=alert(1)>\">",
+            "<a href=\"https://example.com\" onclick=\"alert(1)\">x</a>",
+            "<p onmouseover=\"alert(1)\">x</p>",
+        ]
+    }
+
+    /// Combines tags, event handlers and obfuscated schemes into a larger set of inputs.
+    fn generated_vectors() -> Vec<String> {
+        let tags = ["a", "img", "div", "p", "span", "input", "video", "td", "h2"];
+        let handlers = [
+            "onerror",
+            "onload",
+            "onclick",
+            "onmouseover",
+            "ONFOCUS",
+            "onanimationstart",
+        ];
+        let urls = [
+            "javascript:alert(1)",
+            "JAVASCRIPT:alert(1)",
+            "jav\tascript:alert(1)",
+            "&#x6A;avascript:alert(1)",
+            "vbscript:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+        ];
+        let mut vectors = vec![];
+        for tag in tags {
+            for handler in handlers {
+                vectors.push(format!("<{} {}=\"alert(1)\">x</{}>", tag, handler, tag));
+                vectors.push(format!("<{} {}=alert(1) x=\"\">x</{}>", tag, handler, tag));
+            }
+            for url in urls {
+                for attribute in [
+                    "href",
+                    "src",
+                    "action",
+                    "formaction",
+                    "poster",
+                    "background",
+                ] {
+                    vectors.push(format!("<{} {}=\"{}\">x</{}>", tag, attribute, url, tag));
+                }
+            }
+        }
+        vectors
+    }
+
+    fn assert_safe(input: &str, output: &str) {
+        let handler = Regex::new(r"(?i)<[^>]*\son[a-z]+\s*=").unwrap();
+        let scheme = Regex::new(r#"(?i)<[^>]*=\s*"\s*(javascript|vbscript|data):"#).unwrap();
+        let tag = Regex::new(
+            r"(?i)<\s*(script|style|iframe|object|embed|svg|math|form|meta|base|link)\b",
+        )
+        .unwrap();
+        assert!(!handler.is_match(output), "{} => {}", input, output);
+        assert!(!scheme.is_match(output), "{} => {}", input, output);
+        assert!(!tag.is_match(output), "{} => {}", input, output);
+    }
+
+    #[test]
+    fn should_neutralize_known_xss_vectors() {
+        let sanitizer = sanitizer();
+        for policy in POLICIES {
+            for input in known_vectors() {
+                assert_safe(input, &sanitizer.sanitize(input, policy));
+            }
+        }
+    }
+
+    #[test]
+    fn should_neutralize_generated_xss_vectors() {
+        let sanitizer = sanitizer();
+        for policy in POLICIES {
+            for input in generated_vectors() {
+                assert_safe(&input, &sanitizer.sanitize(&input, policy));
+            }
+        }
+    }
+
+    #[test]
+    fn should_keep_only_basic_formatting_under_the_strict_policy() {
+        let output = sanitizer().sanitize(
+            "<h1 id=\"x\">Title</h1><p><strong>bold</strong> <img src=\"https://x.example/a.png\"></p>",
+            SanitizationPolicy::Strict,
+        );
+
+        assert_eq!(output, "Title<p><strong>bold</strong> </p>");
+    }
+
+    #[test]
+    fn should_keep_rendered_markdown_under_the_relaxed_policy() {
+        let html = "<h2 id=\"install\">Install</h2>\
+            <pre class=\"code\"><code class=\"language-rust\"><span class=\"source rust\">fn</span></code></pre>\
+            <ul><li><input disabled=\"\" type=\"checkbox\" checked=\"\">done</li></ul>\
+            <table><tbody><tr><td>1</td></tr></tbody></table>";
+
+        let output = sanitizer().sanitize(html, SanitizationPolicy::Relaxed);
+
+        assert_eq!(output, html);
+    }
+
+    #[test]
+    fn should_drop_inputs_other_than_checkboxes() {
+        let output = sanitizer().sanitize("<input type=\"password\">", SanitizationPolicy::Relaxed);
+
+        assert_eq!(output, "<input>");
+    }
+
+    #[test]
+    fn should_force_rel_on_external_links_only() {
+        let sanitizer = sanitizer();
+        let cases = [
+            (
+                "<a href=\"https://other.example/x?a=1&b=2\" rel=\"follow\">x</a>",
+                "<a href=\"https://other.example/x?a=1&amp;b=2\" rel=\"nofollow noopener\">x</a>",
+            ),
+            (
+                "<a href=\"//other.example\">x</a>",
+                "<a href=\"//other.example\" rel=\"nofollow noopener\">x</a>",
+            ),
+            (
+                "<a href=\"https://Blog.Example.com:443/post\">x</a>",
+                "<a href=\"https://Blog.Example.com:443/post\">x</a>",
+            ),
+            (
+                "<a href=\"\\\\other.example\">x</a>",
+                "<a href=\"\\\\other.example\" rel=\"nofollow noopener\">x</a>",
+            ),
+            (
+                "<a href=\"https:/other.example\">x</a>",
+                "<a href=\"https:/other.example\" rel=\"nofollow noopener\">x</a>",
+            ),
+            (
+                "<a href=\"/\\other.example\">x</a>",
+                "<a href=\"/\\other.example\" rel=\"nofollow noopener\">x</a>",
+            ),
+            (
+                "<a href=\"https:/blog.example.com/post\">x</a>",
+                "<a href=\"https:/blog.example.com/post\">x</a>",
+            ),
+            ("<a href=\"/posts/1\">x</a>", "<a href=\"/posts/1\">x</a>"),
+            ("<a href=\"#install\">x</a>", "<a href=\"#install\">x</a>"),
+        ];
+        for policy in POLICIES {
+            for (input, expected) in cases {
+                assert_eq!(sanitizer.sanitize(input, policy), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn should_choose_the_policy_from_the_content_kind_and_trust() {
+        assert_eq!(
+            SanitizationPolicy::for_content(ContentKind::Post, true),
+            SanitizationPolicy::Relaxed
+        );
+        assert_eq!(
+            SanitizationPolicy::for_content(ContentKind::Post, false),
+            SanitizationPolicy::Strict
+        );
+        assert_eq!(
+            SanitizationPolicy::for_content(ContentKind::Comment, true),
+            SanitizationPolicy::Strict
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{DateTime, LocalResult, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::errors::ApplicationException::ValidationException;
+use crate::errors::ApplicationResult;
+
+/// The timezone authors think in when they pick a publish time.
+#[derive(Debug, Clone, Copy)]
+pub struct BlogTimezone {
+    tz: Tz,
+}
+
+impl BlogTimezone {
+    pub fn new(tz: Tz) -> Self {
+        Self { tz }
+    }
+
+    /// Converts a wall-clock time of the blog to UTC.
+    ///
+    /// A time repeated by a DST change resolves to its first occurrence; a time skipped by one
+    /// does not exist and is rejected.
+    pub fn to_utc(self, local: NaiveDateTime) -> ApplicationResult<DateTime<Utc>> {
+        match self.tz.from_local_datetime(&local) {
+            LocalResult::Single(time) | LocalResult::Ambiguous(time, _) => {
+                Ok(time.with_timezone(&Utc))
+            }
+            LocalResult::None => Err(ValidationException {
+                key: "publish_at".into(),
+                value: local.to_string(),
+                message: format!("{} does not exist in {}", local, self.tz),
+            }),
+        }
+    }
+
+    pub fn to_local(self, time: DateTime<Utc>) -> NaiveDateTime {
+        time.with_timezone(&self.tz).naive_local()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::test_utils::errors_assertion::assert_validation_error_with_key;
+
+    use super::*;
+
+    fn local(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&local(y, m, d, h, min))
+    }
+
+    #[test]
+    fn should_convert_local_time_to_utc() {
+        let timezone = BlogTimezone::new(chrono_tz::Asia::Tehran);
+
+        let monday_morning = timezone.to_utc(local(2026, 10, 19, 9, 0)).unwrap();
+
+        assert_eq!(monday_morning, utc(2026, 10, 19, 5, 30));
+        assert_eq!(timezone.to_local(monday_morning), local(2026, 10, 19, 9, 0));
+    }
+
+    #[test]
+    fn should_follow_daylight_saving_time() {
+        let timezone = BlogTimezone::new(chrono_tz::Europe::Berlin);
+
+        assert_eq!(
+            timezone.to_utc(local(2026, 7, 6, 9, 0)).unwrap(),
+            utc(2026, 7, 6, 7, 0)
+        );
+        assert_eq!(
+            timezone.to_utc(local(2026, 12, 7, 9, 0)).unwrap(),
+            utc(2026, 12, 7, 8, 0)
+        );
+    }
+
+    #[test]
+    fn should_pick_the_first_occurrence_of_a_repeated_time() {
+        let timezone = BlogTimezone::new(chrono_tz::Europe::Berlin);
+
+        let time = timezone.to_utc(local(2026, 10, 25, 2, 30)).unwrap();
+
+        assert_eq!(time, utc(2026, 10, 25, 0, 30));
+    }
+
+    #[test]
+    fn should_reject_a_time_skipped_by_daylight_saving() {
+        let timezone = BlogTimezone::new(chrono_tz::Europe::Berlin);
+
+        let err = timezone.to_utc(local(2026, 3, 29, 2, 30)).unwrap_err();
+
+        assert_validation_error_with_key(err, "publish_at");
+    }
+}
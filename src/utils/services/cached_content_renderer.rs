@@ -0,0 +1,148 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use sha2::{Digest, Sha256};
+
+use crate::utils::{ContentRenderer, RenderedContent};
+
+/// Caches the output of another renderer per content revision.
+///
+/// A revision is identified by the SHA-256 of its content, so editing a post misses the
+/// cache while re-rendering an unchanged one does not. The oldest revisions are evicted
+/// once `capacity` entries are stored.
+pub struct CachedContentRenderer {
+    inner: Arc<dyn ContentRenderer>,
+    capacity: usize,
+    cache: Mutex<RenderCache>,
+}
+
+#[derive(Default)]
+struct RenderCache {
+    entries: HashMap<[u8; 32], RenderedContent>,
+    order: VecDeque<[u8; 32]>,
+}
+
+impl CachedContentRenderer {
+    pub fn new(inner: Arc<dyn ContentRenderer>, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity,
+            cache: Mutex::new(RenderCache::default()),
+        }
+    }
+
+    pub fn invalidate(&self) {
+        *self.cache.lock().unwrap() = RenderCache::default();
+    }
+
+    fn revision_of(content: &str) -> [u8; 32] {
+        Sha256::digest(content.as_bytes()).into()
+    }
+}
+
+impl ContentRenderer for CachedContentRenderer {
+    fn render(&self, content: &str) -> RenderedContent {
+        let revision = Self::revision_of(content);
+        if let Some(rendered) = self.cache.lock().unwrap().entries.get(&revision) {
+            return rendered.clone();
+        }
+
+        let rendered = self.inner.render(content);
+        if self.capacity == 0 {
+            return rendered;
+        }
+
+        let mut cache = self.cache.lock().unwrap();
+        if cache.entries.insert(revision, rendered.clone()).is_none() {
+            cache.order.push_back(revision);
+        }
+        while cache.order.len() > self.capacity {
+            if let Some(oldest) = cache.order.pop_front() {
+                cache.entries.remove(&oldest);
+            }
+        }
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ContentRendererSpy {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl ContentRendererSpy {
+        fn new() -> Self {
+            Self {
+                calls: Mutex::new(vec![]),
+            }
+        }
+
+        fn get_calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    impl ContentRenderer for ContentRendererSpy {
+        fn render(&self, content: &str) -> RenderedContent {
+            self.calls.lock().unwrap().push(content.into());
+            RenderedContent {
+                html: format!("<p>{}</p>", content),
+                toc: vec![],
+            }
+        }
+    }
+
+    fn create_renderer(capacity: usize) -> (CachedContentRenderer, Arc<ContentRendererSpy>) {
+        let spy = Arc::new(ContentRendererSpy::new());
+        (CachedContentRenderer::new(spy.clone(), capacity), spy)
+    }
+
+    #[test]
+    fn should_render_each_revision_once() {
+        let (renderer, spy) = create_renderer(10);
+
+        let first = renderer.render("a");
+        let second = renderer.render("a");
+
+        assert_eq!(first, second);
+        assert_eq!(spy.get_calls(), ["a"]);
+    }
+
+    #[test]
+    fn should_render_again_when_the_content_changes() {
+        let (renderer, spy) = create_renderer(10);
+
+        renderer.render("a");
+        let rendered = renderer.render("b");
+
+        assert_eq!(rendered.html, "<p>b</p>");
+        assert_eq!(spy.get_calls(), ["a", "b"]);
+    }
+
+    #[test]
+    fn should_evict_the_oldest_revision_when_full() {
+        let (renderer, spy) = create_renderer(2);
+
+        renderer.render("a");
+        renderer.render("b");
+        renderer.render("c");
+        renderer.render("b");
+        renderer.render("a");
+
+        assert_eq!(spy.get_calls(), ["a", "b", "c", "a"]);
+    }
+
+    #[test]
+    fn should_render_again_after_invalidate() {
+        let (renderer, spy) = create_renderer(10);
+
+        renderer.render("a");
+        renderer.invalidate();
+        renderer.render("a");
+
+        assert_eq!(spy.get_calls(), ["a", "a"]);
+    }
+}
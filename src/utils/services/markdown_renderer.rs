@@ -0,0 +1,285 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use pulldown_cmark::{
+    html, CodeBlockKind, CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd,
+};
+use syntect::html::{ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+use crate::utils::{ContentRenderer, RenderedContent, SlugGenerator, TocEntry};
+
+/// Renders CommonMark with the GFM extensions (tables, footnotes, task lists, strikethrough).
+///
+/// Fenced code blocks with a known language are highlighted on the server with CSS classes
+/// (`ClassStyle::Spaced`), so the colors come from the theme's stylesheet. Every heading gets
+/// an anchor id, either the one given with `{#id}` or a slug of its text.
+pub struct MarkdownRenderer {
+    slug_generator: Arc<dyn SlugGenerator>,
+    syntaxes: SyntaxSet,
+}
+
+impl MarkdownRenderer {
+    pub fn new(slug_generator: Arc<dyn SlugGenerator>) -> Self {
+        Self {
+            slug_generator,
+            syntaxes: SyntaxSet::load_defaults_newlines(),
+        }
+    }
+
+    fn options() -> Options {
+        Options::ENABLE_TABLES
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_STRIKETHROUGH
+            | Options::ENABLE_HEADING_ATTRIBUTES
+    }
+
+    fn unique_anchor(
+        &self,
+        explicit: Option<&str>,
+        title: &str,
+        used: &mut HashSet<String>,
+    ) -> String {
+        let base = match explicit {
+            Some(id) => id.to_string(),
+            None => self.slug_generator.slugify(title),
+        };
+        let base = if base.is_empty() {
+            "section".to_string()
+        } else {
+            base
+        };
+        let mut anchor = base.clone();
+        let mut n = 2;
+        while !used.insert(anchor.clone()) {
+            anchor = format!("{}-{}", base, n);
+            n += 1;
+        }
+        anchor
+    }
+
+    fn highlight(&self, info: &str, code: &str) -> Option<String> {
+        let token = info.split_whitespace().next()?;
+        let syntax = self.syntaxes.find_syntax_by_token(token)?;
+        let mut generator =
+            ClassedHTMLGenerator::new_with_class_style(syntax, &self.syntaxes, ClassStyle::Spaced);
+        for line in LinesWithEndings::from(code) {
+            generator
+                .parse_html_for_line_which_includes_newline(line)
+                .ok()?;
+        }
+        Some(format!(
+            "<pre class=\"code\"><code class=\"language-{}\">{}</code></pre>\n",
+            escape_attribute(token),
+            generator.finalize()
+        ))
+    }
+}
+
+impl ContentRenderer for MarkdownRenderer {
+    fn render(&self, content: &str) -> RenderedContent {
+        let mut events: Vec<Event> = Vec::new();
+        let mut toc = Vec::new();
+        let mut used_anchors = HashSet::new();
+
+        let mut parser = Parser::new_ext(content, Self::options());
+        while let Some(event) = parser.next() {
+            match event {
+                Event::Start(Tag::Heading {
+                    level,
+                    id,
+                    classes,
+                    attrs,
+                }) => {
+                    let inner: Vec<Event> = parser
+                        .by_ref()
+                        .take_while(|e| !matches!(e, Event::End(TagEnd::Heading(_))))
+                        .collect();
+                    let title = plain_text(&inner);
+                    let anchor = self.unique_anchor(id.as_deref(), &title, &mut used_anchors);
+                    toc.push(TocEntry {
+                        level: heading_level(level),
+                        title,
+                        anchor: anchor.clone(),
+                    });
+                    events.push(Event::Start(Tag::Heading {
+                        level,
+                        id: Some(CowStr::from(anchor)),
+                        classes,
+                        attrs,
+                    }));
+                    events.extend(inner);
+                    events.push(Event::End(TagEnd::Heading(level)));
+                }
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                    let inner: Vec<Event> = parser
+                        .by_ref()
+                        .take_while(|e| !matches!(e, Event::End(TagEnd::CodeBlock)))
+                        .collect();
+                    let code = plain_text(&inner);
+                    match self.highlight(&info, &code) {
+                        Some(highlighted) => events.push(Event::Html(highlighted.into())),
+                        None => {
+                            events.push(Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))));
+                            events.extend(inner);
+                            events.push(Event::End(TagEnd::CodeBlock));
+                        }
+                    }
+                }
+                event => events.push(event),
+            }
+        }
+
+        let mut output = String::new();
+        html::push_html(&mut output, events.into_iter());
+        RenderedContent { html: output, toc }
+    }
+}
+
+/// The text of a markdown document without its markup, blocks separated by whitespace.
+pub fn markdown_to_text(markdown: &str) -> String {
+    let mut text = String::new();
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Text(t) | Event::Code(t) => text.push_str(&t),
+            Event::End(
+                TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Link
+                | TagEnd::Image,
+            ) => {}
+            Event::SoftBreak | Event::HardBreak | Event::End(_) => text.push(' '),
+            _ => {}
+        }
+    }
+    text.trim_end().to_string()
+}
+
+fn plain_text(events: &[Event]) -> String {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::TransliteratingSlugGenerator;
+
+    use super::*;
+
+    fn render(content: &str) -> RenderedContent {
+        MarkdownRenderer::new(Arc::new(TransliteratingSlugGenerator::default())).render(content)
+    }
+
+    #[test]
+    fn should_render_commonmark() {
+        let rendered = render("Hello *world*\n\n> quote");
+
+        assert_eq!(
+            rendered.html,
+            "<p>Hello <em>world</em></p>\n<blockquote>\n<p>quote</p>\n</blockquote>\n"
+        );
+    }
+
+    #[test]
+    fn should_render_gfm_tables_task_lists_and_footnotes() {
+        let rendered = render(
+            "| a | b |\n|---|---|\n| 1 | 2 |\n\n- [x] done\n- [ ] todo\n\nnote[^1]\n\n[^1]: the note\n",
+        );
+
+        assert!(rendered.html.contains("<table>"));
+        assert!(rendered.html.contains("<td>1</td>"));
+        assert!(rendered
+            .html
+            .contains("<input disabled=\"\" type=\"checkbox\" checked=\"\"/>"));
+        assert!(rendered.html.contains("class=\"footnote-reference\""));
+        assert!(rendered.html.contains("class=\"footnote-definition\""));
+    }
+
+    #[test]
+    fn should_add_anchor_ids_to_headings_and_build_the_toc() {
+        let rendered = render("# Getting `started`\n\n## Install\n\n### Install\n");
+
+        assert!(rendered
+            .html
+            .contains("<h1 id=\"getting-started\">Getting <code>started</code></h1>"));
+        assert!(rendered.html.contains("<h3 id=\"install-2\">Install</h3>"));
+        assert_eq!(
+            rendered.toc,
+            [
+                TocEntry {
+                    level: 1,
+                    title: "Getting started".into(),
+                    anchor: "getting-started".into(),
+                },
+                TocEntry {
+                    level: 2,
+                    title: "Install".into(),
+                    anchor: "install".into(),
+                },
+                TocEntry {
+                    level: 3,
+                    title: "Install".into(),
+                    anchor: "install-2".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_keep_explicit_heading_ids() {
+        let rendered = render("## Setup {#custom}\n");
+
+        assert!(rendered.html.contains("<h2 id=\"custom\">Setup</h2>"));
+        assert_eq!(rendered.toc[0].anchor, "custom");
+    }
+
+    #[test]
+    fn should_highlight_fenced_code_with_a_known_language() {
+        let rendered = render("```rust\nfn main() {}\n```\n");
+
+        assert!(rendered
+            .html
+            .starts_with("<pre class=\"code\"><code class=\"language-rust\">"));
+        assert!(rendered.html.contains("<span class=\"source rust\">"));
+        assert!(rendered
+            .html
+            .contains("<span class=\"storage type function rust\">fn</span>"));
+    }
+
+    #[test]
+    fn should_leave_code_of_unknown_languages_escaped_and_unhighlighted() {
+        let rendered = render("```nope\n<b>\n```\n");
+
+        assert_eq!(
+            rendered.html,
+            "<pre><code class=\"language-nope\">&lt;b&gt;\n</code></pre>\n"
+        );
+    }
+}
@@ -0,0 +1,22 @@
+pub use ammonia_html_sanitizer::AmmoniaHtmlSanitizer;
+pub use blog_timezone::BlogTimezone;
+pub use cached_content_renderer::CachedContentRenderer;
+pub use markdown_renderer::{markdown_to_text, MarkdownRenderer};
+pub use repository_slug_availability::RepositorySlugAvailability;
+pub use run_every::run_every;
+pub use site_urls::SiteUrls;
+pub use system_clock::SystemClock;
+pub use transliterating_slug_generator::{SlugGeneratorConfig, TransliteratingSlugGenerator};
+pub use xml::{escape_xml, push_xml_element};
+
+mod ammonia_html_sanitizer;
+mod blog_timezone;
+mod cached_content_renderer;
+mod markdown_renderer;
+mod repository_slug_availability;
+mod run_every;
+mod site_urls;
+mod system_clock;
+mod transliterating_slug_generator;
+mod transliteration_tables;
+mod xml;
@@ -0,0 +1,28 @@
+use crate::errors::UnknownResult;
+use crate::utils::{SlugAvailability, SlugHistoryRepository, SlugOwners};
+
+/// A slug is available to an entity when neither another entity of the same kind nor a slug
+/// another one retired uses it. `owner_id` is `None` for an entity that does not exist yet.
+pub struct RepositorySlugAvailability<'a, R: ?Sized> {
+    pub repo: &'a R,
+    pub slug_history: &'a dyn SlugHistoryRepository,
+    pub owner_id: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl<'a, R: SlugOwners + ?Sized> SlugAvailability for RepositorySlugAvailability<'a, R> {
+    async fn is_available(&self, slug: &str) -> UnknownResult<bool> {
+        let is_owner = |id: String| Some(id) == self.owner_id;
+        if let Some(owner_id) = self.repo.get_owner_of(slug).await? {
+            if !is_owner(owner_id) {
+                return Ok(false);
+            }
+        }
+        if let Some(retired) = self.slug_history.get(R::KIND, slug).await? {
+            if !is_owner(retired.owner_id) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,31 @@
+use std::future::Future;
+use std::time::Duration;
+
+use crate::errors::{UnknownException, UnknownResult};
+
+/// Runs `task` every `interval` until `shutdown` completes. A failed run is handed to
+/// `on_error` and the task is run again on the next tick; a run that overlaps the next tick
+/// delays it rather than queuing another.
+pub async fn run_every<T, F, Fut>(
+    interval: Duration,
+    shutdown: impl Future<Output = ()>,
+    on_error: impl Fn(UnknownException),
+    mut task: F,
+) where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = UnknownResult<T>>,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    tokio::pin!(shutdown);
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => return,
+            _ = ticker.tick() => {
+                if let Err(e) = task().await {
+                    on_error(e);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+/// Builds the absolute URLs of the public pages, so feeds, sitemaps and the pages themselves
+/// agree on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiteUrls {
+    base: String,
+}
+
+impl SiteUrls {
+    /// `base` is the absolute URL of the home page, e.g. `https://blog.example.com`.
+    pub fn new(base: &str) -> Self {
+        Self {
+            base: base.trim_end_matches('/').into(),
+        }
+    }
+
+    pub fn home(&self) -> String {
+        self.base.clone()
+    }
+
+    pub fn post(&self, slug: &str) -> String {
+        self.page(&format!("posts/{}", slug))
+    }
+
+    pub fn category(&self, slug: &str) -> String {
+        self.page(&format!("categories/{}", slug))
+    }
+
+    pub fn tag(&self, slug: &str) -> String {
+        self.page(&format!("tags/{}", slug))
+    }
+
+    pub fn author(&self, id: &str) -> String {
+        self.page(&format!("authors/{}", id))
+    }
+
+    pub fn page(&self, path: &str) -> String {
+        format!("{}/{}", self.base, path.trim_start_matches('/'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_join_paths_to_the_base_without_doubled_slashes() {
+        let urls = SiteUrls::new("https://blog.example.com/");
+
+        assert_eq!(urls.home(), "https://blog.example.com");
+        assert_eq!(urls.post("hello"), "https://blog.example.com/posts/hello");
+        assert_eq!(
+            urls.page("/sitemap.xml"),
+            "https://blog.example.com/sitemap.xml"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+
+use crate::utils::Clock;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -0,0 +1,289 @@
+use crate::errors::ApplicationException::{DuplicationException, ValidationException};
+use crate::errors::ApplicationResult;
+use crate::utils::services::transliteration_tables::transliterate;
+use crate::utils::{SlugAvailability, SlugGenerator};
+
+const MAX_SUFFIX_ATTEMPTS: u32 = 1000;
+
+#[derive(Debug, Clone)]
+pub struct SlugGeneratorConfig {
+    pub max_length: usize,
+    pub reserved_words: Vec<String>,
+    /// When disabled, letters of non-Latin scripts are kept as they are instead of romanized.
+    pub transliterate: bool,
+}
+
+impl Default for SlugGeneratorConfig {
+    fn default() -> Self {
+        SlugGeneratorConfig {
+            max_length: 80,
+            reserved_words: [
+                "admin", "api", "atom", "edit", "feed", "login", "logout", "new", "rss", "search",
+                "sitemap",
+            ]
+            .iter()
+            .map(|word| word.to_string())
+            .collect(),
+            transliterate: true,
+        }
+    }
+}
+
+/// Builds lowercase, dash separated slugs.
+///
+/// Persian/Arabic, Cyrillic and Greek letters are romanized with the tables in
+/// `transliteration_tables`, accented Latin letters are folded to ASCII and letters of any
+/// other script (CJK for instance) are kept as Unicode, since there is no sensible
+/// character-by-character romanization for them.
+#[derive(Debug, Clone, Default)]
+pub struct TransliteratingSlugGenerator {
+    config: SlugGeneratorConfig,
+}
+
+impl TransliteratingSlugGenerator {
+    pub fn new(config: SlugGeneratorConfig) -> Self {
+        Self { config }
+    }
+
+    fn romanize(&self, c: char, out: &mut String) {
+        if c.is_ascii() {
+            out.push(c);
+            return;
+        }
+        if self.config.transliterate {
+            if let Some(latin) = transliterate(c) {
+                out.push_str(latin);
+                return;
+            }
+            if Self::is_latin(c) {
+                if let Some(folded) = deunicode::deunicode_char(c) {
+                    out.extend(folded.chars().flat_map(char::to_lowercase));
+                    return;
+                }
+            }
+        }
+        out.push(c);
+    }
+
+    fn is_latin(c: char) -> bool {
+        matches!(c, '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}')
+    }
+
+    fn truncate(&self, slug: &str, max_length: usize) -> String {
+        let truncated: String = slug.chars().take(max_length).collect();
+        truncated.trim_end_matches('-').to_string()
+    }
+
+    fn with_suffix(&self, base: &str, n: u32) -> String {
+        let suffix = format!("-{}", n);
+        let max_length = self.config.max_length.saturating_sub(suffix.len());
+        format!("{}{}", self.truncate(base, max_length), suffix)
+    }
+}
+
+#[async_trait::async_trait]
+impl SlugGenerator for TransliteratingSlugGenerator {
+    fn slugify(&self, input: &str) -> String {
+        let mut romanized = String::new();
+        for c in input.chars().flat_map(char::to_lowercase) {
+            self.romanize(c, &mut romanized);
+        }
+
+        let mut slug = String::new();
+        let mut pending_separator = false;
+        for c in romanized.chars() {
+            if c.is_alphanumeric() {
+                if pending_separator && !slug.is_empty() {
+                    slug.push('-');
+                }
+                slug.push(c);
+                pending_separator = false;
+            } else {
+                pending_separator = true;
+            }
+        }
+        self.truncate(&slug, self.config.max_length)
+    }
+
+    fn is_reserved(&self, slug: &str) -> bool {
+        self.config.reserved_words.iter().any(|word| word == slug)
+    }
+
+    async fn generate(
+        &self,
+        input: &str,
+        availability: &(dyn SlugAvailability),
+    ) -> ApplicationResult<String> {
+        let base = self.slugify(input);
+        if base.is_empty() {
+            return Err(ValidationException {
+                key: "slug".into(),
+                value: input.into(),
+                message: "can not generate a slug from the input".into(),
+            });
+        }
+
+        if !self.is_reserved(&base) && availability.is_available(&base).await? {
+            return Ok(base);
+        }
+        for n in 2..MAX_SUFFIX_ATTEMPTS {
+            let candidate = self.with_suffix(&base, n);
+            if availability.is_available(&candidate).await? {
+                return Ok(candidate);
+            }
+        }
+        Err(DuplicationException {
+            key: "slug".into(),
+            value: base,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use crate::errors::UnknownResult;
+    use crate::test_utils::errors_assertion::assert_validation_error_with_key;
+
+    use super::*;
+
+    struct TakenSlugs {
+        taken: Vec<String>,
+        asked: Mutex<Vec<String>>,
+    }
+
+    impl TakenSlugs {
+        fn new(taken: &[&str]) -> Self {
+            Self {
+                taken: taken.iter().map(|s| s.to_string()).collect(),
+                asked: Mutex::new(vec![]),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SlugAvailability for TakenSlugs {
+        async fn is_available(&self, slug: &str) -> UnknownResult<bool> {
+            self.asked.lock().unwrap().push(slug.into());
+            Ok(!self.taken.iter().any(|taken| taken == slug))
+        }
+    }
+
+    fn generator() -> TransliteratingSlugGenerator {
+        TransliteratingSlugGenerator::default()
+    }
+
+    #[test]
+    fn should_slugify_ascii_text() {
+        assert_eq!(generator().slugify("Hello World :)"), "hello-world");
+        assert_eq!(generator().slugify("  --Rust 2021--  "), "rust-2021");
+    }
+
+    #[test]
+    fn should_fold_accented_latin_letters() {
+        assert_eq!(
+            generator().slugify("Crème Brûlée à Paris"),
+            "creme-brulee-a-paris"
+        );
+    }
+
+    #[test]
+    fn should_transliterate_persian_and_arabic() {
+        assert_eq!(generator().slugify("سلام دنیا"), "slam-dnya");
+        assert_eq!(generator().slugify("می‌خواهم"), "mykhvahm");
+        assert_eq!(generator().slugify("مقاله ۱۲"), "mqalh-12");
+        assert_eq!(generator().slugify("كِتَاب"), "ktab");
+    }
+
+    #[test]
+    fn should_transliterate_cyrillic_and_greek() {
+        assert_eq!(generator().slugify("Привет, мир"), "privet-mir");
+        assert_eq!(generator().slugify("Καλημέρα κόσμε"), "kalimera-kosme");
+    }
+
+    #[test]
+    fn should_keep_scripts_without_a_table_as_unicode() {
+        assert_eq!(generator().slugify("東京 Tower"), "東京-tower");
+    }
+
+    #[test]
+    fn should_keep_non_latin_letters_when_transliteration_is_disabled() {
+        let generator = TransliteratingSlugGenerator::new(SlugGeneratorConfig {
+            transliterate: false,
+            ..SlugGeneratorConfig::default()
+        });
+
+        assert_eq!(generator.slugify("سلام دنیا"), "سلام-دنیا");
+    }
+
+    #[test]
+    fn should_respect_the_max_length_without_a_trailing_dash() {
+        let generator = TransliteratingSlugGenerator::new(SlugGeneratorConfig {
+            max_length: 11,
+            ..SlugGeneratorConfig::default()
+        });
+
+        assert_eq!(generator.slugify("hello world again"), "hello-world");
+        assert_eq!(generator.slugify("hello worl again"), "hello-worl");
+    }
+
+    #[test]
+    fn should_report_reserved_words() {
+        assert!(generator().is_reserved("admin"));
+        assert!(!generator().is_reserved("administration"));
+    }
+
+    #[tokio::test]
+    async fn should_return_the_plain_slug_when_available() {
+        let taken = TakenSlugs::new(&[]);
+
+        let slug = generator().generate("Hello World", &taken).await.unwrap();
+
+        assert_eq!(slug, "hello-world");
+    }
+
+    #[tokio::test]
+    async fn should_suffix_until_a_free_slug_is_found() {
+        let taken = TakenSlugs::new(&["hello-world", "hello-world-2"]);
+
+        let slug = generator().generate("Hello World", &taken).await.unwrap();
+
+        assert_eq!(slug, "hello-world-3");
+        assert_eq!(
+            *taken.asked.lock().unwrap(),
+            ["hello-world", "hello-world-2", "hello-world-3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_suffix_reserved_words() {
+        let taken = TakenSlugs::new(&[]);
+
+        let slug = generator().generate("Admin", &taken).await.unwrap();
+
+        assert_eq!(slug, "admin-2");
+    }
+
+    #[tokio::test]
+    async fn should_keep_suffixed_slugs_within_the_max_length() {
+        let generator = TransliteratingSlugGenerator::new(SlugGeneratorConfig {
+            max_length: 11,
+            ..SlugGeneratorConfig::default()
+        });
+        let taken = TakenSlugs::new(&["hello-world"]);
+
+        let slug = generator.generate("hello world", &taken).await.unwrap();
+
+        assert_eq!(slug, "hello-wor-2");
+    }
+
+    #[tokio::test]
+    async fn should_throw_validation_error_when_nothing_is_left_to_slugify() {
+        let taken = TakenSlugs::new(&[]);
+
+        let err = generator().generate("!!! ???", &taken).await.unwrap_err();
+
+        assert_validation_error_with_key(err, "slug");
+    }
+}
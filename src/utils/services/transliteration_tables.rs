@@ -0,0 +1,132 @@
+/// Romanization of a lowercase character from one of the supported non-Latin scripts.
+///
+/// `Some("")` means the character carries no sound of its own (diacritics, tatweel, soft signs)
+/// and is dropped without breaking the word; `None` means the script is not covered.
+pub fn transliterate(c: char) -> Option<&'static str> {
+    persian_arabic(c)
+        .or_else(|| cyrillic(c))
+        .or_else(|| greek(c))
+}
+
+fn persian_arabic(c: char) -> Option<&'static str> {
+    let result = match c {
+        'ا' | 'آ' | 'أ' | 'ٱ' => "a",
+        'إ' => "e",
+        'ء' | 'ع' => "",
+        'ؤ' => "o",
+        'ئ' => "e",
+        'ب' => "b",
+        'پ' => "p",
+        'ت' => "t",
+        'ث' => "s",
+        'ج' => "j",
+        'چ' => "ch",
+        'ح' => "h",
+        'خ' => "kh",
+        'د' => "d",
+        'ذ' => "z",
+        'ر' => "r",
+        'ز' => "z",
+        'ژ' => "zh",
+        'س' => "s",
+        'ش' => "sh",
+        'ص' => "s",
+        'ض' => "z",
+        'ط' => "t",
+        'ظ' => "z",
+        'غ' => "gh",
+        'ف' => "f",
+        'ق' => "q",
+        'ک' | 'ك' => "k",
+        'گ' => "g",
+        'ل' => "l",
+        'م' => "m",
+        'ن' => "n",
+        'و' => "v",
+        'ه' | 'ة' => "h",
+        'ی' | 'ي' => "y",
+        'ى' => "a",
+        '۰' | '٠' => "0",
+        '۱' | '١' => "1",
+        '۲' | '٢' => "2",
+        '۳' | '٣' => "3",
+        '۴' | '٤' => "4",
+        '۵' | '٥' => "5",
+        '۶' | '٦' => "6",
+        '۷' | '٧' => "7",
+        '۸' | '٨' => "8",
+        '۹' | '٩' => "9",
+        // tatweel, harakat and the zero-width non-joiner used inside Persian words
+        'ـ' | '\u{064B}'..='\u{0652}' | '\u{0670}' | '\u{200C}' => "",
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn cyrillic(c: char) -> Option<&'static str> {
+    let result = match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'е' | 'э' => "e",
+        'ё' => "yo",
+        'є' => "ye",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' => "i",
+        'ї' => "yi",
+        'й' | 'ы' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' | 'ь' => "",
+        'ю' => "yu",
+        'я' => "ya",
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn greek(c: char) -> Option<&'static str> {
+    let result = match c {
+        'α' | 'ά' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' | 'έ' => "e",
+        'ζ' => "z",
+        'η' | 'ή' | 'ι' | 'ί' | 'ϊ' | 'ΐ' => "i",
+        'θ' => "th",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ό' | 'ω' | 'ώ' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' | 'ύ' | 'ϋ' | 'ΰ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        _ => return None,
+    };
+    Some(result)
+}
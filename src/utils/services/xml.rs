@@ -0,0 +1,35 @@
+/// Escapes text for use in XML character data and in double quoted attributes.
+pub fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // characters XML 1.0 does not allow at all
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends `<name>text</name>`.
+pub fn push_xml_element(out: &mut String, name: &str, text: &str) {
+    out.push_str(&format!("<{0}>{1}</{0}>", name, escape_xml(text)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_escape_markup_and_drop_control_characters() {
+        assert_eq!(
+            escape_xml("<a href=\"x\">Tom & Jerry's</a>\u{1}"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;"
+        );
+    }
+}
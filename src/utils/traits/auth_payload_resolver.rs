@@ -3,6 +3,6 @@ use crate::users::domain::User;
 use crate::utils::AuthPayload;
 
 #[async_trait::async_trait]
-pub trait AuthPayloadResolver {
+pub trait AuthPayloadResolver: Send + Sync {
     async fn resolve(&self, auth_payload: &(dyn AuthPayload)) -> UnknownResult<User>;
 }
@@ -0,0 +1,16 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub title: String,
+    pub anchor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedContent {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+}
+
+pub trait ContentRenderer: Send + Sync {
+    fn render(&self, content: &str) -> RenderedContent;
+}
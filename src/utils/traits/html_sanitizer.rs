@@ -0,0 +1,27 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ContentKind {
+    Post,
+    Comment,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SanitizationPolicy {
+    /// Inline formatting, lists, quotes, code and plain links only.
+    Strict,
+    /// Everything the markdown renderer produces, including images, tables, ids and classes.
+    Relaxed,
+}
+
+impl SanitizationPolicy {
+    /// Comments are always strict; posts are relaxed when their author is `trusted`.
+    pub fn for_content(kind: ContentKind, trusted: bool) -> Self {
+        match kind {
+            ContentKind::Post if trusted => SanitizationPolicy::Relaxed,
+            _ => SanitizationPolicy::Strict,
+        }
+    }
+}
+
+pub trait HtmlSanitizer: Send + Sync {
+    fn sanitize(&self, html: &str, policy: SanitizationPolicy) -> String;
+}
@@ -3,8 +3,14 @@ pub use auth_payload_resolver::AuthPayloadResolver;
 pub use auth_payload_revoker::AuthRevoker;
 pub use auth_with_password_validator::AuthWithPasswordValidator;
 pub use authorizer::Authorizer;
+pub use clock::Clock;
+pub use content_renderer::{ContentRenderer, RenderedContent, TocEntry};
 pub use crypto_service::CryptoService;
+pub use html_sanitizer::{ContentKind, HtmlSanitizer, SanitizationPolicy};
 pub use random_service::RandomService;
+pub use slug_generator::{SlugAvailability, SlugGenerator, SlugOwners};
+pub use slug_history_repository::{RetiredSlug, SlugHistoryRepository, SlugOwnerKind};
+pub use unit_of_work::{UnitOfWork, Work};
 pub use validatable::Validatable;
 
 mod auth_payload;
@@ -12,6 +18,12 @@ mod auth_payload_resolver;
 mod auth_payload_revoker;
 mod auth_with_password_validator;
 mod authorizer;
+mod clock;
+mod content_renderer;
 mod crypto_service;
+mod html_sanitizer;
 mod random_service;
+mod slug_generator;
+mod slug_history_repository;
+mod unit_of_work;
 mod validatable;
@@ -0,0 +1,27 @@
+use crate::errors::{ApplicationResult, UnknownResult};
+use crate::utils::SlugOwnerKind;
+
+#[async_trait::async_trait]
+pub trait SlugAvailability: Send + Sync {
+    async fn is_available(&self, slug: &str) -> UnknownResult<bool>;
+}
+
+/// A repository of entities addressed by slug, which `RepositorySlugAvailability` checks.
+#[async_trait::async_trait]
+pub trait SlugOwners: Send + Sync {
+    const KIND: SlugOwnerKind;
+    /// The id of the entity that has `slug` now.
+    async fn get_owner_of(&self, slug: &str) -> UnknownResult<Option<String>>;
+}
+
+#[async_trait::async_trait]
+pub trait SlugGenerator: Send + Sync {
+    fn slugify(&self, input: &str) -> String;
+    fn is_reserved(&self, slug: &str) -> bool;
+    /// Generates a slug for `input` that `availability` accepts, suffixing `-2`, `-3`, ... on collision.
+    async fn generate(
+        &self,
+        input: &str,
+        availability: &(dyn SlugAvailability),
+    ) -> ApplicationResult<String>;
+}
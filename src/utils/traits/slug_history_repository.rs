@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ApplicationException::DuplicationException;
+use crate::errors::{ApplicationResult, UnknownResult};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlugOwnerKind {
+    Category,
+    Post,
+    Tag,
+}
+
+/// A slug that an entity used to have. Lookups of it should redirect to the owner's current slug.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetiredSlug {
+    pub kind: SlugOwnerKind,
+    pub slug: String,
+    pub owner_id: String,
+    pub retired_at: DateTime<Utc>,
+}
+
+#[async_trait::async_trait]
+pub trait SlugHistoryRepository: Send + Sync {
+    async fn get(&self, kind: SlugOwnerKind, slug: &str) -> UnknownResult<Option<RetiredSlug>>;
+    async fn get_all(&self) -> UnknownResult<Vec<RetiredSlug>>;
+    /// Stores the retired slug, replacing an older entry of the same kind and slug.
+    async fn retire(&self, retired: &RetiredSlug) -> UnknownResult<()>;
+    async fn release(&self, kind: SlugOwnerKind, slug: &str) -> UnknownResult<()>;
+    /// Points every retired slug of `from_id` to `to_id`, used when an entity is merged away.
+    async fn retarget(&self, kind: SlugOwnerKind, from_id: &str, to_id: &str) -> UnknownResult<()>;
+
+    /// Makes `slug` available to `owner_id`.
+    ///
+    /// An owner may always take back its own retired slug. Taking one retired by another
+    /// entity breaks that entity's old links, so it has to be asked for with `reclaim`.
+    async fn claim(
+        &self,
+        kind: SlugOwnerKind,
+        slug: &str,
+        owner_id: Option<&str>,
+        reclaim: bool,
+    ) -> ApplicationResult<()> {
+        let retired = match self.get(kind, slug).await? {
+            Some(retired) => retired,
+            None => return Ok(()),
+        };
+        if !reclaim && owner_id != Some(retired.owner_id.as_str()) {
+            return Err(DuplicationException {
+                key: "slug".into(),
+                value: slug.into(),
+            });
+        }
+        self.release(kind, slug).await?;
+        Ok(())
+    }
+}
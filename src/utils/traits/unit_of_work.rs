@@ -0,0 +1,15 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::errors::ApplicationResult;
+
+pub type Work<'a> = Pin<Box<dyn Future<Output = ApplicationResult<()>> + Send + 'a>>;
+
+/// Commits the writes `work` makes through the repositories, outbox included, together, or
+/// none of them when it fails.
+///
+/// An implementation opens a transaction the repositories pick up while `work` runs.
+#[async_trait::async_trait]
+pub trait UnitOfWork: Send + Sync {
+    async fn run<'a>(&'a self, work: Work<'a>) -> ApplicationResult<()>;
+}